@@ -20,6 +20,8 @@ pub struct CredentialsCache {
     pub(crate) fetches: FxOnceMap<(Realm, Username), Option<Arc<Credentials>>>,
     /// A cache per URL, uses a trie for efficient prefix queries.
     urls: RwLock<UrlTrie>,
+    /// Bearer tokens per index URL, uses a trie for efficient prefix queries.
+    tokens: RwLock<UrlTrie<Arc<str>>>,
 }
 
 impl Default for CredentialsCache {
@@ -35,9 +37,30 @@ impl CredentialsCache {
             fetches: FxOnceMap::default(),
             realms: RwLock::new(FxHashMap::default()),
             urls: RwLock::new(UrlTrie::new()),
+            tokens: RwLock::new(UrlTrie::new()),
         }
     }
 
+    /// Return the bearer token that should be used for a URL, if any.
+    ///
+    /// Tokens are scoped to the index URL they were configured for, such that they're only sent
+    /// to URLs with the same realm and a matching path prefix.
+    pub(crate) fn get_token(&self, url: &Url) -> Option<Arc<str>> {
+        let tokens = self.tokens.read().unwrap();
+        let Some(token) = tokens.get(url).cloned() else {
+            trace!("No bearer token in cache for URL {url}");
+            return None;
+        };
+        trace!("Found bearer token for URL {url}");
+        Some(token)
+    }
+
+    /// Store a bearer token for an index URL.
+    pub(crate) fn insert_token(&self, url: &Url, token: Arc<str>) {
+        trace!("Caching bearer token for URL {url}");
+        self.tokens.write().unwrap().insert(url, token);
+    }
+
     /// Return the credentials that should be used for a realm and username, if any.
     pub(crate) fn get_realm(&self, realm: Realm, username: Username) -> Option<Arc<Credentials>> {
         let realms = self.realms.read().unwrap();
@@ -142,24 +165,33 @@ impl CredentialsCache {
 }
 
 #[derive(Debug)]
-struct UrlTrie {
-    states: Vec<TrieState>,
+struct UrlTrie<T = Arc<Credentials>> {
+    states: Vec<TrieState<T>>,
 }
 
-#[derive(Debug, Default)]
-struct TrieState {
+#[derive(Debug)]
+struct TrieState<T> {
     children: Vec<(String, usize)>,
-    value: Option<Arc<Credentials>>,
+    value: Option<T>,
+}
+
+impl<T> Default for TrieState<T> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            value: None,
+        }
+    }
 }
 
-impl UrlTrie {
-    fn new() -> UrlTrie {
+impl<T> UrlTrie<T> {
+    fn new() -> UrlTrie<T> {
         let mut trie = UrlTrie { states: vec![] };
         trie.alloc();
         trie
     }
 
-    fn get(&self, url: &Url) -> Option<&Arc<Credentials>> {
+    fn get(&self, url: &Url) -> Option<&T> {
         let mut state = 0;
         let realm = Realm::from(url).to_string();
         for component in [realm.as_str()]
@@ -174,7 +206,7 @@ impl UrlTrie {
         self.states[state].value.as_ref()
     }
 
-    fn insert(&mut self, url: &Url, value: Arc<Credentials>) {
+    fn insert(&mut self, url: &Url, value: T) {
         let mut state = 0;
         let realm = Realm::from(url).to_string();
         for component in [realm.as_str()]
@@ -202,7 +234,7 @@ impl UrlTrie {
     }
 }
 
-impl TrieState {
+impl<T> TrieState<T> {
     fn get(&self, component: &str) -> Option<usize> {
         let i = self.index(component).ok()?;
         Some(self.children[i].1)
@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tracing::trace;
+use url::Url;
+
+use uv_warnings::warn_user_once;
+
+use crate::credentials::Credentials;
+use crate::CredentialsCache;
+
+/// Credentials for package indexes, read from a TOML file provided via `--index-auth-file`.
+///
+/// The file maps index URLs to either a username and password, or a bearer token:
+///
+/// ```toml
+/// "https://example.com/simple" = { username = "user", password = "pass" }
+/// "https://pkgs.example.org/simple" = { token = "secret" }
+/// ```
+#[derive(Debug, Default)]
+pub(crate) struct AuthFile {
+    entries: Vec<(Url, IndexCredentials)>,
+}
+
+/// The credentials for a single index in an [`AuthFile`].
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IndexCredentials {
+    /// A bearer token, sent in an `Authorization: Bearer` header.
+    Token { token: String },
+    /// A username and (optional) password, sent via HTTP Basic authentication.
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+}
+
+impl std::fmt::Debug for IndexCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Token { .. } => f.debug_struct("Token").field("token", &"****").finish(),
+            Self::Basic { username, password } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &password.as_ref().map(|_| "****"))
+                .finish(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthFileError {
+    #[error("Failed to read index credentials from `{}`", _0.display())]
+    Io(PathBuf, #[source] std::io::Error),
+    #[error("Failed to parse index credentials from `{}`", _0.display())]
+    Toml(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Invalid index URL in `{}`: `{}`", _0.display(), _1)]
+    Url(PathBuf, String, #[source] url::ParseError),
+}
+
+impl AuthFile {
+    /// Read the index credentials from a TOML file.
+    ///
+    /// On Unix, warns if the file is readable by other users.
+    pub(crate) fn read(path: &Path) -> Result<Self, AuthFileError> {
+        let contents = fs_err::read_to_string(path)
+            .map_err(|err| AuthFileError::Io(path.to_path_buf(), err))?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            if let Ok(metadata) = fs_err::metadata(path) {
+                if metadata.permissions().mode() & 0o004 != 0 {
+                    warn_user_once!(
+                        "The index credentials file `{}` is readable by other users; consider restricting its permissions (e.g., `chmod 600`)",
+                        path.display()
+                    );
+                }
+            }
+        }
+
+        let table: BTreeMap<String, IndexCredentials> = toml::from_str(&contents)
+            .map_err(|err| AuthFileError::Toml(path.to_path_buf(), Box::new(err)))?;
+
+        let entries = table
+            .into_iter()
+            .map(|(url, credentials)| {
+                let url = Url::parse(&url)
+                    .map_err(|err| AuthFileError::Url(path.to_path_buf(), url.clone(), err))?;
+                Ok((url, credentials))
+            })
+            .collect::<Result<Vec<_>, AuthFileError>>()?;
+
+        Ok(Self { entries })
+    }
+
+    /// Add the credentials to a [`CredentialsCache`], scoped to each index URL.
+    ///
+    /// Credentials in the cache are used before falling back to the netrc file or the keyring, but
+    /// credentials embedded in a request URL always take precedence.
+    pub(crate) fn store_in(&self, cache: &CredentialsCache) {
+        for (url, credentials) in &self.entries {
+            trace!("Caching credentials from file for {url}");
+            match credentials {
+                IndexCredentials::Token { token } => {
+                    cache.insert_token(url, Arc::from(token.as_str()));
+                }
+                IndexCredentials::Basic { username, password } => {
+                    cache.insert(
+                        url,
+                        Arc::new(Credentials::new(Some(username.clone()), password.clone())),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_auth_file() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join("creds.toml");
+        fs_err::write(
+            &path,
+            r#"
+            "https://example.com/simple" = { username = "user", password = "hunter2" }
+            "https://pkgs.example.org/simple" = { token = "secret" }
+            "#,
+        )?;
+
+        let file = AuthFile::read(&path)?;
+        assert_eq!(file.entries.len(), 2);
+        assert!(!format!("{file:?}").contains("secret"));
+        assert!(!format!("{file:?}").contains("hunter2"));
+
+        fs_err::write(&path, r#""not a url" = { token = "secret" }"#)?;
+        assert!(matches!(AuthFile::read(&path), Err(AuthFileError::Url(..))));
+
+        fs_err::write(&path, r#""https://example.com" = { password = "pass" }"#)?;
+        assert!(matches!(
+            AuthFile::read(&path),
+            Err(AuthFileError::Toml(..))
+        ));
+
+        Ok(())
+    }
+}
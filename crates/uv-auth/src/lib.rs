@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::sync::{Arc, LazyLock};
 
 use tracing::trace;
@@ -5,12 +6,15 @@ use url::Url;
 
 use cache::CredentialsCache;
 pub use credentials::Credentials;
+use file::AuthFile;
+pub use file::AuthFileError;
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
 use realm::Realm;
 
 mod cache;
 mod credentials;
+mod file;
 mod keyring;
 mod middleware;
 mod realm;
@@ -35,3 +39,22 @@ pub fn store_credentials_from_url(url: &Url) -> bool {
         false
     }
 }
+
+/// Populate the global authentication store with a bearer token for an index URL.
+///
+/// The token is attached to requests for URLs under the index URL, i.e., in the same realm (scheme,
+/// host, and port) and with the index URL's path as a prefix.
+pub fn store_bearer_token(url: &Url, token: &str) {
+    trace!("Caching bearer token for {url}");
+    CREDENTIALS_CACHE.insert_token(url, Arc::from(token));
+}
+
+/// Populate the global authentication store with the index credentials in a TOML file.
+///
+/// Credentials from the file take precedence over the netrc file and the keyring, but not over
+/// credentials embedded in a URL.
+pub fn store_credentials_from_file(path: &Path) -> Result<(), AuthFileError> {
+    trace!("Reading index credentials from {}", path.display());
+    AuthFile::read(path)?.store_in(&CREDENTIALS_CACHE);
+    Ok(())
+}
@@ -10,6 +10,7 @@ use crate::{
 };
 use anyhow::{anyhow, format_err};
 use netrc::Netrc;
+use reqwest::header::{HeaderValue, InvalidHeaderValue, AUTHORIZATION};
 use reqwest::{Request, Response};
 use reqwest_middleware::{Error, Middleware, Next};
 use tracing::{debug, trace};
@@ -86,6 +87,11 @@ impl Default for AuthMiddleware {
 impl Middleware for AuthMiddleware {
     /// Handle authentication for a request.
     ///
+    /// ## If a bearer token is configured for the index
+    ///
+    /// If the request doesn't carry its own credentials, we attach the token as an
+    /// `Authorization: Bearer` header and perform the request without any further look-ups.
+    ///
     /// ## If the request has a username and password
     ///
     /// We already have a fully authenticated request and we don't need to perform a look-up.
@@ -129,6 +135,18 @@ impl Middleware for AuthMiddleware {
         // Check for credentials attached to the request already
         let credentials = Credentials::from_request(&request);
 
+        // If the request is unauthenticated and a bearer token is configured for its index, use
+        // the token.
+        if credentials.is_none() && !request.headers().contains_key(AUTHORIZATION) {
+            if let Some(token) = self.cache().get_token(request.url()) {
+                trace!("Attaching bearer token to request for {}", request.url());
+                let header = bearer_header_value(&token)
+                    .map_err(|err| Error::Middleware(format_err!("Invalid bearer token: {err}")))?;
+                request.headers_mut().insert(AUTHORIZATION, header);
+                return next.run(request, extensions).await;
+            }
+        }
+
         // In the middleware, existing credentials are already moved from the URL
         // to the headers so for display purposes we restore some information
         let url = if tracing::enabled!(tracing::Level::DEBUG) {
@@ -297,6 +315,13 @@ impl Middleware for AuthMiddleware {
     }
 }
 
+/// Create an HTTP Bearer Authentication header for a token.
+fn bearer_header_value(token: &str) -> Result<HeaderValue, InvalidHeaderValue> {
+    let mut header = HeaderValue::from_str(&format!("Bearer {token}"))?;
+    header.set_sensitive(true);
+    Ok(header)
+}
+
 impl AuthMiddleware {
     /// Run a request to completion.
     ///
@@ -416,9 +441,11 @@ mod tests {
     use test_log::test;
 
     use url::Url;
-    use wiremock::matchers::{basic_auth, method, path_regex};
+    use wiremock::matchers::{basic_auth, bearer_token, method, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    use crate::file::AuthFile;
+
     use super::*;
 
     type Error = Box<dyn std::error::Error>;
@@ -1487,4 +1514,226 @@ mod tests {
 
         Ok(())
     }
+
+    async fn start_bearer_test_server(token: &'static str) -> MockServer {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(bearer_token(token))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    #[test(tokio::test)]
+    async fn test_bearer_token() -> Result<(), Error> {
+        let token = "secret-token";
+
+        let server = start_bearer_test_server(token).await;
+        let base_url = Url::parse(&server.uri())?;
+        let cache = CredentialsCache::new();
+        cache.insert_token(&base_url, Arc::from(token));
+
+        let client = test_client_builder()
+            .with(AuthMiddleware::new().with_cache(cache))
+            .build();
+
+        assert_eq!(client.get(server.uri()).send().await?.status(), 200);
+
+        assert_eq!(
+            client
+                .get(format!("{}/simple/foo/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Requests can be to different paths in the same realm"
+        );
+
+        Ok(())
+    }
+
+    #[test(tokio::test)]
+    async fn test_bearer_token_wrong_token() -> Result<(), Error> {
+        let server = start_bearer_test_server("secret-token").await;
+        let base_url = Url::parse(&server.uri())?;
+        let cache = CredentialsCache::new();
+        cache.insert_token(&base_url, Arc::from("invalid"));
+
+        let client = test_client_builder()
+            .with(AuthMiddleware::new().with_cache(cache))
+            .build();
+
+        assert_eq!(client.get(server.uri()).send().await?.status(), 401);
+
+        Ok(())
+    }
+
+    /// Bearer tokens should only be sent to the index for which they were configured.
+    #[test(tokio::test)]
+    async fn test_bearer_token_other_realm() -> Result<(), Error> {
+        let token = "secret-token";
+
+        let server_1 = start_bearer_test_server(token).await;
+        let server_2 = start_bearer_test_server(token).await;
+        let base_url_1 = Url::parse(&server_1.uri())?;
+        let cache = CredentialsCache::new();
+        cache.insert_token(&base_url_1, Arc::from(token));
+
+        let client = test_client_builder()
+            .with(AuthMiddleware::new().with_cache(cache))
+            .build();
+
+        assert_eq!(client.get(server_1.uri()).send().await?.status(), 200);
+        assert_eq!(
+            client.get(server_2.uri()).send().await?.status(),
+            401,
+            "The token should not be sent to a different port"
+        );
+
+        Ok(())
+    }
+
+    /// Bearer tokens should not be sent to other paths on the same host.
+    #[test(tokio::test)]
+    async fn test_bearer_token_other_index() -> Result<(), Error> {
+        let token = "secret-token";
+
+        let server = start_bearer_test_server(token).await;
+        let index_url = Url::parse(&format!("{}/first/simple", server.uri()))?;
+        let cache = CredentialsCache::new();
+        cache.insert_token(&index_url, Arc::from(token));
+
+        let client = test_client_builder()
+            .with(AuthMiddleware::new().with_cache(cache))
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/first/simple/foo/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200
+        );
+        assert_eq!(
+            client
+                .get(format!("{}/second/simple/foo/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            401,
+            "The token should not be sent to a different index on the same host"
+        );
+
+        Ok(())
+    }
+
+    /// Credentials attached to the request take precedence over a configured bearer token.
+    #[test(tokio::test)]
+    async fn test_bearer_token_credentials_in_url() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+        let cache = CredentialsCache::new();
+        cache.insert_token(&base_url, Arc::from("secret-token"));
+
+        let client = test_client_builder()
+            .with(AuthMiddleware::new().with_cache(cache))
+            .build();
+
+        let mut url = base_url.clone();
+        url.set_username(username).unwrap();
+        url.set_password(Some(password)).unwrap();
+        assert_eq!(client.get(url).send().await?.status(), 200);
+
+        Ok(())
+    }
+
+    /// Credentials read from an index credentials file are applied to the matching index, and take
+    /// precedence over the netrc file.
+    #[test(tokio::test)]
+    async fn test_credentials_from_file() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let mut auth_file = NamedTempFile::new()?;
+        writeln!(
+            auth_file,
+            r#""{base_url}" = {{ username = "{username}", password = "{password}" }}"#
+        )?;
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            r#"machine {} login {username} password invalid"#,
+            base_url.host_str().unwrap()
+        )?;
+
+        let cache = CredentialsCache::new();
+        AuthFile::read(auth_file.path())?.store_in(&cache);
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(cache)
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok()),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{}/simple/foo/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Credentials should be pulled from the file, rather than the netrc file"
+        );
+
+        let mut url = base_url.clone();
+        url.set_username(username).unwrap();
+        url.set_password(Some("invalid")).unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            401,
+            "Credentials in the URL should take precedence and fail"
+        );
+
+        Ok(())
+    }
+
+    /// Bearer tokens read from an index credentials file are applied to the matching index.
+    #[test(tokio::test)]
+    async fn test_bearer_token_from_file() -> Result<(), Error> {
+        let token = "secret-token";
+
+        let server = start_bearer_test_server(token).await;
+
+        let mut auth_file = NamedTempFile::new()?;
+        writeln!(auth_file, r#""{}" = {{ token = "{token}" }}"#, server.uri())?;
+
+        let cache = CredentialsCache::new();
+        AuthFile::read(auth_file.path())?.store_in(&cache);
+
+        let client = test_client_builder()
+            .with(AuthMiddleware::new().with_cache(cache))
+            .build();
+
+        assert_eq!(client.get(server.uri()).send().await?.status(), 200);
+
+        Ok(())
+    }
 }
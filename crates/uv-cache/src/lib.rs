@@ -759,6 +759,13 @@ pub enum CacheBucket {
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
     Environments,
+    /// The output of previous `uv pip compile` resolutions, for `--cache-resolution`.
+    ///
+    /// Cache structure: `resolutions-v0/<digest(inputs)>.json`
+    ///
+    /// Each entry contains the rendered resolution, along with the number of resolved packages and
+    /// any diagnostics emitted during resolution.
+    Resolutions,
 }
 
 impl CacheBucket {
@@ -777,6 +784,7 @@ impl CacheBucket {
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v1",
+            Self::Resolutions => "resolutions-v0",
         }
     }
 
@@ -848,6 +856,16 @@ impl CacheBucket {
                     }
                 }
 
+                // For local archives, we expect a directory for every content digest, followed by
+                // a directory per version. To determine whether the archive is relevant, we need
+                // to search for a wheel matching the package name.
+                let root = cache.bucket(self).join(WheelCacheKind::Archive);
+                for digest in directories(root) {
+                    if directories(&digest).any(|version| is_match(&version, name)) {
+                        summary += rm_rf(digest)?;
+                    }
+                }
+
                 // For Git dependencies, we expect a directory for every repository, followed by a
                 // directory for every SHA. To determine whether the SHA is relevant, we need to
                 // search for a wheel matching the package name.
@@ -893,6 +911,12 @@ impl CacheBucket {
             Self::Environments => {
                 // Nothing to do.
             }
+            Self::Resolutions => {
+                // We can't know if a resolution includes a package, so we just remove the entire
+                // cache bucket.
+                let root = cache.bucket(self);
+                summary += rm_rf(root)?;
+            }
         }
         Ok(summary)
     }
@@ -909,6 +933,7 @@ impl CacheBucket {
             Self::Archive,
             Self::Builds,
             Self::Environments,
+            Self::Resolutions,
         ]
         .iter()
         .copied()
@@ -14,6 +14,11 @@ pub enum WheelCache<'a> {
     Url(&'a Url),
     /// A path dependency, which we key by URL.
     Path(&'a Url),
+    /// A local source distribution archive, which we key by the SHA-256 digest of its contents.
+    ///
+    /// Unlike [`WheelCache::Path`], the key is independent of the archive's location, so the same
+    /// archive maps to the same cache entry across working directories and machines.
+    Archive(&'a str),
     /// An editable dependency, which we key by URL.
     Editable(&'a Url),
     /// A Git dependency, which we key by URL and SHA.
@@ -37,6 +42,7 @@ impl<'a> WheelCache<'a> {
             WheelCache::Path(url) => WheelCacheKind::Path
                 .root()
                 .join(cache_digest(&CanonicalUrl::new(url))),
+            WheelCache::Archive(digest) => WheelCacheKind::Archive.root().join(digest),
             WheelCache::Editable(url) => WheelCacheKind::Editable
                 .root()
                 .join(cache_digest(&CanonicalUrl::new(url))),
@@ -63,6 +69,8 @@ pub(crate) enum WheelCacheKind {
     Url,
     /// A cache of data from a local path.
     Path,
+    /// A cache of data from a local archive, keyed by content.
+    Archive,
     /// A cache of data from an editable URL.
     Editable,
     /// A cache of data from a Git repository.
@@ -76,6 +84,7 @@ impl WheelCacheKind {
             Self::Index => "index",
             Self::Url => "url",
             Self::Path => "path",
+            Self::Archive => "archive",
             Self::Editable => "editable",
             Self::Git => "git",
         }
@@ -1,4 +1,5 @@
 use std::ffi::OsString;
+use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -10,15 +11,19 @@ use clap::{Args, Parser, Subcommand};
 use url::Url;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    ConfigSettingEntry, ExportFormat, IndexAuth, IndexStrategy, KeyringProviderType, LineEnding,
+    OutputFormat, PackageNameSpecifier, TargetTriple, TrustedHost, TrustedPublishing,
+    UpgradeStrategy, VersionControlSystem,
 };
 use uv_distribution_types::{FlatIndexLocation, IndexUrl};
-use uv_normalize::{ExtraName, PackageName};
-use uv_pep508::Requirement;
-use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep508::{MarkerOverride, Requirement};
+use uv_pypi_types::{HashAlgorithm, VerbatimParsedUrl};
+use uv_python::{ImplementationName, PythonDownloads, PythonPreference, PythonVersion};
+use uv_resolver::{
+    AnnotationStyle, DeniedVersion, ExcludeNewer, PackageResolutionMode, PrereleaseMode,
+    ResolutionMode,
+};
 
 pub mod compat;
 pub mod options;
@@ -32,6 +37,15 @@ pub enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// Display errors as human-readable text.
+    #[default]
+    Human,
+    /// Display errors as a machine-readable JSON object.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -53,6 +67,15 @@ fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     })
 }
 
+fn group_name_with_clap_error(arg: &str) -> Result<GroupName> {
+    GroupName::from_str(arg).map_err(|_err| {
+        anyhow!(
+            "Group names must start and end with a letter or digit and may only \
+            contain -, _, ., and alphanumeric characters"
+        )
+    })
+}
+
 // Configures Clap v3-style help menu colors
 const STYLES: Styles = Styles::styled()
     .header(AnsiColor::Green.on_default().effects(Effects::BOLD))
@@ -106,6 +129,9 @@ pub struct TopLevelArgs {
     ///
     /// Normally, configuration files are discovered in the current directory,
     /// parent directories, or user configuration directories.
+    ///
+    /// Any `--config-file` is also ignored. Environment variables and command-line
+    /// arguments are still respected.
     #[arg(global = true, long, env = "UV_NO_CONFIG", value_parser = clap::builder::BoolishValueParser::new(), help_heading = "Global options")]
     pub no_config: bool,
 
@@ -151,9 +177,13 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true)]
     pub python_fetch: Option<PythonDownloads>,
 
-    /// Do not print any output.
-    #[arg(global = true, long, short, conflicts_with = "verbose")]
-    pub quiet: bool,
+    /// Use quiet output.
+    ///
+    /// Suppresses progress bars and informational output, while still showing warnings and errors.
+    /// Repeating this option, e.g., `-qq`, will also suppress warnings, such that only errors are
+    /// shown.
+    #[arg(global = true, action = clap::ArgAction::Count, long, short, conflicts_with = "verbose")]
+    pub quiet: u8,
 
     /// Use verbose output.
     ///
@@ -179,6 +209,21 @@ pub struct GlobalArgs {
     )]
     pub color: ColorChoice,
 
+    /// The format in which to display errors.
+    ///
+    /// With `json`, a failing command prints a single JSON object to stderr, describing the kind of
+    /// error, its message, and any relevant context (e.g., the conflicting packages), in lieu of
+    /// human-readable text.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        default_value = "human",
+        value_name = "ERROR_FORMAT",
+        hide_short_help = true
+    )]
+    pub error_format: ErrorFormat,
+
     /// Whether to load TLS certificates from the platform's native certificate store.
     ///
     /// By default, uv loads certificates from the bundled `webpki-roots` crate. The
@@ -203,6 +248,23 @@ pub struct GlobalArgs {
     #[arg(global = true, long, overrides_with("offline"), hide = true)]
     pub no_offline: bool,
 
+    /// The maximum number of files that may be open at once when downloading and installing
+    /// packages.
+    ///
+    /// The number of concurrent downloads and installs is reduced as needed to stay within this
+    /// limit, to avoid "too many open files" errors on systems with a low limit on open file
+    /// descriptors (e.g., `ulimit -n`).
+    ///
+    /// Defaults to half of the soft limit on open file descriptors for the current process.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_MAX_OPEN_FILES",
+        value_name = "N",
+        hide_short_help = true
+    )]
+    pub max_open_files: Option<NonZeroUsize>,
+
     /// Whether to enable experimental, preview features.
     ///
     /// Preview features may change without warning.
@@ -586,6 +648,12 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Tree(PipTreeArgs),
+    /// Explain why a package is installed in an environment.
+    #[command(
+        after_help = "Use `uv help pip why` for more details.",
+        after_long_help = ""
+    )]
+    Why(PipWhyArgs),
     /// Verify installed packages have compatible dependencies.
     #[command(
         after_help = "Use `uv help pip check` for more details.",
@@ -843,7 +911,8 @@ pub struct PipCompileArgs {
     /// Include all packages listed in the given `requirements.in` files.
     ///
     /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
-    /// requirements for the relevant project.
+    /// requirements for the relevant project. If a `tox.ini` file is provided, uv will extract the
+    /// `deps` of a test environment (see `--tox-env`).
     ///
     /// If `-` is provided, then requirements will be read from stdin.
     ///
@@ -852,6 +921,21 @@ pub struct PipCompileArgs {
     #[arg(required(true), value_parser = parse_file_path)]
     pub src_file: Vec<PathBuf>,
 
+    /// The test environment from which to read `deps`, when compiling a `tox.ini` file.
+    ///
+    /// The `deps` are read from the `[testenv:<TOX_ENV>]` section, falling back to the `[testenv]`
+    /// section if the environment doesn't declare any. If not provided, the `deps` of the
+    /// `[testenv]` section are used.
+    #[arg(long)]
+    pub tox_env: Option<String>,
+
+    /// The maximum depth of nested `-r` and `-c` includes to follow when reading requirements
+    /// files.
+    ///
+    /// Defaults to 50.
+    #[arg(long, env = "UV_MAX_INCLUDE_DEPTH")]
+    pub max_include_depth: Option<usize>,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -871,9 +955,32 @@ pub struct PipCompileArgs {
     /// While constraints are _additive_, in that they're combined with the requirements of the
     /// constituent packages, overrides are _absolute_, in that they completely replace the
     /// requirements of the constituent packages.
+    ///
+    /// Overrides for packages that aren't otherwise required have no effect, unless
+    /// `--override-add` is provided.
     #[arg(long, env = "UV_OVERRIDE", value_delimiter = ' ', value_parser = parse_maybe_file_path)]
     pub r#override: Vec<Maybe<PathBuf>>,
 
+    /// Add any overridden packages to the resolution, even if they aren't otherwise required.
+    ///
+    /// By default, an override only applies to packages that are already part of the resolution.
+    /// With `--override-add`, each override is also treated as a requirement, e.g., to force a
+    /// patched version of a package that none of the requirements depend on (yet).
+    #[arg(long)]
+    pub override_add: bool,
+
+    /// Pin packages to the exact versions in the given requirements files.
+    ///
+    /// Each entry must pin a package to an exact version (e.g., `flask==3.0.0`). Pinned packages
+    /// are held at the pinned version, while all other packages are resolved as usual. Unlike
+    /// constraints, which can be arbitrary version ranges, pin file entries must be exact pins;
+    /// and unlike the preferences read from an existing output file, they're retained on
+    /// `--upgrade`.
+    ///
+    /// Including a package in a pin file will _not_ trigger the installation of that package.
+    #[arg(long, value_parser = parse_file_path)]
+    pub pin_file: Vec<PathBuf>,
+
     /// Constrain build dependencies using the given requirements files when building source
     /// distributions.
     ///
@@ -919,6 +1026,52 @@ pub struct PipCompileArgs {
     #[arg(long, short)]
     pub output_file: Option<PathBuf>,
 
+    /// Overwrite the output file, even if it wasn't generated by uv, or was edited since.
+    ///
+    /// By default, uv refuses to overwrite an existing `requirements.txt` output file that lacks
+    /// the header uv writes to generated files, or that was edited since uv generated it, to avoid
+    /// clobbering manual changes.
+    #[arg(long)]
+    pub force: bool,
+
+    /// Merge the resolution into the existing output file, rather than replacing it.
+    ///
+    /// Entries that are unchanged are left as-is (along with any surrounding comments), entries
+    /// for packages that now resolve to a different version are updated in place, and entries for
+    /// newly-resolved packages are added after the existing entries. Entries for packages that are
+    /// no longer part of the resolution are retained.
+    ///
+    /// Only supported for the `requirements.txt` output format.
+    #[arg(long, requires = "output_file")]
+    pub append: bool,
+
+    /// Write local paths (e.g., `-e ./pkg`) in the output as absolute paths.
+    ///
+    /// By default, relative paths are written relative to the directory containing the
+    /// `--output-file`, such that they remain valid when the output file is written to a
+    /// different directory than the input files.
+    #[arg(long, overrides_with("relative_paths"))]
+    pub absolute_paths: bool,
+
+    #[arg(long, overrides_with("absolute_paths"), hide = true)]
+    pub relative_paths: bool,
+
+    /// The format in which the compiled requirements should be written.
+    ///
+    /// If omitted, the format is inferred from the extension of the `--output-file`: files ending
+    /// in `.json` are written as JSON, and files named `pylock.toml` are written as PEP 751
+    /// lockfiles. Otherwise, the output is written in `requirements.txt` format.
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormat>,
+
+    /// The line ending to use in the output file.
+    ///
+    /// Regardless of the line ending, the output file always ends with exactly one trailing
+    /// newline. Defaults to `lf`, to avoid spurious diffs when the output file is generated on
+    /// different platforms.
+    #[arg(long, value_enum)]
+    pub line_ending: Option<LineEnding>,
+
     /// Include extras in the output file.
     ///
     /// By default, uv strips extras, as any packages pulled in by the extras are already included
@@ -1004,6 +1157,13 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("generate_hashes"), hide = true)]
     pub no_generate_hashes: bool,
 
+    /// The hash algorithm to use when generating hashes with `--generate-hashes`.
+    ///
+    /// Hashes provided by the index are used when available; otherwise, the distribution is
+    /// downloaded and hashed locally.
+    #[arg(long, value_enum, default_value = "sha256")]
+    pub hash_algorithm: HashAlgorithm,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1071,6 +1231,33 @@ pub struct PipCompileArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// The Python implementation for which requirements should be resolved.
+    ///
+    /// For example, `cpython`, `pypy`, or `graalpy`.
+    ///
+    /// Overrides the `implementation_name` and `platform_python_implementation` markers used
+    /// during resolution, which otherwise default to those of the Python interpreter used for
+    /// resolution. Wheel compatibility is still determined by the interpreter.
+    #[arg(long, help_heading = "Python options")]
+    pub python_implementation: Option<ImplementationName>,
+
+    /// Override the value of an environment marker used during resolution, as `KEY=VALUE` (e.g.,
+    /// `platform_machine=aarch64`).
+    ///
+    /// Overrides are applied on top of the markers derived from the Python interpreter (along
+    /// with `--python-version`, `--python-platform`, and `--python-implementation`). Any of the
+    /// environment markers defined by PEP 508 may be overridden, such as `os_name`,
+    /// `platform_machine`, or `sys_platform`. Wheel compatibility is unaffected.
+    ///
+    /// May be provided multiple times.
+    #[arg(
+        long,
+        value_name = "KEY=VALUE",
+        conflicts_with = "universal",
+        help_heading = "Python options"
+    )]
+    pub marker: Vec<MarkerOverride>,
+
     /// Perform a universal resolution, attempting to generate a single `requirements.txt` output
     /// file that is compatible with all operating systems, architectures, and Python
     /// implementations.
@@ -1084,6 +1271,7 @@ pub struct PipCompileArgs {
         long,
         overrides_with("no_universal"),
         conflicts_with("python_platform"),
+        conflicts_with("python_implementation"),
         conflicts_with("strip_markers")
     )]
     pub universal: bool,
@@ -1137,6 +1325,138 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("emit_index_annotation"), hide = true)]
     pub no_emit_index_annotation: bool,
 
+    /// Allow yanked versions of any package to be selected during resolution.
+    ///
+    /// By default, a yanked version is only selected if it's pinned exactly (e.g., `flask==1.0.0`).
+    /// With `--allow-yanked`, yanked versions are treated like any other version, such that a
+    /// range (e.g., `flask>=1.0.0,<=1.0.0`) may resolve to a yanked version. A warning is emitted
+    /// for each yanked version in the resolution.
+    #[arg(long)]
+    pub allow_yanked: bool,
+
+    /// Allow yanked versions of a specific package to be selected during resolution.
+    #[arg(long)]
+    pub allow_yanked_package: Vec<PackageName>,
+
+    /// Only allow the given packages to be included in the resolution.
+    ///
+    /// When provided, any package that isn't explicitly allowed, whether it's requested directly
+    /// or required transitively, is rejected by the resolver.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE")]
+    pub allow_package: Vec<PackageName>,
+
+    /// Forbid any version of the given package from being selected during resolution.
+    ///
+    /// If the package is required, the resolution fails.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE")]
+    pub deny_package: Vec<PackageName>,
+
+    /// Forbid a specific version of a package from being selected during resolution (e.g.,
+    /// `--deny flask==3.0.0`).
+    ///
+    /// The resolver will fall back to another version of the package, if one is available.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE==VERSION")]
+    pub deny: Vec<DeniedVersion>,
+
+    /// Use a different resolution strategy for the given package (e.g.,
+    /// `--resolution-package torch=lowest`), overriding `--resolution`.
+    ///
+    /// Accepts `highest`, `lowest`, or `lowest-direct`. All other packages continue to use the
+    /// global resolution strategy.
+    ///
+    /// May be provided multiple times, but at most once per package.
+    #[arg(long, value_name = "PACKAGE=MODE")]
+    pub resolution_package: Vec<PackageResolutionMode>,
+
+    /// Error if a requirement requests an extra that the resolved version of the package doesn't
+    /// declare (e.g., `requests[foo]`), rather than warning and ignoring the extra.
+    #[arg(long)]
+    pub strict_extras: bool,
+
+    /// The strategy to use when upgrading the packages provided via `--upgrade-package`.
+    ///
+    /// By default (`eager`), upgraded packages are resolved to the highest compatible version.
+    /// With `minimal`, each upgraded package instead retains its pin in the existing output file,
+    /// if still compatible, and is otherwise resolved to the lowest compatible version, to keep
+    /// the change as small as possible (e.g., when applying a security fix with
+    /// `--upgrade-package "requests>=2.32.0"`). Packages without an existing pin are unaffected.
+    #[arg(long, value_enum)]
+    pub upgrade_strategy: Option<UpgradeStrategy>,
+
+    /// Abort the resolution if it doesn't complete within the given number of seconds.
+    ///
+    /// By default, the resolver runs until it finds a resolution or proves that none exists. If
+    /// the limit is exceeded, the resolution fails and reports the packages for which the most
+    /// versions were tried.
+    #[arg(long, value_name = "SECONDS")]
+    pub resolution_timeout: Option<u64>,
+
+    /// Abort the resolution after the given number of solver steps.
+    ///
+    /// Each step corresponds to a package decision or conflict in the solver. Unlike
+    /// `--resolution-timeout`, the limit is deterministic across machines.
+    #[arg(long)]
+    pub max_resolution_steps: Option<u64>,
+
+    /// Reuse the output of a previous resolution with identical inputs, rather than re-resolving.
+    ///
+    /// The resolution is cached by a digest of its inputs, including the requirements,
+    /// constraints, and overrides; the existing output file, if any; the target Python version and
+    /// platform; and the resolver and output options. Any change to the inputs, along with
+    /// `--upgrade` or `--refresh`, invalidates the cached resolution.
+    ///
+    /// As the contents of the index aren't part of the inputs, a cached resolution won't reflect
+    /// newly published versions until it's invalidated. Resolutions that include local or direct
+    /// URL requirements, or use `--find-links`, are never cached.
+    #[arg(long)]
+    pub cache_resolution: bool,
+
+    /// Attach a bearer token to requests for an index, as `URL=bearer:TOKEN`.
+    ///
+    /// The token is sent in an `Authorization: Bearer` header on requests to URLs under the index
+    /// URL, and is never sent to other indexes or hosts. Useful for indexes that expect an access
+    /// token (e.g., Google Artifact Registry) rather than a username and password.
+    ///
+    /// Tokens can also be provided via `UV_INDEX_<NAME>_TOKEN`, where `<NAME>` is the host of the
+    /// index URL, uppercased, with non-alphanumeric characters replaced by underscores.
+    ///
+    /// Can be provided multiple times.
+    #[arg(
+        long,
+        env = "UV_INDEX_AUTH",
+        value_delimiter = ' ',
+        value_name = "URL=bearer:TOKEN",
+        hide_env_values = true
+    )]
+    pub index_auth: Vec<IndexAuth>,
+
+    /// Read credentials for indexes from a TOML file.
+    ///
+    /// The file maps index URLs to either a username and password or a bearer token, e.g.,
+    /// `"https://example.com/simple" = { username = "user", password = "pass" }` or
+    /// `"https://example.com/simple" = { token = "..." }`. Credentials are applied to requests to
+    /// the index's scheme, host, and port, and take precedence over the `.netrc` file, but not over
+    /// credentials embedded in a URL.
+    #[arg(long, env = "UV_INDEX_AUTH_FILE", value_name = "PATH")]
+    pub index_auth_file: Option<PathBuf>,
+
+    /// A mirror of the main index (`--index-url`), to fail over to if the index is unavailable.
+    ///
+    /// Mirrors are treated as interchangeable copies of the main index, rather than as additional
+    /// sources of packages (unlike `--extra-index-url`). If the main index can't be reached or
+    /// returns a server error, each mirror is tried in order; the first to respond successfully is
+    /// used for the rest of the run.
+    ///
+    /// Can be provided multiple times.
+    #[arg(long, value_parser = parse_index_url, value_name = "URL")]
+    pub index_mirror: Vec<Maybe<IndexUrl>>,
+
     #[command(flatten)]
     pub compat_args: compat::PipCompileCompatArgs,
 }
@@ -1147,12 +1467,28 @@ pub struct PipSyncArgs {
     /// Include all packages listed in the given `requirements.txt` files.
     ///
     /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will
-    /// extract the requirements for the relevant project.
+    /// extract the requirements for the relevant project. If a `tox.ini` file is provided, uv will
+    /// extract the `deps` of a test environment (see `--tox-env`).
     ///
     /// If `-` is provided, then requirements will be read from stdin.
     #[arg(required(true), value_parser = parse_file_path)]
     pub src_file: Vec<PathBuf>,
 
+    /// The test environment from which to read `deps`, when syncing a `tox.ini` file.
+    ///
+    /// The `deps` are read from the `[testenv:<TOX_ENV>]` section, falling back to the `[testenv]`
+    /// section if the environment doesn't declare any. If not provided, the `deps` of the
+    /// `[testenv]` section are used.
+    #[arg(long)]
+    pub tox_env: Option<String>,
+
+    /// The maximum depth of nested `-r` and `-c` includes to follow when reading requirements
+    /// files.
+    ///
+    /// Defaults to 50.
+    #[arg(long, env = "UV_MAX_INCLUDE_DEPTH")]
+    pub max_include_depth: Option<usize>,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -1184,6 +1520,12 @@ pub struct PipSyncArgs {
     /// with a corresponding hash or set of hashes. Additionally, if enabled, _all_ requirements
     /// must either be pinned to exact versions (e.g., `==1.0.0`), or be specified via direct URL.
     ///
+    /// As in pip, hash-checking mode is enabled automatically if any requirement includes a hash,
+    /// unless `--no-require-hashes` is provided.
+    ///
+    /// For packages that are built from source, the hash must match the source distribution
+    /// archive (rather than the built wheel), and is verified before the build.
+    ///
     /// Hash-checking mode introduces a number of additional constraints:
     ///
     /// - Git dependencies are not supported.
@@ -1363,23 +1705,100 @@ pub struct PipSyncArgs {
 
     /// Validate the Python environment after completing the installation, to detect and with
     /// missing dependencies or other issues.
+    ///
+    /// Before resolving, also verifies that the dependencies declared in any `pyproject.toml`
+    /// files don't contradict the `tool.uv.constraint-dependencies`. After resolving, any
+    /// constraints for packages that aren't part of the resolution are treated as errors, rather
+    /// than warnings. Requirements files that declare different `--index-url` values are also
+    /// treated as an error, rather than a warning.
     #[arg(long, overrides_with("no_strict"))]
     pub strict: bool,
 
     #[arg(long, overrides_with("strict"), hide = true)]
     pub no_strict: bool,
 
+    /// The number of workers to use when compiling Python files to bytecode.
+    ///
+    /// Bytecode compilation is CPU-bound, and so is limited separately from installation, which is
+    /// largely IO-bound. Only applies when `--compile-bytecode` is enabled.
+    ///
+    /// Defaults to the number of available CPU cores.
+    #[arg(
+        long,
+        env = "UV_COMPILE_BYTECODE_CONCURRENCY",
+        value_name = "N",
+        help_heading = "Installer options"
+    )]
+    pub compile_bytecode_concurrency: Option<NonZeroUsize>,
+
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Check whether the environment is in sync with the requirements, without modifying it.
+    ///
+    /// Implies `--dry-run`. If any package would be installed, uninstalled, or reinstalled, the
+    /// planned changes are printed and uv exits with a non-zero status.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Verify that every installed file matches the hash recorded in its wheel's `RECORD`.
+    ///
+    /// After each wheel is installed, the files that carry a hash in the `RECORD` are re-read from
+    /// the environment and hashed, failing the installation if any of them diverge (e.g., due to
+    /// corruption in the cache or during linking). Disabled by default, as it requires reading
+    /// every installed file.
+    #[arg(long)]
+    pub verify_install: bool,
+
+    /// Attach a bearer token to requests for an index, as `URL=bearer:TOKEN`.
+    ///
+    /// The token is sent in an `Authorization: Bearer` header on requests to URLs under the index
+    /// URL, and is never sent to other indexes or hosts. Useful for indexes that expect an access
+    /// token (e.g., Google Artifact Registry) rather than a username and password.
+    ///
+    /// Tokens can also be provided via `UV_INDEX_<NAME>_TOKEN`, where `<NAME>` is the host of the
+    /// index URL, uppercased, with non-alphanumeric characters replaced by underscores.
+    ///
+    /// Can be provided multiple times.
+    #[arg(
+        long,
+        env = "UV_INDEX_AUTH",
+        value_delimiter = ' ',
+        value_name = "URL=bearer:TOKEN",
+        hide_env_values = true
+    )]
+    pub index_auth: Vec<IndexAuth>,
+
+    /// Read credentials for indexes from a TOML file.
+    ///
+    /// The file maps index URLs to either a username and password or a bearer token, e.g.,
+    /// `"https://example.com/simple" = { username = "user", password = "pass" }` or
+    /// `"https://example.com/simple" = { token = "..." }`. Credentials are applied to requests to
+    /// the index's scheme, host, and port, and take precedence over the `.netrc` file, but not over
+    /// credentials embedded in a URL.
+    #[arg(long, env = "UV_INDEX_AUTH_FILE", value_name = "PATH")]
+    pub index_auth_file: Option<PathBuf>,
+
+    /// A mirror of the main index (`--index-url`), to fail over to if the index is unavailable.
+    ///
+    /// Mirrors are treated as interchangeable copies of the main index, rather than as additional
+    /// sources of packages (unlike `--extra-index-url`). If the main index can't be reached or
+    /// returns a server error, each mirror is tried in order; the first to respond successfully is
+    /// used for the rest of the run.
+    ///
+    /// Can be provided multiple times.
+    #[arg(long, value_parser = parse_index_url, value_name = "URL")]
+    pub index_mirror: Vec<Maybe<IndexUrl>>,
+
     #[command(flatten)]
     pub compat_args: compat::PipSyncCompatArgs,
 }
 
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+#[command(group = clap::ArgGroup::new("install-location"))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipInstallArgs {
     /// Install all listed packages.
@@ -1391,12 +1810,45 @@ pub struct PipInstallArgs {
     /// Install all packages listed in the given `requirements.txt` files.
     ///
     /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will
-    /// extract the requirements for the relevant project.
+    /// extract the requirements for the relevant project. If a `tox.ini` file is provided, uv will
+    /// extract the `deps` of a test environment (see `--tox-env`).
     ///
     /// If `-` is provided, then requirements will be read from stdin.
     #[arg(long, short, group = "sources", value_parser = parse_file_path)]
     pub requirement: Vec<PathBuf>,
 
+    /// The test environment from which to read `deps`, when installing from a `tox.ini` file.
+    ///
+    /// The `deps` are read from the `[testenv:<TOX_ENV>]` section, falling back to the `[testenv]`
+    /// section if the environment doesn't declare any. If not provided, the `deps` of the
+    /// `[testenv]` section are used.
+    #[arg(long)]
+    pub tox_env: Option<String>,
+
+    /// The maximum depth of nested `-r` and `-c` includes to follow when reading requirements
+    /// files.
+    ///
+    /// Defaults to 50.
+    #[arg(long, env = "UV_MAX_INCLUDE_DEPTH")]
+    pub max_include_depth: Option<usize>,
+
+    /// Install all packages listed in the given `requirements.txt` files, without installing their
+    /// dependencies.
+    ///
+    /// Unlike `--no-deps`, which applies to all requirements, this only affects the packages
+    /// listed in the given files. The dependencies of any other requirements will be resolved and
+    /// installed as usual. Useful for combining a file of already-resolved pins with a set of
+    /// loose requirements.
+    #[arg(long, group = "sources", value_parser = parse_file_path)]
+    pub no_deps_requirement: Vec<PathBuf>,
+
+    /// Install all packages listed in the given files, as written by `pip freeze`.
+    ///
+    /// Unlike `--requirement`, editable Git requirements (e.g., `-e git+https://...#egg=flask`)
+    /// are installed as regular, non-editable requirements, rather than rejected.
+    #[arg(long, group = "sources", value_parser = parse_file_path)]
+    pub freeze_requirement: Vec<PathBuf>,
+
     /// Install the editable package based on the provided local file path.
     #[arg(long, short, group = "sources")]
     pub editable: Vec<String>,
@@ -1420,9 +1872,20 @@ pub struct PipInstallArgs {
     /// While constraints are _additive_, in that they're combined with the requirements of the
     /// constituent packages, overrides are _absolute_, in that they completely replace the
     /// requirements of the constituent packages.
+    ///
+    /// Overrides for packages that aren't otherwise required have no effect, unless
+    /// `--override-add` is provided.
     #[arg(long, env = "UV_OVERRIDE", value_delimiter = ' ', value_parser = parse_maybe_file_path)]
     pub r#override: Vec<Maybe<PathBuf>>,
 
+    /// Add any overridden packages to the resolution, even if they aren't otherwise required.
+    ///
+    /// By default, an override only applies to packages that are already part of the resolution.
+    /// With `--override-add`, each override is also treated as a requirement, e.g., to force a
+    /// patched version of a package that none of the requirements depend on (yet).
+    #[arg(long)]
+    pub override_add: bool,
+
     /// Constrain build dependencies using the given requirements files when building source
     /// distributions.
     ///
@@ -1432,6 +1895,16 @@ pub struct PipInstallArgs {
     #[arg(long, short, env = "UV_BUILD_CONSTRAINT", value_delimiter = ' ', value_parser = parse_maybe_file_path)]
     pub build_constraint: Vec<Maybe<PathBuf>>,
 
+    /// Pin the build dependencies of each source distribution to the versions recorded in the
+    /// given file, and record the versions used for any new builds.
+    ///
+    /// The file maps the name of each built package to the exact versions of its build
+    /// dependencies. If the file doesn't exist, it will be created. Reusing the recorded versions
+    /// makes builds reproducible, even as new versions of the build dependencies (e.g.,
+    /// `setuptools`) are released.
+    #[arg(long, value_parser = parse_file_path)]
+    pub build_lock: Option<PathBuf>,
+
     /// Include optional dependencies from the extra group name; may be provided more than once.
     ///
     /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
@@ -1467,6 +1940,12 @@ pub struct PipInstallArgs {
     /// with a corresponding hash or set of hashes. Additionally, if enabled, _all_ requirements
     /// must either be pinned to exact versions (e.g., `==1.0.0`), or be specified via direct URL.
     ///
+    /// As in pip, hash-checking mode is enabled automatically if any requirement includes a hash,
+    /// unless `--no-require-hashes` is provided.
+    ///
+    /// For packages that are built from source, the hash must match the source distribution
+    /// archive (rather than the built wheel), and is verified before the build.
+    ///
     /// Hash-checking mode introduces a number of additional constraints:
     ///
     /// - Git dependencies are not supported.
@@ -1557,7 +2036,7 @@ pub struct PipInstallArgs {
 
     /// Install packages into the specified directory, rather than into the virtual or system Python
     /// environment. The packages will be installed at the top-level of the directory.
-    #[arg(long, conflicts_with = "prefix")]
+    #[arg(long, conflicts_with = "prefix", group = "install-location")]
     pub target: Option<PathBuf>,
 
     /// Install packages into `lib`, `bin`, and other top-level folders under the specified
@@ -1567,9 +2046,28 @@ pub struct PipInstallArgs {
     /// scripts and other artifacts installed via `--prefix` will reference the installing
     /// interpreter, rather than any interpreter added to the `--prefix` directory, rendering them
     /// non-portable.
-    #[arg(long, conflicts_with = "target")]
+    #[arg(long, conflicts_with = "target", group = "install-location")]
     pub prefix: Option<PathBuf>,
 
+    /// Don't write `.dist-info` directories when installing into a `--target` or `--prefix`
+    /// directory.
+    ///
+    /// Only the importable packages (and any scripts) are installed, which can be useful for
+    /// vendoring dependencies into a deployment artifact (e.g., an AWS Lambda layer). Without the
+    /// `.dist-info` metadata, the installed packages can't be detected, upgraded, or uninstalled.
+    #[arg(long, requires = "install-location")]
+    pub no_dist_info: bool,
+
+    /// Install packages into the virtual environment at the specified path, creating it if it
+    /// doesn't exist.
+    ///
+    /// If the virtual environment does not yet exist, it will be created using the Python
+    /// interpreter requested via `--python`, or the first Python found on the system `PATH`.
+    ///
+    /// Incompatible with `--dry-run`, which must not modify the filesystem.
+    #[arg(long, conflicts_with_all = ["system", "target", "prefix", "dry_run"])]
+    pub venv: Option<PathBuf>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1615,6 +2113,17 @@ pub struct PipInstallArgs {
     #[arg(long, conflicts_with = "no_build")]
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
 
+    /// Prefer building the given packages from source, even if a compatible pre-built wheel
+    /// exists.
+    ///
+    /// Unlike `--no-binary`, which disallows pre-built wheels entirely, the pre-built wheel is
+    /// still installed if the source distribution fails to build. The resolver will still use
+    /// pre-built wheels to extract package metadata, if available.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with = "no_build")]
+    pub prefer_sdist_package: Vec<PackageName>,
+
     /// The minimum Python version that should be supported by the requirements (e.g.,
     /// `3.7` or `3.7.9`).
     ///
@@ -1639,17 +2148,182 @@ pub struct PipInstallArgs {
 
     /// Validate the Python environment after completing the installation, to detect and with
     /// missing dependencies or other issues.
+    ///
+    /// Before resolving, also verifies that the dependencies declared in any `pyproject.toml`
+    /// files don't contradict the `tool.uv.constraint-dependencies`. After resolving, any
+    /// constraints or overrides for packages that aren't part of the resolution are treated as
+    /// errors, rather than warnings. Requirements files that declare different `--index-url`
+    /// values are also treated as an error.
     #[arg(long, overrides_with("no_strict"))]
     pub strict: bool,
 
     #[arg(long, overrides_with("strict"), hide = true)]
     pub no_strict: bool,
 
+    /// The number of workers to use when compiling Python files to bytecode.
+    ///
+    /// Bytecode compilation is CPU-bound, and so is limited separately from installation, which is
+    /// largely IO-bound. Only applies when `--compile-bytecode` is enabled.
+    ///
+    /// Defaults to the number of available CPU cores.
+    #[arg(
+        long,
+        env = "UV_COMPILE_BYTECODE_CONCURRENCY",
+        value_name = "N",
+        help_heading = "Installer options"
+    )]
+    pub compile_bytecode_concurrency: Option<NonZeroUsize>,
+
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Verify that every installed file matches the hash recorded in its wheel's `RECORD`.
+    ///
+    /// After each wheel is installed, the files that carry a hash in the `RECORD` are re-read from
+    /// the environment and hashed, failing the installation if any of them diverge (e.g., due to
+    /// corruption in the cache or during linking). Disabled by default, as it requires reading
+    /// every installed file.
+    #[arg(long)]
+    pub verify_install: bool,
+
+    /// Report the time spent in each phase of the operation.
+    ///
+    /// Displays a breakdown of the time spent parsing requirements, resolving (split into fetching
+    /// metadata and solving), building, downloading and building distributions, and installing,
+    /// along with the number of distributions that were served from the cache.
+    #[arg(long)]
+    pub timings: bool,
+
+    /// Allow yanked versions of any package to be selected during resolution.
+    ///
+    /// By default, a yanked version is only selected if it's pinned exactly (e.g., `flask==1.0.0`).
+    /// With `--allow-yanked`, yanked versions are treated like any other version, such that a
+    /// range (e.g., `flask>=1.0.0,<=1.0.0`) may resolve to a yanked version. A warning is emitted
+    /// for each yanked version in the resolution.
+    #[arg(long)]
+    pub allow_yanked: bool,
+
+    /// Allow yanked versions of a specific package to be selected during resolution.
+    #[arg(long)]
+    pub allow_yanked_package: Vec<PackageName>,
+
+    /// Only allow the given packages to be included in the resolution.
+    ///
+    /// When provided, any package that isn't explicitly allowed, whether it's requested directly
+    /// or required transitively, is rejected by the resolver.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE")]
+    pub allow_package: Vec<PackageName>,
+
+    /// Forbid any version of the given package from being selected during resolution.
+    ///
+    /// If the package is required, the resolution fails.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE")]
+    pub deny_package: Vec<PackageName>,
+
+    /// Forbid a specific version of a package from being selected during resolution (e.g.,
+    /// `--deny flask==3.0.0`).
+    ///
+    /// The resolver will fall back to another version of the package, if one is available.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE==VERSION")]
+    pub deny: Vec<DeniedVersion>,
+
+    /// Use a different resolution strategy for the given package (e.g.,
+    /// `--resolution-package torch=lowest`), overriding `--resolution`.
+    ///
+    /// Accepts `highest`, `lowest`, or `lowest-direct`. All other packages continue to use the
+    /// global resolution strategy.
+    ///
+    /// May be provided multiple times, but at most once per package.
+    #[arg(long, value_name = "PACKAGE=MODE")]
+    pub resolution_package: Vec<PackageResolutionMode>,
+
+    /// Error if a requirement requests an extra that the resolved version of the package doesn't
+    /// declare (e.g., `requests[foo]`), rather than warning and ignoring the extra.
+    #[arg(long)]
+    pub strict_extras: bool,
+
+    /// Abort the resolution if it doesn't complete within the given number of seconds.
+    ///
+    /// By default, the resolver runs until it finds a resolution or proves that none exists. If
+    /// the limit is exceeded, the resolution fails and reports the packages for which the most
+    /// versions were tried.
+    #[arg(long, value_name = "SECONDS")]
+    pub resolution_timeout: Option<u64>,
+
+    /// Abort the resolution after the given number of solver steps.
+    ///
+    /// Each step corresponds to a package decision or conflict in the solver. Unlike
+    /// `--resolution-timeout`, the limit is deterministic across machines.
+    #[arg(long)]
+    pub max_resolution_steps: Option<u64>,
+
+    /// Constrain the resolution by the requirements of the packages already installed in the
+    /// environment.
+    ///
+    /// Each installed package's declared dependencies (i.e., its `Requires-Dist` metadata) are
+    /// treated as constraints, such that upgrading a package (e.g., `uv pip install --upgrade
+    /// flask`) respects any bounds imposed by the rest of the environment. The dependencies of the
+    /// packages being installed or upgraded are not considered.
+    #[arg(long)]
+    pub constrain_installed: bool,
+
+    /// Ignore any packages installed in the base interpreter's `site-packages`.
+    ///
+    /// By default, when installing into a virtual environment that was created with
+    /// `--system-site-packages`, packages installed in the base interpreter count as installed,
+    /// such that they aren't installed into the virtual environment again. With
+    /// `--ignore-installed`, they're installed into the virtual environment regardless.
+    #[arg(long)]
+    pub ignore_installed: bool,
+
+    /// Attach a bearer token to requests for an index, as `URL=bearer:TOKEN`.
+    ///
+    /// The token is sent in an `Authorization: Bearer` header on requests to URLs under the index
+    /// URL, and is never sent to other indexes or hosts. Useful for indexes that expect an access
+    /// token (e.g., Google Artifact Registry) rather than a username and password.
+    ///
+    /// Tokens can also be provided via `UV_INDEX_<NAME>_TOKEN`, where `<NAME>` is the host of the
+    /// index URL, uppercased, with non-alphanumeric characters replaced by underscores.
+    ///
+    /// Can be provided multiple times.
+    #[arg(
+        long,
+        env = "UV_INDEX_AUTH",
+        value_delimiter = ' ',
+        value_name = "URL=bearer:TOKEN",
+        hide_env_values = true
+    )]
+    pub index_auth: Vec<IndexAuth>,
+
+    /// Read credentials for indexes from a TOML file.
+    ///
+    /// The file maps index URLs to either a username and password or a bearer token, e.g.,
+    /// `"https://example.com/simple" = { username = "user", password = "pass" }` or
+    /// `"https://example.com/simple" = { token = "..." }`. Credentials are applied to requests to
+    /// the index's scheme, host, and port, and take precedence over the `.netrc` file, but not over
+    /// credentials embedded in a URL.
+    #[arg(long, env = "UV_INDEX_AUTH_FILE", value_name = "PATH")]
+    pub index_auth_file: Option<PathBuf>,
+
+    /// A mirror of the main index (`--index-url`), to fail over to if the index is unavailable.
+    ///
+    /// Mirrors are treated as interchangeable copies of the main index, rather than as additional
+    /// sources of packages (unlike `--extra-index-url`). If the main index can't be reached or
+    /// returns a server error, each mirror is tried in order; the first to respond successfully is
+    /// used for the rest of the run.
+    ///
+    /// Can be provided multiple times.
+    #[arg(long, value_parser = parse_index_url, value_name = "URL")]
+    pub index_mirror: Vec<Maybe<IndexUrl>>,
+
     #[command(flatten)]
     pub compat_args: compat::PipInstallCompatArgs,
 }
@@ -1768,6 +2442,10 @@ pub struct PipFreezeArgs {
     #[arg(long)]
     pub exclude_editable: bool,
 
+    /// Only include editable packages in the output.
+    #[arg(long, conflicts_with = "exclude_editable")]
+    pub editable_only: bool,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -1818,7 +2496,7 @@ pub struct PipFreezeArgs {
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipListArgs {
     /// Only include editable projects.
-    #[arg(short, long)]
+    #[arg(short, long, alias = "editable-only")]
     pub editable: bool,
 
     /// Exclude any editable packages from output.
@@ -2025,6 +2703,46 @@ pub struct PipTreeArgs {
     pub compat_args: compat::PipGlobalCompatArgs,
 }
 
+#[derive(Args)]
+pub struct PipWhyArgs {
+    /// The package to explain.
+    pub package: PackageName,
+
+    /// The Python interpreter for which packages should be explained.
+    ///
+    /// By default, uv looks for packages in a virtual environment but will look
+    /// for packages in a system Python environment if no virtual environment is
+    /// found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = "UV_PYTHON",
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Explain a package in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = "UV_SYSTEM_PYTHON",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct BuildArgs {
@@ -2514,6 +3232,9 @@ pub struct RunArgs {
     #[arg(long, conflicts_with("no_dev"))]
     pub only_dev: bool,
 
+    #[command(flatten)]
+    pub groups: GroupArgs,
+
     /// Install any editable dependencies, including the project and any workspace members, as
     /// non-editable.
     #[arg(long)]
@@ -2679,6 +3400,9 @@ pub struct SyncArgs {
     #[arg(long, conflicts_with("no_dev"))]
     pub only_dev: bool,
 
+    #[command(flatten)]
+    pub groups: GroupArgs,
+
     /// Install any editable dependencies, including the project and any workspace members, as
     /// non-editable.
     #[arg(long)]
@@ -3131,6 +3855,9 @@ pub struct ExportArgs {
     #[arg(long, conflicts_with("no_dev"))]
     pub only_dev: bool,
 
+    #[command(flatten)]
+    pub groups: GroupArgs,
+
     /// Install any editable dependencies, including the project and any workspace members, as
     /// non-editable.
     #[arg(long)]
@@ -3719,8 +4446,8 @@ pub struct GenerateShellCompletionArgs {
     #[arg(long, hide = true)]
     pub no_python_downloads: bool,
 
-    #[arg(long, short, conflicts_with = "verbose", hide = true)]
-    pub quiet: bool,
+    #[arg(long, short, action = clap::ArgAction::Count, conflicts_with = "verbose", hide = true)]
+    pub quiet: u8,
     #[arg(long, short, action = clap::ArgAction::Count, conflicts_with = "quiet", hide = true)]
     pub verbose: u8,
     #[arg(long, default_value = "auto", conflicts_with = "no_color", hide = true)]
@@ -3773,6 +4500,9 @@ pub struct IndexArgs {
     ///
     /// If a URL, the page must contain a flat list of links to package files adhering to the
     /// formats described above.
+    ///
+    /// If the same distribution is available from multiple locations, the location provided first
+    /// takes precedence.
     #[arg(
         long,
         short,
@@ -3789,6 +4519,28 @@ pub struct IndexArgs {
     pub no_index: bool,
 }
 
+/// Arguments that are used by commands that select the dependency groups of a project.
+#[derive(Args)]
+pub struct GroupArgs {
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided more than once.
+    ///
+    /// Dependency groups are defined via `dependency-groups` in a `pyproject.toml`.
+    #[arg(long, conflicts_with = "all_groups", value_parser = group_name_with_clap_error)]
+    pub group: Vec<GroupName>,
+
+    /// Include dependencies from all dependency groups.
+    #[arg(long, conflicts_with = "group")]
+    pub all_groups: bool,
+
+    /// Omit the dependency groups listed in `tool.uv.default-groups`.
+    ///
+    /// Groups requested explicitly via `--group` are still included.
+    #[arg(long, conflicts_with = "all_groups")]
+    pub no_default_groups: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct RefreshArgs {
@@ -3957,6 +4709,12 @@ pub struct InstallerArgs {
     )]
     pub no_build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
+    #[arg(long, help_heading = "Build options")]
+    pub no_build_isolation_package: Vec<PackageName>,
+
     #[arg(
         long,
         overrides_with("no_build_isolation"),
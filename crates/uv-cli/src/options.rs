@@ -93,6 +93,7 @@ impl From<InstallerArgs> for PipOptions {
             allow_insecure_host,
             config_setting,
             no_build_isolation,
+            no_build_isolation_package,
             build_isolation,
             exclude_newer,
             link_mode,
@@ -115,6 +116,7 @@ impl From<InstallerArgs> for PipOptions {
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             no_build_isolation: flag(no_build_isolation, build_isolation),
+            no_build_isolation_package: Some(no_build_isolation_package),
             exclude_newer,
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
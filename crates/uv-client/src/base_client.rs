@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use owo_colors::OwoColorize;
 use reqwest::{Client, ClientBuilder, Response};
 use reqwest_middleware::ClientWithMiddleware;
 use reqwest_retry::policies::ExponentialBackoff;
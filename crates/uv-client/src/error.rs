@@ -260,13 +260,10 @@ impl From<reqwest_middleware::Error> for ErrorKind {
 pub struct WrappedReqwestError(reqwest_middleware::Error);
 
 impl WrappedReqwestError {
-    /// Check if the error chain contains a reqwest error that looks like this:
-    /// * error sending request for url (...)
-    /// * client error (Connect)
-    /// * dns error: failed to lookup address information: Name or service not known
-    /// * failed to lookup address information: Name or service not known
-    fn is_likely_offline(&self) -> bool {
-        let reqwest_err = match &self.0 {
+    /// Return the underlying [`reqwest::Error`], if any, which may be below some context in a
+    /// middleware error.
+    fn reqwest_error(&self) -> Option<&reqwest::Error> {
+        match &self.0 {
             reqwest_middleware::Error::Reqwest(err) => Some(err),
             reqwest_middleware::Error::Middleware(err) => err.chain().find_map(|err| {
                 if let Some(err) = err.downcast_ref::<reqwest::Error>() {
@@ -279,9 +276,26 @@ impl WrappedReqwestError {
                     None
                 }
             }),
-        };
+        }
+    }
 
-        if let Some(reqwest_err) = reqwest_err {
+    /// Returns `true` if the server could not be reached or failed to handle the request, such that
+    /// the request may succeed against an equivalent server (e.g., a mirror).
+    pub(crate) fn is_server_unavailable(&self) -> bool {
+        self.reqwest_error().is_some_and(|err| {
+            err.is_connect()
+                || err.is_timeout()
+                || err.status().is_some_and(|status| status.is_server_error())
+        })
+    }
+
+    /// Check if the error chain contains a reqwest error that looks like this:
+    /// * error sending request for url (...)
+    /// * client error (Connect)
+    /// * dns error: failed to lookup address information: Name or service not known
+    /// * failed to lookup address information: Name or service not known
+    fn is_likely_offline(&self) -> bool {
+        if let Some(reqwest_err) = self.reqwest_error() {
             if !reqwest_err.is_connect() {
                 return false;
             }
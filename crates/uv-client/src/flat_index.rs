@@ -156,7 +156,7 @@ impl<'a> FlatIndexClient<'a> {
             .client
             .uncached_client(url)
             .get(url.clone())
-            .header("Accept-Encoding", "gzip")
+            .header("Accept-Encoding", "gzip, deflate, br")
             .header("Accept", "text/html")
             .build()
             .map_err(ErrorKind::from)?;
@@ -228,9 +228,13 @@ impl<'a> FlatIndexClient<'a> {
         path: &Path,
         flat_index: &FlatIndexLocation,
     ) -> Result<FlatIndexEntries, FindLinksDirectoryError> {
+        // Sort the entries by filename, such that the order in which distributions are considered
+        // doesn't depend on the filesystem.
+        let mut entries = fs_err::read_dir(path)?.collect::<Result<Vec<_>, _>>()?;
+        entries.sort_by_key(fs_err::DirEntry::file_name);
+
         let mut dists = Vec::new();
-        for entry in fs_err::read_dir(path)? {
-            let entry = entry?;
+        for entry in entries {
             let metadata = entry.metadata()?;
 
             if metadata.is_dir() {
@@ -1203,4 +1203,86 @@ mod tests {
         }
         "###);
     }
+
+    #[test]
+    fn parse_relative_href() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+  <body>
+    <h1>Links for jinja2</h1>
+    <a href="jinja2/Jinja2-3.1.2-py3-none-any.whl#sha256=6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61">Jinja2-3.1.2-py3-none-any.whl</a><br/>
+    <a href="../archive/Jinja2-3.1.3.tar.gz#sha256=ac8bd6544d4bb2c9792bf3a159e80bba8fda7f07e81bc3aed565432d5925ba90">Jinja2-3.1.3.tar.gz</a><br/>
+  </body>
+</html>
+        "#;
+        let base = Url::parse("https://example.org/wheels/").unwrap();
+        let SimpleHtml { base, files } = SimpleHtml::parse(text, &base).unwrap();
+
+        let files = files
+            .into_iter()
+            .map(|file| uv_distribution_types::File::try_from(file, base.as_url()).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            files[0].url.to_url().unwrap().as_str(),
+            "https://example.org/wheels/jinja2/Jinja2-3.1.2-py3-none-any.whl#sha256=6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61"
+        );
+        assert_eq!(
+            files[0]
+                .hashes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["sha256:6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61"]
+        );
+        assert_eq!(
+            files[1].url.to_url().unwrap().as_str(),
+            "https://example.org/archive/Jinja2-3.1.3.tar.gz#sha256=ac8bd6544d4bb2c9792bf3a159e80bba8fda7f07e81bc3aed565432d5925ba90"
+        );
+        assert_eq!(
+            files[1]
+                .hashes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["sha256:ac8bd6544d4bb2c9792bf3a159e80bba8fda7f07e81bc3aed565432d5925ba90"]
+        );
+    }
+
+    #[test]
+    fn parse_relative_href_with_base() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+  <head>
+    <base href="https://mirror.example.org/packages/">
+  </head>
+  <body>
+    <h1>Links for jinja2</h1>
+    <a href="Jinja2-3.1.2-py3-none-any.whl#sha256=6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61">Jinja2-3.1.2-py3-none-any.whl</a><br/>
+  </body>
+</html>
+        "#;
+        let base = Url::parse("https://example.org/wheels/").unwrap();
+        let SimpleHtml { base, files } = SimpleHtml::parse(text, &base).unwrap();
+
+        let files = files
+            .into_iter()
+            .map(|file| uv_distribution_types::File::try_from(file, base.as_url()).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            files[0].url.to_url().unwrap().as_str(),
+            "https://mirror.example.org/packages/Jinja2-3.1.2-py3-none-any.whl#sha256=6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61"
+        );
+        assert_eq!(
+            files[0]
+                .hashes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["sha256:6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61"]
+        );
+    }
 }
@@ -7,8 +7,10 @@ use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
-use tracing::{info_span, instrument, trace, warn, Instrument};
+use tracing::{debug, info_span, instrument, trace, warn, Instrument};
 use url::Url;
 
 use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
@@ -140,6 +142,7 @@ impl<'a> RegistryClientBuilder<'a> {
             connectivity,
             client,
             timeout,
+            active_mirror: Arc::default(),
         }
     }
 }
@@ -172,6 +175,9 @@ pub struct RegistryClient {
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: Duration,
+    /// The position of the mirror currently in use for the main index, where `0` refers to the
+    /// main index itself. Once a mirror responds successfully, it's used for the rest of the run.
+    active_mirror: Arc<AtomicUsize>,
 }
 
 impl RegistryClient {
@@ -185,6 +191,11 @@ impl RegistryClient {
         self.client.uncached().for_host(url)
     }
 
+    /// Return the [`IndexUrls`] used by this client.
+    pub fn index_urls(&self) -> &IndexUrls {
+        &self.index_urls
+    }
+
     /// Return the [`Connectivity`] mode used by this client.
     pub fn connectivity(&self) -> Connectivity {
         self.connectivity
@@ -212,7 +223,7 @@ impl RegistryClient {
 
         let mut results = Vec::new();
         for index in it {
-            match self.simple_single_index(package_name, index).await {
+            match self.simple_with_failover(package_name, index).await {
                 Ok(metadata) => {
                     results.push((index.clone(), metadata));
 
@@ -256,6 +267,48 @@ impl RegistryClient {
         Ok(results)
     }
 
+    /// Fetch the [`SimpleMetadata`] from an index for a given package, failing over to each of the
+    /// index's mirrors in turn if the index can't be reached or returns a server error.
+    ///
+    /// The first location to respond successfully is used for all subsequent requests.
+    async fn simple_with_failover(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let mirrors = self.index_urls.mirrors(index);
+        if mirrors.is_empty() {
+            return self.simple_single_index(package_name, index).await;
+        }
+
+        let locations = std::iter::once(index).chain(mirrors).collect::<Vec<_>>();
+        let mut position = self
+            .active_mirror
+            .load(Ordering::Relaxed)
+            .min(locations.len() - 1);
+        loop {
+            let location = locations[position];
+            match self.simple_single_index(package_name, location).await {
+                Ok(metadata) => {
+                    if self.active_mirror.fetch_max(position, Ordering::Relaxed) < position {
+                        debug!("Using index mirror for the rest of the run: {location}");
+                    }
+                    return Ok(metadata);
+                }
+                Err(err) => {
+                    let ErrorKind::WrappedReqwestError(inner) = err.kind() else {
+                        return Err(err);
+                    };
+                    if !inner.is_server_unavailable() || position + 1 == locations.len() {
+                        return Err(err);
+                    }
+                    warn!("Index `{location}` is unavailable, trying the next mirror: {inner}");
+                    position += 1;
+                }
+            }
+        }
+    }
+
     /// Fetch the [`SimpleMetadata`] from a single index for a given package.
     ///
     /// The index can either be a PEP 503-compatible remote repository, or a local directory laid
@@ -310,7 +363,7 @@ impl RegistryClient {
         let simple_request = self
             .uncached_client(url)
             .get(url.clone())
-            .header("Accept-Encoding", "gzip")
+            .header("Accept-Encoding", "gzip, deflate, br")
             .header("Accept", MediaType::accepts())
             .build()
             .map_err(ErrorKind::from)?;
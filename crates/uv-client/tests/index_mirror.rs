@@ -0,0 +1,124 @@
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::CONTENT_TYPE;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+
+use uv_cache::Cache;
+use uv_client::{OwnedArchive, RegistryClient, RegistryClientBuilder};
+use uv_distribution_types::{IndexLocations, IndexUrl};
+use uv_normalize::PackageName;
+
+const SIMPLE_HTML: &str = r#"<!DOCTYPE html>
+<html>
+  <body>
+    <h1>Links for tqdm</h1>
+    <a href="https://files.pythonhosted.org/packages/tqdm-4.66.0-py3-none-any.whl">tqdm-4.66.0-py3-none-any.whl</a>
+    <a href="https://files.pythonhosted.org/packages/tqdm-4.66.1-py3-none-any.whl">tqdm-4.66.1-py3-none-any.whl</a>
+  </body>
+</html>
+"#;
+
+/// Serve the simple-index page for `tqdm` with the given status code.
+///
+/// Returns the address of the server, along with the number of requests it has received.
+async fn serve_simple_page(status: StatusCode) -> Result<(SocketAddr, Arc<AtomicUsize>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    let requests = Arc::new(AtomicUsize::new(0));
+    let received = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let received = received.clone();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                received.fetch_add(1, Ordering::SeqCst);
+                let response = Response::builder()
+                    .status(status)
+                    .header(CONTENT_TYPE, "text/html")
+                    .body(Full::new(Bytes::from(SIMPLE_HTML)));
+                future::ok::<_, hyper::Error>(response.unwrap())
+            });
+            tokio::spawn(async move {
+                let socket = TokioIo::new(socket);
+                let _ = http1::Builder::new().serve_connection(socket, svc).await;
+            });
+        }
+    });
+
+    Ok((addr, requests))
+}
+
+/// Fetch the versions of `tqdm` available on the client's index.
+async fn fetch_versions(client: &RegistryClient) -> Result<Vec<String>> {
+    let results = client.simple(&PackageName::from_str("tqdm")?).await?;
+    let (_, metadata) = results.into_iter().next().unwrap();
+    let metadata = OwnedArchive::deserialize(&metadata);
+    Ok(metadata
+        .iter()
+        .map(|datum| datum.version.to_string())
+        .collect())
+}
+
+#[tokio::test]
+async fn mirror_failover() -> Result<()> {
+    let (primary_addr, primary_requests) =
+        serve_simple_page(StatusCode::SERVICE_UNAVAILABLE).await?;
+    let (mirror_addr, mirror_requests) = serve_simple_page(StatusCode::OK).await?;
+
+    let index = IndexUrl::from_str(&format!("http://{primary_addr}/simple"))?;
+    let mirror = IndexUrl::from_str(&format!("http://{mirror_addr}/simple"))?;
+    let index_locations = IndexLocations::new(Some(index), vec![], vec![], false);
+
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index_locations.index_urls().with_mirrors(vec![mirror]))
+        .retries(0)
+        .build();
+
+    // The primary index is unavailable, so the package should be fetched from the mirror.
+    let versions = fetch_versions(&client).await?;
+    assert_eq!(versions, vec!["4.66.0", "4.66.1"]);
+    assert_eq!(primary_requests.load(Ordering::SeqCst), 1);
+    assert_eq!(mirror_requests.load(Ordering::SeqCst), 1);
+
+    // The mirror should be used for the rest of the run, without retrying the primary index.
+    let versions = fetch_versions(&client).await?;
+    assert_eq!(versions, vec!["4.66.0", "4.66.1"]);
+    assert_eq!(primary_requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mirror_failover_exhausted() -> Result<()> {
+    let (primary_addr, _) = serve_simple_page(StatusCode::SERVICE_UNAVAILABLE).await?;
+    let (mirror_addr, mirror_requests) = serve_simple_page(StatusCode::SERVICE_UNAVAILABLE).await?;
+
+    let index = IndexUrl::from_str(&format!("http://{primary_addr}/simple"))?;
+    let mirror = IndexUrl::from_str(&format!("http://{mirror_addr}/simple"))?;
+    let index_locations = IndexLocations::new(Some(index), vec![], vec![], false);
+
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index_locations.index_urls().with_mirrors(vec![mirror]))
+        .retries(0)
+        .build();
+
+    // If every mirror is unavailable, the error from the last mirror should be returned.
+    assert!(fetch_versions(&client).await.is_err());
+    assert_eq!(mirror_requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::CONTENT_TYPE;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+
+use uv_cache::Cache;
+use uv_client::{OwnedArchive, RegistryClient, RegistryClientBuilder};
+use uv_distribution_types::{IndexUrl, IndexUrls};
+use uv_normalize::PackageName;
+
+/// Serve a simple-index page for `tqdm` that lists a single wheel for the given version.
+async fn serve_simple_page(version: &'static str) -> Result<SocketAddr> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+  <body>
+    <h1>Links for tqdm</h1>
+    <a href="https://files.pythonhosted.org/packages/tqdm-{version}-py3-none-any.whl">tqdm-{version}-py3-none-any.whl</a>
+  </body>
+</html>
+"#
+    );
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let html = html.clone();
+            let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                let response = Response::builder()
+                    .header(CONTENT_TYPE, "text/html")
+                    .body(Full::new(Bytes::from(html.clone())));
+                future::ok::<_, hyper::Error>(response.unwrap())
+            });
+            tokio::spawn(async move {
+                let socket = TokioIo::new(socket);
+                let _ = http1::Builder::new().serve_connection(socket, svc).await;
+            });
+        }
+    });
+
+    Ok(addr)
+}
+
+/// Fetch the versions of `tqdm` available on the client's index.
+async fn fetch_versions(client: &RegistryClient) -> Result<Vec<String>> {
+    let results = client.simple(&PackageName::from_str("tqdm")?).await?;
+    let (_, metadata) = results.into_iter().next().unwrap();
+    let metadata = OwnedArchive::deserialize(&metadata);
+    Ok(metadata
+        .iter()
+        .map(|datum| datum.version.to_string())
+        .collect())
+}
+
+#[tokio::test]
+async fn independent_default_indexes() -> Result<()> {
+    let first_addr = serve_simple_page("4.66.0").await?;
+    let second_addr = serve_simple_page("4.66.1").await?;
+
+    let first_index = IndexUrl::from_str(&format!("http://{first_addr}/simple"))?;
+    let second_index = IndexUrl::from_str(&format!("http://{second_addr}/simple"))?;
+
+    // Configure two clients in the same process, each with a different default index.
+    let cache = Cache::temp()?.init()?;
+    let first = RegistryClientBuilder::new(cache.clone())
+        .index_urls(IndexUrls::new(Some(first_index.clone()), vec![], false))
+        .build();
+    let second = RegistryClientBuilder::new(cache)
+        .index_urls(IndexUrls::new(Some(second_index.clone()), vec![], false))
+        .build();
+
+    assert_eq!(
+        first.index_urls().indexes().collect::<Vec<_>>(),
+        vec![&first_index]
+    );
+    assert_eq!(
+        second.index_urls().indexes().collect::<Vec<_>>(),
+        vec![&second_index]
+    );
+
+    // Each client should only ever query its own index, even when used concurrently.
+    let (first_versions, second_versions) =
+        tokio::try_join!(fetch_versions(&first), fetch_versions(&second))?;
+    assert_eq!(first_versions, vec!["4.66.0"]);
+    assert_eq!(second_versions, vec!["4.66.1"]);
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use std::io::Write;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::{ACCEPT_ENCODING, CACHE_CONTROL, CONTENT_ENCODING, CONTENT_TYPE};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+
+use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_client::{Connectivity, OwnedArchive, RegistryClientBuilder};
+use uv_distribution_types::{IndexLocations, IndexUrl};
+use uv_normalize::PackageName;
+
+const SIMPLE_HTML: &str = r#"<!DOCTYPE html>
+<html>
+  <body>
+    <h1>Links for tqdm</h1>
+    <a href="https://files.pythonhosted.org/packages/tqdm-4.66.0-py3-none-any.whl">tqdm-4.66.0-py3-none-any.whl</a>
+    <a href="https://files.pythonhosted.org/packages/tqdm-4.66.1-py3-none-any.whl">tqdm-4.66.1-py3-none-any.whl</a>
+  </body>
+</html>
+"#;
+
+/// Serve the simple-index page for `tqdm` to a single connection, gzip-encoded if `gzip` is set.
+///
+/// Returns the address of the server, along with the `Accept-Encoding` header of the request.
+async fn serve_simple_page(gzip: bool) -> Result<(SocketAddr, Arc<Mutex<Option<String>>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    let accept_encoding = Arc::new(Mutex::new(None));
+    let received = accept_encoding.clone();
+    tokio::spawn(async move {
+        let svc = service_fn(move |req: Request<hyper::body::Incoming>| {
+            *received.lock().unwrap() = req
+                .headers()
+                .get(ACCEPT_ENCODING)
+                .and_then(|v| v.to_str().ok())
+                .map(ToString::to_string);
+
+            let response = Response::builder()
+                .header(CONTENT_TYPE, "text/html")
+                .header(CACHE_CONTROL, "max-age=600");
+            let response = if gzip {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(SIMPLE_HTML.as_bytes()).unwrap();
+                response
+                    .header(CONTENT_ENCODING, "gzip")
+                    .body(Full::new(Bytes::from(encoder.finish().unwrap())))
+            } else {
+                response.body(Full::new(Bytes::from(SIMPLE_HTML)))
+            };
+            future::ok::<_, hyper::Error>(response.unwrap())
+        });
+        let (socket, _) = listener.accept().await.unwrap();
+        let socket = TokioIo::new(socket);
+        let _ = http1::Builder::new().serve_connection(socket, svc).await;
+    });
+
+    Ok((addr, accept_encoding))
+}
+
+/// Fetch the versions of `tqdm` available on the given index.
+async fn fetch_versions(
+    cache: Cache,
+    index: &IndexUrl,
+    connectivity: Connectivity,
+) -> Result<Vec<String>> {
+    let index_locations = IndexLocations::new(Some(index.clone()), vec![], vec![], false);
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index_locations.index_urls())
+        .connectivity(connectivity)
+        .build();
+
+    let results = client.simple(&PackageName::from_str("tqdm")?).await?;
+    let (_, metadata) = results.into_iter().next().unwrap();
+    let metadata = OwnedArchive::deserialize(&metadata);
+    Ok(metadata
+        .iter()
+        .map(|datum| datum.version.to_string())
+        .collect())
+}
+
+#[tokio::test]
+async fn simple_gzip_encoded() -> Result<()> {
+    let (addr, accept_encoding) = serve_simple_page(true).await?;
+    let index = IndexUrl::from_str(&format!("http://{addr}/simple"))?;
+
+    let cache = Cache::temp()?.init()?;
+    let versions = fetch_versions(cache, &index, Connectivity::Online).await?;
+    assert_eq!(versions, vec!["4.66.0", "4.66.1"]);
+
+    // The client should advertise support for compressed responses.
+    assert_eq!(
+        accept_encoding.lock().unwrap().as_deref(),
+        Some("gzip, deflate, br")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn simple_identity_encoded() -> Result<()> {
+    let (addr, _) = serve_simple_page(false).await?;
+    let index = IndexUrl::from_str(&format!("http://{addr}/simple"))?;
+
+    let cache = Cache::temp()?.init()?;
+    let versions = fetch_versions(cache, &index, Connectivity::Online).await?;
+    assert_eq!(versions, vec!["4.66.0", "4.66.1"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn simple_gzip_encoded_cache() -> Result<()> {
+    let (addr, _) = serve_simple_page(true).await?;
+    let index = IndexUrl::from_str(&format!("http://{addr}/simple"))?;
+
+    let cache = Cache::temp()?.init()?;
+    fetch_versions(cache.clone(), &index, Connectivity::Online).await?;
+
+    // The cache entry should contain the decoded page, rather than the gzip-encoded response.
+    let cache_entry = cache.entry(
+        CacheBucket::Simple,
+        WheelCache::Index(&index).root(),
+        "tqdm.rkyv",
+    );
+    let contents = fs_err::read(cache_entry.path())?;
+    assert!(!contents.starts_with(&[0x1f, 0x8b]));
+    let filename = b"tqdm-4.66.1-py3-none-any.whl";
+    assert!(contents
+        .windows(filename.len())
+        .any(|window| window == filename));
+
+    // The cached page should be readable without the server.
+    let versions = fetch_versions(cache, &index, Connectivity::Offline).await?;
+    assert_eq!(versions, vec!["4.66.0", "4.66.1"]);
+
+    Ok(())
+}
@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use url::Url;
+
 use uv_auth::{self, KeyringProvider};
 
 /// Keyring provider type to use for credential lookup.
@@ -26,3 +30,137 @@ impl KeyringProviderType {
         }
     }
 }
+
+/// A bearer token for an index, provided as `URL=bearer:TOKEN`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct IndexAuth {
+    url: Url,
+    token: String,
+}
+
+impl IndexAuth {
+    /// Read a bearer token for an index URL from the `UV_INDEX_<NAME>_TOKEN` environment variable.
+    ///
+    /// The `<NAME>` is the host of the index URL, uppercased, with any non-alphanumeric characters
+    /// replaced by underscores (e.g., `UV_INDEX_PKGS_EXAMPLE_COM_TOKEN` for
+    /// `https://pkgs.example.com/simple`).
+    pub fn from_env(url: &Url) -> Option<Self> {
+        let token = std::env::var(Self::env_var(url)?).ok()?;
+        if token.is_empty() || !token.chars().all(|c| c.is_ascii_graphic()) {
+            return None;
+        }
+        Some(Self {
+            url: url.clone(),
+            token,
+        })
+    }
+
+    /// Return the name of the environment variable that holds the bearer token for an index URL.
+    fn env_var(url: &Url) -> Option<String> {
+        let name = url
+            .host_str()?
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        Some(format!("UV_INDEX_{name}_TOKEN"))
+    }
+
+    /// Add the token to the global authentication store, scoped to the index URL.
+    pub fn store(&self) {
+        uv_auth::store_bearer_token(&self.url, &self.token);
+    }
+}
+
+impl std::fmt::Debug for IndexAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexAuth")
+            .field("url", &self.url)
+            .field("token", &"****")
+            .finish()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum IndexAuthError {
+    #[error("expected `URL=bearer:TOKEN` for `--index-auth`")]
+    MissingToken,
+    #[error("invalid index URL for `--index-auth`: `{0}`")]
+    InvalidUrl(String, #[source] url::ParseError),
+    #[error(
+        "bearer token for `--index-auth` must be non-empty and consist of visible ASCII characters"
+    )]
+    InvalidToken,
+}
+
+impl FromStr for IndexAuth {
+    type Err = IndexAuthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((url, token)) = s.split_once("=bearer:") else {
+            return Err(IndexAuthError::MissingToken);
+        };
+        let url =
+            Url::parse(url).map_err(|err| IndexAuthError::InvalidUrl(url.to_string(), err))?;
+        if token.is_empty() || !token.chars().all(|c| c.is_ascii_graphic()) {
+            return Err(IndexAuthError::InvalidToken);
+        }
+        Ok(Self {
+            url,
+            token: token.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_index_auth() {
+        let auth = "https://example.com/simple=bearer:abc.def="
+            .parse::<IndexAuth>()
+            .unwrap();
+        assert_eq!(auth.url.as_str(), "https://example.com/simple");
+        assert_eq!(auth.token, "abc.def=");
+
+        assert!(matches!(
+            "https://example.com/simple".parse::<IndexAuth>(),
+            Err(IndexAuthError::MissingToken)
+        ));
+        assert!(matches!(
+            "https://example.com/simple=basic:abc".parse::<IndexAuth>(),
+            Err(IndexAuthError::MissingToken)
+        ));
+        assert!(matches!(
+            "example=bearer:abc".parse::<IndexAuth>(),
+            Err(IndexAuthError::InvalidUrl(..))
+        ));
+        assert!(matches!(
+            "https://example.com/simple=bearer:".parse::<IndexAuth>(),
+            Err(IndexAuthError::InvalidToken)
+        ));
+    }
+
+    #[test]
+    fn index_auth_env_var() {
+        let url = Url::parse("https://us-python.pkg.dev/project/repo/simple").unwrap();
+        assert_eq!(
+            IndexAuth::env_var(&url).as_deref(),
+            Some("UV_INDEX_US_PYTHON_PKG_DEV_TOKEN")
+        );
+    }
+
+    #[test]
+    fn debug_redacts_token() {
+        let auth = "https://example.com/simple=bearer:secret"
+            .parse::<IndexAuth>()
+            .unwrap();
+        assert!(!format!("{auth:?}").contains("secret"));
+    }
+}
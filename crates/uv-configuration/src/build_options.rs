@@ -40,6 +40,8 @@ pub enum BuildOutput {
 pub struct BuildOptions {
     no_binary: NoBinary,
     no_build: NoBuild,
+    #[serde(default)]
+    prefer_sdist: Vec<PackageName>,
 }
 
 impl BuildOptions {
@@ -47,14 +49,47 @@ impl BuildOptions {
         Self {
             no_binary,
             no_build,
+            prefer_sdist: Vec::new(),
         }
     }
 
+    /// Prefer building the given packages from source, even if a compatible wheel exists.
+    ///
+    /// Unlike `--no-binary`, the wheel is still used if the source distribution fails to build.
+    #[must_use]
+    pub fn with_prefer_sdist(self, prefer_sdist: Vec<PackageName>) -> Self {
+        Self {
+            prefer_sdist,
+            ..self
+        }
+    }
+
+    /// Combine the [`BuildOptions`] with the `--no-binary` and `--only-binary` settings from a
+    /// requirements file.
+    ///
+    /// Packages named explicitly in `self` take precedence over conflicting settings from the
+    /// requirements file: for example, `--only-binary foo` on the command line drops `foo` from the
+    /// file's `--no-binary` settings, rather than disallowing both wheels and builds for `foo`.
     #[must_use]
     pub fn combine(self, no_binary: NoBinary, no_build: NoBuild) -> Self {
+        let no_binary = match (&self.no_build, no_binary) {
+            (NoBuild::Packages(packages), NoBinary::Packages(mut no_binary)) => {
+                no_binary.retain(|package| !packages.contains(package));
+                NoBinary::from_args(None, no_binary)
+            }
+            (_, no_binary) => no_binary,
+        };
+        let no_build = match (&self.no_binary, no_build) {
+            (NoBinary::Packages(packages), NoBuild::Packages(mut no_build)) => {
+                no_build.retain(|package| !packages.contains(package));
+                NoBuild::from_args(None, no_build)
+            }
+            (_, no_build) => no_build,
+        };
         Self {
             no_binary: self.no_binary.combine(no_binary),
             no_build: self.no_build.combine(no_build),
+            prefer_sdist: self.prefer_sdist,
         }
     }
 
@@ -82,6 +117,12 @@ impl BuildOptions {
         }
     }
 
+    /// Returns `true` if the package should be built from source, if possible, rather than
+    /// installed from a wheel.
+    pub fn prefer_sdist_package(&self, package_name: &PackageName) -> bool {
+        self.prefer_sdist.contains(package_name) && !self.no_build_package(package_name)
+    }
+
     pub fn no_build_requirement(&self, package_name: Option<&PackageName>) -> bool {
         match package_name {
             Some(name) => self.no_build_package(name),
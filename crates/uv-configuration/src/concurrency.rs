@@ -15,6 +15,11 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub installs: usize,
+    /// The maximum number of files that may be held open at once by concurrent downloads and
+    /// installs.
+    ///
+    /// Note this value must be non-zero.
+    pub open_files: usize,
 }
 
 impl Default for Concurrency {
@@ -23,6 +28,7 @@ impl Default for Concurrency {
             downloads: Concurrency::DEFAULT_DOWNLOADS,
             builds: Concurrency::threads(),
             installs: Concurrency::threads(),
+            open_files: Concurrency::open_files(),
         }
     }
 }
@@ -31,10 +37,24 @@ impl Concurrency {
     // The default concurrent downloads limit.
     pub const DEFAULT_DOWNLOADS: usize = 50;
 
-    // The default concurrent builds and install limit.
+    // The default concurrent builds, installs, and bytecode compilation limit.
     pub fn threads() -> usize {
         std::thread::available_parallelism()
             .map(NonZeroUsize::get)
             .unwrap_or(1)
     }
+
+    // The default limit on simultaneously-open files: half of the soft limit on open file
+    // descriptors for the current process, leaving the remainder for anything that isn't bounded
+    // by the limit (e.g., cache locks and build subprocesses).
+    pub fn open_files() -> usize {
+        #[cfg(unix)]
+        {
+            let limit = rustix::process::getrlimit(rustix::process::Resource::Nofile);
+            if let Some(current) = limit.current {
+                return usize::try_from(current / 2).unwrap_or(usize::MAX).max(1);
+            }
+        }
+        usize::MAX
+    }
 }
@@ -1,8 +1,10 @@
 use std::{
+    borrow::Cow,
     collections::{btree_map::Entry, BTreeMap},
     str::FromStr,
 };
 use uv_cache_key::CacheKeyHasher;
+use uv_pep508::PackageName;
 
 #[derive(Debug, Clone)]
 pub struct ConfigSettingEntry {
@@ -161,6 +163,56 @@ impl ConfigSettings {
     }
 }
 
+/// Settings to pass to the PEP 517 build backend for specific packages, e.g., as provided via
+/// `--config-settings` on a requirement in a `requirements.txt` file.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct PackageConfigSettings(BTreeMap<PackageName, ConfigSettings>);
+
+impl PackageConfigSettings {
+    /// Returns `true` if no package-specific settings are present.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Add settings for the given package, combining them with any existing settings.
+    pub fn insert(&mut self, package_name: PackageName, config_settings: ConfigSettings) {
+        match self.0.entry(package_name) {
+            Entry::Vacant(vacant) => {
+                vacant.insert(config_settings);
+            }
+            Entry::Occupied(mut occupied) => {
+                let existing = std::mem::take(occupied.get_mut());
+                occupied.insert(existing.merge(config_settings));
+            }
+        }
+    }
+
+    /// Add all settings from another [`PackageConfigSettings`].
+    pub fn extend(&mut self, other: PackageConfigSettings) {
+        for (package_name, config_settings) in other.0 {
+            self.insert(package_name, config_settings);
+        }
+    }
+
+    /// Return the settings to use when building the given package.
+    ///
+    /// Any package-specific settings take precedence over the global settings with the same key.
+    pub fn resolve<'a>(
+        &self,
+        package_name: Option<&PackageName>,
+        global: &'a ConfigSettings,
+    ) -> Cow<'a, ConfigSettings> {
+        let Some(package) = package_name.and_then(|package_name| self.0.get(package_name)) else {
+            return Cow::Borrowed(global);
+        };
+        let mut config = global.0.clone();
+        for (key, value) in &package.0 {
+            config.insert(key.clone(), value.clone());
+        }
+        Cow::Owned(ConfigSettings(config))
+    }
+}
+
 impl uv_cache_key::CacheKey for ConfigSettings {
     fn cache_key(&self, state: &mut CacheKeyHasher) {
         for (key, value) in &self.0 {
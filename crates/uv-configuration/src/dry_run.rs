@@ -0,0 +1,30 @@
+/// Whether an operation should modify the environment, or only report the changes it would make.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum DryRun {
+    /// The operation should execute normally, modifying the environment.
+    #[default]
+    Disabled,
+    /// The operation should report the changes it would make, without modifying the environment.
+    Enabled,
+    /// The operation should report the changes it would make, without modifying the environment,
+    /// and fail if any changes would be made.
+    Check,
+}
+
+impl DryRun {
+    /// Return the [`DryRun`] mode from the command-line arguments.
+    pub fn from_args(dry_run: bool, check: bool) -> Self {
+        if check {
+            Self::Check
+        } else if dry_run {
+            Self::Enabled
+        } else {
+            Self::Disabled
+        }
+    }
+
+    /// Returns `true` if the environment should be left untouched.
+    pub fn enabled(self) -> bool {
+        matches!(self, Self::Enabled | Self::Check)
+    }
+}
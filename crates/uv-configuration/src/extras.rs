@@ -1,6 +1,6 @@
 use uv_normalize::ExtraName;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, serde::Serialize)]
 pub enum ExtrasSpecification {
     #[default]
     None,
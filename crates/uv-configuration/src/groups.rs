@@ -0,0 +1,51 @@
+use uv_normalize::GroupName;
+
+/// The dependency groups (as defined by `dependency-groups` in a `pyproject.toml`) to include when
+/// installing or exporting a project, as requested via `--group`, `--all-groups`, and
+/// `--no-default-groups`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupsSpecification {
+    /// Include all dependency groups.
+    All,
+    /// Include the given dependency groups, in addition to the default groups.
+    Some(Vec<GroupName>),
+    /// Include the given dependency groups, excluding the default groups.
+    Exclude(Vec<GroupName>),
+}
+
+impl Default for GroupsSpecification {
+    fn default() -> Self {
+        Self::Some(Vec::new())
+    }
+}
+
+impl GroupsSpecification {
+    /// Determine the groups specification to use based on the command-line arguments.
+    pub fn from_args(group: Vec<GroupName>, all_groups: bool, no_default_groups: bool) -> Self {
+        if all_groups {
+            Self::All
+        } else if no_default_groups {
+            Self::Exclude(group)
+        } else {
+            Self::Some(group)
+        }
+    }
+
+    /// Returns `true` if the specification only includes the default groups.
+    pub fn is_default(&self) -> bool {
+        matches!(self, Self::Some(groups) if groups.is_empty())
+    }
+
+    /// Returns `true` if the default groups should be included.
+    pub fn include_defaults(&self) -> bool {
+        matches!(self, Self::All | Self::Some(_))
+    }
+
+    /// Returns the dependency groups that were explicitly requested, if any.
+    pub fn names(&self) -> &[GroupName] {
+        match self {
+            Self::All => &[],
+            Self::Some(groups) | Self::Exclude(groups) => groups,
+        }
+    }
+}
@@ -4,12 +4,16 @@ pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
 pub use dev::*;
+pub use dry_run::*;
 pub use editable::*;
 pub use export_format::*;
 pub use extras::*;
+pub use groups::*;
 pub use hash::*;
 pub use install_options::*;
+pub use line_ending::*;
 pub use name_specifiers::*;
+pub use output_format::*;
 pub use overrides::*;
 pub use package_options::*;
 pub use preview::*;
@@ -25,12 +29,16 @@ mod concurrency;
 mod config_settings;
 mod constraints;
 mod dev;
+mod dry_run;
 mod editable;
 mod export_format;
 mod extras;
+mod groups;
 mod hash;
 mod install_options;
+mod line_ending;
 mod name_specifiers;
+mod output_format;
 mod overrides;
 mod package_options;
 mod preview;
@@ -0,0 +1,74 @@
+/// The line ending to use when writing the output of `uv pip compile` to a file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum LineEnding {
+    /// Use Unix-style line endings (`\n`).
+    #[default]
+    Lf,
+    /// Use Windows-style line endings (`\r\n`).
+    Crlf,
+    /// Use the native line endings for the current platform.
+    Native,
+}
+
+impl LineEnding {
+    /// Returns the line terminator for the [`LineEnding`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::Crlf => "\r\n",
+            Self::Native => {
+                if cfg!(windows) {
+                    "\r\n"
+                } else {
+                    "\n"
+                }
+            }
+        }
+    }
+
+    /// Normalize the line endings in the given text, ensuring that it ends with exactly one
+    /// trailing newline.
+    ///
+    /// Input that is empty, or that contains only line terminators, is normalized to an empty
+    /// string.
+    pub fn normalize(self, contents: &str) -> String {
+        let contents = contents.trim_end_matches(['\r', '\n']);
+        if contents.is_empty() {
+            return String::new();
+        }
+        let newline = self.as_str();
+        let mut normalized = String::with_capacity(contents.len() + newline.len());
+        for line in contents.lines() {
+            normalized.push_str(line);
+            normalized.push_str(newline);
+        }
+        normalized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(LineEnding::Lf.normalize("a\r\nb"), "a\nb\n");
+        assert_eq!(LineEnding::Crlf.normalize("a\nb\n\n"), "a\r\nb\r\n");
+    }
+
+    #[test]
+    fn normalize_empty() {
+        assert_eq!(LineEnding::Lf.normalize(""), "");
+        assert_eq!(LineEnding::Crlf.normalize(""), "");
+    }
+
+    #[test]
+    fn normalize_newlines_only() {
+        assert_eq!(LineEnding::Lf.normalize("\n"), "");
+        assert_eq!(LineEnding::Lf.normalize("\r\n\n"), "");
+        assert_eq!(LineEnding::Crlf.normalize("\n\n"), "");
+    }
+}
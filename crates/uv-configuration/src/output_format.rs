@@ -0,0 +1,44 @@
+use std::path::Path;
+
+/// The format to use when writing the output of `uv pip compile`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum OutputFormat {
+    /// Write the resolution in `requirements.txt` format.
+    #[default]
+    RequirementsTxt,
+    /// Write the resolution as a JSON document.
+    Json,
+    /// Write the resolution as a PEP 751 `pylock.toml` file.
+    Pylock,
+}
+
+impl OutputFormat {
+    /// Infer the [`OutputFormat`] from the path to an output file.
+    ///
+    /// Files with a `.json` extension are written as JSON, and files named `pylock.toml` (or
+    /// `pylock.<name>.toml`) are written as PEP 751 lockfiles. All other files are written in
+    /// `requirements.txt` format.
+    ///
+    /// When reading, only JSON files written by `uv pip compile` are accepted; any other JSON
+    /// document is rejected, rather than interpreted as a resolution.
+    pub fn from_path(path: &Path) -> Self {
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            return Self::RequirementsTxt;
+        };
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+        {
+            Self::Json
+        } else if file_name == "pylock.toml"
+            || (file_name.starts_with("pylock.") && file_name.ends_with(".toml"))
+        {
+            Self::Pylock
+        } else {
+            Self::RequirementsTxt
+        }
+    }
+}
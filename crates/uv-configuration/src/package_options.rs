@@ -161,6 +161,58 @@ impl Upgrade {
     }
 }
 
+/// The strategy to use when upgrading the packages provided via `--upgrade-package`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum UpgradeStrategy {
+    /// Upgrade each package to the highest compatible version.
+    #[default]
+    Eager,
+    /// Retain each package's existing pin, if compatible, or otherwise upgrade it to the lowest
+    /// compatible version.
+    Minimal,
+}
+
+/// Whether to allow yanked versions of packages to be selected during resolution.
+///
+/// Regardless of this setting, yanked versions are always allowed when pinned exactly (e.g.,
+/// `flask==1.0.0`) or when already pinned in an existing lockfile.
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub enum AllowYanked {
+    /// Only allow yanked versions that are pinned exactly.
+    #[default]
+    None,
+
+    /// Allow yanked versions for all packages.
+    All,
+
+    /// Allow yanked versions, but only for the specified packages.
+    Packages(Vec<PackageName>),
+}
+
+impl AllowYanked {
+    /// Determine the [`AllowYanked`] strategy from the command-line arguments.
+    pub fn from_args(allow_yanked: bool, allow_yanked_package: Vec<PackageName>) -> Self {
+        if allow_yanked {
+            Self::All
+        } else if allow_yanked_package.is_empty() {
+            Self::None
+        } else {
+            Self::Packages(allow_yanked_package)
+        }
+    }
+
+    /// Returns `true` if any yanked version of the specified package may be selected.
+    pub fn contains(&self, package_name: &PackageName) -> bool {
+        match &self {
+            Self::None => false,
+            Self::All => true,
+            Self::Packages(packages) => packages.contains(package_name),
+        }
+    }
+}
+
 /// Create a [`Refresh`] policy by integrating the [`Upgrade`] policy.
 impl From<Upgrade> for Refresh {
     fn from(value: Upgrade) -> Self {
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+
+use uv_distribution_types::{DistributionMetadata, Name, Resolution, VersionOrUrlRef};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::MarkerTree;
+use uv_pypi_types::{Requirement, RequirementSource};
+
+/// The versions of the build requirements used to build each source distribution, keyed by the
+/// name of the package being built.
+///
+/// When building a package with a recorded entry, the build requirements are pinned to the
+/// recorded versions, such that the build environment can be reproduced across runs.
+#[derive(Debug, Default)]
+pub struct BuildLock(Mutex<BTreeMap<PackageName, BTreeMap<PackageName, Version>>>);
+
+impl BuildLock {
+    /// Read a [`BuildLock`] from a TOML file, or return an empty lock if the file doesn't exist.
+    pub fn read(path: &Path) -> Result<Self> {
+        let contents = match fs_err::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err.into()),
+        };
+        let pins = toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+        Ok(Self(Mutex::new(pins)))
+    }
+
+    /// Write the [`BuildLock`] to a TOML file.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let contents = toml::to_string(&*self.0.lock().unwrap())?;
+        fs_err::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Return the recorded build requirements for the given package, as exact pins.
+    pub(crate) fn constraints(&self, package: &PackageName) -> Vec<Requirement> {
+        let pins = self.0.lock().unwrap();
+        let Some(requirements) = pins.get(package) else {
+            return vec![];
+        };
+        requirements
+            .iter()
+            .map(|(name, version)| Requirement {
+                name: name.clone(),
+                extras: vec![],
+                marker: MarkerTree::TRUE,
+                source: RequirementSource::Registry {
+                    specifier: VersionSpecifiers::from(VersionSpecifier::equals_version(
+                        version.clone(),
+                    )),
+                    index: None,
+                },
+                origin: None,
+            })
+            .collect()
+    }
+
+    /// Record the resolved build requirements for the given package.
+    pub(crate) fn record(&self, package: &PackageName, resolution: &Resolution) {
+        let requirements = resolution
+            .distributions()
+            .filter_map(|dist| match dist.version_or_url() {
+                VersionOrUrlRef::Version(version) => Some((dist.name().clone(), version.clone())),
+                VersionOrUrlRef::Url(_) => None,
+            })
+            .collect();
+        self.0.lock().unwrap().insert(package.clone(), requirements);
+    }
+}
@@ -2,29 +2,32 @@
 //! [installer][`uv_installer`] and [build][`uv_build`] through [`BuildDispatch`]
 //! implementing [`BuildContext`].
 
+use std::borrow::Cow;
 use std::ffi::{OsStr, OsString};
 use std::path::Path;
 
 use anyhow::{anyhow, Context, Result};
 use futures::FutureExt;
 use itertools::Itertools;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, instrument};
 
 use uv_build_frontend::{SourceBuild, SourceBuildContext};
 use uv_cache::Cache;
 use uv_client::RegistryClient;
 use uv_configuration::{
-    BuildKind, BuildOptions, ConfigSettings, Constraints, IndexStrategy, Reinstall, SourceStrategy,
+    BuildKind, BuildOptions, ConfigSettings, Constraints, IndexStrategy, PackageConfigSettings,
+    Reinstall, SourceStrategy,
 };
 use uv_configuration::{BuildOutput, Concurrency};
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
-    CachedDist, DependencyMetadata, IndexCapabilities, IndexLocations, Name, Resolution,
-    SourceDist, VersionOrUrlRef,
+    CachedDist, DependencyMetadata, DistributionId, Identifier, IndexCapabilities, IndexLocations,
+    Name, Resolution, SourceDist, VersionOrUrlRef,
 };
 use uv_git::GitResolver;
 use uv_installer::{Installer, Plan, Planner, Preparer, SitePackages};
+use uv_normalize::PackageName;
 use uv_pypi_types::Requirement;
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_resolver::{
@@ -33,8 +36,13 @@ use uv_resolver::{
 };
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 
+pub use crate::build_lock::BuildLock;
+
+mod build_lock;
+
 /// The main implementation of [`BuildContext`], used by the CLI, see [`BuildContext`]
 /// documentation.
+#[derive(Clone)]
 pub struct BuildDispatch<'a> {
     client: &'a RegistryClient,
     cache: &'a Cache,
@@ -52,12 +60,21 @@ pub struct BuildDispatch<'a> {
     link_mode: uv_install_wheel::linker::LinkMode,
     build_options: &'a BuildOptions,
     config_settings: &'a ConfigSettings,
+    config_settings_package: PackageConfigSettings,
     hasher: &'a HashStrategy,
     exclude_newer: Option<ExcludeNewer>,
     source_build_context: SourceBuildContext,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
     sources: SourceStrategy,
     concurrency: Concurrency,
+    /// The source distributions that are currently being built in this chain of nested builds,
+    /// used to detect cyclic build dependencies.
+    build_stack: FxHashSet<DistributionId>,
+    /// The pinned build requirements to reuse, and to which any resolved build requirements are
+    /// recorded.
+    build_lock: Option<&'a BuildLock>,
+    /// The package whose build requirements are resolved by this [`BuildDispatch`], if any.
+    build_package: Option<PackageName>,
 }
 
 impl<'a> BuildDispatch<'a> {
@@ -97,6 +114,7 @@ impl<'a> BuildDispatch<'a> {
             in_flight,
             index_strategy,
             config_settings,
+            config_settings_package: PackageConfigSettings::default(),
             build_isolation,
             link_mode,
             build_options,
@@ -106,6 +124,9 @@ impl<'a> BuildDispatch<'a> {
             build_extra_env_vars: FxHashMap::default(),
             sources,
             concurrency,
+            build_stack: FxHashSet::default(),
+            build_lock: None,
+            build_package: None,
         }
     }
 
@@ -123,6 +144,43 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Set the package-specific [`ConfigSettings`] to use when building source distributions.
+    #[must_use]
+    pub fn with_config_settings_package(
+        mut self,
+        config_settings_package: PackageConfigSettings,
+    ) -> Self {
+        self.config_settings_package = config_settings_package;
+        self
+    }
+
+    /// The package-specific [`ConfigSettings`] to use when building source distributions.
+    pub fn config_settings_package(&self) -> &PackageConfigSettings {
+        &self.config_settings_package
+    }
+
+    /// Set the [`BuildLock`] used to pin and record the build requirements of each source
+    /// distribution.
+    #[must_use]
+    pub fn with_build_lock(mut self, build_lock: Option<&'a BuildLock>) -> Self {
+        self.build_lock = build_lock;
+        self
+    }
+
+    /// Create a [`BuildDispatch`] for the build requirements of a source distribution, tracking
+    /// the given stack of in-progress builds.
+    fn with_build_stack(
+        &self,
+        build_stack: FxHashSet<DistributionId>,
+        build_package: PackageName,
+    ) -> Self {
+        Self {
+            build_stack,
+            build_package: Some(build_package),
+            ..self.clone()
+        }
+    }
 }
 
 impl<'a> BuildContext for BuildDispatch<'a> {
@@ -152,6 +210,11 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         self.config_settings
     }
 
+    fn config_settings_for(&self, package_name: Option<&PackageName>) -> Cow<'_, ConfigSettings> {
+        self.config_settings_package
+            .resolve(package_name, self.config_settings)
+    }
+
     fn sources(&self) -> SourceStrategy {
         self.sources
     }
@@ -165,8 +228,20 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         let markers = self.interpreter.resolver_markers();
         let tags = self.interpreter.tags()?;
 
+        // If the build requirements of this package were recorded in a previous run, pin them to
+        // the recorded versions.
+        let constraints = match (self.build_lock, self.build_package.as_ref()) {
+            (Some(build_lock), Some(package)) => Constraints::from_requirements(
+                self.constraints
+                    .requirements()
+                    .cloned()
+                    .chain(build_lock.constraints(package)),
+            ),
+            _ => self.constraints.clone(),
+        };
+
         let resolver = Resolver::new(
-            Manifest::simple(requirements.to_vec()).with_constraints(self.constraints.clone()),
+            Manifest::simple(requirements.to_vec()).with_constraints(constraints),
             OptionsBuilder::new()
                 .exclude_newer(self.exclude_newer)
                 .index_strategy(self.index_strategy)
@@ -190,7 +265,13 @@ impl<'a> BuildContext for BuildDispatch<'a> {
                     .join(", ")
             )
         })?;
-        Ok(Resolution::from(graph))
+        let resolution = Resolution::from(graph);
+
+        if let (Some(build_lock), Some(package)) = (self.build_lock, self.build_package.as_ref()) {
+            build_lock.record(package, &resolution);
+        }
+
+        Ok(resolution)
     }
 
     #[instrument(
@@ -232,11 +313,24 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             self.hasher,
             self.index_locations,
             self.config_settings,
+            &self.config_settings_package,
             self.cache(),
             venv,
             tags,
         )?;
 
+        // Refuse to build any distribution that's already being built further up the stack, which
+        // would otherwise wait on itself indefinitely.
+        if let Some(dist) = remote
+            .iter()
+            .find(|dist| self.build_stack.contains(&dist.distribution_id()))
+        {
+            return Err(anyhow!(
+                "Cyclic build dependency detected: `{}` requires itself to be built",
+                dist.name()
+            ));
+        }
+
         // Nothing to do.
         if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() {
             debug!("No build requirements to install for build");
@@ -247,7 +341,6 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         let wheels = if remote.is_empty() {
             vec![]
         } else {
-            // TODO(konstin): Check that there is no endless recursion.
             let preparer = Preparer::new(
                 self.cache,
                 tags,
@@ -339,16 +432,36 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             return Err(anyhow!("Building source distributions is disabled"));
         }
 
+        // Guard against cyclic build dependencies, e.g., a package that requires itself (directly
+        // or transitively) in order to be built. The build requirements are resolved and installed
+        // with a [`BuildDispatch`] that tracks the distributions that are currently being built.
+        let nested;
+        let build_context = if let Some(dist) = dist {
+            let id = dist.distribution_id();
+            if self.build_stack.contains(&id) {
+                return Err(anyhow!(
+                    "Cyclic build dependency detected: `{}` requires itself to be built",
+                    dist.name()
+                ));
+            }
+            let mut build_stack = self.build_stack.clone();
+            build_stack.insert(id);
+            nested = self.with_build_stack(build_stack, dist.name().clone());
+            &nested
+        } else {
+            self
+        };
+
         let builder = SourceBuild::setup(
             source,
             subdirectory,
             dist_name,
             dist_version,
             self.interpreter,
-            self,
+            build_context,
             self.source_build_context.clone(),
             version_id,
-            self.config_settings.clone(),
+            self.config_settings_for(dist_name).into_owned(),
             self.build_isolation,
             build_kind,
             self.build_extra_env_vars.clone(),
@@ -406,11 +406,7 @@ impl<'a> IndexLocations {
 
     /// Clone the index locations into a [`IndexUrls`] instance.
     pub fn index_urls(&'a self) -> IndexUrls {
-        IndexUrls {
-            index: self.index.clone(),
-            extra_index: self.extra_index.clone(),
-            no_index: self.no_index,
-        }
+        IndexUrls::new(self.index.clone(), self.extra_index.clone(), self.no_index)
     }
 
     /// Return an iterator over all [`Url`] entries.
@@ -426,11 +422,13 @@ impl<'a> IndexLocations {
 
 /// The index URLs to use for fetching packages.
 ///
-/// From a pip perspective, this type merges `--index-url` and `--extra-index-url`.
+/// From a pip perspective, this type merges `--index-url` and `--extra-index-url`, along with any
+/// `--index-mirror` entries that serve as failovers for the main index.
 #[derive(Debug, Clone)]
 pub struct IndexUrls {
     index: Option<IndexUrl>,
     extra_index: Vec<IndexUrl>,
+    mirrors: Vec<IndexUrl>,
     no_index: bool,
 }
 
@@ -440,11 +438,28 @@ impl Default for IndexUrls {
         Self {
             index: Some(DEFAULT_INDEX_URL.clone()),
             extra_index: Vec::new(),
+            mirrors: Vec::new(),
             no_index: false,
         }
     }
 }
 
+impl IndexUrls {
+    /// Determine the index URLs to use for fetching packages.
+    ///
+    /// If `index` is `None`, the `PyPI` index is used as the main index (unless `no_index` is set).
+    /// Unlike the command-line interface, no environment variables or configuration files are
+    /// consulted, so multiple [`IndexUrls`] can be configured independently within one process.
+    pub fn new(index: Option<IndexUrl>, extra_index: Vec<IndexUrl>, no_index: bool) -> Self {
+        Self {
+            index,
+            extra_index,
+            mirrors: Vec::new(),
+            no_index,
+        }
+    }
+}
+
 impl<'a> IndexUrls {
     /// Return the fallback [`IndexUrl`] entry.
     ///
@@ -480,15 +495,29 @@ impl<'a> IndexUrls {
     pub fn indexes(&'a self) -> impl Iterator<Item = &'a IndexUrl> + 'a {
         self.extra_index().chain(self.index())
     }
+
+    /// Set the mirrors of the main index, to be tried in order if the main index is unavailable.
+    #[must_use]
+    pub fn with_mirrors(mut self, mirrors: Vec<IndexUrl>) -> Self {
+        self.mirrors = mirrors;
+        self
+    }
+
+    /// Return the mirrors of the given [`IndexUrl`], in the order in which they should be tried.
+    ///
+    /// Only the main index can be mirrored; for any other index, this returns an empty slice.
+    pub fn mirrors(&'a self, index: &IndexUrl) -> &'a [IndexUrl] {
+        if self.index() == Some(index) {
+            &self.mirrors
+        } else {
+            &[]
+        }
+    }
 }
 
 impl From<IndexLocations> for IndexUrls {
     fn from(locations: IndexLocations) -> Self {
-        Self {
-            index: locations.index,
-            extra_index: locations.extra_index,
-            no_index: locations.no_index,
-        }
+        Self::new(locations.index, locations.extra_index, locations.no_index)
     }
 }
 
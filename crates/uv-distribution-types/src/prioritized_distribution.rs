@@ -337,6 +337,16 @@ impl PrioritizedDist {
                     prioritized: self,
                 })
             }
+            // If the best wheel was uploaded after the `--exclude-newer` cutoff, but the source
+            // distribution was not, then the wheel didn't exist as of the cutoff, so use the source
+            // distribution for both resolution and installation.
+            (
+                Some((_, WheelCompatibility::Incompatible(IncompatibleWheel::ExcludeNewer(_)))),
+                Some((sdist, SourceDistCompatibility::Compatible(_))),
+            ) => Some(CompatibleDist::SourceDist {
+                sdist,
+                prioritized: self,
+            }),
             // If we have a compatible source distribution and an incompatible wheel, return the
             // wheel. We assume that all distributions have the same metadata for a given package
             // version. If a compatible source distribution exists, we assume we can build it, but
@@ -395,15 +405,27 @@ impl PrioritizedDist {
 
     /// If this prioritized dist has at least one wheel, then this creates
     /// a built distribution with the best wheel in this prioritized dist.
+    ///
+    /// Any wheels that were uploaded after the `--exclude-newer` cutoff are omitted, unless the
+    /// best wheel is itself one of them.
     pub fn built_dist(&self) -> Option<RegistryBuiltDist> {
-        let best_wheel_index = self.0.best_wheel_index?;
-        let wheels = self
+        let best = self.0.best_wheel_index?;
+        let mut best_wheel_index = 0;
+        let mut wheels = Vec::with_capacity(self.0.wheels.len());
+        for (index, (wheel, compatibility)) in self.0.wheels.iter().enumerate() {
+            if index == best {
+                best_wheel_index = wheels.len();
+            } else if compatibility.is_excluded_newer() {
+                continue;
+            }
+            wheels.push(wheel.clone());
+        }
+        let sdist = self
             .0
-            .wheels
-            .iter()
-            .map(|(wheel, _)| wheel.clone())
-            .collect();
-        let sdist = self.0.source.as_ref().map(|(sdist, _)| sdist.clone());
+            .source
+            .as_ref()
+            .filter(|(_, compatibility)| !compatibility.is_excluded_newer())
+            .map(|(sdist, _)| sdist.clone());
         Some(RegistryBuiltDist {
             wheels,
             best_wheel_index,
@@ -413,6 +435,8 @@ impl PrioritizedDist {
 
     /// If this prioritized dist has an sdist, then this creates a source
     /// distribution.
+    ///
+    /// Any wheels that were uploaded after the `--exclude-newer` cutoff are omitted.
     pub fn source_dist(&self) -> Option<RegistrySourceDist> {
         let mut sdist = self.0.source.as_ref().map(|(sdist, _)| sdist.clone())?;
         assert!(
@@ -423,6 +447,7 @@ impl PrioritizedDist {
             .0
             .wheels
             .iter()
+            .filter(|(_, compatibility)| !compatibility.is_excluded_newer())
             .map(|(wheel, _)| wheel.clone())
             .collect();
         Some(sdist)
@@ -485,6 +510,12 @@ impl WheelCompatibility {
         matches!(self, Self::Compatible(_, _, _))
     }
 
+    /// Return `true` if the wheel was uploaded after the `--exclude-newer` cutoff (or has no upload
+    /// time).
+    pub fn is_excluded_newer(&self) -> bool {
+        matches!(self, Self::Incompatible(IncompatibleWheel::ExcludeNewer(_)))
+    }
+
     /// Return `true` if the current compatibility is more compatible than another.
     ///
     /// Compatible wheels are always higher more compatible than incompatible wheels.
@@ -507,6 +538,15 @@ impl WheelCompatibility {
 }
 
 impl SourceDistCompatibility {
+    /// Return `true` if the source distribution was uploaded after the `--exclude-newer` cutoff
+    /// (or has no upload time).
+    pub fn is_excluded_newer(&self) -> bool {
+        matches!(
+            self,
+            Self::Incompatible(IncompatibleSource::ExcludeNewer(_))
+        )
+    }
+
     /// Return the higher priority compatibility.
     ///
     /// Compatible source distributions are always higher priority than incompatible source distributions.
@@ -588,3 +628,93 @@ impl IncompatibleWheel {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use url::Url;
+    use uv_distribution_filename::{SourceDistExtension, WheelFilename};
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use crate::{File, FileLocation, IndexUrl, UrlString};
+
+    use super::*;
+
+    fn file(filename: &str) -> Box<File> {
+        let url = Url::parse(&format!("https://files.pythonhosted.org/{filename}")).unwrap();
+        Box::new(File {
+            dist_info_metadata: false,
+            filename: filename.to_string(),
+            hashes: vec![],
+            requires_python: None,
+            size: None,
+            upload_time_utc_ms: None,
+            url: FileLocation::AbsoluteUrl(UrlString::from(url)),
+            yanked: None,
+        })
+    }
+
+    fn wheel(filename: &str) -> RegistryBuiltWheel {
+        RegistryBuiltWheel {
+            filename: WheelFilename::from_str(filename).unwrap(),
+            file: file(filename),
+            index: IndexUrl::from_str("https://pypi.org/simple").unwrap(),
+        }
+    }
+
+    fn sdist(filename: &str) -> RegistrySourceDist {
+        RegistrySourceDist {
+            name: PackageName::from_str("example").unwrap(),
+            version: Version::from_str("1.0.0").unwrap(),
+            file: file(filename),
+            ext: SourceDistExtension::TarGz,
+            index: IndexUrl::from_str("https://pypi.org/simple").unwrap(),
+            wheels: vec![],
+        }
+    }
+
+    /// If a version's wheel was uploaded after the `--exclude-newer` cutoff, but its source
+    /// distribution was not, the source distribution should remain usable on its own.
+    #[test]
+    fn exclude_newer_wheel() {
+        let mut prioritized = PrioritizedDist::from_built(
+            wheel("example-1.0.0-py3-none-any.whl"),
+            vec![],
+            WheelCompatibility::Incompatible(IncompatibleWheel::ExcludeNewer(Some(0))),
+        );
+        prioritized.insert_source(
+            sdist("example-1.0.0.tar.gz"),
+            vec![],
+            SourceDistCompatibility::Compatible(HashComparison::Matched),
+        );
+
+        let Some(CompatibleDist::SourceDist { sdist, .. }) = prioritized.get() else {
+            panic!("expected the source distribution to be selected");
+        };
+        assert_eq!(sdist.file.filename, "example-1.0.0.tar.gz");
+
+        // The excluded wheel should not be associated with the source distribution.
+        let sdist = prioritized.source_dist().unwrap();
+        assert!(sdist.wheels.is_empty());
+    }
+
+    /// If every file for a version was uploaded after the `--exclude-newer` cutoff, the version
+    /// should be excluded entirely.
+    #[test]
+    fn exclude_newer_all() {
+        let mut prioritized = PrioritizedDist::from_built(
+            wheel("example-1.0.0-py3-none-any.whl"),
+            vec![],
+            WheelCompatibility::Incompatible(IncompatibleWheel::ExcludeNewer(Some(0))),
+        );
+        prioritized.insert_source(
+            sdist("example-1.0.0.tar.gz"),
+            vec![],
+            SourceDistCompatibility::Incompatible(IncompatibleSource::ExcludeNewer(Some(0))),
+        );
+
+        assert!(prioritized.get().is_none());
+    }
+}
@@ -368,14 +368,21 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             return Ok(ArchiveMetadata::from_metadata23(metadata.clone()));
         }
 
-        // If hash generation is enabled, and the distribution isn't hosted on an index, get the
-        // entire wheel to ensure that the hashes are included in the response. If the distribution
-        // is hosted on an index, the hashes will be included in the simple metadata response.
+        // If hash generation is enabled, and the distribution isn't hosted on an index (or the
+        // index doesn't provide a hash for the requested algorithm), get the entire wheel to ensure
+        // that the hashes are included in the response. Otherwise, the hashes will be included in
+        // the simple metadata response.
         // For hash _validation_, callers are expected to enforce the policy when retrieving the
         // wheel.
         // TODO(charlie): Request the hashes via a separate method, to reduce the coupling in this API.
         if hashes.is_generate() {
-            if dist.file().map_or(true, |file| file.hashes.is_empty()) {
+            let algorithms = hashes.algorithms();
+            if dist.file().map_or(true, |file| {
+                !file
+                    .hashes
+                    .iter()
+                    .any(|hash| algorithms.contains(&hash.algorithm))
+            }) {
                 let wheel = self.get_wheel(dist, hashes).await?;
                 let metadata = wheel.metadata()?;
                 let hashes = wheel.hashes;
@@ -517,6 +524,14 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     hasher.finish().await.map_err(Error::HashExhaustion)?;
                 }
 
+                // Reject the archive before it's persisted, such that a mismatched download is
+                // never served from the cache. The temporary directory is removed on drop.
+                let digests = hashers
+                    .into_iter()
+                    .map(HashDigest::from)
+                    .collect::<Vec<_>>();
+                validate_digests(dist, hashes, &digests)?;
+
                 // Persist the temporary directory to the directory store.
                 let id = self
                     .build_context
@@ -529,10 +544,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(
-                    id,
-                    hashers.into_iter().map(HashDigest::from).collect(),
-                ))
+                Ok(Archive::new(id, digests))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -603,7 +615,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let download = |response: reqwest::Response| {
             async {
-                let size = size.or_else(|| content_length(&response));
+                let expected_size = content_length(&response);
+                let size = size.or(expected_size);
 
                 let progress = self
                     .reporter
@@ -615,12 +628,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
-                // Download the wheel to a temporary file.
+                // Download the wheel to a temporary file, which is removed on drop.
                 let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
                 let mut writer = tokio::io::BufWriter::new(tokio::fs::File::from_std(temp_file));
 
-                match progress {
+                let written = match progress {
                     Some((reporter, progress)) => {
                         // Wrap the reader in a progress reporter. This will report 100% progress
                         // after the download is complete, even if we still have to unzip and hash
@@ -630,12 +643,21 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
                         tokio::io::copy(&mut reader, &mut writer)
                             .await
-                            .map_err(Error::CacheWrite)?;
+                            .map_err(Error::CacheWrite)?
                     }
-                    None => {
-                        tokio::io::copy(&mut reader.compat(), &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
+                    None => tokio::io::copy(&mut reader.compat(), &mut writer)
+                        .await
+                        .map_err(Error::CacheWrite)?,
+                };
+
+                // Reject a truncated download, rather than attempting to unzip it.
+                if let Some(expected) = expected_size {
+                    if written != expected {
+                        return Err(Error::TruncatedDownload {
+                            distribution: dist.to_string(),
+                            expected,
+                            actual: written,
+                        });
                     }
                 }
 
@@ -648,7 +670,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(Error::CacheWrite)?;
 
                 // If no hashes are required, parallelize the unzip operation.
-                let hashes = if hashes.is_none() {
+                let digests = if hashes.is_none() {
                     let file = file.into_std().await;
                     tokio::task::spawn_blocking({
                         let target = temp_dir.path().to_owned();
@@ -674,6 +696,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     hashers.into_iter().map(HashDigest::from).collect()
                 };
 
+                // Reject the archive before it's persisted, such that a mismatched download is
+                // never served from the cache.
+                validate_digests(dist, hashes, &digests)?;
+
                 // Persist the temporary directory to the directory store.
                 let id = self
                     .build_context
@@ -686,7 +712,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(id, hashes))
+                Ok(Archive::new(id, digests))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -902,7 +928,8 @@ impl<'a> ManagedClient<'a> {
         }
     }
 
-    /// Perform a request using the client, respecting the concurrency limit.
+    /// Perform a request using the client, respecting the concurrency limit and the limit on open
+    /// files.
     ///
     /// If the concurrency limit has been reached, this method will wait until a pending
     /// operation completes before executing the closure.
@@ -911,10 +938,28 @@ impl<'a> ManagedClient<'a> {
         F: Future<Output = T>,
     {
         let _permit = self.control.acquire().await.unwrap();
+        // Each request holds a socket open, along with the cache file that the response is read
+        // from or written to.
+        let _files = uv_fs::open_files::acquire(2).await;
         f(self.unmanaged).await
     }
 }
 
+/// Returns an error if the digests computed for a downloaded distribution don't match the
+/// expected hashes.
+fn validate_digests(
+    dist: &BuiltDist,
+    hashes: HashPolicy<'_>,
+    digests: &[HashDigest],
+) -> Result<(), Error> {
+    if let HashPolicy::Validate(expected) = hashes {
+        if !digests.iter().any(|digest| expected.contains(digest)) {
+            return Err(Error::hash_mismatch(dist.to_string(), expected, digests));
+        }
+    }
+    Ok(())
+}
+
 /// Returns the value of the `Content-Length` header from the [`reqwest::Response`], if present.
 fn content_length(response: &reqwest::Response) -> Option<u64> {
     response
@@ -110,6 +110,14 @@ pub enum Error {
     #[error("Failed to hash distribution")]
     HashExhaustion(#[source] std::io::Error),
 
+    /// A download that ended before receiving the number of bytes in its `Content-Length` header.
+    #[error("Download of `{distribution}` was truncated: expected {expected} bytes, but received {actual}")]
+    TruncatedDownload {
+        distribution: String,
+        expected: u64,
+        actual: u64,
+    },
+
     #[error("Hash mismatch for `{distribution}`\n\nExpected:\n{expected}\n\nComputed:\n{actual}")]
     MismatchedHashes {
         distribution: String,
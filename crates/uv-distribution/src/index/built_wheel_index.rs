@@ -1,10 +1,13 @@
 use crate::index::cached_wheel::CachedWheel;
-use crate::source::{HttpRevisionPointer, LocalRevisionPointer, HTTP_REVISION, LOCAL_REVISION};
+use crate::source::{
+    ArchiveDigestPointer, HttpRevisionPointer, LocalRevisionPointer, ARCHIVE_DIGEST, HTTP_REVISION,
+    LOCAL_REVISION,
+};
 use crate::Error;
 use uv_cache::{Cache, CacheBucket, CacheShard, WheelCache};
 use uv_cache_info::CacheInfo;
 use uv_cache_key::cache_digest;
-use uv_configuration::ConfigSettings;
+use uv_configuration::{ConfigSettings, PackageConfigSettings};
 use uv_distribution_types::{
     DirectUrlSourceDist, DirectorySourceDist, GitSourceDist, Hashed, PathSourceDist,
 };
@@ -19,6 +22,7 @@ pub struct BuiltWheelIndex<'a> {
     tags: &'a Tags,
     hasher: &'a HashStrategy,
     build_configuration: &'a ConfigSettings,
+    package_configuration: &'a PackageConfigSettings,
 }
 
 impl<'a> BuiltWheelIndex<'a> {
@@ -28,12 +32,14 @@ impl<'a> BuiltWheelIndex<'a> {
         tags: &'a Tags,
         hasher: &'a HashStrategy,
         build_configuration: &'a ConfigSettings,
+        package_configuration: &'a PackageConfigSettings,
     ) -> Self {
         Self {
             cache,
             tags,
             hasher,
             build_configuration,
+            package_configuration,
         }
     }
 
@@ -63,34 +69,53 @@ impl<'a> BuiltWheelIndex<'a> {
         let cache_shard = cache_shard.shard(revision.id());
 
         // If there are build settings, we need to scope to a cache shard.
-        let cache_shard = if self.build_configuration.is_empty() {
+        let config_settings = self
+            .package_configuration
+            .resolve(Some(&source_dist.name), self.build_configuration);
+        let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(self.build_configuration))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         Ok(self.find(&cache_shard))
     }
     /// Return the most compatible [`CachedWheel`] for a given source distribution at a local path.
     pub fn path(&self, source_dist: &PathSourceDist) -> Result<Option<CachedWheel>, Error> {
-        let cache_shard = self.cache.shard(
+        // Read the digest of the archive contents from the cache. If the archive hasn't been hashed
+        // yet, it hasn't been built either.
+        let Some(pointer) = ArchiveDigestPointer::read_from(self.cache.entry(
             CacheBucket::SourceDistributions,
             WheelCache::Path(&source_dist.url).root(),
-        );
-
-        // Read the revision from the cache.
-        let Some(pointer) = LocalRevisionPointer::read_from(cache_shard.entry(LOCAL_REVISION))?
+            ARCHIVE_DIGEST,
+        ))?
         else {
             return Ok(None);
         };
 
         // If the distribution is stale, omit it from the index.
-        let cache_info =
-            CacheInfo::from_file(&source_dist.install_path).map_err(Error::CacheRead)?;
+        let cache_info = match CacheInfo::from_file(&source_dist.install_path) {
+            Ok(cache_info) => cache_info,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(Error::CacheRead(err)),
+        };
         if cache_info != *pointer.cache_info() {
             return Ok(None);
         }
 
+        // For local archives, cache under the digest of the archive contents, such that the cache
+        // entry is independent of the archive's location.
+        let cache_shard = self.cache.shard(
+            CacheBucket::SourceDistributions,
+            WheelCache::Archive(&pointer.into_digest()).root(),
+        );
+
+        // Read the revision from the cache.
+        let Some(pointer) = LocalRevisionPointer::read_from(cache_shard.entry(LOCAL_REVISION))?
+        else {
+            return Ok(None);
+        };
+
         // Enforce hash-checking by omitting any wheels that don't satisfy the required hashes.
         let revision = pointer.into_revision();
         if !revision.satisfies(self.hasher.get(source_dist)) {
@@ -100,10 +125,13 @@ impl<'a> BuiltWheelIndex<'a> {
         let cache_shard = cache_shard.shard(revision.id());
 
         // If there are build settings, we need to scope to a cache shard.
-        let cache_shard = if self.build_configuration.is_empty() {
+        let config_settings = self
+            .package_configuration
+            .resolve(Some(&source_dist.name), self.build_configuration);
+        let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(self.build_configuration))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         Ok(self
@@ -148,10 +176,13 @@ impl<'a> BuiltWheelIndex<'a> {
         let cache_shard = cache_shard.shard(revision.id());
 
         // If there are build settings, we need to scope to a cache shard.
-        let cache_shard = if self.build_configuration.is_empty() {
+        let config_settings = self
+            .package_configuration
+            .resolve(Some(&source_dist.name), self.build_configuration);
+        let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(self.build_configuration))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         Ok(self
@@ -174,10 +205,13 @@ impl<'a> BuiltWheelIndex<'a> {
         );
 
         // If there are build settings, we need to scope to a cache shard.
-        let cache_shard = if self.build_configuration.is_empty() {
+        let config_settings = self
+            .package_configuration
+            .resolve(Some(&source_dist.name), self.build_configuration);
+        let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(self.build_configuration))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         self.find(&cache_shard)
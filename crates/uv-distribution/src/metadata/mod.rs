@@ -7,6 +7,7 @@ use uv_configuration::SourceStrategy;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pypi_types::{HashDigest, ResolutionMetadata};
+use uv_workspace::pyproject::DependencyGroupError;
 use uv_workspace::WorkspaceError;
 
 pub use crate::metadata::lowering::LoweredRequirement;
@@ -24,6 +25,8 @@ pub enum MetadataError {
     LoweringError(PackageName, #[source] LoweringError),
     #[error(transparent)]
     Lower(#[from] LoweringError),
+    #[error(transparent)]
+    DependencyGroup(#[from] DependencyGroupError),
 }
 
 #[derive(Debug, Clone)]
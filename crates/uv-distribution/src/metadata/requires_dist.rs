@@ -56,7 +56,7 @@ impl RequiresDist {
         project_workspace: &ProjectWorkspace,
         source_strategy: SourceStrategy,
     ) -> Result<Self, MetadataError> {
-        // Collect any `tool.uv.sources` and `tool.uv.dev_dependencies` from `pyproject.toml`.
+        // Collect any `tool.uv.sources` from `pyproject.toml`.
         let empty = BTreeMap::default();
         let sources = match source_strategy {
             SourceStrategy::Enabled => project_workspace
@@ -72,45 +72,57 @@ impl RequiresDist {
         };
 
         let dev_dependencies = {
-            let dev_dependencies = project_workspace
-                .current_project()
-                .pyproject_toml()
+            // Collect the PEP 735 dependency groups, along with `tool.uv.dev-dependencies`, which
+            // are treated as the `dev` group.
+            let pyproject_toml = project_workspace.current_project().pyproject_toml();
+            let mut groups = pyproject_toml.dependency_groups()?;
+            if let Some(dev_dependencies) = pyproject_toml
                 .tool
                 .as_ref()
                 .and_then(|tool| tool.uv.as_ref())
                 .and_then(|uv| uv.dev_dependencies.as_ref())
-                .into_iter()
-                .flatten()
-                .cloned();
-            let dev_dependencies = match source_strategy {
-                SourceStrategy::Enabled => dev_dependencies
-                    .flat_map(|requirement| {
-                        let requirement_name = requirement.name.clone();
-                        LoweredRequirement::from_requirement(
-                            requirement,
-                            &metadata.name,
-                            project_workspace.project_root(),
-                            sources,
-                            project_workspace.workspace(),
-                        )
-                        .map(move |requirement| match requirement {
-                            Ok(requirement) => Ok(requirement.into_inner()),
-                            Err(err) => {
-                                Err(MetadataError::LoweringError(requirement_name.clone(), err))
-                            }
-                        })
-                    })
-                    .collect::<Result<Vec<_>, _>>()?,
-                SourceStrategy::Disabled => dev_dependencies
-                    .into_iter()
-                    .map(uv_pypi_types::Requirement::from)
-                    .collect(),
-            };
-            if dev_dependencies.is_empty() {
-                BTreeMap::default()
-            } else {
-                BTreeMap::from([(DEV_DEPENDENCIES.clone(), dev_dependencies)])
+            {
+                groups
+                    .entry(DEV_DEPENDENCIES.clone())
+                    .or_default()
+                    .extend(dev_dependencies.iter().cloned());
             }
+
+            groups
+                .into_iter()
+                .filter(|(_, requirements)| !requirements.is_empty())
+                .map(|(group, requirements)| {
+                    let requirements = match source_strategy {
+                        SourceStrategy::Enabled => requirements
+                            .into_iter()
+                            .flat_map(|requirement| {
+                                let requirement_name = requirement.name.clone();
+                                LoweredRequirement::from_requirement(
+                                    requirement,
+                                    &metadata.name,
+                                    project_workspace.project_root(),
+                                    sources,
+                                    project_workspace.workspace(),
+                                )
+                                .map(move |requirement| {
+                                    match requirement {
+                                        Ok(requirement) => Ok(requirement.into_inner()),
+                                        Err(err) => Err(MetadataError::LoweringError(
+                                            requirement_name.clone(),
+                                            err,
+                                        )),
+                                    }
+                                })
+                            })
+                            .collect::<Result<Vec<_>, _>>()?,
+                        SourceStrategy::Disabled => requirements
+                            .into_iter()
+                            .map(uv_pypi_types::Requirement::from)
+                            .collect(),
+                    };
+                    Ok((group, requirements))
+                })
+                .collect::<Result<BTreeMap<_, _>, MetadataError>>()?
         };
 
         let requires_dist = metadata.requires_dist.into_iter();
@@ -443,4 +455,85 @@ mod test {
         error: metadata field project not found
         "###);
     }
+
+    #[tokio::test]
+    async fn dependency_groups() -> anyhow::Result<()> {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+
+            [dependency-groups]
+            test = ["pytest"]
+            lint = ["ruff", { include-group = "test" }]
+            dev = ["mypy"]
+
+            [tool.uv]
+            dev-dependencies = ["black"]
+        "#};
+
+        let requires_dist = requires_dist_from_pyproject_toml(input).await?;
+        let groups = requires_dist
+            .dev_dependencies
+            .iter()
+            .map(|(group, requirements)| {
+                (
+                    group.to_string(),
+                    requirements
+                        .iter()
+                        .map(|requirement| requirement.name.to_string())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            [
+                (
+                    "dev".to_string(),
+                    vec!["mypy".to_string(), "black".to_string()]
+                ),
+                (
+                    "lint".to_string(),
+                    vec!["ruff".to_string(), "pytest".to_string()]
+                ),
+                ("test".to_string(), vec!["pytest".to_string()]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dependency_groups_cycle() {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+
+            [dependency-groups]
+            test = ["pytest", { include-group = "lint" }]
+            lint = ["ruff", { include-group = "test" }]
+        "#};
+
+        assert_snapshot!(format_err(input).await, @r###"
+        error: Detected a cycle in `dependency-groups`: lint -> test -> lint
+        "###);
+    }
+
+    #[tokio::test]
+    async fn dependency_groups_missing_include() {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+
+            [dependency-groups]
+            lint = ["ruff", { include-group = "test" }]
+        "#};
+
+        assert_snapshot!(format_err(input).await, @r###"
+        error: Failed to find group `test` included by `lint`
+        "###);
+    }
 }
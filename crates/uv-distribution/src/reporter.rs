@@ -5,28 +5,37 @@ use url::Url;
 use uv_distribution_types::BuildableSource;
 use uv_pep508::PackageName;
 
+/// A reporter for build, checkout, and download events.
+///
+/// Each callback defaults to a no-op.
 pub trait Reporter: Send + Sync {
     /// Callback to invoke when a source distribution build is kicked off.
-    fn on_build_start(&self, source: &BuildableSource) -> usize;
+    fn on_build_start(&self, _source: &BuildableSource) -> usize {
+        0
+    }
 
     /// Callback to invoke when a source distribution build is complete.
-    fn on_build_complete(&self, source: &BuildableSource, id: usize);
+    fn on_build_complete(&self, _source: &BuildableSource, _id: usize) {}
 
     /// Callback to invoke when a repository checkout begins.
-    fn on_checkout_start(&self, url: &Url, rev: &str) -> usize;
+    fn on_checkout_start(&self, _url: &Url, _rev: &str) -> usize {
+        0
+    }
 
     /// Callback to invoke when a repository checkout completes.
-    fn on_checkout_complete(&self, url: &Url, rev: &str, id: usize);
+    fn on_checkout_complete(&self, _url: &Url, _rev: &str, _id: usize) {}
 
     /// Callback to invoke when a download is kicked off.
-    fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize;
+    fn on_download_start(&self, _name: &PackageName, _size: Option<u64>) -> usize {
+        0
+    }
 
     /// Callback to invoke when a download makes progress (i.e. some number of bytes are
     /// downloaded).
-    fn on_download_progress(&self, id: usize, inc: u64);
+    fn on_download_progress(&self, _id: usize, _inc: u64) {}
 
     /// Callback to invoke when a download is complete.
-    fn on_download_complete(&self, name: &PackageName, id: usize);
+    fn on_download_complete(&self, _name: &PackageName, _id: usize) {}
 }
 
 /// A facade for converting from [`Reporter`] to [`uv_git::Reporter`].
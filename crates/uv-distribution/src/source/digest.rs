@@ -0,0 +1,126 @@
+use std::io::Read;
+use std::path::Path;
+
+use uv_cache::CacheEntry;
+use uv_cache_info::CacheInfo;
+use uv_extract::hash::Hasher;
+use uv_fs::write_atomic;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::Error;
+
+/// Compute the SHA-256 digest of a local source distribution archive.
+///
+/// The digest depends only on the bytes of the archive, and is used as a location-independent
+/// cache key for wheels built from it. The build requirements are declared within the archive
+/// itself, so they're covered by the digest; config settings and platform tags are accounted for
+/// further down the cache hierarchy.
+pub(crate) fn archive_digest(path: &Path) -> std::io::Result<String> {
+    let mut file = fs_err::File::open(path)?;
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(HashDigest::from(hasher).digest.into_string())
+}
+
+/// A pointer to the content digest of a local source distribution archive, stored alongside the
+/// [`CacheInfo`] of the archive at the time it was hashed.
+///
+/// Encoded with `MsgPack`, and keyed by the location of the archive, such that the archive only
+/// needs to be re-hashed when it changes.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ArchiveDigestPointer {
+    cache_info: CacheInfo,
+    digest: String,
+}
+
+impl ArchiveDigestPointer {
+    /// Create a new [`ArchiveDigestPointer`].
+    pub(crate) fn new(cache_info: CacheInfo, digest: String) -> Self {
+        Self { cache_info, digest }
+    }
+
+    /// Read an [`ArchiveDigestPointer`] from the cache.
+    pub(crate) fn read_from(path: impl AsRef<Path>) -> Result<Option<Self>, Error> {
+        match fs_err::read(path) {
+            Ok(cached) => Ok(Some(rmp_serde::from_slice::<ArchiveDigestPointer>(
+                &cached,
+            )?)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(Error::CacheRead(err)),
+        }
+    }
+
+    /// Write an [`ArchiveDigestPointer`] to the cache.
+    pub(crate) async fn write_to(&self, entry: &CacheEntry) -> Result<(), Error> {
+        fs_err::tokio::create_dir_all(&entry.dir())
+            .await
+            .map_err(Error::CacheWrite)?;
+        write_atomic(entry.path(), rmp_serde::to_vec(&self)?)
+            .await
+            .map_err(Error::CacheWrite)
+    }
+
+    /// Return the [`CacheInfo`] for the pointer.
+    pub(crate) fn cache_info(&self) -> &CacheInfo {
+        &self.cache_info
+    }
+
+    /// Return the content digest for the pointer.
+    pub(crate) fn into_digest(self) -> String {
+        self.digest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_cache::WheelCache;
+
+    use super::archive_digest;
+
+    #[test]
+    fn archive_digest_is_location_independent() -> std::io::Result<()> {
+        let contents = b"not really a tarball, but the bytes are all that matter";
+
+        let first_dir = tempfile::tempdir()?;
+        let first = first_dir.path().join("nested").join("example-1.0.0.tar.gz");
+        fs_err::create_dir_all(first.parent().unwrap())?;
+        fs_err::write(&first, contents)?;
+
+        let second_dir = tempfile::tempdir()?;
+        let second = second_dir.path().join("example-1.0.0.tar.gz");
+        fs_err::write(&second, contents)?;
+
+        let first = archive_digest(&first)?;
+        let second = archive_digest(&second)?;
+        assert_eq!(first, second);
+        assert_eq!(
+            WheelCache::Archive(&first).root(),
+            WheelCache::Archive(&second).root()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn archive_digest_changes_with_contents() -> std::io::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join("example-1.0.0.tar.gz");
+
+        fs_err::write(&path, b"first")?;
+        let first = archive_digest(&path)?;
+
+        fs_err::write(&path, b"second")?;
+        let second = archive_digest(&path)?;
+
+        assert_ne!(first, second);
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@
 use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock, Mutex};
 
 use crate::distribution_database::ManagedClient;
 use crate::error::Error;
@@ -15,6 +15,7 @@ use crate::{Reporter, RequiresDist};
 use fs_err::tokio as fs;
 use futures::{FutureExt, TryStreamExt};
 use reqwest::Response;
+use rustc_hash::FxHashMap;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{debug, info_span, instrument, warn, Instrument};
 use url::Url;
@@ -39,8 +40,12 @@ use uv_types::{BuildContext, SourceBuildTrait};
 use zip::ZipArchive;
 
 mod built_wheel_metadata;
+mod digest;
 mod revision;
 
+use digest::archive_digest;
+pub(crate) use digest::ArchiveDigestPointer;
+
 /// Fetch and build a source distribution from a remote source, or from a local cache.
 pub(crate) struct SourceDistributionBuilder<'a, T: BuildContext> {
     build_context: &'a T,
@@ -56,6 +61,9 @@ pub(crate) const LOCAL_REVISION: &str = "revision.rev";
 /// The name of the file that contains the cached distribution metadata, encoded via `MsgPack`.
 pub(crate) const METADATA: &str = "metadata.msgpack";
 
+/// The name of the file that contains the content digest for a local archive, encoded via `MsgPack`.
+pub(crate) const ARCHIVE_DIGEST: &str = "digest.msgpack";
+
 impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     /// Initialize a [`SourceDistributionBuilder`] from a [`BuildContext`].
     pub(crate) fn new(build_context: &'a T) -> Self {
@@ -169,19 +177,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .await?
             }
             BuildableSource::Dist(SourceDist::Path(dist)) => {
-                let cache_shard = self.build_context.cache().shard(
-                    CacheBucket::SourceDistributions,
-                    WheelCache::Path(&dist.url).root(),
-                );
-                self.archive(
-                    source,
-                    &PathSourceUrl::from(dist),
-                    &cache_shard,
-                    tags,
-                    hashes,
-                )
-                .boxed_local()
-                .await?
+                let resource = PathSourceUrl::from(dist);
+                let cache_shard = self.archive_shard(&resource).await?;
+                self.archive(source, &resource, &cache_shard, tags, hashes)
+                    .boxed_local()
+                    .await?
             }
             BuildableSource::Url(SourceUrl::Direct(resource)) => {
                 let filename = resource
@@ -220,10 +220,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .await?
             }
             BuildableSource::Url(SourceUrl::Path(resource)) => {
-                let cache_shard = self.build_context.cache().shard(
-                    CacheBucket::SourceDistributions,
-                    WheelCache::Path(resource.url).root(),
-                );
+                let cache_shard = self.archive_shard(resource).await?;
                 self.archive(source, resource, &cache_shard, tags, hashes)
                     .boxed_local()
                     .await?
@@ -325,11 +322,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .await?
             }
             BuildableSource::Dist(SourceDist::Path(dist)) => {
-                let cache_shard = self.build_context.cache().shard(
-                    CacheBucket::SourceDistributions,
-                    WheelCache::Path(&dist.url).root(),
-                );
-                self.archive_metadata(source, &PathSourceUrl::from(dist), &cache_shard, hashes)
+                let resource = PathSourceUrl::from(dist);
+                let cache_shard = self.archive_shard(&resource).await?;
+                self.archive_metadata(source, &resource, &cache_shard, hashes)
                     .boxed_local()
                     .await?
             }
@@ -369,10 +364,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .await?
             }
             BuildableSource::Url(SourceUrl::Path(resource)) => {
-                let cache_shard = self.build_context.cache().shard(
-                    CacheBucket::SourceDistributions,
-                    WheelCache::Path(resource.url).root(),
-                );
+                let cache_shard = self.archive_shard(resource).await?;
                 self.archive_metadata(source, resource, &cache_shard, hashes)
                     .boxed_local()
                     .await?
@@ -429,11 +421,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let source_dist_entry = cache_shard.entry(filename);
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.build_context.config_settings_for(source.name());
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         // If the cache contains a compatible wheel, return it.
@@ -563,11 +555,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         };
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.build_context.config_settings_for(source.name());
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         // Otherwise, we either need to build the metadata.
@@ -724,11 +716,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let source_entry = cache_shard.entry("source");
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.build_context.config_settings_for(source.name());
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         // If the cache contains a compatible wheel, return it.
@@ -855,11 +847,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.build_context.config_settings_for(source.name());
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         // Otherwise, we need to build a wheel.
@@ -889,6 +881,48 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         })
     }
 
+    /// Return the cache shard for a local source distribution archive.
+    ///
+    /// Archives are keyed by the digest of their contents, rather than their location, such that
+    /// wheels built from the same archive can be reused across working directories and machines.
+    async fn archive_shard(&self, resource: &PathSourceUrl<'_>) -> Result<CacheShard, Error> {
+        // Verify that the archive exists.
+        if !resource.path.is_file() {
+            return Err(Error::NotFound(resource.url.clone()));
+        }
+
+        // Determine the last-modified time of the source distribution.
+        let cache_info = CacheInfo::from_file(&resource.path).map_err(Error::CacheRead)?;
+
+        // If we've already hashed the archive at this location, and it hasn't changed since, reuse
+        // the existing digest.
+        let digest_entry = self.build_context.cache().entry(
+            CacheBucket::SourceDistributions,
+            WheelCache::Path(resource.url).root(),
+            ARCHIVE_DIGEST,
+        );
+        let digest = match ArchiveDigestPointer::read_from(&digest_entry)? {
+            Some(pointer) if *pointer.cache_info() == cache_info => pointer.into_digest(),
+            _ => {
+                let digest = tokio::task::spawn_blocking({
+                    let path = resource.path.to_path_buf();
+                    move || archive_digest(&path)
+                })
+                .await?
+                .map_err(Error::CacheRead)?;
+                ArchiveDigestPointer::new(cache_info, digest.clone())
+                    .write_to(&digest_entry)
+                    .await?;
+                digest
+            }
+        };
+
+        Ok(self.build_context.cache().shard(
+            CacheBucket::SourceDistributions,
+            WheelCache::Archive(&digest).root(),
+        ))
+    }
+
     /// Return the [`Revision`] for a local archive, refreshing it if necessary.
     async fn archive_revision(
         &self,
@@ -909,12 +943,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let revision_entry = cache_shard.entry(LOCAL_REVISION);
 
         // If the revision already exists, return it. There's no need to check for freshness, since
-        // we use an exact timestamp.
+        // the cache shard is keyed by the contents of the archive. The archive may live at a
+        // different location than when the revision was written, so report the current cache info.
         if let Some(pointer) = LocalRevisionPointer::read_from(&revision_entry)? {
-            if *pointer.cache_info() == cache_info {
-                if pointer.revision().has_digests(hashes) {
-                    return Ok(pointer);
-                }
+            if pointer.revision().has_digests(hashes) {
+                return Ok(LocalRevisionPointer {
+                    cache_info,
+                    revision: pointer.into_revision(),
+                });
             }
         }
 
@@ -979,11 +1015,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.build_context.config_settings_for(source.name());
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         // If the cache contains a compatible wheel, return it.
@@ -1110,11 +1146,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.build_context.config_settings_for(source.name());
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         // Otherwise, we need to build a wheel.
@@ -1228,11 +1264,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let _lock = lock_shard(&cache_shard).await?;
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.build_context.config_settings_for(source.name());
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         // If the cache contains a compatible wheel, return it.
@@ -1362,11 +1398,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.build_context.config_settings();
+        let config_settings = self.build_context.config_settings_for(source.name());
         let cache_shard = if config_settings.is_empty() {
             cache_shard
         } else {
-            cache_shard.shard(cache_digest(config_settings))
+            cache_shard.shard(cache_digest(&*config_settings))
         };
 
         // Otherwise, we need to build a wheel.
@@ -2153,15 +2189,80 @@ fn read_wheel_metadata(
     Ok(ResolutionMetadata::parse_metadata(&dist_info)?)
 }
 
-/// Apply an advisory lock to a [`CacheShard`] to prevent concurrent builds.
-async fn lock_shard(cache_shard: &CacheShard) -> Result<LockedFile, Error> {
+/// The in-process locks for each [`CacheShard`], keyed by the shard's root, which is unique to a
+/// source distribution (i.e., to a package at a given version, or to a given URL or path).
+///
+/// Concurrent requests for the same source distribution (e.g., from separate resolver forks)
+/// wait on the in-process lock before attempting to acquire the advisory lock, such that only one
+/// build runs at a time, and the remaining requests read the metadata and wheel that it wrote to
+/// the cache. Waiting on the in-process lock also avoids tying up a blocking thread per request,
+/// as waiting on the advisory lock would.
+static SHARD_LOCKS: LazyLock<Mutex<FxHashMap<PathBuf, Arc<tokio::sync::Mutex<()>>>>> =
+    LazyLock::new(Mutex::default);
+
+/// A lock on a [`CacheShard`], held for the duration of a build.
+struct ShardLock {
+    /// The advisory lock, which guards against builds in other processes.
+    _file: LockedFile,
+    /// The in-process lock, which guards against concurrent builds within this process.
+    _local: tokio::sync::OwnedMutexGuard<()>,
+}
+
+/// Apply an in-process lock and an advisory lock to a [`CacheShard`] to prevent concurrent builds.
+async fn lock_shard(cache_shard: &CacheShard) -> Result<ShardLock, Error> {
     let root = cache_shard.as_ref();
 
     fs_err::create_dir_all(root).map_err(Error::CacheWrite)?;
 
-    let lock = LockedFile::acquire(root.join(".lock"), root.display())
+    let local = SHARD_LOCKS
+        .lock()
+        .unwrap()
+        .entry(root.to_path_buf())
+        .or_default()
+        .clone();
+    let local = local.lock_owned().await;
+
+    let file = LockedFile::acquire(root.join(".lock"), root.display())
         .await
         .map_err(Error::CacheWrite)?;
 
-    Ok(lock)
+    Ok(ShardLock {
+        _file: file,
+        _local: local,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use uv_cache::{Cache, CacheBucket};
+
+    use super::lock_shard;
+    use crate::Error;
+
+    /// Concurrent requests for the same source distribution within a process should share a single
+    /// build, with the remaining requests waiting on the lock and then reading the built wheel.
+    #[tokio::test]
+    async fn concurrent_builds_share_shard() -> Result<(), Error> {
+        let cache = Cache::temp().map_err(Error::CacheWrite)?;
+        let cache_shard = cache.shard(CacheBucket::SourceDistributions, "example-1.0.0");
+        let builds = AtomicUsize::new(0);
+
+        let build = || async {
+            let _lock = lock_shard(&cache_shard).await?;
+            let wheel = cache_shard.entry("example-1.0.0-py3-none-any.whl");
+            if !wheel.path().exists() {
+                builds.fetch_add(1, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+                fs_err::write(wheel.path(), "").map_err(Error::CacheWrite)?;
+            }
+            Ok::<(), Error>(())
+        };
+        futures::future::try_join_all((0..8).map(|_| build())).await?;
+
+        assert_eq!(builds.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
 }
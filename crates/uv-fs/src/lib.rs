@@ -7,6 +7,8 @@ use tracing::{debug, error, info, trace, warn};
 pub use crate::path::*;
 
 pub mod cachedir;
+#[cfg(feature = "tokio")]
+pub mod open_files;
 mod path;
 
 /// Reads data from the path and requires that it be valid UTF-8 or UTF-16.
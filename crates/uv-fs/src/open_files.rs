@@ -0,0 +1,119 @@
+//! A process-wide limit on the number of files that may be open at once.
+//!
+//! Concurrent downloads and installs each hold a handful of files (and sockets) open for their
+//! duration. On systems with a low limit on open file descriptors (e.g., `ulimit -n 256`), running
+//! enough of them at once fails with "too many open files". Each such operation acquires a permit
+//! for the files it holds open from a shared counter, such that the total stays within the
+//! configured limit, regardless of how many operations are in flight.
+//!
+//! Downloads wait for permits asynchronously, while installs run on the blocking thread pool and
+//! wait on a condition variable, so that neither parks an executor thread.
+
+use std::sync::{Condvar, Mutex, OnceLock};
+
+use tokio::sync::Notify;
+
+/// The counter bounding the number of simultaneously-open files.
+static OPEN_FILES: OnceLock<OpenFiles> = OnceLock::new();
+
+struct OpenFiles {
+    /// The number of files that may still be opened.
+    available: Mutex<u32>,
+    /// Wakes threads blocked in [`acquire_blocking`].
+    condvar: Condvar,
+    /// Wakes tasks waiting in [`acquire`].
+    notify: Notify,
+    /// The maximum number of files that may be open at once.
+    limit: u32,
+}
+
+impl OpenFiles {
+    fn new(limit: usize) -> Self {
+        let limit = u32::try_from(limit.max(1)).unwrap_or(u32::MAX);
+        Self {
+            available: Mutex::new(limit),
+            condvar: Condvar::new(),
+            notify: Notify::new(),
+            limit,
+        }
+    }
+
+    /// Take `files` permits if they're available.
+    fn try_acquire(&self, files: u32) -> bool {
+        let mut available = self.available.lock().unwrap();
+        if *available >= files {
+            *available -= files;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Return `files` permits, waking any waiters.
+    fn release(&self, files: u32) {
+        *self.available.lock().unwrap() += files;
+        self.condvar.notify_all();
+        self.notify.notify_waiters();
+    }
+}
+
+/// A permit for a number of open files, released on drop.
+#[derive(Debug)]
+#[must_use]
+pub struct OpenFilesPermit {
+    files: u32,
+}
+
+impl Drop for OpenFilesPermit {
+    fn drop(&mut self) {
+        open_files().release(self.files);
+    }
+}
+
+/// Set the maximum number of files that may be open at once.
+///
+/// Must be called before any permits are acquired; otherwise, the limit is left unchanged.
+pub fn set_max_open_files(limit: usize) {
+    if OPEN_FILES.set(OpenFiles::new(limit)).is_err() {
+        tracing::warn!("The limit on open files was already initialized");
+    }
+}
+
+/// Return the shared counter, which is unbounded unless a limit was set.
+fn open_files() -> &'static OpenFiles {
+    OPEN_FILES.get_or_init(|| OpenFiles::new(usize::MAX))
+}
+
+/// Wait until `files` more files may be opened.
+///
+/// The returned permit must be held for as long as the files remain open. Requests for more files
+/// than the limit allows are reduced to the limit, such that they can always make progress.
+pub async fn acquire(files: u32) -> OpenFilesPermit {
+    let open_files = open_files();
+    let files = files.min(open_files.limit);
+    loop {
+        // Register for wakeups before checking, so that a release between the check and the wait
+        // isn't missed.
+        let notified = open_files.notify.notified();
+        tokio::pin!(notified);
+        notified.as_mut().enable();
+        if open_files.try_acquire(files) {
+            return OpenFilesPermit { files };
+        }
+        notified.await;
+    }
+}
+
+/// Wait until `files` more files may be opened, blocking the current thread.
+///
+/// See [`acquire`].
+pub fn acquire_blocking(files: u32) -> OpenFilesPermit {
+    let open_files = open_files();
+    let files = files.min(open_files.limit);
+    let mut available = open_files.available.lock().unwrap();
+    while *available < files {
+        available = open_files.condvar.wait(available).unwrap();
+    }
+    *available -= files;
+    OpenFilesPermit { files }
+}
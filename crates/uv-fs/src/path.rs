@@ -118,8 +118,8 @@ impl<T: AsRef<Path>> PythonExt for T {
 
 /// Normalize the `path` component of a URL for use as a file path.
 ///
-/// For example, on Windows, transforms `C:\Users\ferris\wheel-0.42.0.tar.gz` to
-/// `/C:/Users/ferris/wheel-0.42.0.tar.gz`.
+/// For example, on Windows, transforms `/C:/Users/ferris/wheel-0.42.0.tar.gz` to
+/// `C:\Users\ferris\wheel-0.42.0.tar.gz`.
 ///
 /// On other platforms, this is a no-op.
 pub fn normalize_url_path(path: &str) -> Cow<'_, str> {
@@ -128,16 +128,35 @@ pub fn normalize_url_path(path: &str) -> Cow<'_, str> {
 
     // Return the path.
     if cfg!(windows) {
-        Cow::Owned(
-            path.strip_prefix('/')
-                .unwrap_or(&path)
-                .replace('/', std::path::MAIN_SEPARATOR_STR),
-        )
+        Cow::Owned(normalize_windows_url_path(&path))
     } else {
         path
     }
 }
 
+/// Normalize the (percent-decoded) `path` component of a URL for use as a Windows file path.
+///
+/// Users frequently write Windows paths with backslashes, or with a mix of separators, as in
+/// `file:///C:\Users\ferris/wheel-0.42.0.tar.gz`. Both separators are normalized to backslashes,
+/// and the leading slash is stripped from drive-letter paths, such that `/C:/Users/ferris` and
+/// `/C:\Users\ferris` both become `C:\Users\ferris`.
+///
+/// This is applied by [`normalize_url_path`] on Windows, but is available on all platforms.
+pub fn normalize_windows_url_path(path: &str) -> String {
+    // Ex) `/C:/Users/ferris` or `\C:\Users\ferris`
+    let path = path
+        .strip_prefix(['/', '\\'])
+        .filter(|path| has_drive_letter(path))
+        .unwrap_or(path);
+    path.replace('/', "\\")
+}
+
+/// Returns `true` if the path starts with a drive letter, as in `C:` or `c:\Users`.
+fn has_drive_letter(path: &str) -> bool {
+    let mut chars = path.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic()) && chars.next() == Some(':')
+}
+
 /// Normalize a path, removing things like `.` and `..`.
 ///
 /// Source: <https://github.com/rust-lang/cargo/blob/b48c41aedbd69ee3990d62a0e2006edbb506a480/crates/cargo-util/src/paths.rs#L76C1-L109C2>
@@ -439,6 +458,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_normalize_windows_url() {
+        // Absolute drive-letter paths, with forward slashes, backslashes, or both.
+        assert_eq!(
+            normalize_windows_url_path("/C:/Users/ferris/wheel-0.42.0.tar.gz"),
+            "C:\\Users\\ferris\\wheel-0.42.0.tar.gz"
+        );
+        assert_eq!(
+            normalize_windows_url_path("/C:\\Users\\ferris\\wheel-0.42.0.tar.gz"),
+            "C:\\Users\\ferris\\wheel-0.42.0.tar.gz"
+        );
+        assert_eq!(
+            normalize_windows_url_path("C:\\Users/ferris\\wheel-0.42.0.tar.gz"),
+            "C:\\Users\\ferris\\wheel-0.42.0.tar.gz"
+        );
+        assert_eq!(
+            normalize_windows_url_path("/c:/Users/ferris"),
+            "c:\\Users\\ferris"
+        );
+
+        // Relative paths, with backslashes or mixed separators.
+        assert_eq!(
+            normalize_windows_url_path("..\\ferris/wheel-0.42.0.tar.gz"),
+            "..\\ferris\\wheel-0.42.0.tar.gz"
+        );
+        assert_eq!(
+            normalize_windows_url_path(".\\ferris\\wheel-0.42.0.tar.gz"),
+            ".\\ferris\\wheel-0.42.0.tar.gz"
+        );
+
+        // Root-relative paths retain their leading separator.
+        assert_eq!(
+            normalize_windows_url_path("/Users/ferris"),
+            "\\Users\\ferris"
+        );
+    }
+
     #[test]
     fn test_normalize_path() {
         let path = Path::new("/a/b/../c/./d");
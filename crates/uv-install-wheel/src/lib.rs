@@ -65,6 +65,12 @@ pub enum Error {
     WalkDir(#[from] walkdir::Error),
     #[error("RECORD file doesn't match wheel contents: {0}")]
     RecordFile(String),
+    #[error("Installed file doesn't match the hash in RECORD: {} (expected `{expected}`, computed `{computed}`)", path.user_display())]
+    RecordHashMismatch {
+        path: PathBuf,
+        expected: String,
+        computed: String,
+    },
     #[error("RECORD file is invalid")]
     RecordCsv(#[from] csv::Error),
     #[error("Broken virtualenv: {0}")]
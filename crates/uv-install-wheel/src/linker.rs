@@ -7,8 +7,8 @@ use std::time::SystemTime;
 
 use crate::script::{scripts_from_ini, Script};
 use crate::wheel::{
-    extra_dist_info, install_data, parse_wheel_file, read_record_file, write_script_entrypoints,
-    LibKind,
+    extra_dist_info, install_data, parse_wheel_file, read_record_file, verify_record,
+    write_script_entrypoints, LibKind,
 };
 use crate::{Error, Layout};
 use fs_err as fs;
@@ -44,6 +44,8 @@ pub fn install_wheel(
     cache_info: Option<&CacheInfo>,
     installer: Option<&str>,
     link_mode: LinkMode,
+    dist_info: DistInfoMode,
+    verify: bool,
     locks: &Locks,
 ) -> Result<(), Error> {
     let dist_info_prefix = find_dist_info(&wheel)?;
@@ -140,6 +142,21 @@ pub fn install_wheel(
         debug!(?name, "No data");
     }
 
+    // If requested, check that every installed file matches the hash in the RECORD, to catch any
+    // corruption during extraction or linking.
+    if verify {
+        debug!(?name, "Verifying installed files");
+        verify_record(site_packages, &record)?;
+    }
+
+    // If requested, omit the `.dist-info` directory entirely (e.g., for vendoring). The scripts
+    // and data have already been installed, so there's nothing left to record.
+    if dist_info == DistInfoMode::Omit {
+        debug!(?name, "Removing dist-info directory");
+        fs::remove_dir_all(site_packages.join(format!("{dist_info_prefix}.dist-info")))?;
+        return Ok(());
+    }
+
     debug!(?name, "Writing extra metadata");
     extra_dist_info(
         site_packages,
@@ -230,6 +247,27 @@ fn parse_scripts(
     scripts_from_ini(extras, python_minor, ini)
 }
 
+/// Whether to write the `.dist-info` directory when installing a wheel.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DistInfoMode {
+    /// Write the `.dist-info` directory, such that the package can be detected and uninstalled.
+    #[default]
+    Write,
+    /// Omit the `.dist-info` directory (e.g., when vendoring packages into a `--target` directory).
+    Omit,
+}
+
+impl DistInfoMode {
+    /// Determine the [`DistInfoMode`] based on the command-line arguments.
+    pub fn from_args(no_dist_info: bool) -> Self {
+        if no_dist_info {
+            Self::Omit
+        } else {
+            Self::Write
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
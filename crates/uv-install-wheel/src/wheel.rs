@@ -11,8 +11,8 @@ use fs_err as fs;
 use fs_err::{DirEntry, File};
 use mailparse::parse_headers;
 use rustc_hash::FxHashMap;
-use sha2::{Digest, Sha256};
-use tracing::{instrument, warn};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use tracing::{instrument, trace, warn};
 use uv_cache_info::CacheInfo;
 use uv_fs::{relative_to, Simplified};
 use uv_normalize::PackageName;
@@ -806,6 +806,56 @@ pub fn read_record_file(record: &mut impl Read) -> Result<Vec<RecordEntry>, Erro
         .collect()
 }
 
+/// Verify that every installed file listed in the RECORD matches its recorded hash.
+///
+/// Paths are resolved relative to `site_packages`. Entries without a hash (like the `RECORD` file
+/// itself) are skipped.
+pub(crate) fn verify_record(site_packages: &Path, record: &[RecordEntry]) -> Result<(), Error> {
+    for entry in record {
+        let Some(expected) = entry.hash.as_deref() else {
+            continue;
+        };
+        let path = site_packages.join(&entry.path);
+        let computed = match expected.split_once('=') {
+            Some(("sha256", _)) => format!("sha256={}", digest_file::<Sha256>(&path)?),
+            Some(("sha384", _)) => format!("sha384={}", digest_file::<Sha384>(&path)?),
+            Some(("sha512", _)) => format!("sha512={}", digest_file::<Sha512>(&path)?),
+            _ => {
+                return Err(Error::RecordFile(format!(
+                    "Unsupported hash for {}: {expected}",
+                    entry.path
+                )));
+            }
+        };
+        if computed != expected {
+            return Err(Error::RecordHashMismatch {
+                path,
+                expected: expected.to_string(),
+                computed,
+            });
+        }
+        trace!("Verified hash for: {}", entry.path);
+    }
+    Ok(())
+}
+
+/// Compute the URL-safe base64 digest of the file at the given path.
+fn digest_file<D: Digest>(path: &Path) -> io::Result<String> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut hasher = D::new();
+    let mut buf = vec![0; 8 * 1024];
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buf[..len]);
+    }
+    Ok(BASE64URL_NOPAD.encode(&hasher.finalize()))
+}
+
 /// Parse a file with email message format such as WHEEL and METADATA
 fn parse_email_message_file(
     file: impl Read,
@@ -851,7 +901,8 @@ mod test {
     use crate::Error;
 
     use super::{
-        get_script_executable, parse_email_message_file, parse_wheel_file, read_record_file, Script,
+        get_script_executable, parse_email_message_file, parse_wheel_file, read_record_file,
+        verify_record, Script,
     };
 
     #[test]
@@ -954,6 +1005,32 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn record_hash_mismatch() -> Result<()> {
+        let site_packages = assert_fs::TempDir::new()?;
+        site_packages
+            .child("wheel/__init__.py")
+            .write_str("__version__ = '0.1.0'\n")?;
+
+        let record = indoc! {"
+            wheel/__init__.py,sha256=IMjkMO3twhQzluVTo8Z6rE7Eg-9U79_LGKMcsWLKBkY,22
+            wheel-0.1.0.dist-info/RECORD,,
+        "};
+        let entries = read_record_file(&mut record.as_bytes())?;
+
+        // The installed file matches the RECORD.
+        verify_record(&site_packages, &entries)?;
+
+        // Corrupt the installed file.
+        site_packages
+            .child("wheel/__init__.py")
+            .write_str("__version__ = '0.2.0'\n")?;
+        let err = verify_record(&site_packages, &entries).unwrap_err();
+        assert!(matches!(err, Error::RecordHashMismatch { .. }), "{err}");
+
+        Ok(())
+    }
+
     #[test]
     fn test_script_from_value() {
         assert_eq!(
@@ -56,8 +56,10 @@ pub enum CompileError {
 /// Bytecode compile all file in `dir` using a pool of Python interpreters running a Python script
 /// that calls `compileall.compile_file`.
 ///
-/// All compilation errors are muted (like pip). There is a 60s timeout for each file to handle
-/// a broken `python`.
+/// Compilation errors for individual files are reported as warnings, but otherwise ignored (like
+/// pip). There is a 60s timeout for each file to handle a broken `python`.
+///
+/// At most `concurrency` interpreters are run at the same time.
 ///
 /// We only compile all files, but we don't update the RECORD, relying on PEP 491:
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
@@ -67,16 +69,14 @@ pub enum CompileError {
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
+    concurrency: usize,
     cache: &Path,
 ) -> Result<usize, CompileError> {
     debug_assert!(
         dir.is_absolute(),
         "compileall doesn't work with relative paths"
     );
-    let worker_count = std::thread::available_parallelism().unwrap_or_else(|err| {
-        warn_user!("Couldn't determine number of cores, compiling with a single thread: {err}");
-        NonZeroUsize::MIN
-    });
+    let worker_count = NonZeroUsize::new(concurrency).unwrap_or(NonZeroUsize::MIN);
 
     // A larger buffer is significantly faster than just 1 or the worker count.
     let (sender, receiver) = async_channel::bounded::<PathBuf>(worker_count.get() * 10);
@@ -361,10 +361,18 @@ async fn worker_main_loop(
 
         // This is a sanity check, if we don't get the path back something has gone wrong, e.g.
         // we're not actually running a python interpreter.
-        let actual = out_line.trim_end_matches(['\n', '\r']);
+        let response = out_line.trim_end_matches(['\n', '\r']);
+        let (success, actual) = if let Some(actual) = response.strip_prefix("Failed: ") {
+            (false, actual)
+        } else {
+            (true, response)
+        };
         if actual != source_file {
             return Err(CompileError::WrongPath(source_file, actual.to_string()));
         }
+        if !success {
+            warn_user!("Failed to compile `{source_file}` to bytecode");
+        }
     }
     Ok(())
 }
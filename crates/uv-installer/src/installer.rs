@@ -3,7 +3,10 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::convert;
 use tokio::sync::oneshot;
 use tracing::instrument;
-use uv_install_wheel::{linker::LinkMode, Layout};
+use uv_install_wheel::{
+    linker::{DistInfoMode, LinkMode},
+    Layout,
+};
 
 use uv_cache::Cache;
 use uv_distribution_types::CachedDist;
@@ -12,6 +15,8 @@ use uv_python::PythonEnvironment;
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
     link_mode: LinkMode,
+    dist_info: DistInfoMode,
+    verify: bool,
     cache: Option<&'a Cache>,
     reporter: Option<Box<dyn Reporter>>,
     installer_name: Option<String>,
@@ -23,6 +28,8 @@ impl<'a> Installer<'a> {
         Self {
             venv,
             link_mode: LinkMode::default(),
+            dist_info: DistInfoMode::default(),
+            verify: false,
             cache: None,
             reporter: None,
             installer_name: Some("uv".to_string()),
@@ -35,6 +42,20 @@ impl<'a> Installer<'a> {
         Self { link_mode, ..self }
     }
 
+    /// Set the [`DistInfoMode`] to use for this installer.
+    ///
+    /// Without the `.dist-info` directory, the installed packages can't be detected or uninstalled.
+    #[must_use]
+    pub fn with_dist_info(self, dist_info: DistInfoMode) -> Self {
+        Self { dist_info, ..self }
+    }
+
+    /// Set whether to verify the hash of each installed file against the wheel's `RECORD`.
+    #[must_use]
+    pub fn with_verify(self, verify: bool) -> Self {
+        Self { verify, ..self }
+    }
+
     /// Set the [`Cache`] to use for this installer.
     #[must_use]
     pub fn with_cache(self, cache: &'a Cache) -> Self {
@@ -69,6 +90,8 @@ impl<'a> Installer<'a> {
             venv,
             cache,
             link_mode,
+            dist_info,
+            verify,
             reporter,
             installer_name,
         } = self;
@@ -91,6 +114,8 @@ impl<'a> Installer<'a> {
                 layout,
                 installer_name,
                 link_mode,
+                dist_info,
+                verify,
                 reporter,
                 relocatable,
             );
@@ -120,6 +145,8 @@ impl<'a> Installer<'a> {
             self.venv.interpreter().layout(),
             self.installer_name,
             self.link_mode,
+            self.dist_info,
+            self.verify,
             self.reporter,
             self.venv.relocatable(),
         )
@@ -133,11 +160,16 @@ fn install(
     layout: Layout,
     installer_name: Option<String>,
     link_mode: LinkMode,
+    dist_info: DistInfoMode,
+    verify: bool,
     reporter: Option<Box<dyn Reporter>>,
     relocatable: bool,
 ) -> Result<Vec<CachedDist>> {
     let locks = uv_install_wheel::linker::Locks::default();
     wheels.par_iter().try_for_each(|wheel| {
+        // Each install holds (at most) the source and target files open at once.
+        let _files = uv_fs::open_files::acquire_blocking(2);
+
         uv_install_wheel::linker::install_wheel(
             &layout,
             relocatable,
@@ -156,6 +188,8 @@ fn install(
             },
             installer_name.as_deref(),
             link_mode,
+            dist_info,
+            verify,
             &locks,
         )
         .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
@@ -170,10 +204,13 @@ fn install(
     Ok(wheels)
 }
 
+/// A reporter for installation events.
+///
+/// Each callback defaults to a no-op.
 pub trait Reporter: Send + Sync {
     /// Callback to invoke when a dependency is installed.
-    fn on_install_progress(&self, wheel: &CachedDist);
+    fn on_install_progress(&self, _wheel: &CachedDist) {}
 
     /// Callback to invoke when the resolution is complete.
-    fn on_install_complete(&self);
+    fn on_install_complete(&self) {}
 }
@@ -63,5 +63,8 @@ with warnings.catch_warnings():
         success = compileall.compile_file(
             path, invalidation_mode=invalidation_mode, force=force, quiet=2
         )
-        # We're ready for the next file.
-        print(path)
+        # We're ready for the next file. Failures are reported back, but don't stop the worker.
+        if success:
+            print(path)
+        else:
+            print(f"Failed: {path}")
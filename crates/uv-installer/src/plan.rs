@@ -3,7 +3,7 @@ use tracing::debug;
 
 use uv_cache::{Cache, CacheBucket, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
-use uv_configuration::{BuildOptions, ConfigSettings, Reinstall};
+use uv_configuration::{BuildOptions, ConfigSettings, PackageConfigSettings, Reinstall};
 use uv_distribution::{
     BuiltWheelIndex, HttpArchivePointer, LocalArchivePointer, RegistryWheelIndex,
 };
@@ -51,13 +51,20 @@ impl<'a> Planner<'a> {
         hasher: &HashStrategy,
         index_locations: &IndexLocations,
         config_settings: &ConfigSettings,
+        config_settings_package: &PackageConfigSettings,
         cache: &Cache,
         venv: &PythonEnvironment,
         tags: &Tags,
     ) -> Result<Plan> {
         // Index all the already-downloaded wheels in the cache.
         let mut registry_index = RegistryWheelIndex::new(cache, tags, index_locations, hasher);
-        let built_index = BuiltWheelIndex::new(cache, tags, hasher, config_settings);
+        let built_index = BuiltWheelIndex::new(
+            cache,
+            tags,
+            hasher,
+            config_settings,
+            config_settings_package,
+        );
 
         let mut cached = vec![];
         let mut remote = vec![];
@@ -75,11 +82,29 @@ impl<'a> Planner<'a> {
             // Check if installation of a binary version of the package should be allowed.
             let no_binary = build_options.no_binary_package(dist.name());
             let no_build = build_options.no_build_package(dist.name());
+            let prefer_sdist = build_options.prefer_sdist_package(dist.name());
 
             let requirement = Requirement::from(dist);
 
-            // Determine whether the distribution is already installed.
-            let installed_dists = site_packages.remove_packages(&requirement.name);
+            // Determine whether the distribution is already installed. Distributions in the base
+            // interpreter's `site-packages` may satisfy the requirement, but are never uninstalled.
+            let (system_dists, installed_dists): (Vec<_>, Vec<_>) = site_packages
+                .remove_packages(&requirement.name)
+                .into_iter()
+                .partition(|distribution| site_packages.is_system(distribution));
+            if !reinstall && installed_dists.is_empty() {
+                if let [distribution] = system_dists.as_slice() {
+                    if matches!(
+                        RequirementSatisfaction::check(distribution, &requirement.source)?,
+                        RequirementSatisfaction::Satisfied
+                    ) {
+                        debug!(
+                            "Requirement already installed in system site-packages: {distribution}"
+                        );
+                        continue;
+                    }
+                }
+            }
             if reinstall {
                 reinstalls.extend(installed_dists);
             } else {
@@ -130,6 +155,11 @@ impl<'a> Planner<'a> {
                         if !entry.built && no_binary {
                             return None;
                         }
+                        // If the package prefers building from source, attempt the build (which
+                        // may itself be cached), rather than reusing a downloaded wheel.
+                        if !entry.built && prefer_sdist && wheel.sdist.is_some() {
+                            return None;
+                        }
                         Some(&entry.dist)
                     }) {
                         debug!("Requirement already cached: {distribution}");
@@ -307,7 +337,9 @@ impl<'a> Planner<'a> {
             remote.push(installable.clone());
         }
 
-        // Remove any unnecessary packages.
+        // Remove any unnecessary packages, retaining those in the base interpreter's
+        // `site-packages`.
+        site_packages.remove_system_packages();
         if site_packages.any() {
             // Retain seed packages unless: (1) the virtual environment was created by uv and
             // (2) the `--seed` argument was not passed to `uv venv`.
@@ -11,10 +11,12 @@ use uv_cache::Cache;
 use uv_configuration::BuildOptions;
 use uv_distribution::{DistributionDatabase, LocalWheel};
 use uv_distribution_types::{
-    BuildableSource, CachedDist, Dist, Hashed, Identifier, Name, RemoteSource,
+    BuildableSource, BuiltDist, CachedDist, Dist, Hashed, Identifier, Name, RemoteSource,
+    SourceDist,
 };
 use uv_platform_tags::Tags;
 use uv_types::{BuildContext, HashStrategy, InFlight};
+use uv_warnings::warn_user;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -144,29 +146,7 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
 
         let id = dist.distribution_id();
         if in_flight.downloads.register(id.clone()) {
-            let policy = self.hashes.get(&dist);
-
-            let result = self
-                .database
-                .get_or_build_wheel(&dist, self.tags, policy)
-                .boxed_local()
-                .map_err(|err| Error::Fetch(dist.clone(), Box::new(err)))
-                .await
-                .and_then(|wheel: LocalWheel| {
-                    if wheel.satisfies(policy) {
-                        Ok(wheel)
-                    } else {
-                        Err(Error::Fetch(
-                            dist.clone(),
-                            Box::new(uv_distribution::Error::hash_mismatch(
-                                dist.to_string(),
-                                policy.digests(),
-                                wheel.hashes(),
-                            )),
-                        ))
-                    }
-                })
-                .map(CachedDist::from);
+            let result = self.fetch_preferred(&dist).await;
             match result {
                 Ok(cached) => {
                     in_flight.downloads.done(id, Ok(cached.clone()));
@@ -190,37 +170,98 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
             }
         }
     }
+
+    /// Download or build the given distribution.
+    ///
+    /// If the package prefers building from source (as in `--prefer-sdist-package`), attempt to
+    /// build its source distribution first, falling back to the wheel if the build fails.
+    async fn fetch_preferred(&self, dist: &Dist) -> Result<CachedDist, Error> {
+        if let Dist::Built(BuiltDist::Registry(wheels)) = dist {
+            if let Some(sdist) = wheels
+                .sdist
+                .as_ref()
+                .filter(|_| self.build_options.prefer_sdist_package(dist.name()))
+            {
+                let source = Dist::Source(SourceDist::Registry(sdist.clone()));
+                match self.fetch(&source).await {
+                    Ok(cached) => return Ok(cached),
+                    Err(err) => {
+                        debug!("Failed to build `{source}` from source: {err}");
+                        warn_user!(
+                            "Failed to build `{source}` from source; falling back to a pre-built wheel"
+                        );
+                    }
+                }
+            }
+        }
+        self.fetch(dist).await
+    }
+
+    /// Download or build the given distribution, validating its hashes.
+    async fn fetch(&self, dist: &Dist) -> Result<CachedDist, Error> {
+        let policy = self.hashes.get(dist);
+
+        self.database
+            .get_or_build_wheel(dist, self.tags, policy)
+            .boxed_local()
+            .map_err(|err| Error::Fetch(dist.clone(), Box::new(err)))
+            .await
+            .and_then(|wheel: LocalWheel| {
+                if wheel.satisfies(policy) {
+                    Ok(wheel)
+                } else {
+                    Err(Error::Fetch(
+                        dist.clone(),
+                        Box::new(uv_distribution::Error::hash_mismatch(
+                            dist.to_string(),
+                            policy.digests(),
+                            wheel.hashes(),
+                        )),
+                    ))
+                }
+            })
+            .map(CachedDist::from)
+    }
 }
 
+/// A reporter for preparation events.
+///
+/// Each callback defaults to a no-op.
 pub trait Reporter: Send + Sync {
     /// Callback to invoke when a wheel is unzipped. This implies that the wheel was downloaded and,
     /// if necessary, built.
-    fn on_progress(&self, dist: &CachedDist);
+    fn on_progress(&self, _dist: &CachedDist) {}
 
     /// Callback to invoke when the operation is complete.
-    fn on_complete(&self);
+    fn on_complete(&self) {}
 
     /// Callback to invoke when a download is kicked off.
-    fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize;
+    fn on_download_start(&self, _name: &PackageName, _size: Option<u64>) -> usize {
+        0
+    }
 
     /// Callback to invoke when a download makes progress (i.e. some number of bytes are
     /// downloaded).
-    fn on_download_progress(&self, index: usize, bytes: u64);
+    fn on_download_progress(&self, _index: usize, _bytes: u64) {}
 
     /// Callback to invoke when a download is complete.
-    fn on_download_complete(&self, name: &PackageName, index: usize);
+    fn on_download_complete(&self, _name: &PackageName, _index: usize) {}
 
     /// Callback to invoke when a source distribution build is kicked off.
-    fn on_build_start(&self, source: &BuildableSource) -> usize;
+    fn on_build_start(&self, _source: &BuildableSource) -> usize {
+        0
+    }
 
     /// Callback to invoke when a source distribution build is complete.
-    fn on_build_complete(&self, source: &BuildableSource, id: usize);
+    fn on_build_complete(&self, _source: &BuildableSource, _id: usize) {}
 
     /// Callback to invoke when a repository checkout begins.
-    fn on_checkout_start(&self, url: &Url, rev: &str) -> usize;
+    fn on_checkout_start(&self, _url: &Url, _rev: &str) -> usize {
+        0
+    }
 
     /// Callback to invoke when a repository checkout completes.
-    fn on_checkout_complete(&self, url: &Url, rev: &str, index: usize);
+    fn on_checkout_complete(&self, _url: &Url, _rev: &str, _index: usize) {}
 }
 
 /// A facade for converting from [`Reporter`] to [`uv_git::Reporter`].
@@ -6,7 +6,7 @@ use url::Url;
 
 use uv_cache_info::CacheInfo;
 use uv_cache_key::{CanonicalUrl, RepositoryUrl};
-use uv_distribution_types::{InstalledDirectUrlDist, InstalledDist};
+use uv_distribution_types::{InstalledDirectUrlDist, InstalledDist, InstalledLegacyEditable};
 use uv_pypi_types::{DirInfo, DirectUrl, RequirementSource, VcsInfo, VcsKind};
 
 #[derive(Debug, Copy, Clone)]
@@ -204,6 +204,26 @@ impl RequirementSatisfaction {
                 r#virtual: _,
                 url: _,
             } => {
+                // A legacy editable (i.e., an `.egg-link` pointer) reflects the source tree
+                // directly, so it satisfies an editable requirement for the same directory.
+                if let InstalledDist::LegacyEditable(InstalledLegacyEditable { target, .. }) =
+                    &distribution
+                {
+                    if !*requested_editable {
+                        trace!("Editable mismatch: {:?} vs. true", *requested_editable);
+                        return Ok(Self::Mismatch);
+                    }
+
+                    if !(*requested_path == *target
+                        || is_same_file(requested_path, target).unwrap_or(false))
+                    {
+                        trace!("Path mismatch: {:?} vs. {:?}", requested_path, target);
+                        return Ok(Self::Mismatch);
+                    }
+
+                    return Ok(Self::Satisfied);
+                }
+
                 let InstalledDist::Url(InstalledDirectUrlDist {
                     direct_url,
                     cache_info,
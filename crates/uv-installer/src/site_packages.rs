@@ -1,10 +1,12 @@
 use std::collections::BTreeSet;
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use fs_err as fs;
+use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use tracing::debug;
 use url::Url;
 
 use uv_distribution_types::{
@@ -37,6 +39,9 @@ pub struct SitePackages {
     by_name: FxHashMap<PackageName, Vec<usize>>,
     /// The installed editable distributions, keyed by URL.
     by_url: FxHashMap<Url, Vec<usize>>,
+    /// The base interpreter's `site-packages` directories that were indexed, for virtual
+    /// environments created with `--system-site-packages`.
+    system_site_packages: Vec<PathBuf>,
 }
 
 impl SitePackages {
@@ -47,90 +52,121 @@ impl SitePackages {
 
     /// Build an index of installed packages from the given Python executable.
     pub fn from_interpreter(interpreter: &Interpreter) -> Result<Self> {
-        let mut distributions: Vec<Option<InstalledDist>> = Vec::new();
-        let mut by_name = FxHashMap::default();
-        let mut by_url = FxHashMap::default();
-
-        for site_packages in interpreter.site_packages() {
-            // Read the site-packages directory.
-            let site_packages = match fs::read_dir(site_packages) {
-                Ok(site_packages) => {
-                    // Collect sorted directory paths; `read_dir` is not stable across platforms
-                    let dist_likes: BTreeSet<_> = site_packages
-                        .filter_map(|read_dir| match read_dir {
-                            Ok(entry) => match entry.file_type() {
-                                Ok(file_type) => (file_type.is_dir()
-                                    || entry
-                                        .path()
-                                        .extension()
-                                        .is_some_and(|ext| ext == "egg-link" || ext == "egg-info"))
-                                .then_some(Ok(entry.path())),
-                                Err(err) => Some(Err(err)),
-                            },
+        let mut site_packages = Self {
+            interpreter: interpreter.clone(),
+            distributions: Vec::new(),
+            by_name: FxHashMap::default(),
+            by_url: FxHashMap::default(),
+            system_site_packages: Vec::new(),
+        };
+
+        for directory in interpreter.site_packages() {
+            if !site_packages.index(&directory)? {
+                break;
+            }
+        }
+
+        Ok(site_packages)
+    }
+
+    /// Include the packages installed in the base interpreter's `site-packages`, if the
+    /// environment is a virtual environment that was created with `--system-site-packages`.
+    ///
+    /// These packages can satisfy requirements, but are never uninstalled.
+    pub fn with_system_site_packages(mut self, environment: &PythonEnvironment) -> Result<Self> {
+        for directory in environment.system_site_packages() {
+            debug!(
+                "Indexing system site-packages: `{}`",
+                directory.user_display()
+            );
+            if self.index(directory)? {
+                self.system_site_packages.push(directory.to_path_buf());
+            }
+        }
+        Ok(self)
+    }
+
+    /// Index the packages in the given `site-packages` directory.
+    ///
+    /// Returns `false` if the directory doesn't exist.
+    fn index(&mut self, site_packages: &Path) -> Result<bool> {
+        // Read the site-packages directory.
+        let site_packages = match fs::read_dir(site_packages) {
+            Ok(site_packages) => {
+                // Collect sorted directory paths; `read_dir` is not stable across platforms
+                let dist_likes: BTreeSet<_> = site_packages
+                    .filter_map(|read_dir| match read_dir {
+                        Ok(entry) => match entry.file_type() {
+                            Ok(file_type) => (file_type.is_dir()
+                                || entry
+                                    .path()
+                                    .extension()
+                                    .is_some_and(|ext| ext == "egg-link" || ext == "egg-info"))
+                            .then_some(Ok(entry.path())),
                             Err(err) => Some(Err(err)),
-                        })
-                        .collect::<Result<_, std::io::Error>>()?;
-                    dist_likes
+                        },
+                        Err(err) => Some(Err(err)),
+                    })
+                    .collect::<Result<_, std::io::Error>>()?;
+                dist_likes
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(false);
+            }
+            Err(err) => return Err(err).context("Failed to read site-packages directory"),
+        };
+
+        // Index all installed packages by name.
+        for path in site_packages {
+            let dist_info = match InstalledDist::try_from_path(&path) {
+                Ok(Some(dist_info)) => dist_info,
+                Ok(None) => continue,
+                Err(_)
+                    if path.file_name().is_some_and(|name| {
+                        name.to_str().is_some_and(|name| name.starts_with('~'))
+                    }) =>
+                {
+                    warn_user!(
+                        "Ignoring dangling temporary directory: `{}`",
+                        path.simplified_display().cyan()
+                    );
+                    continue;
                 }
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                    return Ok(Self {
-                        interpreter: interpreter.clone(),
-                        distributions,
-                        by_name,
-                        by_url,
-                    });
+                Err(err) => {
+                    return Err(err).context(format!(
+                        "Failed to read metadata from: `{}`",
+                        path.simplified_display()
+                    ));
                 }
-                Err(err) => return Err(err).context("Failed to read site-packages directory"),
             };
 
-            // Index all installed packages by name.
-            for path in site_packages {
-                let dist_info = match InstalledDist::try_from_path(&path) {
-                    Ok(Some(dist_info)) => dist_info,
-                    Ok(None) => continue,
-                    Err(_)
-                        if path.file_name().is_some_and(|name| {
-                            name.to_str().is_some_and(|name| name.starts_with('~'))
-                        }) =>
-                    {
-                        warn_user!(
-                            "Ignoring dangling temporary directory: `{}`",
-                            path.simplified_display().cyan()
-                        );
-                        continue;
-                    }
-                    Err(err) => {
-                        return Err(err).context(format!(
-                            "Failed to read metadata from: `{}`",
-                            path.simplified_display()
-                        ));
-                    }
-                };
-
-                let idx = distributions.len();
+            let idx = self.distributions.len();
 
-                // Index the distribution by name.
-                by_name
-                    .entry(dist_info.name().clone())
-                    .or_default()
-                    .push(idx);
+            // Index the distribution by name.
+            self.by_name
+                .entry(dist_info.name().clone())
+                .or_default()
+                .push(idx);
 
-                // Index the distribution by URL.
-                if let InstalledDist::Url(dist) = &dist_info {
-                    by_url.entry(dist.url.clone()).or_default().push(idx);
+            // Index the distribution by URL.
+            match &dist_info {
+                InstalledDist::Url(dist) => {
+                    self.by_url.entry(dist.url.clone()).or_default().push(idx);
                 }
-
-                // Add the distribution to the database.
-                distributions.push(Some(dist_info));
+                InstalledDist::LegacyEditable(dist) => {
+                    self.by_url
+                        .entry(dist.target_url.clone())
+                        .or_default()
+                        .push(idx);
+                }
+                _ => {}
             }
+
+            // Add the distribution to the database.
+            self.distributions.push(Some(dist_info));
         }
 
-        Ok(Self {
-            interpreter: interpreter.clone(),
-            distributions,
-            by_name,
-            by_url,
-        })
+        Ok(true)
     }
 
     /// Returns the [`Interpreter`] used to install the packages.
@@ -165,6 +201,20 @@ impl SitePackages {
             .collect()
     }
 
+    /// Remove any packages installed in the base interpreter's `site-packages` from the index.
+    pub fn remove_system_packages(&mut self) {
+        let system_site_packages = std::mem::take(&mut self.system_site_packages);
+        for distribution in &mut self.distributions {
+            if distribution.as_ref().is_some_and(|distribution| {
+                system_site_packages
+                    .iter()
+                    .any(|directory| distribution.path().starts_with(directory))
+            }) {
+                *distribution = None;
+            }
+        }
+    }
+
     /// Returns the distributions installed from the given URL, if any.
     pub fn get_urls(&self, url: &Url) -> Vec<&InstalledDist> {
         let Some(indexes) = self.by_url.get(url) else {
@@ -176,6 +226,64 @@ impl SitePackages {
             .collect()
     }
 
+    /// Returns the requirements declared (via `Requires-Dist`) by the installed packages, as
+    /// constraints, such that a resolution doesn't break any package that's already installed.
+    ///
+    /// Requirements of the packages in `exclude` (e.g., those being installed or upgraded) are
+    /// omitted, as are requirements whose markers don't match the environment and direct URL
+    /// requirements.
+    pub fn installed_constraints(
+        &self,
+        markers: &ResolverMarkerEnvironment,
+        exclude: &FxHashSet<&PackageName>,
+    ) -> Result<Vec<NameRequirementSpecification>> {
+        let mut constraints = Vec::new();
+        for distribution in self.iter() {
+            if exclude.contains(distribution.name()) {
+                continue;
+            }
+
+            let metadata = distribution.metadata().with_context(|| {
+                format!(
+                    "Failed to read metadata for installed package: `{}`",
+                    distribution.name()
+                )
+            })?;
+
+            for dependency in metadata.requires_dist {
+                if !dependency.evaluate_markers(markers, &[]) {
+                    continue;
+                }
+                let Some(uv_pep508::VersionOrUrl::VersionSpecifier(specifier)) =
+                    &dependency.version_or_url
+                else {
+                    continue;
+                };
+                if specifier.is_empty() {
+                    continue;
+                }
+                let requirement = uv_pep508::Requirement {
+                    extras: vec![],
+                    marker: uv_pep508::MarkerTree::TRUE,
+                    origin: None,
+                    ..dependency
+                };
+                constraints.push(NameRequirementSpecification::from(Requirement::from(
+                    requirement,
+                )));
+            }
+        }
+        Ok(constraints)
+    }
+
+    /// Returns `true` if the distribution is installed in the base interpreter's `site-packages`,
+    /// rather than in the environment itself.
+    pub fn is_system(&self, distribution: &InstalledDist) -> bool {
+        self.system_site_packages
+            .iter()
+            .any(|directory| distribution.path().starts_with(directory))
+    }
+
     /// Returns `true` if there are any installed packages.
     pub fn any(&self) -> bool {
         self.distributions.iter().any(Option::is_some)
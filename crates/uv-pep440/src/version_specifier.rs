@@ -441,15 +441,7 @@ impl VersionSpecifier {
 
         match self.operator {
             Operator::Equal => other == this,
-            Operator::EqualStar => {
-                this.epoch() == other.epoch()
-                    && self
-                        .version
-                        .release()
-                        .iter()
-                        .zip(other.release())
-                        .all(|(this, other)| this == other)
-            }
+            Operator::EqualStar => is_prefix_match(&this, &other),
             #[allow(deprecated)]
             Operator::ExactEqual => {
                 #[cfg(feature = "tracing")]
@@ -459,14 +451,7 @@ impl VersionSpecifier {
                 self.version.to_string() == version.to_string()
             }
             Operator::NotEqual => other != this,
-            Operator::NotEqualStar => {
-                this.epoch() != other.epoch()
-                    || !this
-                        .release()
-                        .iter()
-                        .zip(version.release())
-                        .all(|(this, other)| this == other)
-            }
+            Operator::NotEqualStar => !is_prefix_match(&this, &other),
             Operator::TildeEqual => {
                 // "For a given release identifier V.N, the compatible release clause is
                 // approximately equivalent to the pair of comparison clauses: `>= V.N, == V.*`"
@@ -761,6 +746,21 @@ pub(crate) fn parse_version_specifiers(
     Ok(version_ranges)
 }
 
+/// Returns `true` if the release segment of `version` starts with the release segment of
+/// `prefix`, as required by the prefix-matching `==V.*` and `!=V.*` operators.
+///
+/// Per PEP 440, the candidate version is zero-padded to the length of the prefix (such that `1`
+/// matches `==1.0.*`, but not `==1.4.*`), and any pre-, post-, or development release segments
+/// of the candidate are ignored (such that `1.4.0rc1` and `1.4.0.post1` match `==1.4.*`).
+fn is_prefix_match(prefix: &Version, version: &Version) -> bool {
+    prefix.epoch() == version.epoch()
+        && prefix
+            .release()
+            .iter()
+            .zip(version.release().iter().chain(std::iter::repeat(&0)))
+            .all(|(prefix, version)| prefix == version)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{cmp::Ordering, str::FromStr};
@@ -1159,6 +1159,7 @@ mod tests {
             // Test the equality operation with a prefix
             ("2.0", "==3.*"),
             ("2.1", "==2.0.*"),
+            ("2", "==2.1.*"),
             // Test the in-equality operation
             ("2.0", "!=2"),
             ("2.0", "!=2.0"),
@@ -1249,6 +1250,34 @@ mod tests {
         }
     }
 
+    /// `!=1.4.*` should exclude every version in the `1.4` series, including its pre-, post-, and
+    /// development releases, and nothing else.
+    #[test]
+    fn test_not_equal_star_series() {
+        let specifier = VersionSpecifier::from_str("!=1.4.*").unwrap();
+        let pairs = [
+            ("1.4.0.dev1", false),
+            ("1.4.0rc1", false),
+            ("1.4.0", false),
+            ("1.4.1", false),
+            ("1.4.0.post1", false),
+            ("1.4.0+local", false),
+            ("1", true),
+            ("1.3.9", true),
+            ("1.40", true),
+            ("1.5.0.dev0", true),
+            ("1.5.0rc1", true),
+            ("1.5.0", true),
+        ];
+        for (version, expected) in pairs {
+            assert_eq!(
+                specifier.contains(&Version::from_str(version).unwrap()),
+                expected,
+                "{version} {specifier}"
+            );
+        }
+    }
+
     #[test]
     fn test_parse_version_specifiers() {
         let result = VersionSpecifiers::from_str("~= 0.9, >= 1.0, != 1.3.4.*, < 2.0").unwrap();
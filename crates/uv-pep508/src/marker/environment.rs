@@ -1,8 +1,10 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use std::sync::Arc;
 
 use uv_pep440::{Version, VersionParseError};
 
-use crate::{MarkerValueString, MarkerValueVersion, StringVersion};
+use crate::{MarkerValue, MarkerValueString, MarkerValueVersion, StringVersion};
 
 /// The marker values for a python interpreter, normally the current one
 ///
@@ -64,6 +66,48 @@ impl MarkerEnvironment {
             }
         }
     }
+
+    /// Override the value of a single marker in the current environment.
+    #[must_use]
+    pub fn with_override(self, marker: &MarkerOverride) -> MarkerEnvironment {
+        match marker {
+            MarkerOverride::Version(key, value) => match key {
+                MarkerValueVersion::ImplementationVersion => {
+                    self.with_implementation_version(value.clone())
+                }
+                MarkerValueVersion::PythonFullVersion => {
+                    self.with_python_full_version(value.clone())
+                }
+                MarkerValueVersion::PythonVersion => self.with_python_version(value.clone()),
+            },
+            MarkerOverride::String(key, value) => match key {
+                MarkerValueString::ImplementationName => {
+                    self.with_implementation_name(value.as_str())
+                }
+                MarkerValueString::OsName | MarkerValueString::OsNameDeprecated => {
+                    self.with_os_name(value.as_str())
+                }
+                MarkerValueString::PlatformMachine
+                | MarkerValueString::PlatformMachineDeprecated => {
+                    self.with_platform_machine(value.as_str())
+                }
+                MarkerValueString::PlatformPythonImplementation
+                | MarkerValueString::PlatformPythonImplementationDeprecated
+                | MarkerValueString::PythonImplementationDeprecated => {
+                    self.with_platform_python_implementation(value.as_str())
+                }
+                MarkerValueString::PlatformRelease => self.with_platform_release(value.as_str()),
+                MarkerValueString::PlatformSystem => self.with_platform_system(value.as_str()),
+                MarkerValueString::PlatformVersion
+                | MarkerValueString::PlatformVersionDeprecated => {
+                    self.with_platform_version(value.as_str())
+                }
+                MarkerValueString::SysPlatform | MarkerValueString::SysPlatformDeprecated => {
+                    self.with_sys_platform(value.as_str())
+                }
+            },
+        }
+    }
 }
 
 /// APIs for retrieving specific parts of a marker environment.
@@ -357,3 +401,46 @@ impl<'a> TryFrom<MarkerEnvironmentBuilder<'a>> for MarkerEnvironment {
         })
     }
 }
+
+/// An override for the value of a single marker in a [`MarkerEnvironment`], parsed from a
+/// `{key}={value}` string (e.g., `platform_machine=aarch64`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MarkerOverride {
+    /// An override for a marker with a PEP 440 version as its value, such as `python_version`.
+    Version(MarkerValueVersion, StringVersion),
+    /// An override for a marker with an arbitrary string as its value, such as `sys_platform`.
+    String(MarkerValueString, String),
+}
+
+impl FromStr for MarkerOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((key, value)) = s.split_once('=') else {
+            return Err(format!(
+                "`{s}` must be a marker name and a value (e.g., `platform_machine=aarch64`)"
+            ));
+        };
+        let (key, value) = (key.trim(), value.trim());
+        match MarkerValue::from_str(key) {
+            Ok(MarkerValue::MarkerEnvVersion(key)) => {
+                let value = StringVersion::from_str(value)
+                    .map_err(|err| format!("Invalid version for `{key}`: {err}"))?;
+                Ok(Self::Version(key, value))
+            }
+            Ok(MarkerValue::MarkerEnvString(key)) => Ok(Self::String(key, value.to_string())),
+            Ok(MarkerValue::Extra | MarkerValue::QuotedString(_)) | Err(_) => Err(format!(
+                "`{key}` is not a known environment marker (expected one of: `implementation_name`, `implementation_version`, `os_name`, `platform_machine`, `platform_python_implementation`, `platform_release`, `platform_system`, `platform_version`, `python_full_version`, `python_version`, `sys_platform`)"
+            )),
+        }
+    }
+}
+
+impl Display for MarkerOverride {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Version(key, value) => write!(f, "{key}={value}"),
+            Self::String(key, value) => write!(f, "{key}={value}"),
+        }
+    }
+}
@@ -15,7 +15,7 @@ pub(crate) mod parse;
 mod simplify;
 mod tree;
 
-pub use environment::{MarkerEnvironment, MarkerEnvironmentBuilder};
+pub use environment::{MarkerEnvironment, MarkerEnvironmentBuilder, MarkerOverride};
 pub use tree::{
     ContainsMarkerTree, ExtraMarkerTree, ExtraOperator, InMarkerTree, MarkerExpression,
     MarkerOperator, MarkerTree, MarkerTreeContents, MarkerTreeDebugGraph, MarkerTreeKind,
@@ -1679,7 +1679,7 @@ mod test {
     use uv_normalize::ExtraName;
     use uv_pep440::Version;
 
-    use crate::marker::{MarkerEnvironment, MarkerEnvironmentBuilder};
+    use crate::marker::{MarkerEnvironment, MarkerEnvironmentBuilder, MarkerOverride};
     use crate::{MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString};
 
     fn parse_err(input: &str) -> String {
@@ -1707,6 +1707,34 @@ mod test {
         .unwrap()
     }
 
+    #[test]
+    fn marker_override() {
+        let env = env37()
+            .with_override(&MarkerOverride::from_str("platform_machine=aarch64").unwrap())
+            .with_override(&MarkerOverride::from_str("python_version=3.12").unwrap());
+        assert!(
+            m("platform_machine == 'aarch64' and python_version >= '3.12'").evaluate(&env, &[])
+        );
+        assert!(!m("platform_machine == 'aarch64'").evaluate(&env37(), &[]));
+
+        // Deprecated names are normalized.
+        let env = env37().with_override(&MarkerOverride::from_str("os.name=nt").unwrap());
+        assert_eq!(env.os_name(), "nt");
+
+        assert_snapshot!(
+            MarkerOverride::from_str("platform_machine").unwrap_err(),
+            @"`platform_machine` must be a marker name and a value (e.g., `platform_machine=aarch64`)"
+        );
+        assert_snapshot!(
+            MarkerOverride::from_str("python_version=three").unwrap_err(),
+            @"Invalid version for `python_version`: expected version to start with a number, but no leading ASCII digits were found"
+        );
+        assert_snapshot!(
+            MarkerOverride::from_str("extra=dev").unwrap_err(),
+            @"`extra` is not a known environment marker (expected one of: `implementation_name`, `implementation_version`, `os_name`, `platform_machine`, `platform_python_implementation`, `platform_release`, `platform_system`, `platform_version`, `python_full_version`, `python_version`, `sys_platform`)"
+        );
+    }
+
     /// Copied from <https://github.com/pypa/packaging/blob/85ff971a250dc01db188ef9775499c15553a8c95/tests/test_markers.py#L175-L221>
     #[test]
     fn test_marker_equivalence() {
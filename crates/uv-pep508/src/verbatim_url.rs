@@ -536,6 +536,32 @@ mod tests {
         assert_eq!(split_scheme("https:"), Some(("https", "")));
     }
 
+    #[test]
+    fn windows_file_url() {
+        /// Convert a `file:` URL to a path, as on Windows.
+        fn to_windows_path(url: &str) -> String {
+            let (_, path) = split_scheme(url).unwrap();
+            uv_fs::normalize_windows_url_path(strip_host(path))
+        }
+
+        assert_eq!(
+            to_windows_path("file:///C:/Users/ferris/wheel-0.42.0.tar.gz"),
+            r"C:\Users\ferris\wheel-0.42.0.tar.gz"
+        );
+        assert_eq!(
+            to_windows_path(r"file:///C:\Users\ferris/wheel-0.42.0.tar.gz"),
+            r"C:\Users\ferris\wheel-0.42.0.tar.gz"
+        );
+        assert_eq!(
+            to_windows_path(r"file://localhost/C:\Users\ferris\wheel-0.42.0.tar.gz"),
+            r"C:\Users\ferris\wheel-0.42.0.tar.gz"
+        );
+        assert_eq!(
+            to_windows_path(r"file:..\ferris\wheel-0.42.0.tar.gz"),
+            r"..\ferris\wheel-0.42.0.tar.gz"
+        );
+    }
+
     #[test]
     fn fragment() {
         assert_eq!(
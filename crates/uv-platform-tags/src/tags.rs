@@ -431,10 +431,13 @@ fn compatible_tags(platform: &Platform) -> Result<Vec<String>, PlatformError> {
             platform_tags
         }
         (Os::Musllinux { major, minor }, _) => {
-            let mut platform_tags = vec![format!("linux_{}", arch)];
             // musl 1.1 is the lowest supported version in musllinux
-            platform_tags
-                .extend((1..=*minor).map(|minor| format!("musllinux_{major}_{minor}_{arch}")));
+            let mut platform_tags: Vec<_> = (1..=*minor)
+                .rev()
+                .map(|minor| format!("musllinux_{major}_{minor}_{arch}"))
+                .collect();
+            // Non-musllinux is lowest priority
+            platform_tags.push(format!("linux_{arch}"));
             platform_tags
         }
         (Os::Macos { major, minor }, Arch::X86_64) => {
@@ -625,6 +628,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_platform_tags_musllinux() {
+        let tags = compatible_tags(&Platform::new(
+            Os::Musllinux { major: 1, minor: 2 },
+            Arch::X86_64,
+        ))
+        .unwrap();
+        assert_debug_snapshot!(
+            tags,
+            @r###"
+        [
+            "musllinux_1_2_x86_64",
+            "musllinux_1_1_x86_64",
+            "linux_x86_64",
+        ]
+        "###
+        );
+    }
+
     #[test]
     fn test_platform_tags_macos() {
         let tags = compatible_tags(&Platform::new(
@@ -940,6 +962,54 @@ mod tests {
         );
     }
 
+    /// Ensure that, for a given interpreter and ABI, the most specific compatible platform tag is
+    /// preferred.
+    #[test]
+    fn test_platform_specificity() {
+        let tags = Tags::from_env(
+            &Platform::new(
+                Os::Manylinux {
+                    major: 2,
+                    minor: 28,
+                },
+                Arch::X86_64,
+            ),
+            (3, 9),
+            "cpython",
+            (3, 9),
+            true,
+            false,
+        )
+        .unwrap();
+        let compatibility = |python: &str, abi: &str, platform: &str| {
+            tags.compatibility(
+                &[python.to_string()],
+                &[abi.to_string()],
+                &[platform.to_string()],
+            )
+        };
+
+        assert!(
+            compatibility("cp39", "cp39", "manylinux_2_28_x86_64")
+                > compatibility("cp39", "cp39", "manylinux2014_x86_64")
+        );
+        assert!(
+            compatibility("cp39", "abi3", "manylinux_2_28_x86_64")
+                > compatibility("cp39", "abi3", "manylinux2014_x86_64")
+        );
+        assert!(
+            compatibility("cp39", "cp39", "manylinux2014_x86_64")
+                > compatibility("cp39", "abi3", "manylinux_2_28_x86_64")
+        );
+        assert!(
+            compatibility("cp39", "abi3", "manylinux2014_x86_64")
+                > compatibility("py3", "none", "manylinux_2_28_x86_64")
+        );
+        assert!(
+            compatibility("py3", "none", "linux_x86_64") > compatibility("cp39", "none", "any")
+        );
+    }
+
     /// Ensure the tags returned do not include the `manylinux` tags
     /// when `manylinux_incompatible` is set to `false`.
     #[test]
@@ -69,6 +69,7 @@ static FIXUPS: &[(FixUp, &str)] = &[
         "removing trailing zero",
     ),
     (remove_stray_quotes, "removing stray quotes"),
+    (quote_marker_versions, "quoting version in marker"),
 ];
 
 // Given `>= 2.7'`, rewrite to `>= 2.7`
@@ -86,6 +87,26 @@ fn remove_stray_quotes(input: &str) -> Cow<'_, str> {
     }
 }
 
+// Given `python_version >= 3.8`, rewrite to `python_version >= '3.8'`
+fn quote_marker_versions(input: &str) -> Cow<'_, str> {
+    /// Ex) `python_version >= 3.8`, `python_full_version<3.11.2`
+    static UNQUOTED_MARKER_VERSION: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(
+            r"(python_version|python_full_version|implementation_version)\s*(===|==|!=|<=|>=|~=|<|>)\s*(\d[\w.*+!-]*)",
+        )
+        .unwrap()
+    });
+
+    // Only touch the markers, since the version specifiers are never quoted.
+    match input.find(';') {
+        Some(markers) => {
+            let quoted = UNQUOTED_MARKER_VERSION.replace_all(&input[markers..], "$1 $2 '$3'");
+            format!("{}{}", &input[..markers], quoted).into()
+        }
+        None => Cow::Borrowed(input),
+    }
+}
+
 fn parse_with_fixups<Err, T: FromStr<Err = Err>>(input: &str, type_name: &str) -> Result<T, Err> {
     match T::from_str(input) {
         Ok(requirement) => Ok(requirement),
@@ -167,7 +188,7 @@ mod tests {
     use std::str::FromStr;
 
     use uv_pep440::VersionSpecifiers;
-    use uv_pep508::Requirement;
+    use uv_pep508::{Requirement, VerbatimUrl};
 
     use crate::LenientVersionSpecifiers;
 
@@ -420,4 +441,43 @@ mod tests {
             Requirement::from_str("numpy >=1.19; python_version >= \"3.7\"").unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn marker_quotes() {
+        // Both single and double quotes are valid.
+        let single: Requirement =
+            LenientRequirement::from_str("numpy >=1.19; python_version >= '3.8'")
+                .unwrap()
+                .into();
+        let double: Requirement =
+            LenientRequirement::from_str("numpy >=1.19; python_version >= \"3.8\"")
+                .unwrap()
+                .into();
+        assert_eq!(single, double);
+        assert_eq!(
+            single,
+            Requirement::from_str("numpy >=1.19; python_version >= '3.8'").unwrap()
+        );
+    }
+
+    #[test]
+    fn marker_unquoted_version() {
+        // Unquoted versions are invalid...
+        assert!(Requirement::<VerbatimUrl>::from_str(
+            "numpy >=1.19; python_version >= 3.8 and python_full_version<3.11.2"
+        )
+        .is_err());
+
+        // ...but are quoted in lenient mode.
+        let actual: Requirement = LenientRequirement::from_str(
+            "numpy >=1.19; python_version >= 3.8 and python_full_version<3.11.2",
+        )
+        .unwrap()
+        .into();
+        let expected: Requirement = Requirement::from_str(
+            "numpy >=1.19; python_version >= '3.8' and python_full_version < '3.11.2'",
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
 }
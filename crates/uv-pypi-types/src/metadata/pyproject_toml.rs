@@ -65,7 +65,9 @@ pub(crate) fn parse_pyproject_toml(contents: &str) -> Result<ResolutionMetadata,
 
     // Extract the optional dependencies.
     let mut provides_extras: Vec<ExtraName> = Vec::new();
-    for (extra, requirements) in project.optional_dependencies.unwrap_or_default() {
+    for (extra, requirements) in
+        normalize_optional_dependencies(project.optional_dependencies.unwrap_or_default())?
+    {
         requires_dist.extend(
             requirements
                 .into_iter()
@@ -86,6 +88,21 @@ pub(crate) fn parse_pyproject_toml(contents: &str) -> Result<ResolutionMetadata,
     })
 }
 
+/// Normalize the keys of `project.optional-dependencies`, merging the requirements of any extras
+/// whose names normalize to the same [`ExtraName`] (e.g., `Dev` and `dev`).
+fn normalize_optional_dependencies(
+    optional_dependencies: IndexMap<String, Vec<String>>,
+) -> Result<IndexMap<ExtraName, Vec<String>>, MetadataError> {
+    let mut normalized: IndexMap<ExtraName, Vec<String>> = IndexMap::new();
+    for (extra, requirements) in optional_dependencies {
+        normalized
+            .entry(ExtraName::new(extra)?)
+            .or_default()
+            .extend(requirements);
+    }
+    Ok(normalized)
+}
+
 /// A `pyproject.toml` as specified in PEP 517.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "kebab-case")]
@@ -128,8 +145,8 @@ struct Project {
     requires_python: Option<String>,
     /// Project dependencies
     dependencies: Option<Vec<String>>,
-    /// Optional dependencies
-    optional_dependencies: Option<IndexMap<ExtraName, Vec<String>>>,
+    /// Optional dependencies, keyed by the extra name as written (i.e., prior to normalization)
+    optional_dependencies: Option<IndexMap<String, Vec<String>>>,
     /// Specifies which fields listed by PEP 621 were intentionally unspecified
     /// so another tool can/will provide such metadata dynamically.
     dynamic: Option<Vec<String>>,
@@ -204,7 +221,9 @@ impl RequiresDist {
 
         // Extract the optional dependencies.
         let mut provides_extras: Vec<ExtraName> = Vec::new();
-        for (extra, requirements) in project.optional_dependencies.unwrap_or_default() {
+        for (extra, requirements) in
+            normalize_optional_dependencies(project.optional_dependencies.unwrap_or_default())?
+        {
             requires_dist.extend(
                 requirements
                     .into_iter()
@@ -311,4 +330,28 @@ mod tests {
         );
         assert_eq!(meta.provides_extras, vec!["dotenv".parse().unwrap()]);
     }
+
+    #[test]
+    fn test_parse_pyproject_toml_duplicate_extras() {
+        let s = r#"
+            [project]
+            name = "asdf"
+            version = "1.0"
+            dependencies = ["foo"]
+
+            [project.optional-dependencies]
+            Dev = ["bar"]
+            dev = ["baz"]
+        "#;
+        let meta = parse_pyproject_toml(s).unwrap();
+        assert_eq!(
+            meta.requires_dist,
+            vec![
+                "foo".parse().unwrap(),
+                "bar; extra == \"dev\"".parse().unwrap(),
+                "baz; extra == \"dev\"".parse().unwrap()
+            ]
+        );
+        assert_eq!(meta.provides_extras, vec!["dev".parse().unwrap()]);
+    }
 }
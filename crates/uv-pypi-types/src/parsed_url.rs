@@ -4,6 +4,7 @@ use thiserror::Error;
 use url::{ParseError, Url};
 use uv_distribution_filename::{DistExtension, ExtensionError};
 use uv_git::{GitReference, GitSha, GitUrl, OidParseError};
+use uv_normalize::{InvalidNameError, PackageName};
 use uv_pep508::{Pep508Url, UnnamedRequirementUrl, VerbatimUrl, VerbatimUrlError};
 
 use crate::{ArchiveInfo, DirInfo, DirectUrl, VcsInfo, VcsKind};
@@ -327,6 +328,24 @@ fn get_subdirectory(url: &Url) -> Option<PathBuf> {
     Some(PathBuf::from(subdirectory))
 }
 
+/// If the URL includes an `egg` fragment, extract the package name hint, as in:
+///   `git+https://git.example.com/MyProject.git@v1.0#egg=pkg`
+///   `git+https://git.example.com/MyProject.git@v1.0#egg=pkg&subdirectory=pkg_dir`
+///
+/// Returns an error if the `egg` fragment is not a valid package name.
+pub fn get_egg_name(url: &Url) -> Result<Option<PackageName>, InvalidNameError> {
+    let Some(fragment) = url.fragment() else {
+        return Ok(None);
+    };
+    let Some(egg) = fragment
+        .split('&')
+        .find_map(|fragment| fragment.strip_prefix("egg="))
+    else {
+        return Ok(None);
+    };
+    PackageName::new(egg.to_string()).map(Some)
+}
+
 impl TryFrom<Url> for ParsedUrl {
     type Error = ParsedUrlError;
 
@@ -508,10 +527,14 @@ impl From<ParsedGitUrl> for Url {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
     use anyhow::Result;
     use url::Url;
+    use uv_normalize::PackageName;
 
-    use crate::parsed_url::ParsedUrl;
+    use crate::parsed_url::{get_egg_name, ParsedUrl};
 
     #[test]
     fn direct_url_from_url() -> Result<()> {
@@ -541,6 +564,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn egg_and_subdirectory_fragments() -> Result<()> {
+        let url = Url::parse(
+            "git+https://github.com/pallets/flask.git@main#egg=flask&subdirectory=src/flask",
+        )?;
+        assert_eq!(get_egg_name(&url)?, Some(PackageName::from_str("flask")?));
+        let ParsedUrl::Git(parsed) = ParsedUrl::try_from(url)? else {
+            panic!("Expected a Git URL");
+        };
+        assert_eq!(parsed.subdirectory, Some(PathBuf::from("src/flask")));
+
+        let url = Url::parse("git+https://github.com/pallets/flask.git@main#egg=flask")?;
+        assert_eq!(get_egg_name(&url)?, Some(PackageName::from_str("flask")?));
+        let ParsedUrl::Git(parsed) = ParsedUrl::try_from(url)? else {
+            panic!("Expected a Git URL");
+        };
+        assert_eq!(parsed.subdirectory, None);
+
+        let url =
+            Url::parse("git+https://github.com/pallets/flask.git@main#subdirectory=src/flask")?;
+        assert_eq!(get_egg_name(&url)?, None);
+        let ParsedUrl::Git(parsed) = ParsedUrl::try_from(url)? else {
+            panic!("Expected a Git URL");
+        };
+        assert_eq!(parsed.subdirectory, Some(PathBuf::from("src/flask")));
+
+        let url = Url::parse("git+https://github.com/pallets/flask.git@main")?;
+        assert_eq!(get_egg_name(&url)?, None);
+        let ParsedUrl::Git(parsed) = ParsedUrl::try_from(url)? else {
+            panic!("Expected a Git URL");
+        };
+        assert_eq!(parsed.subdirectory, None);
+
+        let url = Url::parse("git+https://github.com/pallets/flask.git@main#egg=-flask")?;
+        assert!(get_egg_name(&url).is_err());
+
+        Ok(())
+    }
+
     #[test]
     #[cfg(unix)]
     fn direct_url_from_url_absolute() -> Result<()> {
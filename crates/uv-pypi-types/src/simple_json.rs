@@ -313,7 +313,9 @@ impl FromStr for Hashes {
     rkyv::Serialize,
 )]
 #[rkyv(derive(Debug))]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum HashAlgorithm {
+    #[cfg_attr(feature = "clap", value(skip))]
     Md5,
     Sha256,
     Sha384,
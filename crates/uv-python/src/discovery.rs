@@ -72,10 +72,14 @@ pub enum PythonPreference {
     ///
     /// System Python installations are still preferred over downloading managed Python versions.
     /// Use `only-managed` to always fetch a managed Python version.
+    #[serde(alias = "prefer-managed")]
+    #[cfg_attr(feature = "clap", value(alias = "prefer-managed"))]
     Managed,
     /// Prefer system Python installations over managed Python installations.
     ///
     /// If a system Python installation cannot be found, a managed Python installation can be used.
+    #[serde(alias = "prefer-system")]
+    #[cfg_attr(feature = "clap", value(alias = "prefer-system"))]
     System,
     /// Only use system Python installations; never use managed Python installations.
     OnlySystem,
@@ -248,6 +248,30 @@ impl PythonEnvironment {
         Ok(PyVenvConfiguration::parse(self.0.root.join("pyvenv.cfg"))?)
     }
 
+    /// Returns the `site-packages` directories of the base interpreter, if the environment is a
+    /// virtual environment that was created with `--system-site-packages`.
+    pub fn system_site_packages(&self) -> Vec<&Path> {
+        if !self
+            .cfg()
+            .is_ok_and(|cfg| cfg.include_system_site_packages())
+        {
+            return Vec::new();
+        }
+        let interpreter = self.interpreter();
+        interpreter
+            .sys_path()
+            .iter()
+            .filter(|path| {
+                path.starts_with(interpreter.sys_base_prefix())
+                    && !path.starts_with(interpreter.sys_prefix())
+                    && path
+                        .file_name()
+                        .is_some_and(|name| name == "site-packages" || name == "dist-packages")
+            })
+            .map(PathBuf::as_path)
+            .collect()
+    }
+
     /// Returns `true` if the environment is "relocatable".
     pub fn relocatable(&self) -> bool {
         self.cfg().is_ok_and(|cfg| cfg.is_relocatable())
@@ -4,6 +4,8 @@ use std::{
 };
 use thiserror::Error;
 
+use uv_pep508::MarkerEnvironment;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("Unknown Python implementation `{0}`")]
@@ -40,6 +42,41 @@ impl ImplementationName {
             Self::GraalPy => "GraalPy",
         }
     }
+
+    /// Return the `platform_python_implementation` marker corresponding to this implementation.
+    ///
+    /// Ex) `platform_python_implementation == "CPython"`
+    pub fn platform_python_implementation(self) -> &'static str {
+        match self {
+            Self::CPython => "CPython",
+            Self::PyPy => "PyPy",
+            Self::GraalPy => "GraalVM",
+        }
+    }
+
+    /// Return a [`MarkerEnvironment`] compatible with the given [`ImplementationName`], based on
+    /// a base [`MarkerEnvironment`].
+    ///
+    /// The returned [`MarkerEnvironment`] will preserve the base environment's version and
+    /// platform markers, but override its implementation markers.
+    pub fn markers(self, base: &MarkerEnvironment) -> MarkerEnvironment {
+        let mut markers = base
+            .clone()
+            .with_implementation_name(<&'static str>::from(self))
+            .with_platform_python_implementation(self.platform_python_implementation());
+
+        // Ex) `implementation_version == "3.12.0"`
+        //
+        // For CPython, the implementation version matches the Python version. For other
+        // implementations, the implementation version can't be inferred, so we retain the base
+        // environment's version.
+        if self == Self::CPython {
+            let python_full_version = markers.python_full_version().clone();
+            markers = markers.with_implementation_version(python_full_version);
+        }
+
+        markers
+    }
 }
 
 impl LenientImplementationName {
@@ -109,8 +109,10 @@ mod tests {
         PythonPreference,
     };
     use crate::{
-        implementation::ImplementationName, installation::PythonInstallation,
-        managed::ManagedPythonInstallations, virtualenv::virtualenv_python_executable,
+        implementation::ImplementationName,
+        installation::PythonInstallation,
+        managed::{platform_key_from_env, ManagedPythonInstallation, ManagedPythonInstallations},
+        virtualenv::virtualenv_python_executable,
         PythonNotFound, PythonRequest, PythonSource, PythonVersion,
     };
 
@@ -375,6 +377,24 @@ mod tests {
             Ok(())
         }
 
+        /// Create a fake managed Python installation for the given CPython version.
+        ///
+        /// The installation is added to the test context's managed Python directory.
+        fn add_managed_python(&self, version: &'static str) -> Result<()> {
+            let installation = ManagedPythonInstallation::new(
+                self.installations
+                    .root()
+                    .join(format!("cpython-{version}-{}", platform_key_from_env()?)),
+            )?;
+            Self::create_mock_interpreter(
+                &installation.executable(),
+                &PythonVersion::from_str(version).expect("Test uses valid version"),
+                ImplementationName::CPython,
+                true,
+                false,
+            )
+        }
+
         /// Create a mock virtual environment at the given directory
         fn mock_venv(path: impl AsRef<Path>, version: &'static str) -> Result<()> {
             let executable = virtualenv_python_executable(path.as_ref());
@@ -683,6 +703,106 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_managed_and_system_preference() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_versions(&["3.12.2"])?;
+        context.add_managed_python("3.12.1")?;
+
+        for (preference, version, source) in [
+            (
+                PythonPreference::OnlyManaged,
+                "3.12.1",
+                PythonSource::Managed,
+            ),
+            (PythonPreference::Managed, "3.12.1", PythonSource::Managed),
+            (PythonPreference::System, "3.12.2", PythonSource::SearchPath),
+            (
+                PythonPreference::OnlySystem,
+                "3.12.2",
+                PythonSource::SearchPath,
+            ),
+        ] {
+            let python = context.run(|| {
+                find_python_installation(
+                    &PythonRequest::parse("3.12"),
+                    EnvironmentPreference::OnlySystem,
+                    preference,
+                    &context.cache,
+                )
+            })??;
+            assert_eq!(
+                python.interpreter().python_full_version().to_string(),
+                version,
+                "Should find the {source} interpreter with `{preference:?}`"
+            );
+            assert_eq!(python.source(), &source);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_managed_and_system_preference_fallback() -> Result<()> {
+        // With only a managed interpreter, a system preference falls back to it...
+        let context = TestContext::new()?;
+        context.add_managed_python("3.12.1")?;
+
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.12"),
+                EnvironmentPreference::OnlySystem,
+                PythonPreference::System,
+                &context.cache,
+            )
+        })??;
+        assert_eq!(python.source(), &PythonSource::Managed);
+
+        // ...but a system-only preference does not.
+        let result = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.12"),
+                EnvironmentPreference::OnlySystem,
+                PythonPreference::OnlySystem,
+                &context.cache,
+            )
+        })?;
+        assert!(
+            matches!(result, Err(PythonNotFound { .. })),
+            "Managed interpreters should not be used with `only-system`; got {result:?}"
+        );
+
+        // With only a system interpreter, a managed preference falls back to it...
+        let mut context = TestContext::new()?;
+        context.add_python_versions(&["3.12.2"])?;
+
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.12"),
+                EnvironmentPreference::OnlySystem,
+                PythonPreference::Managed,
+                &context.cache,
+            )
+        })??;
+        assert_eq!(python.source(), &PythonSource::SearchPath);
+
+        // ...but a managed-only preference does not.
+        let result = context.run(|| {
+            find_python_installation(
+                &PythonRequest::parse("3.12"),
+                EnvironmentPreference::OnlySystem,
+                PythonPreference::OnlyManaged,
+                &context.cache,
+            )
+        })?;
+        assert!(
+            matches!(result, Err(PythonNotFound { .. })),
+            "System interpreters should not be used with `only-managed`; got {result:?}"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_system_python_required() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -33,6 +33,8 @@ pub struct PyVenvConfiguration {
     pub(crate) relocatable: bool,
     /// Was the virtual environment populated with seed packages?
     pub(crate) seed: bool,
+    /// Does the virtual environment have access to the base interpreter's `site-packages`?
+    pub(crate) include_system_site_packages: bool,
 }
 
 #[derive(Debug, Error)]
@@ -143,6 +145,7 @@ impl PyVenvConfiguration {
         let mut uv = false;
         let mut relocatable = false;
         let mut seed = false;
+        let mut include_system_site_packages = false;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -166,6 +169,9 @@ impl PyVenvConfiguration {
                 "seed" => {
                     seed = value.trim().to_lowercase() == "true";
                 }
+                "include-system-site-packages" => {
+                    include_system_site_packages = value.trim().to_lowercase() == "true";
+                }
                 _ => {}
             }
         }
@@ -175,6 +181,7 @@ impl PyVenvConfiguration {
             uv,
             relocatable,
             seed,
+            include_system_site_packages,
         })
     }
 
@@ -197,4 +204,10 @@ impl PyVenvConfiguration {
     pub fn is_seed(&self) -> bool {
         self.seed
     }
+
+    /// Returns true if the virtual environment has access to the base interpreter's
+    /// `site-packages`.
+    pub fn include_system_site_packages(&self) -> bool {
+        self.include_system_site_packages
+    }
 }
@@ -7,6 +7,7 @@
 //!  * `-r`
 //!  * `-c`
 //!  * `--hash` (postfix)
+//!  * `--config-settings` and `-C` (postfix)
 //!  * `-e`
 //!
 //! Unsupported:
@@ -28,8 +29,10 @@
 //! # https://packaging.python.org/en/latest/specifications/name-normalization/#valid-non-normalized-names
 //! # This does not (yet?) support plain files or urls, we use a letter or a number as first
 //! # character to assume a PEP 508 requirement
-//! requirement = [a-zA-Z0-9] pep508_grammar_tail wrappable_whitespaces hashes
-//! hashes = ('--hash' ('=' | wrappable_whitespaces) [a-zA-Z0-9-_]+ ':' [a-zA-Z0-9-_] wrappable_whitespaces+)*
+//! requirement = [a-zA-Z0-9] pep508_grammar_tail wrappable_whitespaces options
+//! options = (hash | config_setting)*
+//! hash = '--hash' ('=' | wrappable_whitespaces) [a-zA-Z0-9-_]+ ':' [a-zA-Z0-9-_] wrappable_whitespaces+
+//! config_setting = ('--config-settings' | '-C') ('=' | wrappable_whitespaces) key '=' value wrappable_whitespaces+
 //! # This should indicate a single backslash before a newline
 //! wrappable_whitespaces = whitespace ('\\\n' | whitespace)*
 //! ```
@@ -40,6 +43,7 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+use tokio::io::AsyncBufReadExt;
 use tracing::instrument;
 use unscanny::{Pattern, Scanner};
 use url::Url;
@@ -47,7 +51,10 @@ use url::Url;
 #[cfg(feature = "http")]
 use uv_client::BaseClient;
 use uv_client::BaseClientBuilder;
-use uv_configuration::{NoBinary, NoBuild, PackageNameSpecifier};
+use uv_configuration::{
+    ConfigSettingEntry, ConfigSettings, NoBinary, NoBuild, PackageConfigSettings,
+    PackageNameSpecifier,
+};
 use uv_distribution_types::{UnresolvedRequirement, UnresolvedRequirementSpecification};
 use uv_fs::Simplified;
 use uv_pep508::{expand_env_vars, Pep508Error, RequirementOrigin, VerbatimUrl};
@@ -59,6 +66,9 @@ pub use crate::requirement::RequirementsTxtRequirement;
 
 mod requirement;
 
+/// The default maximum depth of nested `-r` and `-c` includes.
+pub const DEFAULT_MAX_INCLUDE_DEPTH: usize = 50;
+
 /// We emit one of those for each `requirements.txt` entry.
 enum RequirementsTxtStatement {
     /// `-r` inclusion filename
@@ -73,10 +83,10 @@ enum RequirementsTxtStatement {
         start: usize,
         end: usize,
     },
-    /// PEP 508 requirement plus metadata
-    RequirementEntry(RequirementEntry),
-    /// `-e`
-    EditableRequirementEntry(RequirementEntry),
+    /// PEP 508 requirement plus metadata, along with any `--config-settings` and `# uv:` pragma
+    RequirementEntry(RequirementEntry, ConfigSettings, Option<Pragma>),
+    /// `-e`, along with any `--config-settings` and `# uv:` pragma
+    EditableRequirementEntry(RequirementEntry, ConfigSettings, Option<Pragma>),
     /// `--index-url`
     IndexUrl(VerbatimUrl),
     /// `--extra-index-url`
@@ -91,6 +101,47 @@ enum RequirementsTxtStatement {
     OnlyBinary(NoBuild),
 }
 
+/// A per-requirement policy, set via a trailing `# uv: no-binary`, `# uv: only-binary`, or
+/// `# uv: pre` comment.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Pragma {
+    /// `# uv: no-binary`, equivalent to `--no-binary <package>`.
+    NoBinary,
+    /// `# uv: only-binary`, equivalent to `--only-binary <package>`.
+    OnlyBinary,
+    /// `# uv: pre`, which allows pre-release versions of the package.
+    Pre,
+}
+
+impl Pragma {
+    /// Parse a `# uv:` pragma from the contents of a trailing comment (excluding the `#`).
+    ///
+    /// Returns `Ok(None)` for comments that aren't `uv:` pragmas.
+    fn parse(comment: &str) -> Result<Option<Self>, String> {
+        let Some(pragma) = comment.trim().strip_prefix("uv:") else {
+            return Ok(None);
+        };
+        match pragma.trim() {
+            "no-binary" => Ok(Some(Self::NoBinary)),
+            "only-binary" => Ok(Some(Self::OnlyBinary)),
+            "pre" => Ok(Some(Self::Pre)),
+            pragma => Err(format!(
+                "Unknown pragma `# uv: {pragma}` (expected `no-binary`, `only-binary`, or `pre`)"
+            )),
+        }
+    }
+}
+
+impl Display for Pragma {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoBinary => f.write_str("# uv: no-binary"),
+            Self::OnlyBinary => f.write_str("# uv: only-binary"),
+            Self::Pre => f.write_str("# uv: pre"),
+        }
+    }
+}
+
 /// A [Requirement] with additional metadata from the `requirements.txt`, currently only hashes but in
 /// the future also editable and similar information.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -150,6 +201,12 @@ pub struct RequirementsTxt {
     pub no_binary: NoBinary,
     /// Whether to allow only wheels, specified with `--only-binary`.
     pub only_binary: NoBuild,
+    /// The build backend settings for specific packages, specified with `--config-settings` (or
+    /// `-C`) after a requirement.
+    pub config_settings: PackageConfigSettings,
+    /// The packages for which pre-release versions are allowed, specified with a trailing
+    /// `# uv: pre` comment after a requirement.
+    pub prerelease: Vec<PackageName>,
 }
 
 impl RequirementsTxt {
@@ -163,58 +220,142 @@ impl RequirementsTxt {
         working_dir: impl AsRef<Path>,
         client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self, RequirementsTxtFileError> {
-        let requirements_txt = requirements_txt.as_ref();
-        let working_dir = working_dir.as_ref();
+        Self::parse_with_max_depth(
+            requirements_txt,
+            working_dir,
+            client_builder,
+            DEFAULT_MAX_INCLUDE_DEPTH,
+        )
+        .await
+    }
 
-        let content =
-            if requirements_txt.starts_with("http://") | requirements_txt.starts_with("https://") {
-                #[cfg(not(feature = "http"))]
-                {
-                    return Err(RequirementsTxtFileError {
-                        file: requirements_txt.to_path_buf(),
-                        error: RequirementsTxtParserError::Io(io::Error::new(
-                            io::ErrorKind::InvalidInput,
-                            "Remote file not supported without `http` feature",
-                        )),
-                    });
-                }
+    /// Parse a `requirements.txt` file, allowing at most `max_depth` levels of nested `-r` and
+    /// `-c` includes.
+    pub async fn parse_with_max_depth(
+        requirements_txt: impl AsRef<Path>,
+        working_dir: impl AsRef<Path>,
+        client_builder: &BaseClientBuilder<'_>,
+        max_depth: usize,
+    ) -> Result<Self, RequirementsTxtFileError> {
+        Self::parse_at_depth(
+            requirements_txt.as_ref(),
+            working_dir.as_ref(),
+            client_builder,
+            IncludeDepth {
+                current: 0,
+                max: max_depth,
+            },
+            false,
+        )
+        .await
+    }
 
-                #[cfg(feature = "http")]
-                {
-                    // Avoid constructing a client if network is disabled already
-                    if client_builder.is_offline() {
-                        return Err(RequirementsTxtFileError {
-                            file: requirements_txt.to_path_buf(),
-                            error: RequirementsTxtParserError::Io(io::Error::new(
-                                io::ErrorKind::InvalidInput,
-                                format!("Network connectivity is disabled, but a remote requirements file was requested: {}", requirements_txt.display()),
-                            )),
-                        });
-                    }
+    /// Parse the output of `pip freeze`, which is a `requirements.txt` file with a few quirks.
+    ///
+    /// In addition to the comments that `pip freeze` emits (e.g., `## !! Could not determine
+    /// repository location`), editable requirements that can't be installed in editable mode (like
+    /// the `-e git+https://...#egg=flask` entries emitted for editable Git installs) are treated as
+    /// regular requirements, with the package name taken from the `#egg=` fragment.
+    ///
+    /// At most `max_depth` levels of nested `-r` and `-c` includes are allowed.
+    pub async fn parse_freeze(
+        requirements_txt: impl AsRef<Path>,
+        working_dir: impl AsRef<Path>,
+        client_builder: &BaseClientBuilder<'_>,
+        max_depth: usize,
+    ) -> Result<Self, RequirementsTxtFileError> {
+        Self::parse_at_depth(
+            requirements_txt.as_ref(),
+            working_dir.as_ref(),
+            client_builder,
+            IncludeDepth {
+                current: 0,
+                max: max_depth,
+            },
+            true,
+        )
+        .await
+    }
+
+    /// Parse a `requirements.txt` file that was reached through `depth.current` levels of
+    /// includes.
+    ///
+    /// If `freeze` is set, the file is parsed as the output of `pip freeze`.
+    async fn parse_at_depth(
+        requirements_txt: &Path,
+        working_dir: &Path,
+        client_builder: &BaseClientBuilder<'_>,
+        depth: IncludeDepth,
+        freeze: bool,
+    ) -> Result<Self, RequirementsTxtFileError> {
+        let requirements_dir = requirements_txt.parent().unwrap_or(working_dir);
+        let data = if requirements_txt.starts_with("http://")
+            | requirements_txt.starts_with("https://")
+        {
+            #[cfg(not(feature = "http"))]
+            {
+                Err(RequirementsTxtParserError::Io(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "Remote file not supported without `http` feature",
+                )))
+            }
 
+            #[cfg(feature = "http")]
+            {
+                // Avoid constructing a client if network is disabled already
+                if client_builder.is_offline() {
+                    Err(RequirementsTxtParserError::Io(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("Network connectivity is disabled, but a remote requirements file was requested: {}", requirements_txt.display()),
+                    )))
+                } else {
                     let client = client_builder.build();
-                    read_url_to_string(&requirements_txt, client).await
+                    match read_url_to_string(&requirements_txt, client).await {
+                        Ok(content) => {
+                            Self::parse_content(
+                                &content,
+                                working_dir,
+                                requirements_dir,
+                                client_builder,
+                                requirements_txt,
+                                depth,
+                                freeze,
+                            )
+                            .await
+                        }
+                        Err(err) => Err(err),
+                    }
                 }
-            } else {
-                // Ex) `file:///home/ferris/project/requirements.txt`
-                uv_fs::read_to_string_transcode(&requirements_txt)
+            }
+        } else if requirements_txt == Path::new("-") {
+            // Ex) `uv pip install -r -`, which reads from stdin.
+            match uv_fs::read_to_string_transcode(&requirements_txt).await {
+                Ok(content) => {
+                    Self::parse_content(
+                        &content,
+                        working_dir,
+                        requirements_dir,
+                        client_builder,
+                        requirements_txt,
+                        depth,
+                        freeze,
+                    )
                     .await
-                    .map_err(RequirementsTxtParserError::Io)
+                }
+                Err(err) => Err(RequirementsTxtParserError::Io(err)),
             }
-            .map_err(|err| RequirementsTxtFileError {
-                file: requirements_txt.to_path_buf(),
-                error: err,
-            })?;
-
-        let requirements_dir = requirements_txt.parent().unwrap_or(working_dir);
-        let data = Self::parse_inner(
-            &content,
-            working_dir,
-            requirements_dir,
-            client_builder,
-            requirements_txt,
-        )
-        .await
+        } else {
+            // Ex) `file:///home/ferris/project/requirements.txt`
+            Self::parse_file(
+                working_dir,
+                requirements_dir,
+                client_builder,
+                requirements_txt,
+                depth,
+                freeze,
+            )
+            .await
+        }
         .map_err(|err| RequirementsTxtFileError {
             file: requirements_txt.to_path_buf(),
             error: err,
@@ -229,6 +370,87 @@ impl RequirementsTxt {
         Ok(data)
     }
 
+    /// Parse a local `requirements.txt` file one statement at a time as it's read, rather than
+    /// reading it into memory in full.
+    ///
+    /// Generated files can span tens of thousands of lines; reading them statement-by-statement
+    /// keeps only the current statement in memory, and stops at the first malformed statement
+    /// without reading the remainder of the file. Includes are parsed as they're encountered.
+    /// Positions in errors are relative to the start of the file, as with [`Self::parse_content`].
+    async fn parse_file(
+        working_dir: &Path,
+        requirements_dir: &Path,
+        client_builder: &BaseClientBuilder<'_>,
+        requirements_txt: &Path,
+        depth: IncludeDepth,
+        freeze: bool,
+    ) -> Result<Self, RequirementsTxtParserError> {
+        let file = fs_err::tokio::File::open(requirements_txt).await?;
+        let mut reader = tokio::io::BufReader::new(file);
+
+        let mut data = Self::default();
+        let mut line = Vec::new();
+        let mut statement = String::new();
+        // The byte offset and the number of lines preceding the current statement.
+        let mut offset = 0;
+        let mut lines = 0;
+        let mut first = true;
+        loop {
+            line.clear();
+            let eof = reader.read_until(b'\n', &mut line).await? == 0;
+
+            if std::mem::take(&mut first) {
+                if line.starts_with(&[0xFF, 0xFE]) || line.starts_with(&[0xFE, 0xFF]) {
+                    // UTF-16 files (e.g., as written by PowerShell) are transcoded in full.
+                    let content = uv_fs::read_to_string_transcode(requirements_txt).await?;
+                    return Self::parse_content(
+                        &content,
+                        working_dir,
+                        requirements_dir,
+                        client_builder,
+                        requirements_txt,
+                        depth,
+                        freeze,
+                    )
+                    .await;
+                }
+                if line.starts_with(&[0xEF, 0xBB, 0xBF]) {
+                    line.drain(..3);
+                }
+            }
+
+            statement.push_str(&String::from_utf8_lossy(&line));
+            if !eof && is_continued(&statement) {
+                continue;
+            }
+            if statement.is_empty() {
+                break;
+            }
+
+            data.parse_statements(
+                &statement,
+                working_dir,
+                requirements_dir,
+                client_builder,
+                requirements_txt,
+                depth,
+                freeze,
+                lines,
+            )
+            .await
+            .map_err(|err| err.with_offset(offset, lines))?;
+
+            offset += statement.len();
+            lines += calculate_row_column(&statement, statement.len()).0 - 1;
+            statement.clear();
+
+            if eof {
+                break;
+            }
+        }
+        Ok(data)
+    }
+
     /// See module level documentation.
     ///
     /// When parsing, relative paths to requirements (e.g., `-e ../editable/`) are resolved against
@@ -242,10 +464,73 @@ impl RequirementsTxt {
         client_builder: &BaseClientBuilder<'_>,
         requirements_txt: &Path,
     ) -> Result<Self, RequirementsTxtParserError> {
-        let mut s = Scanner::new(content);
+        Self::parse_content(
+            content,
+            working_dir,
+            requirements_dir,
+            client_builder,
+            requirements_txt,
+            IncludeDepth {
+                current: 0,
+                max: DEFAULT_MAX_INCLUDE_DEPTH,
+            },
+            false,
+        )
+        .await
+    }
 
+    /// Parse the contents of a `requirements.txt` file that was reached through `depth.current`
+    /// levels of includes.
+    ///
+    /// If `freeze` is set, the contents are parsed as the output of `pip freeze`.
+    async fn parse_content(
+        content: &str,
+        working_dir: &Path,
+        requirements_dir: &Path,
+        client_builder: &BaseClientBuilder<'_>,
+        requirements_txt: &Path,
+        depth: IncludeDepth,
+        freeze: bool,
+    ) -> Result<Self, RequirementsTxtParserError> {
         let mut data = Self::default();
-        while let Some(statement) = parse_entry(&mut s, content, working_dir, requirements_txt)? {
+        data.parse_statements(
+            content,
+            working_dir,
+            requirements_dir,
+            client_builder,
+            requirements_txt,
+            depth,
+            freeze,
+            0,
+        )
+        .await?;
+        Ok(data)
+    }
+
+    /// Parse the statements in `content`, adding them to the data parsed so far.
+    ///
+    /// `content` may be a portion of a file, preceded by `lines` lines that were already parsed.
+    async fn parse_statements(
+        &mut self,
+        content: &str,
+        working_dir: &Path,
+        requirements_dir: &Path,
+        client_builder: &BaseClientBuilder<'_>,
+        requirements_txt: &Path,
+        depth: IncludeDepth,
+        freeze: bool,
+        lines: usize,
+    ) -> Result<(), RequirementsTxtParserError> {
+        let mut s = Scanner::new(content);
+
+        while let Some(statement) = parse_entry(
+            &mut s,
+            content,
+            working_dir,
+            requirements_txt,
+            freeze,
+            lines,
+        )? {
             match statement {
                 RequirementsTxtStatement::Requirements {
                     filename,
@@ -275,19 +560,24 @@ impl RequirementsTxt {
                         } else {
                             requirements_dir.join(filename.as_ref())
                         };
-                    let sub_requirements =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
-                            .await
-                            .map_err(|err| RequirementsTxtParserError::Subfile {
-                                source: Box::new(err),
-                                start,
-                                end,
-                            })?;
+                    let sub_requirements = Box::pin(Self::parse_at_depth(
+                        &sub_file,
+                        working_dir,
+                        client_builder,
+                        depth.nested(start, end)?,
+                        freeze,
+                    ))
+                    .await
+                    .map_err(|err| RequirementsTxtParserError::Subfile {
+                        source: Box::new(err),
+                        start,
+                        end,
+                    })?;
 
                     // Disallow conflicting `--index-url` in nested `requirements` files.
                     if sub_requirements.index_url.is_some()
-                        && data.index_url.is_some()
-                        && sub_requirements.index_url != data.index_url
+                        && self.index_url.is_some()
+                        && sub_requirements.index_url != self.index_url
                     {
                         let (line, column) = calculate_row_column(content, s.cursor());
                         return Err(RequirementsTxtParserError::Parser {
@@ -300,7 +590,7 @@ impl RequirementsTxt {
                     }
 
                     // Add each to the correct category.
-                    data.update_from(sub_requirements);
+                    self.update_from(sub_requirements);
                 }
                 RequirementsTxtStatement::Constraint {
                     filename,
@@ -330,14 +620,19 @@ impl RequirementsTxt {
                         } else {
                             requirements_dir.join(filename.as_ref())
                         };
-                    let sub_constraints =
-                        Box::pin(Self::parse(&sub_file, working_dir, client_builder))
-                            .await
-                            .map_err(|err| RequirementsTxtParserError::Subfile {
-                                source: Box::new(err),
-                                start,
-                                end,
-                            })?;
+                    let sub_constraints = Box::pin(Self::parse_at_depth(
+                        &sub_file,
+                        working_dir,
+                        client_builder,
+                        depth.nested(start, end)?,
+                        freeze,
+                    ))
+                    .await
+                    .map_err(|err| RequirementsTxtParserError::Subfile {
+                        source: Box::new(err),
+                        start,
+                        end,
+                    })?;
 
                     // Treat any nested requirements or constraints as constraints. This differs
                     // from `pip`, which seems to treat `-r` requirements in constraints files as
@@ -345,7 +640,7 @@ impl RequirementsTxt {
                     for entry in sub_constraints.requirements {
                         match entry.requirement {
                             RequirementsTxtRequirement::Named(requirement) => {
-                                data.constraints.push(requirement);
+                                self.constraints.push(requirement);
                             }
                             RequirementsTxtRequirement::Unnamed(_) => {
                                 return Err(RequirementsTxtParserError::UnnamedConstraint {
@@ -356,17 +651,29 @@ impl RequirementsTxt {
                         }
                     }
                     for constraint in sub_constraints.constraints {
-                        data.constraints.push(constraint);
+                        self.constraints.push(constraint);
                     }
                 }
-                RequirementsTxtStatement::RequirementEntry(requirement_entry) => {
-                    data.requirements.push(requirement_entry);
+                RequirementsTxtStatement::RequirementEntry(
+                    requirement_entry,
+                    config_settings,
+                    pragma,
+                ) => {
+                    self.add_config_settings(&requirement_entry, config_settings, content, &s)?;
+                    self.add_pragma(&requirement_entry, pragma, content, &s)?;
+                    self.requirements.push(requirement_entry);
                 }
-                RequirementsTxtStatement::EditableRequirementEntry(editable) => {
-                    data.editables.push(editable);
+                RequirementsTxtStatement::EditableRequirementEntry(
+                    editable,
+                    config_settings,
+                    pragma,
+                ) => {
+                    self.add_config_settings(&editable, config_settings, content, &s)?;
+                    self.add_pragma(&editable, pragma, content, &s)?;
+                    self.editables.push(editable);
                 }
                 RequirementsTxtStatement::IndexUrl(url) => {
-                    if data.index_url.is_some() {
+                    if self.index_url.is_some() {
                         let (line, column) = calculate_row_column(content, s.cursor());
                         return Err(RequirementsTxtParserError::Parser {
                             message: "Multiple `--index-url` values provided".to_string(),
@@ -374,26 +681,92 @@ impl RequirementsTxt {
                             column,
                         });
                     }
-                    data.index_url = Some(url);
+                    self.index_url = Some(url);
                 }
                 RequirementsTxtStatement::ExtraIndexUrl(url) => {
-                    data.extra_index_urls.push(url);
+                    self.extra_index_urls.push(url);
                 }
                 RequirementsTxtStatement::FindLinks(url) => {
-                    data.find_links.push(url);
+                    self.find_links.push(url);
                 }
                 RequirementsTxtStatement::NoIndex => {
-                    data.no_index = true;
+                    self.no_index = true;
                 }
                 RequirementsTxtStatement::NoBinary(no_binary) => {
-                    data.no_binary.extend(no_binary);
+                    self.no_binary.extend(no_binary);
                 }
                 RequirementsTxtStatement::OnlyBinary(only_binary) => {
-                    data.only_binary.extend(only_binary);
+                    self.only_binary.extend(only_binary);
                 }
             }
         }
-        Ok(data)
+        Ok(())
+    }
+
+    /// Record the `--config-settings` provided for a requirement, which must be named.
+    fn add_config_settings(
+        &mut self,
+        entry: &RequirementEntry,
+        config_settings: ConfigSettings,
+        content: &str,
+        s: &Scanner,
+    ) -> Result<(), RequirementsTxtParserError> {
+        if config_settings.is_empty() {
+            return Ok(());
+        }
+        match &entry.requirement {
+            RequirementsTxtRequirement::Named(requirement) => {
+                self.config_settings
+                    .insert(requirement.name.clone(), config_settings);
+                Ok(())
+            }
+            RequirementsTxtRequirement::Unnamed(requirement) => {
+                let (line, column) = calculate_row_column(content, s.cursor());
+                Err(RequirementsTxtParserError::Parser {
+                    message: format!(
+                        "`--config-settings` requires a named requirement (found: `{requirement}`)"
+                    ),
+                    line,
+                    column,
+                })
+            }
+        }
+    }
+
+    /// Record the `# uv:` pragma provided for a requirement, which must be named.
+    fn add_pragma(
+        &mut self,
+        entry: &RequirementEntry,
+        pragma: Option<Pragma>,
+        content: &str,
+        s: &Scanner,
+    ) -> Result<(), RequirementsTxtParserError> {
+        let Some(pragma) = pragma else {
+            return Ok(());
+        };
+        match &entry.requirement {
+            RequirementsTxtRequirement::Named(requirement) => {
+                let packages = vec![requirement.name.clone()];
+                match pragma {
+                    Pragma::NoBinary => self.no_binary.extend(NoBinary::Packages(packages)),
+                    Pragma::OnlyBinary => {
+                        self.only_binary.extend(NoBuild::Packages(packages));
+                    }
+                    Pragma::Pre => self.prerelease.extend(packages),
+                }
+                Ok(())
+            }
+            RequirementsTxtRequirement::Unnamed(requirement) => {
+                let (line, column) = calculate_row_column(content, s.cursor());
+                Err(RequirementsTxtParserError::Parser {
+                    message: format!(
+                        "`{pragma}` requires a named requirement (found: `{requirement}`)"
+                    ),
+                    line,
+                    column,
+                })
+            }
+        }
     }
 
     /// Merge the data from a nested `requirements` file (`other`) into this one.
@@ -408,6 +781,8 @@ impl RequirementsTxt {
             no_index,
             no_binary,
             only_binary,
+            config_settings,
+            prerelease,
         } = other;
         self.requirements.extend(requirements);
         self.constraints.extend(constraints);
@@ -420,6 +795,35 @@ impl RequirementsTxt {
         self.no_index = self.no_index || no_index;
         self.no_binary.extend(no_binary);
         self.only_binary.extend(only_binary);
+        self.config_settings.extend(config_settings);
+        self.prerelease.extend(prerelease);
+    }
+}
+
+/// The nesting depth of a `requirements.txt` file within a tree of `-r` and `-c` includes.
+#[derive(Debug, Copy, Clone)]
+struct IncludeDepth {
+    /// The number of includes traversed to reach the current file.
+    current: usize,
+    /// The maximum number of includes that may be traversed.
+    max: usize,
+}
+
+impl IncludeDepth {
+    /// Return the depth of a file included from the current file, or an error if doing so would
+    /// exceed the maximum depth.
+    fn nested(self, start: usize, end: usize) -> Result<Self, RequirementsTxtParserError> {
+        if self.current >= self.max {
+            return Err(RequirementsTxtParserError::MaxIncludeDepth {
+                max_depth: self.max,
+                start,
+                end,
+            });
+        }
+        Ok(Self {
+            current: self.current + 1,
+            max: self.max,
+        })
     }
 }
 
@@ -427,11 +831,15 @@ impl RequirementsTxt {
 ///
 /// Consumes all preceding trivia (whitespace and comments). If it returns None, we've reached
 /// the end of file
+///
+/// `lines` is the number of lines in the file that precede `content`.
 fn parse_entry(
     s: &mut Scanner,
     content: &str,
     working_dir: &Path,
     requirements_txt: &Path,
+    freeze: bool,
+    lines: usize,
 ) -> Result<Option<RequirementsTxtStatement>, RequirementsTxtParserError> {
     // Eat all preceding whitespace, this may run us to the end of file
     eat_wrappable_whitespace(s);
@@ -467,20 +875,39 @@ fn parse_entry(
             Some(requirements_txt)
         };
 
-        let (requirement, hashes) =
-            parse_requirement_and_hashes(s, content, source, working_dir, true)?;
-        let requirement =
-            requirement
-                .into_editable()
-                .map_err(|err| RequirementsTxtParserError::NonEditable {
+        let RequirementAndOptions {
+            requirement,
+            hashes,
+            config_settings,
+            pragma,
+        } = parse_requirement_and_options(s, content, source, working_dir, true, lines)?;
+        match requirement.clone().into_editable() {
+            Ok(requirement) => RequirementsTxtStatement::EditableRequirementEntry(
+                RequirementEntry {
+                    requirement,
+                    hashes,
+                },
+                config_settings,
+                pragma,
+            ),
+            // `pip freeze` emits editable VCS installs as `-e git+https://...#egg=flask`, which
+            // we install as a regular requirement instead.
+            Err(_) if freeze => RequirementsTxtStatement::RequirementEntry(
+                RequirementEntry {
+                    requirement: requirement.with_egg_name(),
+                    hashes,
+                },
+                config_settings,
+                pragma,
+            ),
+            Err(err) => {
+                return Err(RequirementsTxtParserError::NonEditable {
                     source: err,
                     start,
                     end: s.cursor(),
-                })?;
-        RequirementsTxtStatement::EditableRequirementEntry(RequirementEntry {
-            requirement,
-            hashes,
-        })
+                });
+            }
+        }
     } else if s.eat_if("-i") || s.eat_if("--index-url") {
         let given = parse_value(content, s, |c: char| !['\n', '\r', '#'].contains(&c))?;
         let expanded = expand_env_vars(given);
@@ -590,12 +1017,20 @@ fn parse_entry(
             Some(requirements_txt)
         };
 
-        let (requirement, hashes) =
-            parse_requirement_and_hashes(s, content, source, working_dir, false)?;
-        RequirementsTxtStatement::RequirementEntry(RequirementEntry {
+        let RequirementAndOptions {
             requirement,
             hashes,
-        })
+            config_settings,
+            pragma,
+        } = parse_requirement_and_options(s, content, source, working_dir, false, lines)?;
+        RequirementsTxtStatement::RequirementEntry(
+            RequirementEntry {
+                requirement,
+                hashes,
+            },
+            config_settings,
+            pragma,
+        )
     } else if let Some(char) = s.peek() {
         let (line, column) = calculate_row_column(content, s.cursor());
         return Err(RequirementsTxtParserError::Parser {
@@ -611,21 +1046,27 @@ fn parse_entry(
     }))
 }
 
+/// Returns `true` for whitespace that doesn't end a line, i.e., spaces and tabs, but also less
+/// common whitespace like non-breaking spaces and form feeds.
+fn is_inline_whitespace(c: char) -> bool {
+    c.is_whitespace() && !matches!(c, '\n' | '\r')
+}
+
 /// Eat whitespace and ignore newlines escaped with a backslash
 fn eat_wrappable_whitespace<'a>(s: &mut Scanner<'a>) -> &'a str {
     let start = s.cursor();
-    s.eat_while([' ', '\t']);
+    s.eat_while(is_inline_whitespace);
     // Allow multiple escaped line breaks
     // With the order we support `\n`, `\r`, `\r\n` without accidentally eating a `\n\r`
     while s.eat_if("\\\n") || s.eat_if("\\\r\n") || s.eat_if("\\\r") {
-        s.eat_while([' ', '\t']);
+        s.eat_while(is_inline_whitespace);
     }
     s.from(start)
 }
 
 /// Eats the end of line or a potential trailing comma
 fn eat_trailing_line(content: &str, s: &mut Scanner) -> Result<(), RequirementsTxtParserError> {
-    s.eat_while([' ', '\t']);
+    s.eat_while(is_inline_whitespace);
     match s.eat() {
         None | Some('\n') => {} // End of file or end of line, nothing to do
         Some('\r') => {
@@ -649,18 +1090,32 @@ fn eat_trailing_line(content: &str, s: &mut Scanner) -> Result<(), RequirementsT
     Ok(())
 }
 
-/// Parse a PEP 508 requirement with optional trailing hashes
-fn parse_requirement_and_hashes(
+/// A PEP 508 requirement, along with its trailing options.
+struct RequirementAndOptions {
+    requirement: RequirementsTxtRequirement,
+    /// The `--hash` values.
+    hashes: Vec<String>,
+    /// The `--config-settings` values.
+    config_settings: ConfigSettings,
+    /// The `# uv:` pragma from the trailing comment, if any.
+    pragma: Option<Pragma>,
+}
+
+/// Parse a PEP 508 requirement with optional trailing hashes, config settings, and a `# uv:`
+/// pragma
+fn parse_requirement_and_options(
     s: &mut Scanner,
     content: &str,
     source: Option<&Path>,
     working_dir: &Path,
     editable: bool,
-) -> Result<(RequirementsTxtRequirement, Vec<String>), RequirementsTxtParserError> {
+    lines: usize,
+) -> Result<RequirementAndOptions, RequirementsTxtParserError> {
     // PEP 508 requirement
     let start = s.cursor();
+    let mut comment = None;
     // Termination: s.eat() eventually becomes None
-    let (end, has_hashes) = loop {
+    let (end, has_options) = loop {
         let end = s.cursor();
 
         //  We look for the end of the line ...
@@ -671,11 +1126,12 @@ fn parse_requirement_and_hashes(
             s.eat_if('\n'); // Support `\r\n` but also accept stray `\r`
             break (end, false);
         }
-        // ... or `--hash`, an escaped newline or a comment separated by whitespace ...
+        // ... or `--hash`, `-C`, an escaped newline or a comment separated by whitespace ...
         if !eat_wrappable_whitespace(s).is_empty() {
-            if s.after().starts_with("--") {
+            if s.after().starts_with("--") || s.after().starts_with("-C") {
                 break (end, true);
-            } else if s.eat_if('#') {
+            } else if s.at('#') {
+                comment = Some(s.cursor());
                 s.eat_until(['\r', '\n']);
                 if s.at('\r') {
                     s.eat_if('\n'); // `\r\n`, but just `\r` is also accepted
@@ -690,7 +1146,13 @@ fn parse_requirement_and_hashes(
         }
     };
 
-    let requirement = &content[start..end];
+    if comment.is_none() {
+        check_continuation(content, s)?;
+    }
+
+    // Join any lines that were continued with a trailing backslash.
+    let requirement = join_continued_lines(&content[start..end]);
+    let requirement = requirement.as_ref();
 
     // If the requirement looks like a `requirements.txt` file (with a missing `-r`), raise an
     // error.
@@ -726,39 +1188,156 @@ fn parse_requirement_and_hashes(
             end,
         })?;
 
-    let hashes = if has_hashes {
-        parse_hashes(content, s)?
+    let (hashes, config_settings) = if has_options {
+        let options = parse_options(content, s)?;
+        // A comment may also follow the options.
+        if s.at('#') {
+            comment = Some(s.cursor());
+        } else {
+            check_continuation(content, s)?;
+        }
+        options
     } else {
-        Vec::new()
+        (Vec::new(), ConfigSettings::default())
     };
-    Ok((requirement, hashes))
+
+    // Parse the `# uv:` pragma from the trailing comment, if any. Unknown pragmas are ignored, such
+    // that files remain usable with older (or newer) versions of uv.
+    let pragma = comment.and_then(|comment| {
+        let text = content[comment + 1..]
+            .split(['\r', '\n'])
+            .next()
+            .unwrap_or_default();
+        Pragma::parse(text).unwrap_or_else(|message| {
+            let (line, column) = calculate_row_column(content, comment);
+            let line = line + lines;
+            if let Some(source) = source {
+                warn_user!(
+                    "{message} at {}:{line}:{column}; ignoring",
+                    source.user_display()
+                );
+            } else {
+                warn_user!("{message} at {line}:{column}; ignoring");
+            }
+            None
+        })
+    });
+
+    Ok(RequirementAndOptions {
+        requirement,
+        hashes,
+        config_settings,
+        pragma,
+    })
 }
 
-/// Parse `--hash=... --hash ...` after a requirement
-fn parse_hashes(content: &str, s: &mut Scanner) -> Result<Vec<String>, RequirementsTxtParserError> {
-    let mut hashes = Vec::new();
-    if s.eat_while("--hash").is_empty() {
-        let (line, column) = calculate_row_column(content, s.cursor());
+/// Remove any escaped newlines (i.e., a trailing backslash) from a requirement, joining the lines
+/// that were continued.
+///
+/// As in pip, the continued lines are joined as-is, such that a URL can be split across lines.
+fn join_continued_lines(text: &str) -> Cow<'_, str> {
+    if text.contains("\\\n") || text.contains("\\\r") {
+        Cow::Owned(
+            text.replace("\\\r\n", "")
+                .replace("\\\n", "")
+                .replace("\\\r", ""),
+        )
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Returns `true` if a statement read line-by-line may continue onto the next line.
+///
+/// Besides a trailing backslash, an option that takes a value (e.g., `-r` or `--hash`) may have
+/// its value on a subsequent line. Continuing a statement that is in fact complete is harmless.
+fn is_continued(statement: &str) -> bool {
+    let statement = statement.trim_end();
+    if statement.ends_with('\\') {
+        return true;
+    }
+    statement
+        .split_whitespace()
+        .next_back()
+        .is_some_and(|last| {
+            matches!(
+                last,
+                "-r" | "--requirement"
+                    | "-c"
+                    | "--constraint"
+                    | "-e"
+                    | "--editable"
+                    | "-i"
+                    | "--index-url"
+                    | "--extra-index-url"
+                    | "-f"
+                    | "--find-links"
+                    | "--no-binary"
+                    | "--only-binary"
+                    | "--hash"
+                    | "-C"
+                    | "--config-settings"
+            )
+        })
+}
+
+/// Error if the scanner reached the end of the file immediately after a line continuation (i.e.,
+/// a trailing backslash), which has no line to continue onto.
+fn check_continuation(content: &str, s: &Scanner) -> Result<(), RequirementsTxtParserError> {
+    if !s.done() {
+        return Ok(());
+    }
+    let before = s.before().trim_end();
+    if before.ends_with('\\') {
+        let (line, column) = calculate_row_column(content, before.len() - 1);
         return Err(RequirementsTxtParserError::Parser {
-            message: format!(
-                "Expected `--hash`, found `{:?}`",
-                s.eat_while(|c: char| !c.is_whitespace())
-            ),
+            message: "Unexpected end of file after line continuation (`\\`)".to_string(),
             line,
             column,
         });
     }
-    let hash = parse_value(content, s, |c: char| !c.is_whitespace())?;
-    hashes.push(hash.to_string());
+    Ok(())
+}
+
+/// Parse `--hash=... --config-settings=... -C ...` after a requirement
+fn parse_options(
+    content: &str,
+    s: &mut Scanner,
+) -> Result<(Vec<String>, ConfigSettings), RequirementsTxtParserError> {
+    let mut hashes = Vec::new();
+    let mut config_settings = Vec::new();
     loop {
-        eat_wrappable_whitespace(s);
-        if !s.eat_if("--hash") {
+        if s.eat_if("--hash") {
+            let hash = parse_value(content, s, |c: char| !c.is_whitespace())?;
+            hashes.push(hash.to_string());
+        } else if s.eat_if("--config-settings") || s.eat_if("-C") {
+            let start = s.cursor();
+            let value = parse_value(content, s, |c: char| !c.is_whitespace())?;
+            let entry = ConfigSettingEntry::from_str(value).map_err(|err| {
+                let (line, column) = calculate_row_column(content, start);
+                RequirementsTxtParserError::Parser {
+                    message: format!("Invalid `--config-settings` value `{value}`: {err}"),
+                    line,
+                    column,
+                }
+            })?;
+            config_settings.push(entry);
+        } else if hashes.is_empty() && config_settings.is_empty() {
+            let (line, column) = calculate_row_column(content, s.cursor());
+            return Err(RequirementsTxtParserError::Parser {
+                message: format!(
+                    "Expected `--hash` or `--config-settings`, found `{:?}`",
+                    s.eat_while(|c: char| !c.is_whitespace())
+                ),
+                line,
+                column,
+            });
+        } else {
             break;
         }
-        let hash = parse_value(content, s, |c: char| !c.is_whitespace())?;
-        hashes.push(hash.to_string());
+        eat_wrappable_whitespace(s);
     }
-    Ok(hashes)
+    Ok((hashes, config_settings.into_iter().collect()))
 }
 
 /// In `-<key>=<value>` or `-<key> value`, this parses the part after the key
@@ -815,6 +1394,13 @@ pub struct RequirementsTxtFileError {
     error: RequirementsTxtParserError,
 }
 
+impl RequirementsTxtFileError {
+    /// Returns the path to the file that failed to parse.
+    pub fn file(&self) -> &Path {
+        &self.file
+    }
+}
+
 /// Error parsing requirements.txt, error disambiguation
 #[derive(Debug)]
 pub enum RequirementsTxtParserError {
@@ -885,6 +1471,11 @@ pub enum RequirementsTxtParserError {
         start: usize,
         end: usize,
     },
+    MaxIncludeDepth {
+        max_depth: usize,
+        start: usize,
+        end: usize,
+    },
     NonUnicodeUrl {
         url: PathBuf,
     },
@@ -892,6 +1483,118 @@ pub enum RequirementsTxtParserError {
     Reqwest(reqwest_middleware::Error),
 }
 
+impl RequirementsTxtParserError {
+    /// Shift the positions in the error for a statement that starts `offset` bytes and `lines`
+    /// lines into the file.
+    fn with_offset(self, offset: usize, lines: usize) -> Self {
+        match self {
+            Self::Url {
+                source,
+                url,
+                start,
+                end,
+            } => Self::Url {
+                source,
+                url,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::FileUrl { url, start, end } => Self::FileUrl {
+                url,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::VerbatimUrl {
+                source,
+                url,
+                start,
+                end,
+            } => Self::VerbatimUrl {
+                source,
+                url,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::NonEditable { source, start, end } => Self::NonEditable {
+                source,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::NoBinary {
+                source,
+                specifier,
+                start,
+                end,
+            } => Self::NoBinary {
+                source,
+                specifier,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::OnlyBinary {
+                source,
+                specifier,
+                start,
+                end,
+            } => Self::OnlyBinary {
+                source,
+                specifier,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::UnnamedConstraint { start, end } => Self::UnnamedConstraint {
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::Parser {
+                message,
+                line,
+                column,
+            } => Self::Parser {
+                message,
+                line: line + lines,
+                column,
+            },
+            Self::UnsupportedRequirement { source, start, end } => Self::UnsupportedRequirement {
+                source,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::Pep508 { source, start, end } => Self::Pep508 {
+                source,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::ParsedUrl { source, start, end } => Self::ParsedUrl {
+                source,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::Subfile { source, start, end } => Self::Subfile {
+                source,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::MaxIncludeDepth {
+                max_depth,
+                start,
+                end,
+            } => Self::MaxIncludeDepth {
+                max_depth,
+                start: start + offset,
+                end: end + offset,
+            },
+            Self::Io(_)
+            | Self::UrlConversion(_)
+            | Self::UnsupportedUrl(_)
+            | Self::MissingRequirementPrefix(_)
+            | Self::NonUnicodeUrl { .. } => self,
+            #[cfg(feature = "http")]
+            Self::Reqwest(_) => self,
+        }
+    }
+}
+
 impl Display for RequirementsTxtParserError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -945,6 +1648,14 @@ impl Display for RequirementsTxtParserError {
             Self::Subfile { start, .. } => {
                 write!(f, "Error parsing included file at position {start}")
             }
+            Self::MaxIncludeDepth {
+                max_depth, start, ..
+            } => {
+                write!(
+                    f,
+                    "Exceeded the maximum include depth of {max_depth} at position {start}"
+                )
+            }
             Self::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -978,6 +1689,7 @@ impl std::error::Error for RequirementsTxtParserError {
             Self::Pep508 { source, .. } => Some(source),
             Self::ParsedUrl { source, .. } => Some(source),
             Self::Subfile { source, .. } => Some(source.as_ref()),
+            Self::MaxIncludeDepth { .. } => None,
             Self::Parser { .. } => None,
             Self::NonUnicodeUrl { .. } => None,
             #[cfg(feature = "http")]
@@ -1099,6 +1811,15 @@ impl Display for RequirementsTxtFileError {
                     self.file.user_display(),
                 )
             }
+            RequirementsTxtParserError::MaxIncludeDepth {
+                max_depth, start, ..
+            } => {
+                write!(
+                    f,
+                    "Exceeded the maximum include depth of {max_depth} in `{}` at position {start}",
+                    self.file.user_display(),
+                )
+            }
             RequirementsTxtParserError::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -1198,9 +1919,15 @@ mod test {
     use unscanny::Scanner;
 
     use uv_client::BaseClientBuilder;
+    use uv_configuration::{ConfigSettings, NoBinary, NoBuild};
     use uv_fs::Simplified;
+    use uv_normalize::{ExtraName, PackageName};
+    use uv_pep508::VersionOrUrl;
 
-    use crate::{calculate_row_column, RequirementsTxt};
+    use crate::{
+        calculate_row_column, RequirementsTxt, RequirementsTxtRequirement,
+        DEFAULT_MAX_INCLUDE_DEPTH,
+    };
 
     fn workspace_test_data_dir() -> PathBuf {
         Path::new("./test-data").simple_canonicalize().unwrap()
@@ -1228,6 +1955,7 @@ mod test {
     #[test_case(Path::new("include-a.txt"))]
     #[test_case(Path::new("include-b.txt"))]
     #[test_case(Path::new("poetry-with-hashes.txt"))]
+    #[test_case(Path::new("shebang.txt"))]
     #[test_case(Path::new("small.txt"))]
     #[test_case(Path::new("whitespace.txt"))]
     #[tokio::test]
@@ -1260,6 +1988,7 @@ mod test {
     #[test_case(Path::new("include-a.txt"))]
     #[test_case(Path::new("include-b.txt"))]
     #[test_case(Path::new("poetry-with-hashes.txt"))]
+    #[test_case(Path::new("shebang.txt"))]
     #[test_case(Path::new("small.txt"))]
     #[test_case(Path::new("whitespace.txt"))]
     #[tokio::test]
@@ -1358,61 +2087,416 @@ mod test {
                 .await
                 .unwrap();
 
-        let snapshot = format!("parse-windows-{}", path.to_string_lossy());
+        let snapshot = format!("parse-windows-{}", path.to_string_lossy());
+
+        insta::with_settings!({
+            filters => path_filters(&path_filter(&working_dir)),
+        }, {
+            insta::assert_debug_snapshot!(snapshot, actual);
+        });
+    }
+
+    #[tokio::test]
+    async fn invalid_include_missing_file() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let missing_txt = temp_dir.child("missing.txt");
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            -r missing.txt
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error)
+            .chain()
+            // The last error is operating-system specific.
+            .take(2)
+            .join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let missing_txt = regex::escape(&missing_txt.path().user_display().to_string());
+        let filters = vec![
+            (requirement_txt.as_str(), "<REQUIREMENTS_TXT>"),
+            (missing_txt.as_str(), "<MISSING_TXT>"),
+        ];
+        insta::with_settings!({
+            filters => filters,
+        }, {
+            insta::assert_snapshot!(errors, @r###"
+            Error parsing included file in `<REQUIREMENTS_TXT>` at position 0
+            failed to read from file `<MISSING_TXT>`
+            "###);
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_requirement_version() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            numpy[ö]==1.29
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let filters = vec![(requirement_txt.as_str(), "<REQUIREMENTS_TXT>")];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @r###"
+            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 0
+            Expected an alphanumeric character starting the extra name, found `ö`
+            numpy[ö]==1.29
+                  ^
+            "###);
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_requirement_url() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            numpy @ https:///
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let filters = vec![(requirement_txt.as_str(), "<REQUIREMENTS_TXT>")];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @r###"
+            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 0
+            empty host
+            numpy @ https:///
+                    ^^^^^^^^^
+            "###);
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unsupported_editable() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            -e https://localhost:8080/
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let filters = vec![(requirement_txt.as_str(), "<REQUIREMENTS_TXT>")];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @r###"
+            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 3
+            Expected direct URL (`https://localhost:8080/`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
+            https://localhost:8080/
+            ^^^^^^^^^^^^^^^^^^^^^^^
+            "###);
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unsupported_editable_extension() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            -e https://files.pythonhosted.org/packages/f7/69/96766da2cdb5605e6a31ef2734aff0be17901cefb385b885c2ab88896d76/ruff-0.5.6.tar.gz
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let filters = vec![(requirement_txt.as_str(), "<REQUIREMENTS_TXT>")];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @r###"
+            Unsupported editable requirement in `<REQUIREMENTS_TXT>`
+            Editable must refer to a local directory, not an HTTPS URL: `https://files.pythonhosted.org/packages/f7/69/96766da2cdb5605e6a31ef2734aff0be17901cefb385b885c2ab88896d76/ruff-0.5.6.tar.gz`
+            "###);
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_editable_extra() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            -e black[,abcdef]
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let filters = vec![(requirement_txt.as_str(), "<REQUIREMENTS_TXT>")];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @r###"
+            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 3
+            Expected either alphanumerical character (starting the extra name) or `]` (ending the extras section), found `,`
+            black[,abcdef]
+                  ^
+            "###);
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn config_settings() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            flask --config-settings=--build-option=--fast -C key=value
+            black \\
+                --hash=sha256:1234 \\
+                --config-settings key=other
+            -r child.txt
+        "})?;
+
+        let child = temp_dir.child("child.txt");
+        child.write_str(indoc! {"
+            flask -C key=child
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(requirements.requirements.len(), 3);
+        assert_eq!(requirements.requirements[1].hashes, vec!["sha256:1234"]);
+
+        let global = ConfigSettings::default();
+        let flask = PackageName::from_str("flask")?;
+        assert_eq!(
+            requirements
+                .config_settings
+                .resolve(Some(&flask), &global)
+                .escape_for_python(),
+            r#"{"--build-option":"--fast","key":["value","child"]}"#
+        );
+        let black = PackageName::from_str("black")?;
+        assert_eq!(
+            requirements
+                .config_settings
+                .resolve(Some(&black), &global)
+                .escape_for_python(),
+            r#"{"key":"other"}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn continuation() -> Result<()> {
+        let working_dir = workspace_test_data_dir().join("requirements-txt");
+        let requirements_txt = working_dir.join("continuation.txt");
+
+        let requirements =
+            RequirementsTxt::parse(requirements_txt, &working_dir, &BaseClientBuilder::new())
+                .await
+                .unwrap();
+
+        // The continued lines should be joined into a single requirement.
+        assert_eq!(requirements.requirements.len(), 1);
+        let RequirementsTxtRequirement::Named(requirement) =
+            &requirements.requirements[0].requirement
+        else {
+            panic!("Expected a named requirement");
+        };
+        assert_eq!(requirement.name, PackageName::from_str("requests")?);
+        assert_eq!(requirement.extras, vec![ExtraName::from_str("security")?]);
+        assert_eq!(
+            requirement
+                .version_or_url
+                .as_ref()
+                .map(ToString::to_string)
+                .as_deref(),
+            Some(">=2.8.1, <3")
+        );
+        assert!(!requirement.marker.is_true());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn hash_continuation() -> Result<()> {
+        let working_dir = workspace_test_data_dir().join("requirements-txt");
+        let requirements_txt = working_dir.join("hash-continuation.txt");
+
+        let requirements =
+            RequirementsTxt::parse(requirements_txt, &working_dir, &BaseClientBuilder::new())
+                .await
+                .unwrap();
+
+        // Each of the continued `--hash` lines should be attached to the first requirement.
+        assert_eq!(requirements.requirements.len(), 2);
+        assert_eq!(
+            requirements.requirements[0].hashes,
+            vec![
+                "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3",
+                "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+                "sha256:0000000000000000000000000000000000000000000000000000000000000000",
+            ]
+        );
+
+        // The following requirement should be unaffected.
+        let RequirementsTxtRequirement::Named(requirement) =
+            &requirements.requirements[1].requirement
+        else {
+            panic!("Expected a named requirement");
+        };
+        assert_eq!(requirement.name, PackageName::from_str("tomli")?);
+        assert!(requirements.requirements[1].hashes.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn continuation_url() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-\\
+            py3-none-any.whl
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(requirements.requirements.len(), 1);
+        let RequirementsTxtRequirement::Named(requirement) =
+            &requirements.requirements[0].requirement
+        else {
+            panic!("Expected a named requirement");
+        };
+        let Some(VersionOrUrl::Url(url)) = &requirement.version_or_url else {
+            panic!("Expected a URL requirement");
+        };
+        assert!(url
+            .verbatim
+            .to_string()
+            .ends_with("/anyio-4.3.0-py3-none-any.whl"));
 
-        insta::with_settings!({
-            filters => path_filters(&path_filter(&working_dir)),
-        }, {
-            insta::assert_debug_snapshot!(snapshot, actual);
-        });
+        Ok(())
     }
 
     #[tokio::test]
-    async fn invalid_include_missing_file() -> Result<()> {
+    async fn nameless_url() -> Result<()> {
         let temp_dir = assert_fs::TempDir::new()?;
-        let missing_txt = temp_dir.child("missing.txt");
         let requirements_txt = temp_dir.child("requirements.txt");
         requirements_txt.write_str(indoc! {"
-            -r missing.txt
+            @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl
+            @ https://files.pythonhosted.org/packages/d4/0f/1a4e1b4dd19d3fac1bc3a0ec03bc2bf7d0ed4e5e1bf4c0b81bce3f5c2d3c/Tomli-2.0.1.tar.gz
         "})?;
 
-        let error = RequirementsTxt::parse(
+        let requirements = RequirementsTxt::parse(
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
         )
         .await
-        .unwrap_err();
-        let errors = anyhow::Error::new(error)
-            .chain()
-            // The last error is operating-system specific.
-            .take(2)
-            .join("\n");
+        .unwrap();
 
-        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
-        let missing_txt = regex::escape(&missing_txt.path().user_display().to_string());
-        let filters = vec![
-            (requirement_txt.as_str(), "<REQUIREMENTS_TXT>"),
-            (missing_txt.as_str(), "<MISSING_TXT>"),
-        ];
-        insta::with_settings!({
-            filters => filters,
-        }, {
-            insta::assert_snapshot!(errors, @r###"
-            Error parsing included file in `<REQUIREMENTS_TXT>` at position 0
-            failed to read from file `<MISSING_TXT>`
-            "###);
-        });
+        // The package names should be derived from the filenames.
+        assert_eq!(requirements.requirements.len(), 2);
+        let names = requirements
+            .requirements
+            .iter()
+            .map(|entry| {
+                let RequirementsTxtRequirement::Named(requirement) = &entry.requirement else {
+                    panic!("Expected a named requirement");
+                };
+                assert!(matches!(
+                    requirement.version_or_url,
+                    Some(VersionOrUrl::Url(_))
+                ));
+                requirement.name.clone()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                PackageName::from_str("anyio")?,
+                PackageName::from_str("tomli")?
+            ]
+        );
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn invalid_requirement_version() -> Result<()> {
+    async fn nameless_url_invalid_filename() -> Result<()> {
         let temp_dir = assert_fs::TempDir::new()?;
         let requirements_txt = temp_dir.child("requirements.txt");
         requirements_txt.write_str(indoc! {"
-            numpy[ö]==1.29
+            @ https://example.com/wheel.whl
         "})?;
 
         let error = RequirementsTxt::parse(
@@ -1431,22 +2515,150 @@ mod test {
         }, {
             insta::assert_snapshot!(errors, @r###"
             Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 0
-            Expected an alphanumeric character starting the extra name, found `ö`
-            numpy[ö]==1.29
-                  ^
+            Unable to determine the package name from the filename of `https://example.com/wheel.whl`. Add the name of the package before the `@` (e.g., `package_name @ https://example.com/wheel.whl`).
+            @ https://example.com/wheel.whl
+            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
             "###);
         });
 
         Ok(())
     }
 
+    /// Requirements with tabs, irregular spacing, and indentation should parse identically to the
+    /// canonical form.
     #[tokio::test]
-    async fn invalid_requirement_url() -> Result<()> {
+    async fn irregular_whitespace() -> Result<()> {
+        let working_dir = workspace_test_data_dir().join("requirements-txt");
+        let irregular = RequirementsTxt::parse(
+            working_dir.join("irregular-whitespace.txt"),
+            &working_dir,
+            &BaseClientBuilder::new(),
+        )
+        .await?;
+
         let temp_dir = assert_fs::TempDir::new()?;
         let requirements_txt = temp_dir.child("requirements.txt");
         requirements_txt.write_str(indoc! {"
-            numpy @ https:///
+            flask==2.0.1
+            requests>=2.31,<3
+            pandas[tabulate]; python_version >= '3.8'
+            numpy==1.26.4
         "})?;
+        let canonical = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await?;
+
+        // Ignore the origin of each requirement, which differs between the two files.
+        let requirements = |requirements_txt: RequirementsTxt| {
+            requirements_txt
+                .requirements
+                .into_iter()
+                .map(|entry| {
+                    let RequirementsTxtRequirement::Named(mut requirement) = entry.requirement
+                    else {
+                        panic!("Expected a named requirement");
+                    };
+                    requirement.origin = None;
+                    requirement
+                })
+                .collect::<Vec<_>>()
+        };
+        let irregular = requirements(irregular);
+        assert_eq!(irregular.len(), 4);
+        assert_eq!(irregular, requirements(canonical));
+
+        Ok(())
+    }
+
+    /// Constraints should propagate from arbitrarily deep `-r` includes, and from `-c` files that
+    /// themselves include `-c` files.
+    #[tokio::test]
+    async fn nested_constraints() -> Result<()> {
+        let working_dir = workspace_test_data_dir().join("requirements-txt");
+        let requirements_txt = RequirementsTxt::parse(
+            working_dir.join("nested-include-a.txt"),
+            &working_dir,
+            &BaseClientBuilder::new(),
+        )
+        .await?;
+
+        let requirements = requirements_txt
+            .requirements
+            .iter()
+            .map(|entry| match &entry.requirement {
+                RequirementsTxtRequirement::Named(requirement) => requirement.to_string(),
+                RequirementsTxtRequirement::Unnamed(_) => panic!("Expected a named requirement"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(requirements, ["requests", "flask"]);
+
+        let constraints = requirements_txt
+            .constraints
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(constraints, ["flask<3", "requests==2.31.0"]);
+
+        Ok(())
+    }
+
+    /// The output of `pip freeze` should parse into installable requirements, with editable Git
+    /// installs treated as regular, named requirements.
+    #[tokio::test]
+    async fn freeze() -> Result<()> {
+        let working_dir = workspace_test_data_dir().join("requirements-txt");
+        let path = working_dir.join("freeze.txt");
+
+        // As a regular `requirements.txt` file, the editable Git requirement is rejected.
+        let err = RequirementsTxt::parse(&path, &working_dir, &BaseClientBuilder::new())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Unsupported editable requirement in `{}`",
+                path.user_display()
+            )
+        );
+
+        let requirements_txt = RequirementsTxt::parse_freeze(
+            &path,
+            &working_dir,
+            &BaseClientBuilder::new(),
+            DEFAULT_MAX_INCLUDE_DEPTH,
+        )
+        .await?;
+
+        let requirements = requirements_txt
+            .requirements
+            .iter()
+            .map(|entry| match &entry.requirement {
+                RequirementsTxtRequirement::Named(requirement) => {
+                    if let Some(VersionOrUrl::Url(url)) = &requirement.version_or_url {
+                        assert!(!url.parsed_url.is_editable());
+                    }
+                    requirement.name.to_string()
+                }
+                RequirementsTxtRequirement::Unnamed(_) => panic!("Expected a named requirement"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            requirements,
+            ["anyio", "flask", "idna", "iniconfig", "sniffio"]
+        );
+        assert_eq!(requirements_txt.editables.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn continuation_end_of_file() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str("numpy>=1,<2\ntqdm \\\n")?;
 
         let error = RequirementsTxt::parse(
             requirements_txt.path(),
@@ -1463,23 +2675,76 @@ mod test {
             filters => filters
         }, {
             insta::assert_snapshot!(errors, @r###"
-            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 0
-            empty host
-            numpy @ https:///
-                    ^^^^^^^^^
+            Unexpected end of file after line continuation (`\`) at <REQUIREMENTS_TXT>:2:6
             "###);
         });
 
         Ok(())
     }
 
+    /// Parse a large, generated file, which is read statement-by-statement, and report a malformed
+    /// line in the middle of it with its line number.
     #[tokio::test]
-    async fn unsupported_editable() -> Result<()> {
+    async fn large_file() -> Result<()> {
+        use std::fmt::Write;
+
         let temp_dir = assert_fs::TempDir::new()?;
         let requirements_txt = temp_dir.child("requirements.txt");
-        requirements_txt.write_str(indoc! {"
-            -e https://localhost:8080/
-        "})?;
+
+        let mut contents = String::from("# This file was autogenerated by uv\n");
+        for i in 0..20_000 {
+            writeln!(contents, "package-{i}==1.0.{i} \\")?;
+            writeln!(contents, "    --hash=sha256:{i:064x}")?;
+        }
+        requirements_txt.write_str(&contents)?;
+
+        let actual = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await?;
+        let expected = RequirementsTxt::parse_inner(
+            &contents,
+            temp_dir.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+            requirements_txt.path(),
+        )
+        .await?;
+        assert_eq!(actual.requirements.len(), 20_000);
+        assert_eq!(actual, expected);
+
+        // Positions in errors are relative to the start of the file, as when it's parsed in full.
+        let mut invalid = contents.clone();
+        invalid.insert_str(
+            invalid.find("package-10000==").unwrap(),
+            "package-10000==???\n",
+        );
+        requirements_txt.write_str(&invalid)?;
+
+        let actual = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let expected = RequirementsTxt::parse_inner(
+            &invalid,
+            temp_dir.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+            requirements_txt.path(),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(actual.error.to_string(), expected.to_string());
+
+        // A malformed line is reported with its line number.
+        let mut invalid = contents.clone();
+        invalid.insert_str(invalid.find("package-10000==").unwrap(), "!package-10000\n");
+        requirements_txt.write_str(&invalid)?;
 
         let error = RequirementsTxt::parse(
             requirements_txt.path(),
@@ -1496,10 +2761,7 @@ mod test {
             filters => filters
         }, {
             insta::assert_snapshot!(errors, @r###"
-            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 3
-            Expected direct URL (`https://localhost:8080/`) to end in a supported file extension: `.whl`, `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
-            https://localhost:8080/
-            ^^^^^^^^^^^^^^^^^^^^^^^
+            Unexpected '!', expected '-c', '-e', '-r' or the start of a requirement at <REQUIREMENTS_TXT>:20002:1
             "###);
         });
 
@@ -1507,12 +2769,13 @@ mod test {
     }
 
     #[tokio::test]
-    async fn unsupported_editable_extension() -> Result<()> {
+    async fn unnamed_config_settings() -> Result<()> {
         let temp_dir = assert_fs::TempDir::new()?;
         let requirements_txt = temp_dir.child("requirements.txt");
         requirements_txt.write_str(indoc! {"
-            -e https://files.pythonhosted.org/packages/f7/69/96766da2cdb5605e6a31ef2734aff0be17901cefb385b885c2ab88896d76/ruff-0.5.6.tar.gz
+            ./flask -C key=value
         "})?;
+        temp_dir.child("flask").create_dir_all()?;
 
         let error = RequirementsTxt::parse(
             requirements_txt.path(),
@@ -1529,8 +2792,7 @@ mod test {
             filters => filters
         }, {
             insta::assert_snapshot!(errors, @r###"
-            Unsupported editable requirement in `<REQUIREMENTS_TXT>`
-            Editable must refer to a local directory, not an HTTPS URL: `https://files.pythonhosted.org/packages/f7/69/96766da2cdb5605e6a31ef2734aff0be17901cefb385b885c2ab88896d76/ruff-0.5.6.tar.gz`
+            `--config-settings` requires a named requirement (found: `./flask`) at <REQUIREMENTS_TXT>:1:21
             "###);
         });
 
@@ -1538,34 +2800,87 @@ mod test {
     }
 
     #[tokio::test]
-    async fn invalid_editable_extra() -> Result<()> {
+    async fn binary_pragma() -> Result<()> {
         let temp_dir = assert_fs::TempDir::new()?;
         let requirements_txt = temp_dir.child("requirements.txt");
         requirements_txt.write_str(indoc! {"
-            -e black[,abcdef]
+            scipy  # uv: no-binary
+            numpy --hash=sha256:1234  # uv:only-binary
+            flask  # uv is great
         "})?;
 
-        let error = RequirementsTxt::parse(
+        let requirements = RequirementsTxt::parse(
             requirements_txt.path(),
             temp_dir.path(),
             &BaseClientBuilder::new(),
         )
         .await
-        .unwrap_err();
-        let errors = anyhow::Error::new(error).chain().join("\n");
+        .unwrap();
 
-        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
-        let filters = vec![(requirement_txt.as_str(), "<REQUIREMENTS_TXT>")];
-        insta::with_settings!({
-            filters => filters
-        }, {
-            insta::assert_snapshot!(errors, @r###"
-            Couldn't parse requirement in `<REQUIREMENTS_TXT>` at position 3
-            Expected either alphanumerical character (starting the extra name) or `]` (ending the extras section), found `,`
-            black[,abcdef]
-                  ^
-            "###);
-        });
+        assert_eq!(requirements.requirements.len(), 3);
+        assert_eq!(requirements.requirements[1].hashes, vec!["sha256:1234"]);
+        assert_eq!(
+            requirements.no_binary,
+            NoBinary::Packages(vec![PackageName::from_str("scipy")?])
+        );
+        assert_eq!(
+            requirements.only_binary,
+            NoBuild::Packages(vec![PackageName::from_str("numpy")?])
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn prerelease_pragma() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            scipy  # uv: pre
+            numpy==2.0.0rc1  # uv:pre
+            flask
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(requirements.requirements.len(), 3);
+        assert_eq!(
+            requirements.prerelease,
+            vec![
+                PackageName::from_str("scipy")?,
+                PackageName::from_str("numpy")?
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unknown_binary_pragma() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            flask # uv: no-wheels
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap();
+
+        // Unknown pragmas are ignored.
+        assert_eq!(requirements.requirements.len(), 1);
+        assert_eq!(requirements.no_binary, NoBinary::None);
+        assert_eq!(requirements.only_binary, NoBuild::None);
 
         Ok(())
     }
@@ -1729,6 +3044,10 @@ mod test {
                 no_index: false,
                 no_binary: None,
                 only_binary: None,
+                config_settings: PackageConfigSettings(
+                    {},
+                ),
+                prerelease: [],
             }
             "###);
         });
@@ -1799,6 +3118,10 @@ mod test {
                     ],
                 ),
                 only_binary: None,
+                config_settings: PackageConfigSettings(
+                    {},
+                ),
+                prerelease: [],
             }
             "###);
         });
@@ -1900,6 +3223,10 @@ mod test {
                 no_index: true,
                 no_binary: None,
                 only_binary: None,
+                config_settings: PackageConfigSettings(
+                    {},
+                ),
+                prerelease: [],
             }
             "###);
         });
@@ -1947,6 +3274,95 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn max_include_depth() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            -r child.txt
+        "})?;
+
+        let child = temp_dir.child("child.txt");
+        child.write_str(indoc! {"
+            -r grandchild.txt
+        "})?;
+
+        let grandchild = temp_dir.child("grandchild.txt");
+        grandchild.write_str(indoc! {"
+            -c great-grandchild.txt
+        "})?;
+
+        let great_grandchild = temp_dir.child("great-grandchild.txt");
+        great_grandchild.write_str(indoc! {"
+            flask
+        "})?;
+
+        // Three levels of includes are allowed...
+        RequirementsTxt::parse_with_max_depth(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+            3,
+        )
+        .await?;
+
+        // ...but not at a maximum depth of two.
+        let error = RequirementsTxt::parse_with_max_depth(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+            2,
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        insta::with_settings!({
+            filters => path_filters(&path_filter(temp_dir.path())),
+        }, {
+            insta::assert_snapshot!(errors, @r###"
+            Error parsing included file in `<REQUIREMENTS_DIR>/requirements.txt` at position 0
+            Error parsing included file in `<REQUIREMENTS_DIR>/child.txt` at position 0
+            Exceeded the maximum include depth of 2 in `<REQUIREMENTS_DIR>/grandchild.txt` at position 0
+            "###);
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn recursive_include() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            flask
+            -r requirements.txt
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error)
+            .chain()
+            .last()
+            .unwrap()
+            .to_string();
+
+        insta::with_settings!({
+            filters => path_filters(&path_filter(temp_dir.path())),
+        }, {
+            insta::assert_snapshot!(errors, @"Exceeded the maximum include depth of 50 in `<REQUIREMENTS_DIR>/requirements.txt` at position 6");
+        });
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn comments() -> Result<()> {
         let temp_dir = assert_fs::TempDir::new()?;
@@ -2157,6 +3573,10 @@ mod test {
                 no_index: false,
                 no_binary: All,
                 only_binary: None,
+                config_settings: PackageConfigSettings(
+                    {},
+                ),
+                prerelease: [],
             }
             "###);
         });
@@ -2515,6 +3935,10 @@ mod test {
                 no_index: false,
                 no_binary: None,
                 only_binary: None,
+                config_settings: PackageConfigSettings(
+                    {},
+                ),
+                prerelease: [],
             }
             "###);
         });
@@ -1,10 +1,14 @@
 use std::path::Path;
+use std::str::FromStr;
 
+use uv_distribution_filename::{SourceDistFilename, WheelFilename};
+use uv_distribution_types::RemoteSource;
 use uv_normalize::PackageName;
 use uv_pep508::{
     Pep508Error, Pep508ErrorSource, RequirementOrigin, TracingReporter, UnnamedRequirement,
+    VersionOrUrl,
 };
-use uv_pypi_types::{ParsedDirectoryUrl, ParsedUrl, VerbatimParsedUrl};
+use uv_pypi_types::{get_egg_name, ParsedDirectoryUrl, ParsedUrl, VerbatimParsedUrl};
 
 #[derive(Debug, thiserror::Error)]
 pub enum EditableError {
@@ -127,12 +131,39 @@ impl RequirementsTxtRequirement {
 }
 
 impl RequirementsTxtRequirement {
+    /// Convert an unnamed requirement into a named requirement, if its URL includes an `#egg=`
+    /// fragment (e.g., `flask` for `git+https://github.com/pallets/flask.git#egg=flask`).
+    #[must_use]
+    pub fn with_egg_name(self) -> Self {
+        let Self::Unnamed(requirement) = self else {
+            return self;
+        };
+        let Ok(Some(name)) = get_egg_name(&requirement.url.verbatim) else {
+            return Self::Unnamed(requirement);
+        };
+        Self::Named(uv_pep508::Requirement {
+            name,
+            extras: requirement.extras,
+            version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+            marker: requirement.marker,
+            origin: requirement.origin,
+        })
+    }
+
     /// Parse a requirement as seen in a `requirements.txt` file.
     pub fn parse(
         input: &str,
         working_dir: impl AsRef<Path>,
         editable: bool,
     ) -> Result<Self, Box<Pep508Error<VerbatimParsedUrl>>> {
+        // As a special-case, derive the package name from the filename of a URL requirement that
+        // omits it (e.g., `@ https://example.com/anyio-4.3.0-py3-none-any.whl`).
+        if let Some(url) = input.trim_start().strip_prefix('@').map(str::trim_start) {
+            let requirement = UnnamedRequirement::parse(url, &working_dir, &mut TracingReporter)
+                .map_err(Box::new)?;
+            return Self::name_from_filename(input, requirement).map(Self::Named);
+        }
+
         // Attempt to parse as a PEP 508-compliant requirement.
         match uv_pep508::Requirement::parse(input, &working_dir) {
             Ok(requirement) => {
@@ -161,4 +192,52 @@ impl RequirementsTxtRequirement {
         }
         .map_err(Box::new)
     }
+
+    /// Derive the package name of an [`UnnamedRequirement`] from the filename of the wheel or
+    /// source distribution that it points to (e.g., `anyio` for `anyio-4.3.0-py3-none-any.whl`).
+    fn name_from_filename(
+        input: &str,
+        requirement: UnnamedRequirement<VerbatimParsedUrl>,
+    ) -> Result<uv_pep508::Requirement<VerbatimParsedUrl>, Box<Pep508Error<VerbatimParsedUrl>>>
+    {
+        let name = requirement
+            .url
+            .verbatim
+            .filename()
+            .ok()
+            .and_then(|filename| {
+                if Path::new(filename.as_ref())
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("whl"))
+                {
+                    WheelFilename::from_str(&filename)
+                        .ok()
+                        .map(|filename| filename.name)
+                } else {
+                    SourceDistFilename::parsed_normalized_filename(&filename)
+                        .ok()
+                        .map(|filename| filename.name)
+                }
+            });
+
+        let Some(name) = name else {
+            return Err(Box::new(Pep508Error {
+                message: Pep508ErrorSource::String(format!(
+                    "Unable to determine the package name from the filename of `{}`. Add the name of the package before the `@` (e.g., `package_name @ {}`).",
+                    requirement.url.verbatim, requirement.url.verbatim
+                )),
+                start: 0,
+                len: input.len(),
+                input: input.to_string(),
+            }));
+        };
+
+        Ok(uv_pep508::Requirement {
+            name,
+            extras: requirement.extras,
+            version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+            marker: requirement.marker,
+            origin: requirement.origin,
+        })
+    }
 }
@@ -0,0 +1,194 @@
+//! Reading the JSON and `pylock.toml` outputs of `uv pip compile` back in as requirements.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use uv_configuration::OutputFormat;
+use uv_fs::Simplified;
+use uv_pep508::RequirementOrigin;
+use uv_requirements_txt::{RequirementEntry, RequirementsTxtRequirement};
+use uv_resolver::{JsonResolution, PylockPackage, PylockToml};
+
+use crate::FileNotFound;
+
+/// Read the pinned requirements from a JSON or `pylock.toml` file written by `uv pip compile`.
+///
+/// Each package is pinned to its resolved version (or URL), along with any recorded hashes.
+pub async fn read_compiled_requirements(
+    path: &Path,
+    format: OutputFormat,
+) -> Result<Vec<RequirementEntry>> {
+    let contents = match fs_err::tokio::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(FileNotFound {
+                path: path.to_path_buf(),
+            }
+            .into());
+        }
+        Err(err) => {
+            return Err(anyhow::anyhow!(
+                "Failed to read `{}`: {}",
+                path.user_display(),
+                err
+            ));
+        }
+    };
+
+    // Relative paths are resolved relative to the directory containing the file.
+    let working_dir = path.parent().unwrap_or(Path::new(""));
+
+    let entries = match format {
+        OutputFormat::RequirementsTxt => {
+            return Err(anyhow::anyhow!(
+                "Expected a JSON or `pylock.toml` file, but found: `{}`",
+                path.user_display()
+            ));
+        }
+        OutputFormat::Json => {
+            let resolution: JsonResolution = serde_json::from_str(&contents)
+                .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+            if resolution.created_by != JsonResolution::CREATED_BY {
+                return Err(anyhow::anyhow!(
+                    "`{}` is not a JSON file written by `uv pip compile --format json`",
+                    path.user_display()
+                ));
+            }
+            resolution
+                .packages
+                .into_iter()
+                .map(|package| {
+                    let requirement = if package.editable {
+                        let url = package.url.as_deref().unwrap_or_default();
+                        RequirementsTxtRequirement::parse(url, working_dir, true)?
+                            .into_editable()?
+                    } else {
+                        let extras = if package.extras.is_empty() {
+                            String::new()
+                        } else {
+                            format!(
+                                "[{}]",
+                                package
+                                    .extras
+                                    .iter()
+                                    .map(ToString::to_string)
+                                    .collect::<Vec<_>>()
+                                    .join(",")
+                            )
+                        };
+                        let mut requirement = match &package.url {
+                            Some(url) => format!("{}{extras} @ {url}", package.name),
+                            None => format!("{}{extras}=={}", package.name, package.version),
+                        };
+                        if let Some(marker) = &package.marker {
+                            requirement.push_str(" ; ");
+                            requirement.push_str(marker);
+                        }
+                        RequirementsTxtRequirement::parse(&requirement, working_dir, false)?
+                    };
+                    Ok(RequirementEntry {
+                        requirement: requirement
+                            .with_origin(RequirementOrigin::File(path.to_path_buf())),
+                        hashes: package.hashes,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+                .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?
+        }
+        OutputFormat::Pylock => {
+            let pylock: PylockToml = toml::from_str(&contents)
+                .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+            pylock
+                .packages
+                .into_iter()
+                .map(|package| {
+                    let requirement = pylock_requirement(&package, working_dir)?;
+                    Ok(RequirementEntry {
+                        requirement: requirement
+                            .with_origin(RequirementOrigin::File(path.to_path_buf())),
+                        hashes: pylock_hashes(&package),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+                .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?
+        }
+    };
+
+    Ok(entries)
+}
+
+/// Convert a package in a `pylock.toml` file to a requirement pinned to its locked source.
+fn pylock_requirement(
+    package: &PylockPackage,
+    working_dir: &Path,
+) -> Result<RequirementsTxtRequirement> {
+    if let Some(directory) = &package.directory {
+        if directory.editable {
+            return Ok(
+                RequirementsTxtRequirement::parse(&directory.path, working_dir, true)?
+                    .into_editable()?,
+            );
+        }
+    }
+
+    let mut requirement = if let Some(vcs) = &package.vcs {
+        let mut url = format!("{}+{}@{}", vcs.r#type, vcs.url, vcs.commit_id);
+        if let Some(subdirectory) = &vcs.subdirectory {
+            url.push_str("#subdirectory=");
+            url.push_str(subdirectory);
+        }
+        format!("{} @ {url}", package.name)
+    } else if let Some(directory) = &package.directory {
+        format!("{} @ {}", package.name, directory.path)
+    } else if let Some(archive) = &package.archive {
+        let Some(location) = archive.url.as_ref().or(archive.path.as_ref()) else {
+            return Err(anyhow::anyhow!(
+                "Archive for `{}` is missing a `url` or `path`",
+                package.name
+            ));
+        };
+        let mut location = location.clone();
+        if let Some(subdirectory) = &archive.subdirectory {
+            location.push_str("#subdirectory=");
+            location.push_str(subdirectory);
+        }
+        format!("{} @ {location}", package.name)
+    } else if let Some(version) = &package.version {
+        format!("{}=={version}", package.name)
+    } else {
+        return Err(anyhow::anyhow!(
+            "Package `{}` is missing a `version` or source",
+            package.name
+        ));
+    };
+
+    if let Some(marker) = &package.marker {
+        requirement.push_str(" ; ");
+        requirement.push_str(marker);
+    }
+
+    Ok(RequirementsTxtRequirement::parse(
+        &requirement,
+        working_dir,
+        false,
+    )?)
+}
+
+/// Collect the hashes of all distributions for a package in a `pylock.toml` file, as
+/// `{algorithm}:{digest}`.
+fn pylock_hashes(package: &PylockPackage) -> Vec<String> {
+    package
+        .wheels
+        .iter()
+        .chain(package.sdist.iter())
+        .flat_map(|file| file.hashes.iter())
+        .chain(
+            package
+                .archive
+                .iter()
+                .flat_map(|archive| archive.hashes.iter()),
+        )
+        .map(|(algorithm, digest)| format!("{algorithm}:{digest}"))
+        .collect()
+}
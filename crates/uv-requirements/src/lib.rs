@@ -1,9 +1,11 @@
+pub use crate::compiled::*;
 pub use crate::lookahead::*;
 pub use crate::source_tree::*;
 pub use crate::sources::*;
 pub use crate::specification::*;
 pub use crate::unnamed::*;
 
+mod compiled;
 mod lookahead;
 mod source_tree;
 mod sources;
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use futures::stream::FuturesOrdered;
 use futures::TryStreamExt;
 use url::Url;
@@ -15,9 +15,10 @@ use uv_distribution_types::{
 use uv_fs::Simplified;
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep508::RequirementOrigin;
-use uv_pypi_types::Requirement;
+use uv_pypi_types::{HashAlgorithm, Requirement};
 use uv_resolver::{InMemoryIndex, MetadataResponse};
 use uv_types::{BuildContext, HashStrategy};
+use uv_workspace::pyproject::PyProjectToml;
 
 #[derive(Debug, Clone)]
 pub struct SourceTreeResolution {
@@ -111,6 +112,9 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
             })
             .collect();
 
+        // Track the extras that are enabled, including those enabled recursively.
+        let mut enabled = extras.to_vec();
+
         // Resolve any recursive extras.
         loop {
             // Find the first recursive requirement.
@@ -131,6 +135,22 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
                     .clone()
                     .simplify_extras(&recursive.extras);
             }
+            enabled.extend(recursive.extras.iter().cloned());
+        }
+
+        // Reject any set of extras that the project declares as mutually exclusive.
+        for conflict in read_conflicting_extras(path).await {
+            if conflict.len() > 1 && conflict.iter().all(|extra| enabled.contains(extra)) {
+                bail!(
+                    "The extras {} of `{}` are declared as conflicting in `tool.uv.conflicting-extras`, and can't be enabled together",
+                    conflict
+                        .iter()
+                        .map(|extra| format!("`{extra}`"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    metadata.name
+                );
+            }
         }
 
         let project = metadata.name;
@@ -180,8 +200,8 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
         // manual match.
         let hashes = match self.hasher {
             HashStrategy::None => HashPolicy::None,
-            HashStrategy::Generate => HashPolicy::Generate,
-            HashStrategy::Verify(_) => HashPolicy::Generate,
+            HashStrategy::Generate(algorithm) => HashPolicy::Generate(*algorithm),
+            HashStrategy::Verify(_) => HashPolicy::Generate(HashAlgorithm::Sha256),
             HashStrategy::Require(_) => {
                 return Err(anyhow::anyhow!(
                     "Hash-checking is not supported for local directories: {}",
@@ -225,3 +245,16 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
         Ok(RequiresDist::from(metadata))
     }
 }
+
+/// Read the sets of mutually exclusive extras declared in the `pyproject.toml` alongside the given
+/// source tree file (e.g., a `setup.py`), if any.
+async fn read_conflicting_extras(path: &Path) -> Vec<Vec<ExtraName>> {
+    let Ok(contents) = fs_err::tokio::read_to_string(path.with_file_name("pyproject.toml")).await
+    else {
+        return Vec::new();
+    };
+    let Ok(pyproject) = toml::from_str::<PyProjectToml>(&contents) else {
+        return Vec::new();
+    };
+    pyproject.conflicting_extras().to_vec()
+}
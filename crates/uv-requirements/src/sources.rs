@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use console::Term;
 
+use uv_configuration::OutputFormat;
 use uv_fs::Simplified;
 use uv_warnings::warn_user;
 
@@ -13,12 +14,25 @@ pub enum RequirementsSource {
     Editable(String),
     /// Dependencies were provided via a `requirements.txt` file (e.g., `pip install -r requirements.txt`).
     RequirementsTxt(PathBuf),
+    /// Dependencies were provided via a `requirements.txt` file, to be installed without their
+    /// transitive dependencies (e.g., `pip install --no-deps-requirement requirements.txt`).
+    NoDepsRequirementsTxt(PathBuf),
+    /// Dependencies were provided via the output of `pip freeze` (e.g.,
+    /// `pip install --freeze-requirement freeze.txt`).
+    Freeze(PathBuf),
     /// Dependencies were provided via a `pyproject.toml` file (e.g., `pip-compile pyproject.toml`).
     PyprojectToml(PathBuf),
     /// Dependencies were provided via a `setup.py` file (e.g., `pip-compile setup.py`).
     SetupPy(PathBuf),
     /// Dependencies were provided via a `setup.cfg` file (e.g., `pip-compile setup.cfg`).
     SetupCfg(PathBuf),
+    /// Dependencies were provided via the `deps` of a test environment in a `tox.ini` file (e.g.,
+    /// `pip-compile tox.ini`). If `env` is `None`, the `deps` of the `[testenv]` section are used.
+    ToxIni { path: PathBuf, env: Option<String> },
+    /// Dependencies were provided via a JSON file written by `uv pip compile --format json`.
+    Json(PathBuf),
+    /// Dependencies were provided via a PEP 751 `pylock.toml` file.
+    PylockToml(PathBuf),
     /// Dependencies were provided via a path to a source tree (e.g., `pip install .`).
     SourceTree(PathBuf),
 }
@@ -33,8 +47,14 @@ impl RequirementsSource {
             Self::SetupPy(path)
         } else if path.ends_with("setup.cfg") {
             Self::SetupCfg(path)
+        } else if path.ends_with("tox.ini") {
+            Self::ToxIni { path, env: None }
         } else {
-            Self::RequirementsTxt(path)
+            match OutputFormat::from_path(&path) {
+                OutputFormat::Json => Self::Json(path),
+                OutputFormat::Pylock => Self::PylockToml(path),
+                OutputFormat::RequirementsTxt => Self::RequirementsTxt(path),
+            }
         }
     }
 
@@ -52,6 +72,35 @@ impl RequirementsSource {
         Self::RequirementsTxt(path)
     }
 
+    /// Parse a [`RequirementsSource`] from a `requirements.txt` file whose requirements should be
+    /// installed without their dependencies.
+    pub fn from_no_deps_requirements_txt(path: PathBuf) -> Self {
+        for filename in ["pyproject.toml", "setup.py", "setup.cfg"] {
+            if path.ends_with(filename) {
+                warn_user!(
+                    "The file `{}` appears to be a `{}` file, but requirements must be specified in `requirements.txt` format.",
+                    path.user_display(),
+                    filename
+                );
+            }
+        }
+        Self::NoDepsRequirementsTxt(path)
+    }
+
+    /// Parse a [`RequirementsSource`] from a file containing the output of `pip freeze`.
+    pub fn from_freeze(path: PathBuf) -> Self {
+        for filename in ["pyproject.toml", "setup.py", "setup.cfg"] {
+            if path.ends_with(filename) {
+                warn_user!(
+                    "The file `{}` appears to be a `{}` file, but requirements must be specified in `pip freeze` format.",
+                    path.user_display(),
+                    filename
+                );
+            }
+        }
+        Self::Freeze(path)
+    }
+
     /// Parse a [`RequirementsSource`] from a `constraints.txt` file.
     pub fn from_constraints_txt(path: PathBuf) -> Self {
         for filename in ["pyproject.toml", "setup.py", "setup.cfg"] {
@@ -127,6 +176,18 @@ impl RequirementsSource {
         Self::SourceTree(path)
     }
 
+    /// Read the `deps` of the given test environment, if the source is a `tox.ini` file.
+    #[must_use]
+    pub fn with_tox_env(self, env: Option<&str>) -> Self {
+        match (self, env) {
+            (Self::ToxIni { path, .. }, Some(env)) => Self::ToxIni {
+                path,
+                env: Some(env.to_string()),
+            },
+            (source, _) => source,
+        }
+    }
+
     /// Returns `true` if the source allows extras to be specified.
     pub fn allows_extras(&self) -> bool {
         matches!(
@@ -142,9 +203,14 @@ impl std::fmt::Display for RequirementsSource {
             Self::Package(package) => write!(f, "{package}"),
             Self::Editable(path) => write!(f, "-e {path}"),
             Self::RequirementsTxt(path)
+            | Self::NoDepsRequirementsTxt(path)
+            | Self::Freeze(path)
             | Self::PyprojectToml(path)
             | Self::SetupPy(path)
             | Self::SetupCfg(path)
+            | Self::ToxIni { path, .. }
+            | Self::Json(path)
+            | Self::PylockToml(path)
             | Self::SourceTree(path) => {
                 write!(f, "{}", path.simplified_display())
             }
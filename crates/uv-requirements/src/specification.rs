@@ -17,7 +17,8 @@
 //!   must be valid (in other circumstances we allow invalid `dependencies` e.g. for hatch's
 //!   relative path support), but it can be dynamic. We set the `project` from the `name` entry. If it is static, we add
 //!   all `dependencies` from the pyproject.toml as `requirements` (and drop the directory). If it
-//!   is dynamic, we add the directory to `source_trees`.
+//!   is dynamic, we add the directory to `source_trees`. Any `constraint-dependencies` and
+//!   `override-dependencies` in the `[tool.uv]` table are added as `constraints` and `overrides`.
 //! * `uv pip install .` in a directory with `pyproject.toml` or `uv pip compile requirements.in`
 //!   where the `requirements.in` points to that directory: The directory is listed in
 //!   `requirements`. The lookahead resolver reads the static metadata from `pyproject.toml` if
@@ -26,29 +27,55 @@
 //!   lookahead resolver resolves it the same.
 //! * `setup.py` or `setup.cfg` instead of `pyproject.toml`: Directory is an entry in
 //!   `source_trees`.
+//!
+//! # JSON and `pylock.toml` sources
+//!
+//! The JSON and `pylock.toml` outputs of `uv pip compile` are read back as pinned `requirements`.
+//!
+//! # `tox.ini` source
+//!
+//! The `deps` of a `[testenv]` (or `[testenv:NAME]`) section are read as `requirements`, with any
+//! `-r` and `-c` includes resolved relative to the directory containing the `tox.ini`.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
+use configparser::ini::Ini;
+use pubgrub::Range;
 use rustc_hash::FxHashSet;
-use tracing::instrument;
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+use tracing::{debug, instrument};
 
 use uv_cache_key::CanonicalUrl;
 use uv_client::BaseClientBuilder;
-use uv_configuration::{NoBinary, NoBuild};
+use uv_configuration::{
+    ExtrasSpecification, NoBinary, NoBuild, OutputFormat, PackageConfigSettings,
+};
 use uv_distribution_types::{
     FlatIndexLocation, IndexUrl, NameRequirementSpecification, UnresolvedRequirement,
     UnresolvedRequirementSpecification,
 };
 use uv_fs::{Simplified, CWD};
 use uv_normalize::{ExtraName, PackageName};
-use uv_pep508::{MarkerTree, UnnamedRequirement, UnnamedRequirementUrl};
-use uv_pypi_types::Requirement;
-use uv_pypi_types::VerbatimParsedUrl;
-use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement};
+use uv_pep440::VersionSpecifiers;
+use uv_pep508::{MarkerTree, RequirementOrigin, UnnamedRequirement, UnnamedRequirementUrl};
+use uv_pubgrub::PubGrubSpecifier;
+use uv_pypi_types::{ParsedUrl, VerbatimParsedUrl};
+use uv_pypi_types::{Requirement, RequirementSource};
+use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement, DEFAULT_MAX_INCLUDE_DEPTH};
+use uv_warnings::warn_user;
 use uv_workspace::pyproject::PyProjectToml;
 
-use crate::RequirementsSource;
+use crate::{read_compiled_requirements, RequirementsSource};
+
+/// A requirements source that doesn't exist on disk.
+#[derive(Debug, thiserror::Error)]
+#[error("File not found: `{}`", .path.user_display())]
+pub struct FileNotFound {
+    pub path: PathBuf,
+}
 
 #[derive(Debug, Default)]
 pub struct RequirementsSpecification {
@@ -76,13 +103,32 @@ pub struct RequirementsSpecification {
     pub no_binary: NoBinary,
     /// The `--no-build` flags to enforce when selecting distributions.
     pub no_build: NoBuild,
+    /// The packages whose dependencies should not be resolved or installed.
+    pub no_deps: BTreeSet<PackageName>,
+    /// The per-package `--config-settings` to use when building source distributions.
+    pub config_settings: PackageConfigSettings,
+    /// The packages for which pre-release versions are allowed, regardless of the pre-release
+    /// strategy.
+    pub prerelease: BTreeSet<PackageName>,
+    /// The `Requires-Python` declared by the project (i.e., `project.requires-python` in a
+    /// `pyproject.toml`), if any.
+    pub requires_python: Option<VersionSpecifiers>,
+    /// The static dependencies declared by any `pyproject.toml` sources (i.e.,
+    /// `project.dependencies`), used to validate the constraints ahead of resolution.
+    pub project_dependencies: Vec<Requirement>,
+    /// Whether any requirement includes a hash (e.g., `--hash=sha256:...`), in which case every
+    /// requirement must include a hash, as in pip's hash-checking mode.
+    pub require_hashes: bool,
 }
 
 impl RequirementsSpecification {
     /// Read the requirements and constraints from a source.
+    ///
+    /// At most `max_include_depth` levels of nested `-r` and `-c` includes are followed.
     #[instrument(skip_all, level = tracing::Level::DEBUG, fields(source = % source))]
     pub async fn from_source(
         source: &RequirementsSource,
+        max_include_depth: usize,
         client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self> {
         Ok(match source {
@@ -104,17 +150,33 @@ impl RequirementsSpecification {
                     ..Self::default()
                 }
             }
-            RequirementsSource::RequirementsTxt(path) => {
+            RequirementsSource::RequirementsTxt(path)
+            | RequirementsSource::NoDepsRequirementsTxt(path)
+            | RequirementsSource::Freeze(path) => {
                 if !(path == Path::new("-")
                     || path.starts_with("http://")
                     || path.starts_with("https://")
                     || path.exists())
                 {
-                    return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                    return Err(FileNotFound {
+                        path: path.to_path_buf(),
+                    }
+                    .into());
                 }
 
-                let requirements_txt = RequirementsTxt::parse(path, &*CWD, client_builder).await?;
-                Self {
+                let requirements_txt = if matches!(source, RequirementsSource::Freeze(_)) {
+                    RequirementsTxt::parse_freeze(path, &*CWD, client_builder, max_include_depth)
+                        .await?
+                } else {
+                    RequirementsTxt::parse_with_max_depth(
+                        path,
+                        &*CWD,
+                        client_builder,
+                        max_include_depth,
+                    )
+                    .await?
+                };
+                let mut spec = Self {
                     requirements: requirements_txt
                         .requirements
                         .into_iter()
@@ -146,14 +208,37 @@ impl RequirementsSpecification {
                         .collect(),
                     no_binary: requirements_txt.no_binary,
                     no_build: requirements_txt.only_binary,
+                    config_settings: requirements_txt.config_settings,
+                    prerelease: requirements_txt.prerelease.into_iter().collect(),
                     ..Self::default()
+                };
+
+                // Mark the requirements such that their dependencies are not resolved.
+                if matches!(source, RequirementsSource::NoDepsRequirementsTxt(_)) {
+                    for entry in &spec.requirements {
+                        match &entry.requirement {
+                            UnresolvedRequirement::Named(requirement) => {
+                                spec.no_deps.insert(requirement.name.clone());
+                            }
+                            UnresolvedRequirement::Unnamed(requirement) => {
+                                return Err(anyhow::anyhow!(
+                                    "Unnamed requirements are not allowed without dependencies (found: `{requirement}`)"
+                                ));
+                            }
+                        }
+                    }
                 }
+
+                spec
             }
             RequirementsSource::PyprojectToml(path) => {
                 let contents = match fs_err::tokio::read_to_string(&path).await {
                     Ok(contents) => contents,
                     Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                        return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                        return Err(FileNotFound {
+                            path: path.to_path_buf(),
+                        }
+                        .into());
                     }
                     Err(err) => {
                         return Err(anyhow::anyhow!(
@@ -163,17 +248,110 @@ impl RequirementsSpecification {
                         ));
                     }
                 };
-                let _ = toml::from_str::<PyProjectToml>(&contents)
+                // Distinguish a file that isn't valid TOML from one that doesn't match the
+                // `pyproject.toml` schema.
+                if let Err(err) = toml::from_str::<toml::Table>(&contents) {
+                    return Err(anyhow::Error::new(err).context(format!(
+                        "Failed to parse `{}`: not a valid TOML file",
+                        path.user_display()
+                    )));
+                }
+                let pyproject = toml::from_str::<PyProjectToml>(&contents)
                     .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
 
+                // A `pyproject.toml` without a `[project]` table is valid (e.g., for Poetry or
+                // `setup.py`-based projects); the requirements are determined by the build backend.
+                if pyproject.project.is_none() {
+                    debug!(
+                        "No `[project]` table found in `{}`; querying the build backend for requirements",
+                        path.user_display()
+                    );
+                }
+
+                // Retain the static dependencies, for validation against the constraints. Any
+                // invalid dependencies are reported when the source tree is resolved.
+                let working_dir = path.parent().unwrap_or(Path::new(""));
+                let project_dependencies = pyproject
+                    .project
+                    .as_ref()
+                    .map(|project| {
+                        project
+                            .dependencies
+                            .iter()
+                            .flatten()
+                            .filter_map(|dependency| {
+                                uv_pep508::Requirement::<VerbatimParsedUrl>::parse(
+                                    dependency,
+                                    working_dir,
+                                )
+                                .ok()
+                            })
+                            .map(|dependency| {
+                                Requirement::from(dependency.with_origin(
+                                    RequirementOrigin::Project(path.clone(), project.name.clone()),
+                                ))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let requires_python = pyproject
+                    .project
+                    .and_then(|project| project.requires_python);
+
+                // Read the constraints and overrides declared in the `[tool.uv]` table. The
+                // dependencies (and any requested extras) are read from the source tree itself.
+                let tool_uv = pyproject.tool.and_then(|tool| tool.uv);
+                let constraints = tool_uv
+                    .as_ref()
+                    .and_then(|tool_uv| tool_uv.constraint_dependencies.clone())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|requirement| {
+                        Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                    })
+                    .map(NameRequirementSpecification::from)
+                    .collect();
+                let overrides = tool_uv
+                    .and_then(|tool_uv| tool_uv.override_dependencies)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|requirement| {
+                        Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                    })
+                    .map(UnresolvedRequirementSpecification::from)
+                    .collect();
+
                 Self {
+                    constraints,
+                    overrides,
                     source_trees: vec![path.clone()],
+                    requires_python,
+                    project_dependencies,
+                    ..Self::default()
+                }
+            }
+            RequirementsSource::Json(path) | RequirementsSource::PylockToml(path) => {
+                let format = if matches!(source, RequirementsSource::Json(_)) {
+                    OutputFormat::Json
+                } else {
+                    OutputFormat::Pylock
+                };
+                let entries = read_compiled_requirements(path, format).await?;
+                Self {
+                    requirements: entries
+                        .into_iter()
+                        .map(UnresolvedRequirementSpecification::from)
+                        .collect(),
                     ..Self::default()
                 }
             }
             RequirementsSource::SetupPy(path) | RequirementsSource::SetupCfg(path) => {
                 if !path.is_file() {
-                    return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                    return Err(FileNotFound {
+                        path: path.to_path_buf(),
+                    }
+                    .into());
                 }
 
                 Self {
@@ -181,6 +359,16 @@ impl RequirementsSpecification {
                     ..Self::default()
                 }
             }
+            RequirementsSource::ToxIni { path, env } => {
+                if !path.is_file() {
+                    return Err(FileNotFound {
+                        path: path.to_path_buf(),
+                    }
+                    .into());
+                }
+
+                read_tox_deps(path, env.as_deref(), max_include_depth, client_builder).await?
+            }
             RequirementsSource::SourceTree(path) => {
                 if !path.is_dir() {
                     return Err(anyhow::anyhow!(
@@ -211,50 +399,74 @@ impl RequirementsSpecification {
         requirements: &[RequirementsSource],
         constraints: &[RequirementsSource],
         overrides: &[RequirementsSource],
+        strict: bool,
+        max_include_depth: usize,
         client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self> {
         let mut spec = Self::default();
 
+        // The packages that are required by at least one source that resolves their dependencies.
+        let mut with_deps = BTreeSet::new();
+
         // Read all requirements, and keep track of all requirements _and_ constraints.
         // A `requirements.txt` can contain a `-c constraints.txt` directive within it, so reading
         // a requirements file can also add constraints.
         for source in requirements {
-            let source = Self::from_source(source, client_builder).await?;
+            let source = Self::from_source(source, max_include_depth, client_builder).await?;
+
+            for entry in &source.requirements {
+                if let UnresolvedRequirement::Named(requirement) = &entry.requirement {
+                    if !source.no_deps.contains(&requirement.name) {
+                        with_deps.insert(requirement.name.clone());
+                    }
+                }
+            }
+
+            // As in pip, if any requirement includes a hash, enable hash-checking mode.
+            spec.require_hashes |= source
+                .requirements
+                .iter()
+                .any(|entry| !entry.hashes.is_empty());
+
             spec.requirements.extend(source.requirements);
             spec.constraints.extend(source.constraints);
             spec.overrides.extend(source.overrides);
             spec.extras.extend(source.extras);
             spec.source_trees.extend(source.source_trees);
+            spec.no_deps.extend(source.no_deps);
+            spec.config_settings.extend(source.config_settings);
+            spec.prerelease.extend(source.prerelease);
+            spec.project_dependencies
+                .extend(source.project_dependencies);
 
             // Use the first project name discovered.
             if spec.project.is_none() {
                 spec.project = source.project;
             }
 
-            if let Some(index_url) = source.index_url {
-                if let Some(existing) = spec.index_url {
-                    if CanonicalUrl::new(index_url.url()) != CanonicalUrl::new(existing.url()) {
-                        return Err(anyhow::anyhow!(
-                            "Multiple index URLs specified: `{existing}` vs. `{index_url}`",
-                        ));
-                    }
-                }
-                spec.index_url = Some(index_url);
+            // Use the first `Requires-Python` discovered.
+            if spec.requires_python.is_none() {
+                spec.requires_python = source.requires_python;
             }
+
+            spec.merge_index_urls(source.index_url, source.extra_index_urls, strict)?;
             spec.no_index |= source.no_index;
-            spec.extra_index_urls.extend(source.extra_index_urls);
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
         }
 
+        // Only skip the dependencies of a package if every source that requires it opted out.
+        spec.no_deps.retain(|name| !with_deps.contains(name));
+
         // Read all constraints, treating both requirements _and_ constraints as constraints.
         // Overrides are ignored.
         for source in constraints {
-            let source = Self::from_source(source, client_builder).await?;
+            let source = Self::from_source(source, max_include_depth, client_builder).await?;
             for entry in source.requirements {
                 match entry.requirement {
                     UnresolvedRequirement::Named(requirement) => {
+                        reject_url_constraint(&requirement)?;
                         spec.constraints.push(NameRequirementSpecification {
                             requirement,
                             hashes: entry.hashes,
@@ -267,20 +479,13 @@ impl RequirementsSpecification {
                     }
                 }
             }
-            spec.constraints.extend(source.constraints);
-
-            if let Some(index_url) = source.index_url {
-                if let Some(existing) = spec.index_url {
-                    if CanonicalUrl::new(index_url.url()) != CanonicalUrl::new(existing.url()) {
-                        return Err(anyhow::anyhow!(
-                            "Multiple index URLs specified: `{existing}` vs. `{index_url}`",
-                        ));
-                    }
-                }
-                spec.index_url = Some(index_url);
+            for constraint in source.constraints {
+                reject_url_constraint(&constraint.requirement)?;
+                spec.constraints.push(constraint);
             }
+
+            spec.merge_index_urls(source.index_url, source.extra_index_urls, strict)?;
             spec.no_index |= source.no_index;
-            spec.extra_index_urls.extend(source.extra_index_urls);
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
@@ -289,22 +494,12 @@ impl RequirementsSpecification {
         // Read all overrides, treating both requirements _and_ overrides as overrides.
         // Constraints are ignored.
         for source in overrides {
-            let source = Self::from_source(source, client_builder).await?;
+            let source = Self::from_source(source, max_include_depth, client_builder).await?;
             spec.overrides.extend(source.requirements);
             spec.overrides.extend(source.overrides);
 
-            if let Some(index_url) = source.index_url {
-                if let Some(existing) = spec.index_url {
-                    if CanonicalUrl::new(index_url.url()) != CanonicalUrl::new(existing.url()) {
-                        return Err(anyhow::anyhow!(
-                            "Multiple index URLs specified: `{existing}` vs. `{index_url}`",
-                        ));
-                    }
-                }
-                spec.index_url = Some(index_url);
-            }
+            spec.merge_index_urls(source.index_url, source.extra_index_urls, strict)?;
             spec.no_index |= source.no_index;
-            spec.extra_index_urls.extend(source.extra_index_urls);
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
@@ -325,7 +520,182 @@ impl RequirementsSpecification {
         requirements: &[RequirementsSource],
         client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self> {
-        Self::from_sources(requirements, &[], &[], client_builder).await
+        Self::from_sources(
+            requirements,
+            &[],
+            &[],
+            false,
+            DEFAULT_MAX_INCLUDE_DEPTH,
+            client_builder,
+        )
+        .await
+    }
+
+    /// Merge the `--index-url` and `--extra-index-url` entries declared by a source.
+    ///
+    /// Indexes that were already declared by an earlier source are skipped, such that each index
+    /// is listed once, in the order in which it was first declared. Since only one primary index
+    /// can apply, a different `--index-url` is an error in `strict` mode; otherwise, the first
+    /// `--index-url` is retained and a warning is emitted.
+    fn merge_index_urls(
+        &mut self,
+        index_url: Option<IndexUrl>,
+        extra_index_urls: Vec<IndexUrl>,
+        strict: bool,
+    ) -> Result<()> {
+        if let Some(index_url) = index_url {
+            match &self.index_url {
+                Some(existing)
+                    if CanonicalUrl::new(index_url.url()) != CanonicalUrl::new(existing.url()) =>
+                {
+                    if strict {
+                        return Err(anyhow::anyhow!(
+                            "Multiple index URLs specified: `{existing}` vs. `{index_url}`",
+                        ));
+                    }
+                    warn_user!(
+                        "Multiple index URLs specified: `{existing}` vs. `{index_url}` (using: `{existing}`)"
+                    );
+                }
+                Some(_) => {}
+                None => self.index_url = Some(index_url),
+            }
+        }
+
+        for extra_index_url in extra_index_urls {
+            let canonical = CanonicalUrl::new(extra_index_url.url());
+            if !self
+                .extra_index_urls
+                .iter()
+                .any(|existing| CanonicalUrl::new(existing.url()) == canonical)
+            {
+                self.extra_index_urls.push(extra_index_url);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read the names of the extras declared by a `pyproject.toml` source, without resolving any
+    /// requirements.
+    ///
+    /// Only the keys of the `[project.optional-dependencies]` table are read; the extras are
+    /// returned in sorted order.
+    pub fn available_extras(source: &RequirementsSource) -> Result<Vec<ExtraName>> {
+        let RequirementsSource::PyprojectToml(path) = source else {
+            return Err(anyhow::anyhow!(
+                "Extras can only be read from a `pyproject.toml` file (found: `{source}`)"
+            ));
+        };
+        let contents = match fs_err::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(FileNotFound {
+                    path: path.to_path_buf(),
+                }
+                .into());
+            }
+            Err(err) => {
+                return Err(anyhow::anyhow!(
+                    "Failed to read `{}`: {}",
+                    path.user_display(),
+                    err
+                ));
+            }
+        };
+        let pyproject = toml::from_str::<ExtrasPyProjectToml>(&contents)
+            .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+        Ok(pyproject
+            .project
+            .map(|project| project.optional_dependencies.into_keys().collect())
+            .unwrap_or_default())
+    }
+
+    /// Apply the requested extras to any requirement on the current project (e.g., `.[dev]` or
+    /// `-e .` in a `requirements.txt` file), merging them with the extras declared inline.
+    ///
+    /// Returns `true` if the specification includes a requirement on the current project.
+    pub fn apply_project_extras(&mut self, extras: &ExtrasSpecification) -> Result<bool> {
+        let mut found = false;
+        for entry in &mut self.requirements {
+            let UnresolvedRequirement::Unnamed(requirement) = &mut entry.requirement else {
+                continue;
+            };
+            let ParsedUrl::Directory(directory) = &requirement.url.parsed_url else {
+                continue;
+            };
+            if directory.install_path != *CWD {
+                continue;
+            }
+            found = true;
+
+            let requested = match extras {
+                ExtrasSpecification::None => continue,
+                ExtrasSpecification::Some(extras) => extras.clone(),
+                ExtrasSpecification::All => {
+                    let pyproject = directory.install_path.join("pyproject.toml");
+                    if pyproject.is_file() {
+                        Self::available_extras(&RequirementsSource::PyprojectToml(pyproject))?
+                    } else {
+                        vec![]
+                    }
+                }
+            };
+            for extra in requested {
+                if !requirement.extras.contains(&extra) {
+                    requirement.extras.push(extra);
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    /// Verify that the static `[project.dependencies]` of each `pyproject.toml` source are
+    /// compatible with the `tool.uv.constraint-dependencies`, both those declared alongside the
+    /// dependencies and the given workspace constraints.
+    ///
+    /// Returns an error naming both entries if a dependency and a constraint on the same package
+    /// allow disjoint sets of versions (e.g., `numpy>=2` and `numpy<2`) under overlapping markers,
+    /// as the resolution would be unsatisfiable.
+    pub fn check_constraints(&self, constraints: &[Requirement]) -> Result<()> {
+        let constraints = self
+            .constraints
+            .iter()
+            .map(|constraint| &constraint.requirement)
+            .filter(|constraint| constraint.origin == Some(RequirementOrigin::Workspace))
+            .chain(constraints);
+        for constraint in constraints {
+            let RequirementSource::Registry { specifier, .. } = &constraint.source else {
+                continue;
+            };
+            for dependency in &self.project_dependencies {
+                if dependency.name != constraint.name {
+                    continue;
+                }
+                let RequirementSource::Registry {
+                    specifier: specifiers,
+                    ..
+                } = &dependency.source
+                else {
+                    continue;
+                };
+                if dependency.marker.is_disjoint(&constraint.marker) {
+                    continue;
+                }
+                if is_disjoint(specifiers, specifier) {
+                    let path = dependency
+                        .origin
+                        .as_ref()
+                        .map(RequirementOrigin::path)
+                        .unwrap_or(Path::new("pyproject.toml"));
+                    return Err(anyhow::anyhow!(
+                        "The dependency `{dependency}` in `{}` contradicts the constraint `{constraint}` in `tool.uv.constraint-dependencies`",
+                        path.user_display()
+                    ));
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Initialize a [`RequirementsSpecification`] from a list of [`Requirement`].
@@ -344,3 +714,243 @@ impl RequirementsSpecification {
         self.requirements.is_empty() && self.source_trees.is_empty() && self.overrides.is_empty()
     }
 }
+
+/// A minimal view of a `pyproject.toml` file, as read by
+/// [`RequirementsSpecification::available_extras`].
+#[derive(Deserialize)]
+struct ExtrasPyProjectToml {
+    project: Option<ExtrasProject>,
+}
+
+/// The `[project]` table of an [`ExtrasPyProjectToml`], including only the extra names.
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ExtrasProject {
+    #[serde(default)]
+    optional_dependencies: BTreeMap<ExtraName, IgnoredAny>,
+}
+
+/// Read the `deps` of a test environment in a `tox.ini` file.
+///
+/// Each line is parsed as a requirement, relative to the directory containing the `tox.ini`, with
+/// `{toxinidir}` expanded to that directory. Lines of the form `-r requirements.txt` and
+/// `-c constraints.txt` include the referenced files, along with any index and build options they
+/// declare; `-e path` adds an editable requirement.
+///
+/// If `env` is provided, the `deps` are read from the `[testenv:{env}]` section, falling back to
+/// those of the `[testenv]` section if the environment doesn't declare any.
+async fn read_tox_deps(
+    path: &Path,
+    env: Option<&str>,
+    max_include_depth: usize,
+    client_builder: &BaseClientBuilder<'_>,
+) -> Result<RequirementsSpecification> {
+    let contents = fs_err::tokio::read_to_string(path).await?;
+    let mut ini = Ini::new_cs();
+    ini.set_multiline(true);
+    // Markers may contain semicolons (e.g., `pytest; python_version < "3.10"`), so only `#`
+    // starts a comment.
+    ini.set_comment_symbols(&['#']);
+    let sections = ini
+        .read(contents)
+        .map_err(|err| anyhow::anyhow!("Failed to parse `{}`: {err}", path.user_display()))?;
+
+    let section = match env {
+        Some(env) => {
+            let name = format!("testenv:{env}");
+            if !sections.contains_key(&name) {
+                return Err(anyhow::anyhow!(
+                    "No `[{name}]` section found in: `{}`",
+                    path.user_display()
+                ));
+            }
+            name
+        }
+        None => "testenv".to_string(),
+    };
+    let deps = sections
+        .get(&section)
+        .and_then(|section| section.get("deps").cloned().flatten())
+        .or_else(|| {
+            sections
+                .get("testenv")
+                .and_then(|section| section.get("deps").cloned().flatten())
+        })
+        .unwrap_or_default();
+
+    let tox_ini_dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => CWD.join(parent),
+        _ => CWD.to_path_buf(),
+    };
+    let tox_ini_dir_str = tox_ini_dir.simplified_display().to_string();
+
+    let mut spec = RequirementsSpecification::default();
+    for line in deps.lines() {
+        // Strip any trailing comment.
+        let line = line.split_once(" #").map_or(line, |(line, _)| line).trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.replace("{toxinidir}", &tox_ini_dir_str);
+
+        if let Some(file) = line
+            .strip_prefix("-r")
+            .or_else(|| line.strip_prefix("--requirement"))
+        {
+            let requirements_txt = RequirementsTxt::parse_with_max_depth(
+                tox_ini_dir.join(file.trim_start_matches('=').trim()),
+                &tox_ini_dir,
+                client_builder,
+                max_include_depth,
+            )
+            .await?;
+            merge_requirements_txt_options(&mut spec, &requirements_txt)?;
+            spec.requirements.extend(
+                requirements_txt
+                    .requirements
+                    .into_iter()
+                    .map(UnresolvedRequirementSpecification::from)
+                    .chain(
+                        requirements_txt
+                            .editables
+                            .into_iter()
+                            .map(UnresolvedRequirementSpecification::from),
+                    ),
+            );
+            spec.constraints.extend(
+                requirements_txt
+                    .constraints
+                    .into_iter()
+                    .map(Requirement::from)
+                    .map(NameRequirementSpecification::from),
+            );
+        } else if let Some(file) = line
+            .strip_prefix("-c")
+            .or_else(|| line.strip_prefix("--constraint"))
+        {
+            let requirements_txt = RequirementsTxt::parse_with_max_depth(
+                tox_ini_dir.join(file.trim_start_matches('=').trim()),
+                &tox_ini_dir,
+                client_builder,
+                max_include_depth,
+            )
+            .await?;
+            merge_requirements_txt_options(&mut spec, &requirements_txt)?;
+            spec.constraints.extend(
+                requirements_txt
+                    .requirements
+                    .into_iter()
+                    .filter_map(|entry| match entry.requirement {
+                        RequirementsTxtRequirement::Named(requirement) => Some(requirement),
+                        RequirementsTxtRequirement::Unnamed(_) => None,
+                    })
+                    .chain(requirements_txt.constraints)
+                    .map(Requirement::from)
+                    .map(NameRequirementSpecification::from),
+            );
+        } else if let Some(editable) = line
+            .strip_prefix("-e")
+            .or_else(|| line.strip_prefix("--editable"))
+        {
+            let requirement = RequirementsTxtRequirement::parse(
+                editable.trim_start_matches('=').trim(),
+                &tox_ini_dir,
+                true,
+            )
+            .with_context(|| format!("Failed to parse: `{editable}`"))?
+            .into_editable()?
+            .with_origin(RequirementOrigin::File(path.to_path_buf()));
+            spec.requirements
+                .push(UnresolvedRequirementSpecification::from(requirement));
+        } else {
+            let requirement = RequirementsTxtRequirement::parse(&line, &tox_ini_dir, false)
+                .with_context(|| format!("Failed to parse: `{line}`"))?
+                .with_origin(RequirementOrigin::File(path.to_path_buf()));
+            spec.requirements
+                .push(UnresolvedRequirementSpecification::from(requirement));
+        }
+    }
+
+    Ok(spec)
+}
+
+/// Merge the index and build options (e.g., `--index-url` or `--no-binary`) declared by a file
+/// included from a `tox.ini`.
+fn merge_requirements_txt_options(
+    spec: &mut RequirementsSpecification,
+    requirements_txt: &RequirementsTxt,
+) -> Result<()> {
+    spec.merge_index_urls(
+        requirements_txt.index_url.clone().map(IndexUrl::from),
+        requirements_txt
+            .extra_index_urls
+            .iter()
+            .cloned()
+            .map(IndexUrl::from)
+            .collect(),
+        false,
+    )?;
+    spec.no_index |= requirements_txt.no_index;
+    spec.find_links.extend(
+        requirements_txt
+            .find_links
+            .iter()
+            .cloned()
+            .map(FlatIndexLocation::from),
+    );
+    spec.no_binary.extend(requirements_txt.no_binary.clone());
+    spec.no_build.extend(requirements_txt.only_binary.clone());
+    spec.config_settings
+        .extend(requirements_txt.config_settings.clone());
+    spec.prerelease
+        .extend(requirements_txt.prerelease.iter().cloned());
+    Ok(())
+}
+
+/// Return an error if a constraint is a direct URL reference (e.g., `foo @ https://...`), including
+/// references to local paths.
+///
+/// Constraints can only narrow the set of allowed versions, so a URL should instead be provided
+/// as a requirement.
+fn reject_url_constraint(requirement: &Requirement) -> anyhow::Result<()> {
+    if !matches!(requirement.source, RequirementSource::Registry { .. }) {
+        return Err(anyhow::anyhow!(
+            "Direct URL references are not allowed as constraints (found: `{requirement}`). Constraints must be version specifiers; to install `{}` from a URL, add it to your requirements file instead.",
+            requirement.name
+        ));
+    }
+    Ok(())
+}
+
+/// Returns `true` if no version satisfies both sets of specifiers.
+fn is_disjoint(left: &VersionSpecifiers, right: &VersionSpecifiers) -> bool {
+    let (Ok(left), Ok(right)) = (
+        PubGrubSpecifier::from_pep440_specifiers(left),
+        PubGrubSpecifier::from_pep440_specifiers(right),
+    ) else {
+        return false;
+    };
+    Range::from(left)
+        .intersection(&Range::from(right))
+        .is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::{RequirementsSource, RequirementsSpecification};
+
+    #[test]
+    fn available_extras() -> Result<()> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../scripts/packages/black_editable/pyproject.toml");
+        let source = RequirementsSource::PyprojectToml(path);
+        let extras = RequirementsSpecification::available_extras(&source)?;
+        let extras = extras.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(extras, ["colorama", "d", "dev", "jupyter", "uvloop"]);
+        Ok(())
+    }
+}
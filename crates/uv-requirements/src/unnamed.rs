@@ -17,8 +17,8 @@ use uv_distribution_types::{
 };
 use uv_normalize::PackageName;
 use uv_pep508::{UnnamedRequirement, VersionOrUrl};
+use uv_pypi_types::{get_egg_name, ParsedUrl, VerbatimParsedUrl};
 use uv_pypi_types::{Metadata10, Requirement};
-use uv_pypi_types::{ParsedUrl, VerbatimParsedUrl};
 use uv_resolver::{InMemoryIndex, MetadataResponse};
 use uv_types::{BuildContext, HashStrategy};
 
@@ -32,6 +32,9 @@ pub enum NamedRequirementsError {
 
     #[error(transparent)]
     WheelFilename(#[from] uv_distribution_filename::WheelFilenameError),
+
+    #[error("Invalid package name in `egg` fragment of URL: `{0}`")]
+    EggName(String, #[source] uv_normalize::InvalidNameError),
 }
 
 /// Like [`RequirementsSpecification`], but with concrete names for all requirements.
@@ -154,6 +157,31 @@ impl<'a, Context: BuildContext> NamedRequirementsResolver<'a, Context> {
             }
         }
 
+        // If the requirement is a remote Git repository or archive with an `egg` fragment, use the
+        // fragment as the package name, to avoid fetching and building the distribution.
+        //
+        // Ex) `git+https://github.com/pallets/flask.git@main#egg=flask&subdirectory=src/flask`
+        if matches!(
+            requirement.url.parsed_url,
+            ParsedUrl::Git(_) | ParsedUrl::Archive(_)
+        ) {
+            if let Some(name) = get_egg_name(&requirement.url.verbatim).map_err(|err| {
+                NamedRequirementsError::EggName(requirement.url.verbatim.to_string(), err)
+            })? {
+                debug!(
+                    "Found `egg` fragment for {url} ({name})",
+                    url = requirement.url.verbatim
+                );
+                return Ok(uv_pep508::Requirement {
+                    name,
+                    extras: requirement.extras,
+                    version_or_url: Some(VersionOrUrl::Url(requirement.url)),
+                    marker: requirement.marker,
+                    origin: requirement.origin,
+                });
+            }
+        }
+
         let source = match &requirement.url.parsed_url {
             // If the path points to a directory, attempt to read the name from static metadata.
             ParsedUrl::Directory(parsed_directory_url) => {
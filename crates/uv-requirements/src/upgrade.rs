@@ -3,12 +3,14 @@ use std::path::Path;
 use anyhow::Result;
 
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::Upgrade;
+use uv_configuration::{OutputFormat, Upgrade};
 use uv_fs::CWD;
 use uv_git::ResolvedRepositoryReference;
 use uv_requirements_txt::RequirementsTxt;
 use uv_resolver::{Lock, Preference, PreferenceError};
 
+use crate::read_compiled_requirements;
+
 #[derive(Debug, Default)]
 pub struct LockedRequirements {
     /// The pinned versions from the lockfile.
@@ -17,9 +19,11 @@ pub struct LockedRequirements {
     pub git: Vec<ResolvedRepositoryReference>,
 }
 
-/// Load the preferred requirements from an existing `requirements.txt`, applying the upgrade strategy.
+/// Load the preferred requirements from an existing `requirements.txt` (or JSON or `pylock.toml`
+/// output file), applying the upgrade strategy.
 pub async fn read_requirements_txt(
     output_file: Option<&Path>,
+    format: OutputFormat,
     upgrade: &Upgrade,
 ) -> Result<Vec<Preference>> {
     // As an optimization, skip reading the lockfile is we're upgrading all packages anyway.
@@ -33,16 +37,23 @@ pub async fn read_requirements_txt(
     };
 
     // Parse the requirements from the lockfile.
-    let requirements_txt = RequirementsTxt::parse(
-        output_file,
-        &*CWD,
-        &BaseClientBuilder::new().connectivity(Connectivity::Offline),
-    )
-    .await?;
+    let requirements = match format {
+        OutputFormat::RequirementsTxt => {
+            RequirementsTxt::parse(
+                output_file,
+                &*CWD,
+                &BaseClientBuilder::new().connectivity(Connectivity::Offline),
+            )
+            .await?
+            .requirements
+        }
+        OutputFormat::Json | OutputFormat::Pylock => {
+            read_compiled_requirements(output_file, format).await?
+        }
+    };
 
     // Map each entry in the lockfile to a preference.
-    let preferences = requirements_txt
-        .requirements
+    let preferences = requirements
         .into_iter()
         .map(Preference::from_entry)
         .filter_map(Result::transpose)
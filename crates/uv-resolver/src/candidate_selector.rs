@@ -1,5 +1,6 @@
 use itertools::Itertools;
 use pubgrub::Range;
+use rustc_hash::FxHashMap;
 use std::fmt::{Display, Formatter};
 use tracing::{debug, trace};
 
@@ -21,6 +22,8 @@ use crate::{Exclusions, Manifest, Options, ResolverMarkers};
 #[allow(clippy::struct_field_names)]
 pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
+    /// The resolution strategies that override `resolution_strategy` for specific packages.
+    package_resolution_strategies: FxHashMap<PackageName, ResolutionStrategy>,
     prerelease_strategy: PrereleaseStrategy,
     index_strategy: IndexStrategy,
 }
@@ -28,7 +31,7 @@ pub(crate) struct CandidateSelector {
 impl CandidateSelector {
     /// Return a [`CandidateSelector`] for the given [`Manifest`].
     pub(crate) fn for_resolution(
-        options: Options,
+        options: &Options,
         manifest: &Manifest,
         markers: &ResolverMarkers,
     ) -> Self {
@@ -39,6 +42,12 @@ impl CandidateSelector {
                 markers,
                 options.dependency_mode,
             ),
+            package_resolution_strategies: ResolutionStrategy::from_packages(
+                &options.resolution_modes,
+                manifest,
+                markers,
+                options.dependency_mode,
+            ),
             prerelease_strategy: PrereleaseStrategy::from_mode(
                 options.prerelease_mode,
                 manifest,
@@ -407,7 +416,11 @@ impl CandidateSelector {
     /// By default, we select the latest version, but we also allow using the lowest version instead
     /// to check the lower bounds.
     pub(crate) fn use_highest_version(&self, package_name: &PackageName) -> bool {
-        match &self.resolution_strategy {
+        let resolution_strategy = self
+            .package_resolution_strategies
+            .get(package_name)
+            .unwrap_or(&self.resolution_strategy);
+        match resolution_strategy {
             ResolutionStrategy::Highest => true,
             ResolutionStrategy::Lowest => false,
             ResolutionStrategy::LowestDirect(direct_dependencies) => {
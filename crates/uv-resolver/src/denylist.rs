@@ -0,0 +1,78 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+/// A specific version of a package that may not be selected during resolution (e.g.,
+/// `flask==3.0.0`).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DeniedVersion {
+    pub name: PackageName,
+    pub version: Version,
+}
+
+impl FromStr for DeniedVersion {
+    type Err = String;
+
+    /// Parse a [`DeniedVersion`] from a string of the form `{name}=={version}`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some((name, version)) = input.split_once("==") else {
+            return Err(format!(
+                "`{input}` must be a package name and an exact version (e.g., `flask==3.0.0`)"
+            ));
+        };
+        let name = PackageName::from_str(name.trim()).map_err(|err| err.to_string())?;
+        let version = Version::from_str(version.trim()).map_err(|err| err.to_string())?;
+        Ok(Self { name, version })
+    }
+}
+
+impl Display for DeniedVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}=={}", self.name, self.version)
+    }
+}
+
+/// The packages, and specific versions of packages, that may not be selected during resolution.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct Denylist {
+    /// Packages for which no version may be selected.
+    packages: BTreeSet<PackageName>,
+    /// Specific versions that may not be selected.
+    versions: BTreeMap<PackageName, BTreeSet<Version>>,
+}
+
+impl Denylist {
+    /// Determine the [`Denylist`] from the command-line arguments.
+    pub fn from_args(deny_package: Vec<PackageName>, deny: Vec<DeniedVersion>) -> Self {
+        let mut versions: BTreeMap<PackageName, BTreeSet<Version>> = BTreeMap::new();
+        for DeniedVersion { name, version } in deny {
+            versions.entry(name).or_default().insert(version);
+        }
+        Self {
+            packages: deny_package.into_iter().collect(),
+            versions,
+        }
+    }
+
+    /// Returns `true` if nothing is denied.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty() && self.versions.is_empty()
+    }
+
+    /// Returns `true` if every version of the given package is denied.
+    pub fn contains_package(&self, name: &PackageName) -> bool {
+        self.packages.contains(name)
+    }
+
+    /// Returns `true` if the given version of the package is denied.
+    pub fn contains_version(&self, name: &PackageName, version: &Version) -> bool {
+        self.packages.contains(name)
+            || self
+                .versions
+                .get(name)
+                .is_some_and(|versions| versions.contains(version))
+    }
+}
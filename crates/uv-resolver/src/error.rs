@@ -3,6 +3,7 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use indexmap::IndexSet;
+use itertools::Itertools;
 use pubgrub::{DefaultStringReporter, DerivationTree, Derived, External, Range, Reporter};
 use rustc_hash::FxHashMap;
 
@@ -91,6 +92,30 @@ pub enum ResolveError {
 
     #[error("Package `{0}` is unavailable")]
     PackageUnavailable(PackageName),
+
+    #[error("Resolution is too complex: {reason}{}", most_constrained_hint(.packages))]
+    TooComplex {
+        reason: String,
+        packages: Vec<(PackageName, usize)>,
+    },
+}
+
+/// Format a hint listing the packages for which the solver tried the most versions before
+/// exhausting its budget.
+fn most_constrained_hint(packages: &[(PackageName, usize)]) -> String {
+    if packages.is_empty() {
+        return String::new();
+    }
+    format!(
+        "\n\nhint: The most constrained packages were: {}. Consider adding tighter bounds or constraints for these packages.",
+        packages
+            .iter()
+            .map(|(name, count)| {
+                let versions = if *count == 1 { "version" } else { "versions" };
+                format!("`{name}` ({count} {versions} tried)")
+            })
+            .join(", ")
+    )
 }
 
 impl<T> From<tokio::sync::mpsc::error::SendError<T>> for ResolveError {
@@ -197,6 +222,15 @@ impl NoSolutionError {
     pub fn header(&self) -> NoSolutionHeader {
         NoSolutionHeader::new(self.markers.clone())
     }
+
+    /// Returns the names of the packages involved in the conflict, in sorted order.
+    pub fn packages(&self) -> BTreeSet<&PackageName> {
+        self.error
+            .packages()
+            .into_iter()
+            .filter_map(|package| package.name_no_root())
+            .collect()
+    }
 }
 
 impl std::error::Error for NoSolutionError {}
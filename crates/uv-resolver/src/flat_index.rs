@@ -1,5 +1,5 @@
 use std::collections::btree_map::Entry;
-use std::collections::BTreeMap;
+use std::collections::{hash_map, BTreeMap};
 
 use rustc_hash::FxHashMap;
 use tracing::instrument;
@@ -17,6 +17,7 @@ use uv_pep440::Version;
 use uv_platform_tags::{TagCompatibility, Tags};
 use uv_pypi_types::HashDigest;
 use uv_types::HashStrategy;
+use uv_warnings::warn_user_once;
 
 /// A set of [`PrioritizedDist`] from a `--find-links` entry, indexed by [`PackageName`]
 /// and [`Version`].
@@ -31,6 +32,11 @@ pub struct FlatIndex {
 
 impl FlatIndex {
     /// Collect all files from a `--find-links` target into a [`FlatIndex`].
+    ///
+    /// The entries are expected in the order in which the `--find-links` locations were provided.
+    /// If the same distribution is available from multiple locations, the first location takes
+    /// precedence; across locations, compatible wheels are still preferred over source
+    /// distributions.
     #[instrument(skip_all)]
     pub fn from_entries(
         entries: FlatIndexEntries,
@@ -38,9 +44,34 @@ impl FlatIndex {
         hasher: &HashStrategy,
         build_options: &BuildOptions,
     ) -> Self {
+        // Track the location and hashes of each file, to detect the same file being provided with
+        // different contents by multiple locations.
+        let mut seen: FxHashMap<String, (IndexUrl, Vec<HashDigest>)> = FxHashMap::default();
+
         // Collect compatible distributions.
         let mut index = FxHashMap::default();
         for (filename, file, url) in entries.entries {
+            match seen.entry(file.filename.clone()) {
+                hash_map::Entry::Occupied(entry) => {
+                    let (existing, hashes) = entry.get();
+                    if *existing != url
+                        && !hashes.is_empty()
+                        && !file.hashes.is_empty()
+                        && !file.hashes.iter().any(|hash| hashes.contains(hash))
+                    {
+                        warn_user_once!(
+                            "`{}` was found in multiple `--find-links` locations with different hashes; using the file from `{existing}` over `{url}`",
+                            file.filename
+                        );
+                    }
+                    // The first location takes precedence, so skip the file entirely.
+                    continue;
+                }
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert((url.clone(), file.hashes.clone()));
+                }
+            }
+
             let distributions = index.entry(filename.name().clone()).or_default();
             Self::add_file(
                 distributions,
@@ -1,3 +1,4 @@
+pub use denylist::{DeniedVersion, Denylist};
 pub use dependency_mode::DependencyMode;
 pub use error::{NoSolutionError, NoSolutionHeader, ResolveError};
 pub use exclude_newer::ExcludeNewer;
@@ -7,16 +8,20 @@ pub use lock::{
     Lock, LockError, RequirementsTxtExport, ResolverManifest, SatisfiesResult, TreeDisplay,
 };
 pub use manifest::Manifest;
-pub use options::{Options, OptionsBuilder};
+pub use options::{Options, OptionsBuilder, ResolutionBudget};
 pub use preferences::{Preference, PreferenceError, Preferences};
 pub use prerelease::PrereleaseMode;
 pub use pubgrub::{PubGrubSpecifier, PubGrubSpecifierError};
 pub use python_requirement::PythonRequirement;
 pub use requires_python::{RequiresPython, RequiresPythonError, RequiresPythonRange};
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolutionGraph,
+    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, JsonPackage,
+    JsonResolution, PylockArchive, PylockDirectory, PylockError, PylockFile, PylockPackage,
+    PylockToml, PylockVcs, ResolutionGraph,
+};
+pub use resolution_mode::{
+    DuplicatePackageResolutionMode, PackageResolutionMode, PackageResolutionModes, ResolutionMode,
 };
-pub use resolution_mode::ResolutionMode;
 pub use resolver::{
     BuildId, DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageVersionsResult,
     Reporter as ResolverReporter, Resolver, ResolverMarkers, ResolverProvider, VersionsResponse,
@@ -28,6 +33,7 @@ pub use yanks::AllowedYanks;
 mod bare;
 mod candidate_selector;
 
+mod denylist;
 mod dependency_mode;
 mod dependency_provider;
 mod error;
@@ -606,8 +606,13 @@ impl Lock {
 
         // Add any dependency groups that are exclusive to the workspace root (e.g., dev
         // dependencies in (legacy) non-project workspace roots).
-        for group in dev.iter() {
-            for dependency in project.group(group) {
+        let groups = dev
+            .iter()
+            .map(|group| project.group(group))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(LockErrorKind::DependencyGroup)?;
+        for group in &groups {
+            for dependency in group {
                 if dependency.marker.evaluate(marker_env, &[]) {
                     let root_name = &dependency.name;
                     let root = self
@@ -4042,6 +4047,13 @@ enum LockErrorKind {
         /// The ID of the package.
         name: PackageName,
     },
+    /// An error that occurs when the dependency groups of the workspace root are invalid.
+    #[error("failed to read the dependency groups of the workspace root")]
+    DependencyGroup(
+        /// The inner error we forward.
+        #[source]
+        uv_workspace::pyproject::DependencyGroupError,
+    ),
 }
 
 /// An error that occurs when a source string could not be parsed.
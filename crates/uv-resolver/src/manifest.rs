@@ -3,13 +3,13 @@ use std::collections::BTreeSet;
 
 use either::Either;
 
-use uv_configuration::{Constraints, Overrides};
+use uv_configuration::{AllowYanked, Constraints, Overrides};
 use uv_normalize::{GroupName, PackageName};
 use uv_pypi_types::Requirement;
 use uv_types::RequestedRequirements;
 
 use crate::preferences::Preferences;
-use crate::{DependencyMode, Exclusions, ResolverMarkers};
+use crate::{Denylist, DependencyMode, Exclusions, ResolverMarkers};
 
 /// A manifest of requirements, constraints, and preferences.
 #[derive(Clone, Debug)]
@@ -52,6 +52,25 @@ pub struct Manifest {
     /// determinations around "allowed" versions (for example, "allowed" URLs or "allowed"
     /// pre-release versions).
     pub(crate) lookaheads: Vec<RequestedRequirements>,
+
+    /// The packages whose dependencies should not be resolved, e.g., because they were provided
+    /// in a pinned requirements file that should be installed without dependencies.
+    pub(crate) no_deps: BTreeSet<PackageName>,
+
+    /// The packages for which yanked versions may be selected, even without an exact pin.
+    pub(crate) allow_yanked: AllowYanked,
+
+    /// The packages for which pre-release versions may be selected, regardless of the
+    /// pre-release strategy (e.g., via a `# uv: pre` pragma).
+    pub(crate) prerelease: BTreeSet<PackageName>,
+
+    /// The only packages that may be included in the resolution, if any (e.g., via
+    /// `--allow-package`). Any other package, whether direct or transitive, is rejected.
+    pub(crate) allowed: Option<BTreeSet<PackageName>>,
+
+    /// The packages, and specific versions of packages, that may not be included in the
+    /// resolution (e.g., via `--deny-package` or `--deny`).
+    pub(crate) denied: Denylist,
 }
 
 impl Manifest {
@@ -76,6 +95,11 @@ impl Manifest {
             workspace_members: workspace_members.unwrap_or_default(),
             exclusions,
             lookaheads,
+            no_deps: BTreeSet::new(),
+            allow_yanked: AllowYanked::default(),
+            prerelease: BTreeSet::new(),
+            allowed: None,
+            denied: Denylist::default(),
         }
     }
 
@@ -90,6 +114,11 @@ impl Manifest {
             exclusions: Exclusions::default(),
             workspace_members: BTreeSet::new(),
             lookaheads: Vec::new(),
+            no_deps: BTreeSet::new(),
+            allow_yanked: AllowYanked::default(),
+            prerelease: BTreeSet::new(),
+            allowed: None,
+            denied: Denylist::default(),
         }
     }
 
@@ -99,6 +128,41 @@ impl Manifest {
         self
     }
 
+    /// Set the packages whose dependencies should not be resolved.
+    #[must_use]
+    pub fn with_no_deps(mut self, no_deps: BTreeSet<PackageName>) -> Self {
+        self.no_deps = no_deps;
+        self
+    }
+
+    /// Set the packages for which yanked versions may be selected.
+    #[must_use]
+    pub fn with_allow_yanked(mut self, allow_yanked: AllowYanked) -> Self {
+        self.allow_yanked = allow_yanked;
+        self
+    }
+
+    /// Set the packages for which pre-release versions may be selected.
+    #[must_use]
+    pub fn with_prerelease(mut self, prerelease: BTreeSet<PackageName>) -> Self {
+        self.prerelease = prerelease;
+        self
+    }
+
+    /// Set the only packages that may be included in the resolution.
+    #[must_use]
+    pub fn with_allowed(mut self, allowed: Option<BTreeSet<PackageName>>) -> Self {
+        self.allowed = allowed;
+        self
+    }
+
+    /// Set the packages, and specific versions of packages, that may not be selected.
+    #[must_use]
+    pub fn with_denied(mut self, denied: Denylist) -> Self {
+        self.denied = denied;
+        self
+    }
+
     /// Return an iterator over all requirements, constraints, and overrides, in priority order,
     /// such that requirements come first, followed by constraints, followed by overrides.
     ///
@@ -1,25 +1,51 @@
+use std::time::Duration;
+
 use uv_configuration::IndexStrategy;
 
-use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use crate::{DependencyMode, ExcludeNewer, PackageResolutionModes, PrereleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
+    #[serde(skip)]
+    pub resolution_modes: PackageResolutionModes,
     pub prerelease_mode: PrereleaseMode,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
     pub index_strategy: IndexStrategy,
+    #[serde(skip)]
+    pub budget: ResolutionBudget,
+}
+
+/// A limit on the amount of work the solver may perform before aborting the resolution.
+///
+/// By default, the solver runs until it finds a resolution or proves that none exists.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ResolutionBudget {
+    /// The maximum wall-clock time to spend solving.
+    pub timeout: Option<Duration>,
+    /// The maximum number of solver steps (i.e., package decisions and conflicts) to perform.
+    pub max_steps: Option<u64>,
+}
+
+impl ResolutionBudget {
+    /// Returns `true` if the budget imposes no limits.
+    pub fn is_unlimited(&self) -> bool {
+        self.timeout.is_none() && self.max_steps.is_none()
+    }
 }
 
 /// Builder for [`Options`].
 #[derive(Debug, Default, Clone)]
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
+    resolution_modes: PackageResolutionModes,
     prerelease_mode: PrereleaseMode,
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
     index_strategy: IndexStrategy,
+    budget: ResolutionBudget,
 }
 
 impl OptionsBuilder {
@@ -35,6 +61,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`ResolutionMode`] for specific packages, overriding the global mode.
+    #[must_use]
+    pub fn resolution_modes(mut self, resolution_modes: PackageResolutionModes) -> Self {
+        self.resolution_modes = resolution_modes;
+        self
+    }
+
     /// Sets the [`PrereleaseMode`].
     #[must_use]
     pub fn prerelease_mode(mut self, prerelease_mode: PrereleaseMode) -> Self {
@@ -63,14 +96,23 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`ResolutionBudget`].
+    #[must_use]
+    pub fn budget(mut self, budget: ResolutionBudget) -> Self {
+        self.budget = budget;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
+            resolution_modes: self.resolution_modes,
             prerelease_mode: self.prerelease_mode,
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
             index_strategy: self.index_strategy,
+            budget: self.budget,
         }
     }
 }
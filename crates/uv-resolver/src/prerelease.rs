@@ -73,10 +73,9 @@ impl PrereleaseStrategy {
         let mut packages = ForkSet::default();
 
         match mode {
-            PrereleaseMode::Disallow => Self::Disallow,
-            PrereleaseMode::Allow => Self::Allow,
-            PrereleaseMode::IfNecessary => Self::IfNecessary,
-            _ => {
+            PrereleaseMode::Allow => return Self::Allow,
+            PrereleaseMode::Disallow | PrereleaseMode::IfNecessary => {}
+            PrereleaseMode::Explicit | PrereleaseMode::IfNecessaryOrExplicit => {
                 for requirement in manifest.requirements(markers, dependencies) {
                     let RequirementSource::Registry { specifier, .. } = &requirement.source else {
                         continue;
@@ -89,14 +88,28 @@ impl PrereleaseStrategy {
                         packages.add(&requirement, ());
                     }
                 }
+            }
+        }
 
-                match mode {
-                    PrereleaseMode::Explicit => Self::Explicit(packages),
-                    PrereleaseMode::IfNecessaryOrExplicit => Self::IfNecessaryOrExplicit(packages),
-                    _ => unreachable!(),
+        // Allow pre-release versions for any packages that opted in individually, regardless of
+        // the mode.
+        if !manifest.prerelease.is_empty() {
+            for requirement in manifest.requirements(markers, dependencies) {
+                if manifest.prerelease.contains(&requirement.name) {
+                    packages.add(&requirement, ());
                 }
             }
         }
+
+        match mode {
+            PrereleaseMode::Disallow if packages.is_empty() => Self::Disallow,
+            PrereleaseMode::IfNecessary if packages.is_empty() => Self::IfNecessary,
+            PrereleaseMode::Disallow | PrereleaseMode::Explicit => Self::Explicit(packages),
+            PrereleaseMode::IfNecessary | PrereleaseMode::IfNecessaryOrExplicit => {
+                Self::IfNecessaryOrExplicit(packages)
+            }
+            PrereleaseMode::Allow => unreachable!(),
+        }
     }
 
     /// Returns `true` if a [`PackageName`] is allowed to have pre-release versions.
@@ -530,6 +530,17 @@ impl PubGrubReportFormatter<'_> {
                         );
                     }
 
+                    // Check for no versions due to a URL requirement (e.g., an editable), which
+                    // takes precedence over any versions on the index.
+                    if let DerivationTree::External(External::NoVersions(..)) = derivation_tree {
+                        if let Some(url) = fork_urls.get(name) {
+                            output_hints.insert(PubGrubHint::UrlRequirement {
+                                package: package.clone(),
+                                url: url.verbatim.verbatim().to_string(),
+                            });
+                        }
+                    }
+
                     // Check for no versions due to no `--find-links` flat index.
                     Self::index_hints(
                         package,
@@ -650,7 +661,11 @@ impl PubGrubReportFormatter<'_> {
                     reason: reason.clone(),
                 });
             }
-            Some(UnavailablePackage::NotFound) => {}
+            Some(
+                UnavailablePackage::NotFound
+                | UnavailablePackage::NotAllowed
+                | UnavailablePackage::Denied,
+            ) => {}
             None => {}
         }
 
@@ -867,6 +882,13 @@ pub(crate) enum PubGrubHint {
         // excluded from `PartialEq` and `Hash`
         next_index: IndexUrl,
     },
+    /// A package was requested by URL (e.g., as an editable), such that the versions available on
+    /// the index were not considered.
+    UrlRequirement {
+        package: PubGrubPackage,
+        // excluded from `PartialEq` and `Hash`
+        url: String,
+    },
 }
 
 /// This private enum mirrors [`PubGrubHint`] but only includes fields that should be
@@ -915,6 +937,9 @@ enum PubGrubHintCore {
     UncheckedIndex {
         package: PubGrubPackage,
     },
+    UrlRequirement {
+        package: PubGrubPackage,
+    },
 }
 
 impl From<PubGrubHint> for PubGrubHintCore {
@@ -968,6 +993,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
                 workspace,
             },
             PubGrubHint::UncheckedIndex { package, .. } => Self::UncheckedIndex { package },
+            PubGrubHint::UrlRequirement { package, .. } => Self::UrlRequirement { package },
         }
     }
 }
@@ -1205,6 +1231,17 @@ impl std::fmt::Display for PubGrubHint {
                     "--index-strategy unsafe-best-match".green(),
                 )
             }
+            Self::UrlRequirement { package, url } => {
+                write!(
+                    f,
+                    "{}{} {} was requested from `{}`, which takes precedence over any versions of {} on the index",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.bold(),
+                    url.cyan(),
+                    package.bold(),
+                )
+            }
         }
     }
 }
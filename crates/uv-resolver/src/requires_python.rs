@@ -221,11 +221,16 @@ impl RequiresPython {
     /// compatible, since all versions in the `Requires-Python` range are also covered by the
     /// provided range. However, `>=3.9` would not be considered compatible, as the
     /// `Requires-Python` includes Python 3.8, but `>=3.9` does not.
+    ///
+    /// Exclusions within the provided range are respected: if the `Requires-Python` is
+    /// `>=3.9.2`, then `>=3.7,!=3.9.*` would not be considered compatible. As with the rest of
+    /// `Requires-Python`, any upper bound on the provided range is ignored.
     pub fn is_contained_by(&self, target: &VersionSpecifiers) -> bool {
         let Ok(target) = crate::pubgrub::PubGrubSpecifier::from_release_specifiers(target) else {
             return false;
         };
-        let target = target
+        let target = Range::from(target);
+        let target_lower = target
             .iter()
             .next()
             .map(|(lower, _)| lower)
@@ -235,7 +240,7 @@ impl RequiresPython {
         // `>=3.7`.
         //
         // That is: `version_lower` should be less than or equal to `requires_python_lower`.
-        match (target, self.range.lower().as_ref()) {
+        let lower = match (target_lower, self.range.lower().as_ref()) {
             (Bound::Included(target_lower), Bound::Included(requires_python_lower)) => {
                 target_lower <= requires_python_lower
             }
@@ -253,6 +258,42 @@ impl RequiresPython {
             // If we have no lower bound, then there must be versions we support that the
             // dependency does not.
             (_, Bound::Unbounded) => false,
+        };
+        if !lower {
+            return false;
+        }
+
+        // The minimum supported version must not fall within an exclusion in the target range
+        // (e.g., `!=3.9.*`). Since upper bounds are ignored, extend the final segment of the
+        // range indefinitely before testing.
+        let Some((last_lower, _)) = target.iter().last() else {
+            return true;
+        };
+        let target = target.union(&Range::from_range_bounds((
+            last_lower.clone(),
+            Bound::Unbounded,
+        )));
+        match self.range.lower().as_ref() {
+            // Ex) `>=3.9.2` must be contained by the target.
+            Bound::Included(requires_python_lower) => target.contains(requires_python_lower),
+            // Ex) `>3.9.1` requires that the target includes the versions immediately above
+            // `3.9.1`, i.e., a segment that starts at or below `3.9.1` and ends above it.
+            Bound::Excluded(requires_python_lower) => target.iter().any(|(lower, upper)| {
+                let lower = match lower {
+                    Bound::Included(lower) | Bound::Excluded(lower) => {
+                        lower <= requires_python_lower
+                    }
+                    Bound::Unbounded => true,
+                };
+                let upper = match upper {
+                    Bound::Included(upper) | Bound::Excluded(upper) => {
+                        upper > requires_python_lower
+                    }
+                    Bound::Unbounded => true,
+                };
+                lower && upper
+            }),
+            Bound::Unbounded => true,
         }
     }
 
@@ -879,6 +920,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_contained_by_exclusion() {
+        let specifiers = VersionSpecifiers::from_str(">=3.7,!=3.9.*,<4").unwrap();
+        for (target, expected) in [
+            (">=3.7", true),
+            (">=3.8", true),
+            (">=3.9.2", false),
+            (">=3.10", true),
+            (">3.8", true),
+            (">3.8.5", true),
+            (">3.9.1", false),
+            (">3.10", true),
+        ] {
+            let requires_python =
+                RequiresPython::from_specifiers(&VersionSpecifiers::from_str(target).unwrap())
+                    .unwrap();
+            assert_eq!(
+                requires_python.is_contained_by(&specifiers),
+                expected,
+                "{target}"
+            );
+        }
+    }
+
     #[test]
     fn lower_bound_ordering() {
         let versions = &[
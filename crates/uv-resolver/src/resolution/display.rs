@@ -1,4 +1,5 @@
 use std::collections::BTreeSet;
+use std::path::Path;
 
 use owo_colors::OwoColorize;
 use petgraph::visit::EdgeRef;
@@ -17,17 +18,17 @@ use crate::{ResolutionGraph, ResolverMarkers};
 #[allow(clippy::struct_excessive_bools)]
 pub struct DisplayResolutionGraph<'a> {
     /// The underlying graph.
-    resolution: &'a ResolutionGraph,
+    pub(crate) resolution: &'a ResolutionGraph,
     /// The marker environment, used to determine the markers that apply to each package.
     marker_env: &'a ResolverMarkers,
     /// The packages to exclude from the output.
     no_emit_packages: &'a [PackageName],
     /// Whether to include hashes in the output.
-    show_hashes: bool,
+    pub(crate) show_hashes: bool,
     /// Whether to include extras in the output (e.g., `black[colorama]`).
-    include_extras: bool,
+    pub(crate) include_extras: bool,
     /// Whether to include environment markers in the output (e.g., `black ; sys_platform == "win32"`).
-    include_markers: bool,
+    pub(crate) include_markers: bool,
     /// Whether to include annotations in the output, to indicate which dependency or dependencies
     /// requested each package.
     include_annotations: bool,
@@ -36,6 +37,10 @@ pub struct DisplayResolutionGraph<'a> {
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
+    /// The directory against which to rebase relative paths (i.e., that of the output file).
+    output_dir: Option<&'a Path>,
+    /// Whether to write local paths as absolute paths.
+    absolute_paths: bool,
 }
 
 #[derive(Debug)]
@@ -66,6 +71,8 @@ impl<'a> DisplayResolutionGraph<'a> {
         include_annotations: bool,
         include_index_annotation: bool,
         annotation_style: AnnotationStyle,
+        output_dir: Option<&'a Path>,
+        absolute_paths: bool,
     ) -> DisplayResolutionGraph<'a> {
         Self {
             resolution: underlying,
@@ -77,10 +84,40 @@ impl<'a> DisplayResolutionGraph<'a> {
             include_annotations,
             include_index_annotation,
             annotation_style,
+            output_dir,
+            absolute_paths,
         }
     }
 }
 
+impl<'a> DisplayResolutionGraph<'a> {
+    /// Return the distributions to include in the output, with the nodes for each package
+    /// combined regardless of extras, sorted by name (but with editable packages first).
+    pub(crate) fn dists(&self) -> Vec<RequirementsTxtDist<'a>> {
+        let petgraph = self.resolution.petgraph.map(
+            |_index, node| match node {
+                ResolutionGraphNode::Root => DisplayResolutionGraphNode::Root,
+                ResolutionGraphNode::Dist(dist) => {
+                    DisplayResolutionGraphNode::Dist(RequirementsTxtDist::from_annotated_dist(dist))
+                }
+            },
+            |_index, _edge| (),
+        );
+        let petgraph = combine_extras(&petgraph);
+
+        let mut nodes = petgraph
+            .node_indices()
+            .filter(|index| !self.no_emit_packages.contains(petgraph[*index].name()))
+            .collect::<Vec<_>>();
+        nodes.sort_unstable_by_key(|index| (petgraph[*index].to_comparator(), *index));
+
+        nodes
+            .into_iter()
+            .map(|index| petgraph[index].clone())
+            .collect()
+    }
+}
+
 /// Write the graph in the `{name}=={version}` format of requirements.txt that pip uses.
 impl std::fmt::Display for DisplayResolutionGraph<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -185,13 +222,21 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                     &self.resolution.requires_python,
                     self.include_extras,
                     self.include_markers,
+                    self.output_dir,
+                    self.absolute_paths,
                 )
                 .to_string();
 
             // Display the distribution hashes, if any.
             let mut has_hashes = false;
             if self.show_hashes {
-                for hash in node.hashes {
+                // Sort the hashes (by algorithm, then digest), such that the output doesn't
+                // depend on the order in which they were discovered (e.g., from an existing
+                // output file or the index).
+                let mut hashes = node.hashes.iter().collect::<Vec<_>>();
+                hashes.sort_unstable();
+                hashes.dedup();
+                for hash in hashes {
                     has_hashes = true;
                     line.push_str(" \\\n");
                     line.push_str("    --hash=");
@@ -284,6 +329,15 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 writeln!(f, "{line}")?;
             }
 
+            // If enabled, include the Git reference from which each pinned commit was resolved, so
+            // that the original tag or branch is retained alongside the commit (e.g.,
+            // `# resolved from v1.2.3`).
+            if self.include_annotations {
+                if let Some(reference) = node.git_reference() {
+                    writeln!(f, "{}", format!("    # resolved from {reference}").green())?;
+                }
+            }
+
             // If enabled, include indexes to indicate which index was used for each package (e.g.,
             // `# from https://pypi.org/simple`).
             if self.include_index_annotation {
@@ -300,7 +354,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
 
 /// Indicate the style of annotation comments, used to indicate the dependencies that requested each
 /// package.
-#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -18,6 +18,7 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifier};
 use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind, VerbatimUrl};
 use uv_pypi_types::{HashDigest, ParsedUrlError, Requirement, VerbatimParsedUrl, Yanked};
+use uv_types::HashStrategy;
 
 use crate::graph_ops::marker_reachability;
 use crate::pins::FilePins;
@@ -93,6 +94,28 @@ struct PackageRef<'a> {
 }
 
 impl ResolutionGraph {
+    /// Create an empty graph, for a resolution without any requirements.
+    pub(crate) fn empty(
+        constraints: &Constraints,
+        overrides: &Overrides,
+        python: &PythonRequirement,
+        options: Options,
+    ) -> Self {
+        let mut petgraph = Graph::new();
+        petgraph.add_node(ResolutionGraphNode::Root);
+        Self {
+            petgraph,
+            requires_python: python.target().clone(),
+            fork_markers: vec![],
+            diagnostics: vec![],
+            requirements: vec![],
+            constraints: constraints.clone(),
+            overrides: overrides.clone(),
+            options,
+            package_markers: FxHashMap::default(),
+        }
+    }
+
     /// Create a new graph from the resolved PubGrub state.
     pub(crate) fn from_state(
         resolutions: &[Resolution],
@@ -100,6 +123,7 @@ impl ResolutionGraph {
         constraints: &Constraints,
         overrides: &Overrides,
         preferences: &Preferences,
+        hasher: &HashStrategy,
         index: &InMemoryIndex,
         git: &GitResolver,
         python: &PythonRequirement,
@@ -145,6 +169,7 @@ impl ResolutionGraph {
                     &mut inverse,
                     &mut diagnostics,
                     preferences,
+                    hasher,
                     &resolution.pins,
                     index,
                     git,
@@ -319,6 +344,7 @@ impl ResolutionGraph {
         inverse: &mut FxHashMap<PackageRef<'a>, NodeIndex>,
         diagnostics: &mut Vec<ResolutionDiagnostic>,
         preferences: &Preferences,
+        hasher: &HashStrategy,
         pins: &FilePins,
         index: &InMemoryIndex,
         git: &GitResolver,
@@ -339,6 +365,7 @@ impl ResolutionGraph {
             pins,
             diagnostics,
             preferences,
+            hasher,
             index,
             git,
         )?;
@@ -396,6 +423,7 @@ impl ResolutionGraph {
         pins: &FilePins,
         diagnostics: &mut Vec<ResolutionDiagnostic>,
         preferences: &Preferences,
+        hasher: &HashStrategy,
         index: &InMemoryIndex,
         git: &GitResolver,
     ) -> Result<(ResolvedDist, Vec<HashDigest>, Option<Metadata>), ResolveError> {
@@ -406,8 +434,15 @@ impl ResolutionGraph {
             let version_id = VersionId::from_url(&url.verbatim);
 
             // Extract the hashes.
-            let hashes =
-                Self::get_hashes(name, Some(url), &version_id, version, preferences, index);
+            let hashes = Self::get_hashes(
+                name,
+                Some(url),
+                &version_id,
+                version,
+                preferences,
+                hasher,
+                index,
+            );
 
             // Extract the metadata.
             let metadata = {
@@ -449,7 +484,8 @@ impl ResolutionGraph {
             }
 
             // Extract the hashes.
-            let hashes = Self::get_hashes(name, None, &version_id, version, preferences, index);
+            let hashes =
+                Self::get_hashes(name, None, &version_id, version, preferences, hasher, index);
 
             // Extract the metadata.
             let metadata = {
@@ -468,25 +504,37 @@ impl ResolutionGraph {
 
     /// Identify the hashes for the [`VersionId`], preserving any hashes that were provided by the
     /// lockfile.
+    ///
+    /// If hashes are being generated, only those computed with the requested algorithm are
+    /// returned.
     fn get_hashes(
         name: &PackageName,
         url: Option<&VerbatimParsedUrl>,
         version_id: &VersionId,
         version: &Version,
         preferences: &Preferences,
+        hasher: &HashStrategy,
         index: &InMemoryIndex,
     ) -> Vec<HashDigest> {
+        let filter = |mut digests: Vec<HashDigest>| {
+            if let HashStrategy::Generate(algorithm) = hasher {
+                digests.retain(|digest| digest.algorithm == *algorithm);
+            }
+            digests
+        };
+
         // 1. Look for hashes from the lockfile.
         if let Some(digests) = preferences.match_hashes(name, version) {
+            let digests = filter(digests.to_vec());
             if !digests.is_empty() {
-                return digests.to_vec();
+                return digests;
             }
         }
 
         // 2. Look for hashes for the distribution (i.e., the specific wheel or source distribution).
         if let Some(metadata_response) = index.distributions().get(version_id) {
             if let MetadataResponse::Found(ref archive) = *metadata_response {
-                let mut digests = archive.hashes.clone();
+                let mut digests = filter(archive.hashes.clone());
                 digests.sort_unstable();
                 if !digests.is_empty() {
                     return digests;
@@ -501,7 +549,8 @@ impl ResolutionGraph {
                     if let Some(digests) = version_maps
                         .iter()
                         .find_map(|version_map| version_map.hashes(version))
-                        .map(|mut digests| {
+                        .map(|digests| {
+                            let mut digests = filter(digests);
                             digests.sort_unstable();
                             digests
                         })
@@ -13,10 +13,15 @@ use uv_pypi_types::HashDigest;
 pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
 pub(crate) use crate::resolution::graph::ResolutionGraphNode;
 pub use crate::resolution::graph::{ConflictingDistributionError, ResolutionGraph};
+pub use crate::resolution::output::{
+    JsonPackage, JsonResolution, PylockArchive, PylockDirectory, PylockError, PylockFile,
+    PylockPackage, PylockToml, PylockVcs,
+};
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
 
 mod display;
 mod graph;
+mod output;
 mod requirements_txt;
 
 /// A pinned package with its resolved distribution and metadata. The [`ResolvedDist`] refers to a
@@ -0,0 +1,355 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use uv_distribution_types::{
+    BuiltDist, Dist, DistributionMetadata, File, Name, ResolvedDist, SourceDist, ToUrlError,
+    Verbatim, VersionOrUrlRef,
+};
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::Version;
+use uv_pypi_types::HashDigest;
+
+use crate::requires_python::SimplifiedMarkerTree;
+use crate::resolution::RequirementsTxtDist;
+use crate::DisplayResolutionGraph;
+
+/// A resolution in the JSON format written by `uv pip compile --format json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct JsonResolution {
+    /// The tool that wrote the resolution, used to distinguish uv's output from arbitrary JSON.
+    #[serde(default)]
+    pub created_by: String,
+    pub packages: Vec<JsonPackage>,
+}
+
+/// A pinned package in a [`JsonResolution`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct JsonPackage {
+    pub name: PackageName,
+    pub version: Version,
+    /// The extras enabled for the package, if extras are included in the output.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extras: Vec<ExtraName>,
+    /// The direct URL (or path) from which the package is installed, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Whether the package is installed in editable mode.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub editable: bool,
+    /// The index from which the package is installed, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<String>,
+    /// The environment markers under which the package should be installed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+    /// The hashes of the package's distributions, as `{algorithm}:{digest}`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hashes: Vec<String>,
+}
+
+impl JsonResolution {
+    /// The value of `created-by` in the JSON files written by uv.
+    pub const CREATED_BY: &'static str = "uv";
+
+    /// Construct a [`JsonResolution`] from the resolved packages in a [`DisplayResolutionGraph`].
+    pub fn from_display(display: &DisplayResolutionGraph) -> Self {
+        let packages = display
+            .dists()
+            .into_iter()
+            .map(|node| {
+                let url = match node.version_or_url() {
+                    VersionOrUrlRef::Url(url) => Some(url.verbatim().to_string()),
+                    VersionOrUrlRef::Version(_) => None,
+                };
+                let mut extras = if display.include_extras {
+                    node.extras.clone()
+                } else {
+                    vec![]
+                };
+                extras.sort_unstable();
+                extras.dedup();
+                JsonPackage {
+                    name: node.name().clone(),
+                    version: node.version.clone(),
+                    extras,
+                    url,
+                    editable: node.dist.is_editable(),
+                    index: node.dist.index().map(|index| index.redacted().to_string()),
+                    marker: marker(display, &node),
+                    hashes: if display.show_hashes {
+                        node.hashes.iter().map(ToString::to_string).collect()
+                    } else {
+                        vec![]
+                    },
+                }
+            })
+            .collect();
+        Self {
+            created_by: Self::CREATED_BY.to_string(),
+            packages,
+        }
+    }
+}
+
+/// An error encountered while writing a resolution in the PEP 751 `pylock.toml` format.
+#[derive(Debug, thiserror::Error)]
+pub enum PylockError {
+    #[error(transparent)]
+    ToUrl(#[from] ToUrlError),
+    #[error("Git distribution `{0}` is missing a resolved commit")]
+    MissingCommit(PackageName),
+}
+
+/// A resolution in the PEP 751 `pylock.toml` format.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/pylock-toml/>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PylockToml {
+    pub lock_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires_python: Option<String>,
+    #[serde(default)]
+    pub extras: Vec<ExtraName>,
+    #[serde(default)]
+    pub dependency_groups: Vec<String>,
+    #[serde(default)]
+    pub default_groups: Vec<String>,
+    #[serde(default)]
+    pub created_by: String,
+    #[serde(default)]
+    pub packages: Vec<PylockPackage>,
+}
+
+/// A package entry in a [`PylockToml`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PylockPackage {
+    pub name: PackageName,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<Version>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vcs: Option<PylockVcs>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<PylockDirectory>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive: Option<PylockArchive>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sdist: Option<PylockFile>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wheels: Vec<PylockFile>,
+}
+
+/// A package installed from a version control system, in a [`PylockToml`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PylockVcs {
+    pub r#type: String,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_revision: Option<String>,
+    pub commit_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdirectory: Option<String>,
+}
+
+/// A package installed from a local directory, in a [`PylockToml`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PylockDirectory {
+    pub path: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub editable: bool,
+}
+
+/// A package installed from a direct URL or local archive, in a [`PylockToml`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PylockArchive {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hashes: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdirectory: Option<String>,
+}
+
+/// A source distribution or wheel from a package index, in a [`PylockToml`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PylockFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hashes: BTreeMap<String, String>,
+}
+
+impl PylockToml {
+    /// The version of the `pylock.toml` format written by uv.
+    const LOCK_VERSION: &'static str = "1.0";
+
+    /// Construct a [`PylockToml`] from the resolved packages in a [`DisplayResolutionGraph`].
+    ///
+    /// As required by PEP 751, the hashes of every archive are included, regardless of whether
+    /// hashes were requested for the `requirements.txt` output.
+    pub fn from_display(display: &DisplayResolutionGraph) -> Result<Self, PylockError> {
+        let requires_python = &display.resolution.requires_python;
+        let packages = display
+            .dists()
+            .into_iter()
+            .map(|node| PylockPackage::from_dist(display, &node))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            lock_version: Self::LOCK_VERSION.to_string(),
+            requires_python: if requires_python.is_unbounded() {
+                None
+            } else {
+                Some(requires_python.specifiers().to_string())
+            },
+            extras: vec![],
+            dependency_groups: vec![],
+            default_groups: vec![],
+            created_by: "uv".to_string(),
+            packages,
+        })
+    }
+}
+
+impl PylockPackage {
+    fn from_dist(
+        display: &DisplayResolutionGraph,
+        node: &RequirementsTxtDist,
+    ) -> Result<Self, PylockError> {
+        let mut package = Self {
+            name: node.name().clone(),
+            version: Some(node.version.clone()),
+            marker: marker(display, node),
+            index: node.dist.index().map(|index| index.redacted().to_string()),
+            vcs: None,
+            directory: None,
+            archive: None,
+            sdist: None,
+            wheels: vec![],
+        };
+
+        let ResolvedDist::Installable(dist) = node.dist else {
+            return Ok(package);
+        };
+
+        match dist {
+            Dist::Built(BuiltDist::Registry(dist)) => {
+                for wheel in &dist.wheels {
+                    package.wheels.push(PylockFile::from_file(&wheel.file)?);
+                }
+                if let Some(sdist) = &dist.sdist {
+                    package.sdist = Some(PylockFile::from_file(&sdist.file)?);
+                }
+            }
+            Dist::Source(SourceDist::Registry(dist)) => {
+                for wheel in &dist.wheels {
+                    package.wheels.push(PylockFile::from_file(&wheel.file)?);
+                }
+                package.sdist = Some(PylockFile::from_file(&dist.file)?);
+            }
+            Dist::Built(BuiltDist::DirectUrl(dist)) => {
+                package.archive = Some(PylockArchive {
+                    url: Some(dist.url.verbatim().to_string()),
+                    path: None,
+                    hashes: hashes(node.hashes),
+                    subdirectory: None,
+                });
+            }
+            Dist::Source(SourceDist::DirectUrl(dist)) => {
+                package.archive = Some(PylockArchive {
+                    url: Some(dist.location.to_string()),
+                    path: None,
+                    hashes: hashes(node.hashes),
+                    subdirectory: dist
+                        .subdirectory
+                        .as_ref()
+                        .map(|path| path.to_string_lossy().into_owned()),
+                });
+            }
+            Dist::Built(BuiltDist::Path(dist)) => {
+                package.archive = Some(PylockArchive {
+                    url: None,
+                    path: Some(dist.url.verbatim().to_string()),
+                    hashes: hashes(node.hashes),
+                    subdirectory: None,
+                });
+            }
+            Dist::Source(SourceDist::Path(dist)) => {
+                package.archive = Some(PylockArchive {
+                    url: None,
+                    path: Some(dist.url.verbatim().to_string()),
+                    hashes: hashes(node.hashes),
+                    subdirectory: None,
+                });
+            }
+            Dist::Source(SourceDist::Git(dist)) => {
+                // Git distributions are always pinned to a specific commit in the resolution.
+                package.version = None;
+                package.vcs = Some(PylockVcs {
+                    r#type: "git".to_string(),
+                    url: dist.git.repository().to_string(),
+                    requested_revision: dist.git.reference().as_str().map(ToString::to_string),
+                    commit_id: dist
+                        .git
+                        .precise()
+                        .ok_or_else(|| PylockError::MissingCommit(dist.name.clone()))?
+                        .to_string(),
+                    subdirectory: dist
+                        .subdirectory
+                        .as_ref()
+                        .map(|path| path.to_string_lossy().into_owned()),
+                });
+            }
+            Dist::Source(SourceDist::Directory(dist)) => {
+                package.version = None;
+                package.directory = Some(PylockDirectory {
+                    path: dist.url.verbatim().to_string(),
+                    editable: dist.editable,
+                });
+            }
+        }
+
+        Ok(package)
+    }
+}
+
+impl PylockFile {
+    fn from_file(file: &File) -> Result<Self, ToUrlError> {
+        Ok(Self {
+            name: Some(file.filename.clone()),
+            url: file.url.to_url_string()?.to_string(),
+            hashes: hashes(&file.hashes),
+        })
+    }
+}
+
+/// Return the simplified markers for a package, if markers are included in the output.
+fn marker(display: &DisplayResolutionGraph, node: &RequirementsTxtDist) -> Option<String> {
+    if !display.include_markers {
+        return None;
+    }
+    SimplifiedMarkerTree::new(&display.resolution.requires_python, node.markers.clone())
+        .try_to_string()
+}
+
+/// Convert a set of hashes to a map from algorithm to digest.
+fn hashes(hashes: &[HashDigest]) -> BTreeMap<String, String> {
+    hashes
+        .iter()
+        .map(|hash| (hash.algorithm.to_string(), hash.digest.to_string()))
+        .collect()
+}
@@ -4,7 +4,10 @@ use std::path::Path;
 
 use itertools::Itertools;
 
-use uv_distribution_types::{DistributionMetadata, Name, ResolvedDist, Verbatim, VersionOrUrlRef};
+use uv_distribution_types::{
+    Dist, DistributionMetadata, Name, ResolvedDist, SourceDist, Verbatim, VersionOrUrlRef,
+};
+use uv_fs::{normalize_path, relative_to, Simplified};
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::{split_scheme, MarkerTree, Scheme};
@@ -32,14 +35,24 @@ impl<'dist> RequirementsTxtDist<'dist> {
     /// This typically results in a PEP 508 representation of the requirement, but will write an
     /// unnamed requirement for relative paths, which can't be represented with PEP 508 (but are
     /// supported in `requirements.txt`).
+    ///
+    /// Local paths are rebased against the `output_dir`, or written as absolute paths if
+    /// `absolute_paths` is set.
     pub(crate) fn to_requirements_txt(
         &self,
         requires_python: &RequiresPython,
         include_extras: bool,
         include_markers: bool,
+        output_dir: Option<&Path>,
+        absolute_paths: bool,
     ) -> Cow<str> {
+        let rebased = self.rebase(output_dir, absolute_paths);
+
         // If the URL is editable, write it as an editable requirement.
         if self.dist.is_editable() {
+            if let Some(path) = &rebased {
+                return Cow::Owned(format!("-e {path}"));
+            }
             if let VersionOrUrlRef::Url(url) = self.dist.version_or_url() {
                 let given = url.verbatim();
                 return Cow::Owned(format!("-e {given}"));
@@ -92,6 +105,7 @@ impl<'dist> RequirementsTxtDist<'dist> {
                         Some(given)
                     }
                 };
+                let given = rebased.map(Cow::Owned).or(given);
                 if let Some(given) = given {
                     return if let Some(markers) =
                         SimplifiedMarkerTree::new(requires_python, self.markers.clone())
@@ -141,6 +155,58 @@ impl<'dist> RequirementsTxtDist<'dist> {
         }
     }
 
+    /// Return the path to write for a local distribution, if it differs from the path as given.
+    ///
+    /// With `absolute_paths`, local paths are always written as absolute paths. Otherwise, relative
+    /// paths are rewritten to be relative to the `output_dir`, unless they already resolve to the
+    /// same location from there.
+    fn rebase(&self, output_dir: Option<&Path>, absolute_paths: bool) -> Option<String> {
+        if !self.dist.is_local() {
+            return None;
+        }
+        let VersionOrUrlRef::Url(url) = self.dist.version_or_url() else {
+            return None;
+        };
+        let path = url.to_file_path().ok()?;
+        if absolute_paths {
+            return Some(path.simplified_display().to_string());
+        }
+
+        // Only rebase paths that were given as relative paths, without a scheme.
+        let output_dir = output_dir?;
+        let given = url.given()?;
+        if given.starts_with("${PROJECT_ROOT}")
+            || split_scheme(given).is_some_and(|(scheme, _)| Scheme::parse(scheme).is_some())
+            || Path::new(given).is_absolute()
+        {
+            return None;
+        }
+        if normalize_path(&output_dir.join(given)) == path {
+            return None;
+        }
+
+        let relative = relative_to(&path, output_dir).ok()?;
+        if relative.starts_with("..") {
+            Some(relative.portable_display().to_string())
+        } else {
+            Some(format!("./{}", relative.portable_display()))
+        }
+    }
+
+    /// Return the Git reference (e.g., a tag or branch) from which the pinned commit was resolved,
+    /// if the distribution was requested from a Git repository at anything other than a commit.
+    pub(crate) fn git_reference(&self) -> Option<&str> {
+        let ResolvedDist::Installable(Dist::Source(SourceDist::Git(dist))) = self.dist else {
+            return None;
+        };
+        let reference = dist.git.reference().as_str()?;
+        let precise = dist.git.precise()?;
+        if precise.to_string().starts_with(reference) {
+            return None;
+        }
+        Some(reference)
+    }
+
     pub(crate) fn to_comparator(&self) -> RequirementsTxtComparator {
         if self.dist.is_editable() {
             if let VersionOrUrlRef::Url(url) = self.dist.version_or_url() {
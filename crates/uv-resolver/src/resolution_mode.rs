@@ -1,4 +1,7 @@
-use rustc_hash::FxHashSet;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_normalize::PackageName;
 
@@ -29,6 +32,79 @@ impl std::fmt::Display for ResolutionMode {
     }
 }
 
+impl FromStr for ResolutionMode {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "highest" => Ok(Self::Highest),
+            "lowest" => Ok(Self::Lowest),
+            "lowest-direct" => Ok(Self::LowestDirect),
+            _ => Err(format!(
+                "`{input}` is not a valid resolution mode (expected `highest`, `lowest`, or `lowest-direct`)"
+            )),
+        }
+    }
+}
+
+/// A [`ResolutionMode`] for a specific package (e.g., `torch=lowest`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageResolutionMode {
+    pub name: PackageName,
+    pub mode: ResolutionMode,
+}
+
+impl FromStr for PackageResolutionMode {
+    type Err = String;
+
+    /// Parse a [`PackageResolutionMode`] from a string of the form `{name}={mode}`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some((name, mode)) = input.split_once('=') else {
+            return Err(format!(
+                "`{input}` must be a package name and a resolution mode (e.g., `torch=lowest`)"
+            ));
+        };
+        let name = PackageName::from_str(name.trim()).map_err(|err| err.to_string())?;
+        let mode = ResolutionMode::from_str(mode.trim())?;
+        Ok(Self { name, mode })
+    }
+}
+
+/// An error raised when a package is assigned more than one [`ResolutionMode`].
+#[derive(Debug, thiserror::Error)]
+#[error("`{0}` was provided multiple times to `--resolution-package`")]
+pub struct DuplicatePackageResolutionMode(PackageName);
+
+/// The [`ResolutionMode`] for each package that overrides the global resolution mode.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct PackageResolutionModes(BTreeMap<PackageName, ResolutionMode>);
+
+impl PackageResolutionModes {
+    /// Determine the [`PackageResolutionModes`] from the command-line arguments.
+    pub fn from_args(
+        modes: Vec<PackageResolutionMode>,
+    ) -> Result<Self, DuplicatePackageResolutionMode> {
+        let mut packages = BTreeMap::new();
+        for PackageResolutionMode { name, mode } in modes {
+            if packages.contains_key(&name) {
+                return Err(DuplicatePackageResolutionMode(name));
+            }
+            packages.insert(name, mode);
+        }
+        Ok(Self(packages))
+    }
+
+    /// Assign a [`ResolutionMode`] to the given package, unless it was already assigned one.
+    pub fn or_insert(&mut self, name: PackageName, mode: ResolutionMode) {
+        self.0.entry(name).or_insert(mode);
+    }
+
+    /// Returns `true` if no package overrides the global resolution mode.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
 /// Like [`ResolutionMode`], but with any additional information required to select a candidate,
 /// like the set of direct dependencies.
 #[derive(Debug, Clone)]
@@ -43,6 +119,26 @@ pub(crate) enum ResolutionStrategy {
 }
 
 impl ResolutionStrategy {
+    /// Determine the [`ResolutionStrategy`] for each package with a per-package
+    /// [`ResolutionMode`].
+    pub(crate) fn from_packages(
+        modes: &PackageResolutionModes,
+        manifest: &Manifest,
+        markers: &ResolverMarkers,
+        dependencies: DependencyMode,
+    ) -> FxHashMap<PackageName, Self> {
+        modes
+            .0
+            .iter()
+            .map(|(name, mode)| {
+                (
+                    name.clone(),
+                    Self::from_mode(*mode, manifest, markers, dependencies),
+                )
+            })
+            .collect()
+    }
+
     pub(crate) fn from_mode(
         mode: ResolutionMode,
         manifest: &Manifest,
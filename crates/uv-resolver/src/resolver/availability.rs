@@ -40,6 +40,8 @@ pub(crate) enum UnavailableVersion {
     InvalidStructure,
     /// The wheel metadata was not found in the cache and the network is not available.
     Offline,
+    /// The version was denied (i.e., `--deny`).
+    Denied,
 }
 
 impl UnavailableVersion {
@@ -51,6 +53,7 @@ impl UnavailableVersion {
             UnavailableVersion::InconsistentMetadata => "inconsistent metadata".into(),
             UnavailableVersion::InvalidStructure => "an invalid package format".into(),
             UnavailableVersion::Offline => "to be downloaded from a registry".into(),
+            UnavailableVersion::Denied => "denied".into(),
         }
     }
 
@@ -62,6 +65,7 @@ impl UnavailableVersion {
             UnavailableVersion::InconsistentMetadata => format!("has {self}"),
             UnavailableVersion::InvalidStructure => format!("has {self}"),
             UnavailableVersion::Offline => format!("needs {self}"),
+            UnavailableVersion::Denied => format!("is {self}"),
         }
     }
 
@@ -73,6 +77,7 @@ impl UnavailableVersion {
             UnavailableVersion::InconsistentMetadata => format!("have {self}"),
             UnavailableVersion::InvalidStructure => format!("have {self}"),
             UnavailableVersion::Offline => format!("need {self}"),
+            UnavailableVersion::Denied => format!("are {self}"),
         }
     }
 }
@@ -98,6 +103,10 @@ pub(crate) enum UnavailablePackage {
     InvalidMetadata(String),
     /// The package has an invalid structure.
     InvalidStructure(String),
+    /// The package is not in the list of allowed packages (i.e., `--allow-package`).
+    NotAllowed,
+    /// Every version of the package was denied (i.e., `--deny-package`).
+    Denied,
 }
 
 impl UnavailablePackage {
@@ -109,6 +118,8 @@ impl UnavailablePackage {
             UnavailablePackage::MissingMetadata => "not include a `METADATA` file",
             UnavailablePackage::InvalidMetadata(_) => "invalid metadata",
             UnavailablePackage::InvalidStructure(_) => "an invalid package format",
+            UnavailablePackage::NotAllowed => "not in the list of allowed packages",
+            UnavailablePackage::Denied => "denied",
         }
     }
 
@@ -120,6 +131,8 @@ impl UnavailablePackage {
             UnavailablePackage::MissingMetadata => format!("does {self}"),
             UnavailablePackage::InvalidMetadata(_) => format!("has {self}"),
             UnavailablePackage::InvalidStructure(_) => format!("has {self}"),
+            UnavailablePackage::NotAllowed => format!("is {self}"),
+            UnavailablePackage::Denied => format!("is {self}"),
         }
     }
 }
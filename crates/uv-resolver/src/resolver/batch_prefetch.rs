@@ -7,6 +7,7 @@ use tokio::sync::mpsc::Sender;
 use tracing::{debug, trace};
 
 use uv_distribution_types::{CompatibleDist, DistributionMetadata, IndexCapabilities};
+use uv_normalize::PackageName;
 use uv_pep440::Version;
 
 use crate::candidate_selector::CandidateSelector;
@@ -232,6 +233,18 @@ impl BatchPrefetcher {
         (num_tried, do_prefetch)
     }
 
+    /// Return the packages for which the most versions were tried, in decreasing order.
+    pub(crate) fn most_tried(&self, limit: usize) -> Vec<(PackageName, usize)> {
+        self.tried_versions
+            .iter()
+            .filter_map(|(package, count)| Some((package.name()?.clone(), *count)))
+            .sorted_by(|(name1, count1), (name2, count2)| {
+                count1.cmp(count2).reverse().then(name1.cmp(name2))
+            })
+            .take(limit)
+            .collect()
+    }
+
     /// Log stats about how many versions we tried.
     ///
     /// Note that they may be inflated when we count the same version repeatedly during
@@ -38,6 +38,11 @@ impl<T> ForkMap<T> {
             .push(entry);
     }
 
+    /// Returns `true` if the map contains no values.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Returns `true` if the map contains any values for a package that are compatible with the
     /// given fork.
     pub(crate) fn contains(&self, package_name: &PackageName, markers: &ResolverMarkers) -> bool {
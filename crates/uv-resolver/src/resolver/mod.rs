@@ -5,8 +5,8 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::{Display, Formatter, Write};
 use std::ops::Bound;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use std::{iter, thread};
 
 use dashmap::DashMap;
@@ -67,7 +67,7 @@ pub use crate::resolver::provider::{
 use crate::resolver::reporter::Facade;
 pub use crate::resolver::reporter::{BuildId, Reporter};
 use crate::yanks::AllowedYanks;
-use crate::{marker, DependencyMode, Exclusions, FlatIndex, Options};
+use crate::{marker, Denylist, DependencyMode, Exclusions, FlatIndex, Options};
 
 mod availability;
 mod batch_prefetch;
@@ -105,6 +105,9 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     markers: ResolverMarkers,
     python_requirement: PythonRequirement,
     workspace_members: BTreeSet<PackageName>,
+    no_deps: BTreeSet<PackageName>,
+    allowed: Option<BTreeSet<PackageName>>,
+    denied: Denylist,
     selector: CandidateSelector,
     index: InMemoryIndex,
     installed_packages: InstalledPackages,
@@ -116,6 +119,8 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     options: Options,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
+    /// The time the solver spent blocked, waiting for metadata to be fetched.
+    metadata_wait: Mutex<Duration>,
 }
 
 impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
@@ -200,13 +205,16 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             index: index.clone(),
             git: git.clone(),
             capabilities: capabilities.clone(),
-            selector: CandidateSelector::for_resolution(options, &manifest, &markers),
+            selector: CandidateSelector::for_resolution(&options, &manifest, &markers),
             dependency_mode: options.dependency_mode,
             urls: Urls::from_manifest(&manifest, &markers, git, options.dependency_mode)?,
             locals: Locals::from_manifest(&manifest, &markers, options.dependency_mode),
             groups: Groups::from_manifest(&manifest, &markers),
             project: manifest.project,
             workspace_members: manifest.workspace_members,
+            no_deps: manifest.no_deps,
+            allowed: manifest.allowed,
+            denied: manifest.denied,
             requirements: manifest.requirements,
             constraints: manifest.constraints,
             overrides: manifest.overrides,
@@ -221,6 +229,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             incomplete_packages: DashMap::default(),
             options,
             reporter: None,
+            metadata_wait: Mutex::default(),
         };
         Ok(Self { state, provider })
     }
@@ -241,6 +250,18 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
 
     /// Resolve a set of requirements into a set of pinned versions.
     pub async fn resolve(self) -> Result<ResolutionGraph, ResolveError> {
+        // If there's nothing to resolve (e.g., an empty `requirements.txt`), skip the solver.
+        if self.state.requirements.is_empty() && self.state.project.is_none() {
+            debug!("No requirements to resolve");
+            self.state.on_complete();
+            return Ok(ResolutionGraph::empty(
+                &self.state.constraints,
+                &self.state.overrides,
+                &self.state.python_requirement,
+                self.state.options.clone(),
+            ));
+        }
+
         let state = Arc::new(self.state);
         let provider = Arc::new(self.provider);
 
@@ -258,7 +279,9 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         thread::Builder::new()
             .name("uv-resolver".into())
             .spawn(move || {
+                let start = Instant::now();
                 let result = solver.solve(request_sink);
+                solver.on_solve_complete(start.elapsed());
 
                 // This may fail if the main thread returned early due to an error.
                 let _ = tx.send(result);
@@ -268,7 +291,19 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let resolve_fut = async move { rx.await.map_err(|_| ResolveError::ChannelClosed) };
 
         // Wait for both to complete.
-        let ((), resolution) = tokio::try_join!(requests_fut, resolve_fut)?;
+        let join_fut = async { tokio::try_join!(requests_fut, resolve_fut) };
+        let ((), resolution) = if let Some(timeout) = state.options.budget.timeout {
+            // The solver only checks its deadline between steps, so enforce it here as well, in
+            // case the solver is blocked waiting on metadata.
+            tokio::time::timeout(timeout, join_fut)
+                .await
+                .map_err(|_| ResolveError::TooComplex {
+                    reason: format!("exceeded the timeout of {}s", timeout.as_secs()),
+                    packages: Vec::new(),
+                })??
+        } else {
+            join_fut.await?
+        };
 
         state.on_complete();
         resolution
@@ -317,6 +352,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             };
         let mut resolutions = vec![];
 
+        // Track the work performed across all forks, to enforce the resolution budget.
+        let mut steps: u64 = 0;
+        let deadline = self
+            .options
+            .budget
+            .timeout
+            .map(|timeout| Instant::now() + timeout);
+
         'FORK: while let Some(mut state) = forked_states.pop() {
             if let ResolverMarkers::Fork(markers) = &state.markers {
                 let requires_python = state.python_requirement.target();
@@ -324,6 +367,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
             let start = Instant::now();
             loop {
+                // Abort if the solver has exhausted its budget.
+                steps += 1;
+                self.check_budget(steps, deadline, &prefetcher)?;
+
                 // Run unit propagation.
                 if let Err(err) = state.pubgrub.unit_propagation(state.next.clone()) {
                     return Err(self.convert_no_solution_err(
@@ -580,11 +627,12 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             &self.constraints,
             &self.overrides,
             &self.preferences,
+            &self.hasher,
             &self.index,
             &self.git,
             &self.python_requirement,
             self.selector.resolution_strategy(),
-            self.options,
+            self.options.clone(),
         )
     }
 
@@ -766,6 +814,33 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         Ok(())
     }
 
+    /// Return an error if the solver has exceeded its [`ResolutionBudget`], i.e., it has performed
+    /// more than the allowed number of steps or run past its deadline.
+    ///
+    /// [`ResolutionBudget`]: crate::ResolutionBudget
+    fn check_budget(
+        &self,
+        steps: u64,
+        deadline: Option<Instant>,
+        prefetcher: &BatchPrefetcher,
+    ) -> Result<(), ResolveError> {
+        let budget = &self.options.budget;
+        let reason = if let Some(max_steps) = budget.max_steps.filter(|max| steps > *max) {
+            format!("exceeded the limit of {max_steps} solver steps")
+        } else if let Some(timeout) = budget
+            .timeout
+            .filter(|_| deadline.is_some_and(|deadline| Instant::now() >= deadline))
+        {
+            format!("exceeded the timeout of {}s", timeout.as_secs())
+        } else {
+            return Ok(());
+        };
+        Err(ResolveError::TooComplex {
+            reason,
+            packages: prefetcher.most_tried(5),
+        })
+    }
+
     /// Visit the set of [`PubGrubPackage`] candidates prior to selection. This allows us to fetch
     /// metadata for all packages in parallel.
     fn pre_visit<'data>(
@@ -833,6 +908,27 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             | PubGrubPackageInner::Extra { name, .. }
             | PubGrubPackageInner::Dev { name, .. }
             | PubGrubPackageInner::Package { name, .. } => {
+                // Reject any package that isn't explicitly allowed, before considering any of its
+                // versions.
+                if self
+                    .allowed
+                    .as_ref()
+                    .is_some_and(|allowed| !allowed.contains(name))
+                {
+                    debug!("Rejecting package that isn't in the allowed packages: {name}");
+                    self.unavailable_packages
+                        .insert(name.clone(), UnavailablePackage::NotAllowed);
+                    return Ok(None);
+                }
+
+                // Reject any package that was denied outright.
+                if self.denied.contains_package(name) {
+                    debug!("Rejecting denied package: {name}");
+                    self.unavailable_packages
+                        .insert(name.clone(), UnavailablePackage::Denied);
+                    return Ok(None);
+                }
+
                 if let Some(url) = package.name().and_then(|name| fork_urls.get(name)) {
                     self.choose_version_url(name, range, url, python_requirement)
                 } else {
@@ -868,9 +964,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
         let dist = PubGrubDistribution::from_url(name, url);
         let response = self
-            .index
-            .distributions()
-            .wait_blocking(&dist.version_id())
+            .wait_for_metadata(|| self.index.distributions().wait_blocking(&dist.version_id()))
             .ok_or_else(|| ResolveError::UnregisteredTask(dist.version_id().to_string()))?;
 
         // If we failed to fetch the metadata for a URL, we can't proceed.
@@ -964,9 +1058,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
     ) -> Result<Option<ResolverVersion>, ResolveError> {
         // Wait for the metadata to be available.
         let versions_response = self
-            .index
-            .packages()
-            .wait_blocking(name)
+            .wait_for_metadata(|| self.index.packages().wait_blocking(name))
             .ok_or_else(|| ResolveError::UnregisteredTask(name.to_string()))?;
         visited.insert(name.clone());
 
@@ -1005,6 +1097,15 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             return Ok(None);
         };
 
+        // If the version was denied, reject it, such that the resolver backtracks to another.
+        if self.denied.contains_version(name, candidate.version()) {
+            debug!("Rejecting denied version: {name}=={}", candidate.version());
+            return Ok(Some(ResolverVersion::Unavailable(
+                candidate.version().clone(),
+                UnavailableVersion::Denied,
+            )));
+        }
+
         let dist = match candidate.dist() {
             CandidateDist::Compatible(dist) => dist,
             CandidateDist::Incompatible(incompatibility) => {
@@ -1197,7 +1298,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 marker,
             } => {
                 // If we're excluding transitive dependencies, short-circuit.
-                if self.dependency_mode.is_direct() {
+                if self.dependency_mode.is_direct() || self.no_deps.contains(name) {
                     return Ok(Dependencies::Unforkable(Vec::default()));
                 }
 
@@ -1222,9 +1323,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                 // Wait for the metadata to be available.
                 let response = self
-                    .index
-                    .distributions()
-                    .wait_blocking(&version_id)
+                    .wait_for_metadata(|| self.index.distributions().wait_blocking(&version_id))
                     .ok_or_else(|| ResolveError::UnregisteredTask(version_id.to_string()))?;
 
                 let metadata = match &*response {
@@ -2012,6 +2111,23 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             reporter.on_complete();
         }
     }
+
+    /// Block the solver until the requested metadata is available, recording the time spent
+    /// waiting.
+    fn wait_for_metadata<T>(&self, wait: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = wait();
+        *self.metadata_wait.lock().unwrap() += start.elapsed();
+        result
+    }
+
+    /// Report the time spent solving, separating out the time spent waiting for metadata.
+    fn on_solve_complete(&self, elapsed: Duration) {
+        if let Some(reporter) = self.reporter.as_ref() {
+            let fetch = *self.metadata_wait.lock().unwrap();
+            reporter.on_solve_complete(elapsed.saturating_sub(fetch), fetch);
+        }
+    }
 }
 
 /// State that is used during unit propagation in the resolver, one instance per fork.
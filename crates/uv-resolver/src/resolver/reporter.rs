@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use url::Url;
 
@@ -7,34 +8,48 @@ use uv_normalize::PackageName;
 
 pub type BuildId = usize;
 
+/// A reporter for resolution events.
+///
+/// Each callback defaults to a no-op, so custom reporters (e.g., for embedding uv in another tool)
+/// need only implement the events they care about.
 pub trait Reporter: Send + Sync {
     /// Callback to invoke when a dependency is resolved.
-    fn on_progress(&self, name: &PackageName, version: &VersionOrUrlRef);
+    fn on_progress(&self, _name: &PackageName, _version: &VersionOrUrlRef) {}
 
     /// Callback to invoke when the resolution is complete.
-    fn on_complete(&self);
+    fn on_complete(&self) {}
+
+    /// Callback to invoke when the solver completes, with the time spent solving and the time the
+    /// solver spent waiting for metadata to be fetched.
+    fn on_solve_complete(&self, _solve: Duration, _fetch: Duration) {}
 
     /// Callback to invoke when a source distribution build is kicked off.
-    fn on_build_start(&self, source: &BuildableSource) -> usize;
+    fn on_build_start(&self, _source: &BuildableSource) -> usize {
+        0
+    }
 
     /// Callback to invoke when a source distribution build is complete.
-    fn on_build_complete(&self, source: &BuildableSource, id: usize);
+    fn on_build_complete(&self, _source: &BuildableSource, _id: usize) {}
 
     /// Callback to invoke when a download is kicked off.
-    fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize;
+    fn on_download_start(&self, _name: &PackageName, _size: Option<u64>) -> usize {
+        0
+    }
 
     /// Callback to invoke when a download makes progress (i.e. some number of bytes are
     /// downloaded).
-    fn on_download_progress(&self, id: usize, bytes: u64);
+    fn on_download_progress(&self, _id: usize, _bytes: u64) {}
 
     /// Callback to invoke when a download is complete.
-    fn on_download_complete(&self, name: &PackageName, id: usize);
+    fn on_download_complete(&self, _name: &PackageName, _id: usize) {}
 
     /// Callback to invoke when a repository checkout begins.
-    fn on_checkout_start(&self, url: &Url, rev: &str) -> usize;
+    fn on_checkout_start(&self, _url: &Url, _rev: &str) -> usize {
+        0
+    }
 
     /// Callback to invoke when a repository checkout completes.
-    fn on_checkout_complete(&self, url: &Url, rev: &str, id: usize);
+    fn on_checkout_complete(&self, _url: &Url, _rev: &str, _id: usize) {}
 }
 
 /// A facade for converting from [`Reporter`] to [`uv_distribution::Reporter`].
@@ -71,3 +86,63 @@ impl uv_distribution::Reporter for Facade {
         self.reporter.on_download_complete(name, id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+
+    use uv_normalize::PackageName;
+
+    use super::{Facade, Reporter};
+
+    /// A custom reporter that records download events, relying on the default no-op
+    /// implementations for everything else.
+    #[derive(Default)]
+    struct DownloadRecorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl Reporter for DownloadRecorder {
+        fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
+            let mut events = self.events.lock().unwrap();
+            events.push(format!("start {name} ({} bytes)", size.unwrap_or_default()));
+            events.len()
+        }
+
+        fn on_download_progress(&self, id: usize, bytes: u64) {
+            let mut events = self.events.lock().unwrap();
+            events.push(format!("progress {id} ({bytes} bytes)"));
+        }
+
+        fn on_download_complete(&self, name: &PackageName, id: usize) {
+            let mut events = self.events.lock().unwrap();
+            events.push(format!("complete {name} ({id})"));
+        }
+    }
+
+    #[test]
+    fn custom_reporter() {
+        let recorder = Arc::new(DownloadRecorder::default());
+        let facade = Facade {
+            reporter: recorder.clone(),
+        };
+
+        let name = PackageName::from_str("anyio").unwrap();
+        let id = uv_distribution::Reporter::on_download_start(&facade, &name, Some(1024));
+        uv_distribution::Reporter::on_download_progress(&facade, id, 512);
+        uv_distribution::Reporter::on_download_complete(&facade, &name, id);
+
+        // Callbacks that aren't implemented fall back to the default no-op.
+        recorder.on_complete();
+
+        assert_eq!(
+            *recorder.events.lock().unwrap(),
+            vec![
+                "start anyio (1024 bytes)".to_string(),
+                "progress 1 (512 bytes)".to_string(),
+                "complete anyio (1)".to_string(),
+            ]
+        );
+    }
+}
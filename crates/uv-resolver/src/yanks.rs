@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use uv_configuration::AllowYanked;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pypi_types::RequirementSource;
@@ -11,7 +12,12 @@ use crate::{DependencyMode, Manifest, ResolverMarkers};
 /// A set of package versions that are permitted, even if they're marked as yanked by the
 /// relevant index.
 #[derive(Debug, Default, Clone)]
-pub struct AllowedYanks(Arc<FxHashMap<PackageName, FxHashSet<Version>>>);
+pub struct AllowedYanks {
+    /// The package-versions that are pinned exactly, and so may be selected even if yanked.
+    versions: Arc<FxHashMap<PackageName, FxHashSet<Version>>>,
+    /// The packages for which any yanked version may be selected (e.g., via `--allow-yanked`).
+    packages: Arc<AllowYanked>,
+}
 
 impl AllowedYanks {
     pub fn from_manifest(
@@ -48,13 +54,18 @@ impl AllowedYanks {
                 .extend(preferences.map(|(_markers, version)| version.clone()));
         }
 
-        Self(Arc::new(allowed_yanks))
+        Self {
+            versions: Arc::new(allowed_yanks),
+            packages: Arc::new(manifest.allow_yanked.clone()),
+        }
     }
 
     /// Returns `true` if the package-version is allowed, even if it's marked as yanked.
     pub fn contains(&self, package_name: &PackageName, version: &Version) -> bool {
-        self.0
-            .get(package_name)
-            .map_or(false, |versions| versions.contains(version))
+        self.packages.contains(package_name)
+            || self
+                .versions
+                .get(package_name)
+                .map_or(false, |versions| versions.contains(version))
     }
 }
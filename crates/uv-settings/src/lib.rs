@@ -1,6 +1,7 @@
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 
+use owo_colors::OwoColorize;
 use tracing::debug;
 
 use uv_fs::Simplified;
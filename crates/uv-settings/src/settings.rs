@@ -225,6 +225,22 @@ pub struct GlobalOptions {
         "#
     )]
     pub concurrent_installs: Option<NonZeroUsize>,
+    /// The maximum number of files that uv will hold open at once when downloading and installing
+    /// packages.
+    ///
+    /// Concurrent downloads and installs wait for others to complete as needed to stay within this
+    /// limit, to avoid "too many open files" errors on systems with a low limit on open file
+    /// descriptors (e.g., `ulimit -n`).
+    ///
+    /// Defaults to half of the soft limit on open file descriptors for the current process.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            max-open-files = 256
+        "#
+    )]
+    pub max_open_files: Option<NonZeroUsize>,
 }
 
 /// Settings relevant to all installer operations.
@@ -1210,6 +1226,20 @@ pub struct PipOptions {
         "#
     )]
     pub compile_bytecode: Option<bool>,
+    /// The number of workers to use when compiling Python files to bytecode.
+    ///
+    /// Bytecode compilation is CPU-bound, and so is limited separately from installation, which is
+    /// largely IO-bound. Only applies when `compile-bytecode` is enabled.
+    ///
+    /// Defaults to the number of available CPU cores.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            compile-bytecode-concurrency = 4
+        "#
+    )]
+    pub compile_bytecode_concurrency: Option<NonZeroUsize>,
     /// Require a matching hash for each requirement.
     ///
     /// Hash-checking mode is all or nothing. If enabled, _all_ requirements must be provided
@@ -1461,6 +1491,7 @@ pub struct OptionsWire {
     concurrent_downloads: Option<NonZeroUsize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
+    max_open_files: Option<NonZeroUsize>,
 
     // #[serde(flatten)]
     // top_level: ResolverInstallerOptions,
@@ -1510,6 +1541,10 @@ pub struct OptionsWire {
     #[allow(dead_code)]
     dev_dependencies: Option<serde::de::IgnoredAny>,
     #[allow(dead_code)]
+    default_groups: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
+    conflicting_extras: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
     managed: Option<serde::de::IgnoredAny>,
     #[allow(dead_code)]
     r#package: Option<serde::de::IgnoredAny>,
@@ -1528,6 +1563,7 @@ impl From<OptionsWire> for Options {
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
+            max_open_files,
             index_url,
             extra_index_url,
             no_index,
@@ -1563,6 +1599,8 @@ impl From<OptionsWire> for Options {
             workspace: _,
             sources: _,
             dev_dependencies: _,
+            default_groups: _,
+            conflicting_extras: _,
             managed: _,
             package: _,
         } = value;
@@ -1579,6 +1617,7 @@ impl From<OptionsWire> for Options {
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
+                max_open_files,
             },
             top_level: ResolverInstallerOptions {
                 index_url,
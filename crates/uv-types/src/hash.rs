@@ -10,7 +10,8 @@ use uv_distribution_types::{
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pypi_types::{
-    HashDigest, HashError, Hashes, Requirement, RequirementSource, ResolverMarkerEnvironment,
+    HashAlgorithm, HashDigest, HashError, Hashes, Requirement, RequirementSource,
+    ResolverMarkerEnvironment,
 };
 
 #[derive(Debug, Default, Clone)]
@@ -18,8 +19,8 @@ pub enum HashStrategy {
     /// No hash policy is specified.
     #[default]
     None,
-    /// Hashes should be generated (specifically, a SHA-256 hash), but not validated.
-    Generate,
+    /// Hashes should be generated (using the given algorithm), but not validated.
+    Generate(HashAlgorithm),
     /// Hashes should be validated, if present, but ignored if absent.
     ///
     /// If necessary, hashes should be generated to ensure that the archive is valid.
@@ -35,7 +36,7 @@ impl HashStrategy {
     pub fn get<T: DistributionMetadata>(&self, distribution: &T) -> HashPolicy {
         match self {
             Self::None => HashPolicy::None,
-            Self::Generate => HashPolicy::Generate,
+            Self::Generate(algorithm) => HashPolicy::Generate(*algorithm),
             Self::Verify(hashes) => {
                 if let Some(hashes) = hashes.get(&distribution.version_id()) {
                     HashPolicy::Validate(hashes.as_slice())
@@ -56,7 +57,7 @@ impl HashStrategy {
     pub fn get_package(&self, name: &PackageName, version: &Version) -> HashPolicy {
         match self {
             Self::None => HashPolicy::None,
-            Self::Generate => HashPolicy::Generate,
+            Self::Generate(algorithm) => HashPolicy::Generate(*algorithm),
             Self::Verify(hashes) => {
                 if let Some(hashes) =
                     hashes.get(&VersionId::from_registry(name.clone(), version.clone()))
@@ -79,7 +80,7 @@ impl HashStrategy {
     pub fn get_url(&self, url: &Url) -> HashPolicy {
         match self {
             Self::None => HashPolicy::None,
-            Self::Generate => HashPolicy::Generate,
+            Self::Generate(algorithm) => HashPolicy::Generate(*algorithm),
             Self::Verify(hashes) => {
                 if let Some(hashes) = hashes.get(&VersionId::from_url(url)) {
                     HashPolicy::Validate(hashes.as_slice())
@@ -100,7 +101,7 @@ impl HashStrategy {
     pub fn allows_package(&self, name: &PackageName, version: &Version) -> bool {
         match self {
             Self::None => true,
-            Self::Generate => true,
+            Self::Generate(_) => true,
             Self::Verify(_) => true,
             Self::Require(hashes) => {
                 hashes.contains_key(&VersionId::from_registry(name.clone(), version.clone()))
@@ -112,7 +113,7 @@ impl HashStrategy {
     pub fn allows_url(&self, url: &Url) -> bool {
         match self {
             Self::None => true,
-            Self::Generate => true,
+            Self::Generate(_) => true,
             Self::Verify(_) => true,
             Self::Require(hashes) => hashes.contains_key(&VersionId::from_url(url)),
         }
@@ -120,6 +121,9 @@ impl HashStrategy {
 
     /// Generate the required hashes from a set of [`UnresolvedRequirement`] entries.
     ///
+    /// If the same package is listed multiple times (e.g., across multiple requirements files), the
+    /// allowed hashes are the union of the hashes provided for each entry, matching pip.
+    ///
     /// When the environment is not given, this treats all marker expressions
     /// that reference the environment as true. In other words, it does
     /// environment independent expression evaluation. (Which in turn devolves
@@ -170,12 +174,13 @@ impl HashStrategy {
                 continue;
             }
 
-            constraint_hashes.insert(id, digests);
+            Self::extend(constraint_hashes.entry(id).or_default(), digests);
         }
 
-        // For each requirement, map from name to allowed hashes. We use the last entry for each
-        // package.
+        // For each requirement, map from name to allowed hashes. If a package is listed multiple
+        // times, we take the union of the hashes for each entry.
         let mut requirement_hashes = FxHashMap::<VersionId, Vec<HashDigest>>::default();
+        let mut missing = Vec::new();
         for (requirement, digests) in requirements {
             if !requirement
                 .evaluate_markers(marker_env.map(ResolverMarkerEnvironment::markers), &[])
@@ -219,10 +224,10 @@ impl HashStrategy {
                     .collect::<Result<Vec<_>, _>>()?
             };
 
-            let digests = if let Some(constraint) = constraint_hashes.remove(&id) {
+            let digests = if let Some(constraint) = constraint_hashes.get(&id) {
                 if digests.is_empty() {
                     // If there are _only_ hashes on the constraints, use them.
-                    constraint
+                    constraint.clone()
                 } else {
                     // If there are constraint and requirement hashes, take the intersection.
                     let intersection: Vec<_> = digests
@@ -244,15 +249,17 @@ impl HashStrategy {
             // Under `--require-hashes`, every requirement must include a hash.
             if digests.is_empty() {
                 if mode.is_require() {
-                    return Err(HashStrategyError::MissingHashes(
-                        requirement.to_string(),
-                        mode,
-                    ));
+                    missing.push(requirement.to_string());
                 }
                 continue;
             }
 
-            requirement_hashes.insert(id, digests);
+            Self::extend(requirement_hashes.entry(id).or_default(), digests);
+        }
+
+        // Report every requirement that's missing a hash at once.
+        if !missing.is_empty() {
+            return Err(HashStrategyError::MissingHashes(missing.join(", "), mode));
         }
 
         // Merge the hashes, preferring requirements over constraints, since overlapping
@@ -267,6 +274,28 @@ impl HashStrategy {
         }
     }
 
+    /// Generate the hashes to verify from the URL fragments of a set of [`UnresolvedRequirement`]
+    /// entries (e.g., `https://example.com/foo-0.1.0.tar.gz#sha256=...`).
+    ///
+    /// Unlike [`HashStrategy::from_requirements`], this doesn't require `--require-hashes` or
+    /// `--verify-hashes`, since a hash embedded in a URL is always expected to match. Returns
+    /// [`HashStrategy::None`] if none of the requirements include a hash fragment.
+    pub fn from_url_fragments<'a>(
+        requirements: impl Iterator<Item = &'a UnresolvedRequirement>,
+        marker_env: Option<&ResolverMarkerEnvironment>,
+    ) -> Result<Self, HashStrategyError> {
+        let strategy = Self::from_requirements(
+            requirements.map(|requirement| (requirement, [].as_slice())),
+            std::iter::empty(),
+            marker_env,
+            HashCheckingMode::Verify,
+        )?;
+        match strategy {
+            Self::Verify(hashes) if hashes.is_empty() => Ok(Self::None),
+            strategy => Ok(strategy),
+        }
+    }
+
     /// Generate the required hashes from a [`Resolution`].
     pub fn from_resolution(
         resolution: &Resolution,
@@ -295,6 +324,15 @@ impl HashStrategy {
         }
     }
 
+    /// Add the given digests to a set of allowed hashes, skipping any duplicates.
+    fn extend(hashes: &mut Vec<HashDigest>, digests: Vec<HashDigest>) {
+        for digest in digests {
+            if !hashes.contains(&digest) {
+                hashes.push(digest);
+            }
+        }
+    }
+
     /// Pin a [`Requirement`] to a [`PackageId`], if possible.
     fn pin(requirement: &Requirement) -> Option<VersionId> {
         match &requirement.source {
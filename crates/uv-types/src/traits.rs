@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::future::Future;
 use std::path::{Path, PathBuf};
 
@@ -75,6 +76,10 @@ pub trait BuildContext {
     /// The [`ConfigSettings`] used to build distributions.
     fn config_settings(&self) -> &ConfigSettings;
 
+    /// The [`ConfigSettings`] used to build the given package, with any package-specific settings
+    /// taking precedence over the global settings.
+    fn config_settings_for(&self, package_name: Option<&PackageName>) -> Cow<'_, ConfigSettings>;
+
     /// Whether to incorporate `tool.uv.sources` when resolving requirements.
     fn sources(&self) -> SourceStrategy;
 
@@ -1,11 +1,7 @@
 use std::sync::atomic::AtomicBool;
-use std::sync::{LazyLock, Mutex};
+use std::sync::{LazyLock, Mutex, OnceLock};
 
-// macro hygiene: The user might not have direct dependencies on those crates
-#[doc(hidden)]
-pub use anstream;
-#[doc(hidden)]
-pub use owo_colors;
+use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
 
 /// Whether user-facing warnings are enabled.
@@ -21,17 +17,37 @@ pub fn disable() {
     ENABLED.store(false, std::sync::atomic::Ordering::SeqCst);
 }
 
+/// A function with which to render user-facing warnings, in lieu of human-readable text.
+static FORMATTER: OnceLock<fn(&str)> = OnceLock::new();
+
+/// Render user-facing warnings with the given function (e.g., as JSON), rather than as
+/// human-readable text.
+pub fn set_formatter(formatter: fn(&str)) {
+    let _ = FORMATTER.set(formatter);
+}
+
+/// Write a user-facing warning to stderr.
+#[doc(hidden)]
+pub fn write(message: &str) {
+    if let Some(formatter) = FORMATTER.get() {
+        formatter(message);
+    } else {
+        anstream::eprintln!(
+            "{}{} {}",
+            "warning".yellow().bold(),
+            ":".bold(),
+            message.bold()
+        );
+    }
+}
+
 /// Warn a user, if warnings are enabled.
 #[macro_export]
 macro_rules! warn_user {
     ($($arg:tt)*) => {
-        use $crate::anstream::eprintln;
-        use $crate::owo_colors::OwoColorize;
-
         if $crate::ENABLED.load(std::sync::atomic::Ordering::SeqCst) {
             let message = format!("{}", format_args!($($arg)*));
-            let formatted = message.bold();
-            eprintln!("{}{} {formatted}", "warning".yellow().bold(), ":".bold());
+            $crate::write(&message);
         }
     };
 }
@@ -43,14 +59,11 @@ pub static WARNINGS: LazyLock<Mutex<FxHashSet<String>>> = LazyLock::new(Mutex::d
 #[macro_export]
 macro_rules! warn_user_once {
     ($($arg:tt)*) => {
-        use $crate::anstream::eprintln;
-        use $crate::owo_colors::OwoColorize;
-
         if $crate::ENABLED.load(std::sync::atomic::Ordering::SeqCst) {
             if let Ok(mut states) = $crate::WARNINGS.lock() {
                 let message = format!("{}", format_args!($($arg)*));
                 if states.insert(message.clone()) {
-                    eprintln!("{}{} {}", "warning".yellow().bold(), ":".bold(), message.bold());
+                    $crate::write(&message);
                 }
             }
         }
@@ -7,6 +7,7 @@
 //! Then lowers them into a dependency specification.
 
 use glob::Pattern;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use serde::{de::IntoDeserializer, Deserialize, Deserializer, Serialize};
 use std::ops::Deref;
@@ -19,7 +20,7 @@ use url::Url;
 use uv_fs::{relative_to, PortablePathBuf};
 use uv_git::GitReference;
 use uv_macros::OptionsMetadata;
-use uv_normalize::{ExtraName, PackageName};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{RequirementSource, SupportedEnvironments, VerbatimParsedUrl};
@@ -41,6 +42,8 @@ pub enum PyprojectTomlError {
 pub struct PyProjectToml {
     /// PEP 621-compliant project metadata.
     pub project: Option<Project>,
+    /// PEP 735 dependency groups.
+    pub dependency_groups: Option<BTreeMap<GroupName, Vec<DependencyGroupSpecifier>>>,
     /// Tool-specific metadata.
     pub tool: Option<Tool>,
     /// The raw unserialized document.
@@ -86,6 +89,45 @@ impl PyProjectToml {
         self.build_system.is_some()
     }
 
+    /// Returns the PEP 735 dependency groups, with any `include-group` references expanded.
+    pub fn dependency_groups(
+        &self,
+    ) -> Result<
+        BTreeMap<GroupName, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
+        DependencyGroupError,
+    > {
+        let Some(groups) = self.dependency_groups.as_ref() else {
+            return Ok(BTreeMap::new());
+        };
+
+        let mut flattened = BTreeMap::new();
+        for name in groups.keys() {
+            let mut requirements = Vec::new();
+            flatten_group(name, groups, &mut Vec::new(), &mut requirements)?;
+            flattened.insert(name.clone(), requirements);
+        }
+        Ok(flattened)
+    }
+
+    /// Returns the dependency groups that should be enabled by default, as declared in
+    /// `tool.uv.default-groups`.
+    pub fn default_groups(&self) -> &[GroupName] {
+        self.tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.default_groups.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Returns the sets of mutually exclusive extras, as declared in `tool.uv.conflicting-extras`.
+    pub fn conflicting_extras(&self) -> &[Vec<ExtraName>] {
+        self.tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.conflicting_extras.as_deref())
+            .unwrap_or_default()
+    }
+
     /// Returns whether the project manifest contains any script table.
     pub fn has_scripts(&self) -> bool {
         if let Some(ref project) = self.project {
@@ -99,7 +141,9 @@ impl PyProjectToml {
 // Ignore raw document in comparison.
 impl PartialEq for PyProjectToml {
     fn eq(&self, other: &Self) -> bool {
-        self.project.eq(&other.project) && self.tool.eq(&other.tool)
+        self.project.eq(&other.project)
+            && self.dependency_groups.eq(&other.dependency_groups)
+            && self.tool.eq(&other.tool)
     }
 }
 
@@ -111,6 +155,67 @@ impl AsRef<[u8]> for PyProjectToml {
     }
 }
 
+/// An entry in a PEP 735 `[dependency-groups]` table.
+///
+/// See <https://peps.python.org/pep-0735/>.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(test, derive(Serialize))]
+#[serde(untagged)]
+pub enum DependencyGroupSpecifier {
+    /// A PEP 508 requirement, e.g., `ruff==0.5.0`.
+    Requirement(uv_pep508::Requirement<VerbatimParsedUrl>),
+    /// A reference to another dependency group, e.g., `{include-group = "test"}`.
+    IncludeGroup {
+        #[serde(rename = "include-group")]
+        include_group: GroupName,
+    },
+}
+
+#[derive(Error, Debug)]
+pub enum DependencyGroupError {
+    #[error("Failed to find group `{0}` included by `{1}`")]
+    MissingGroup(GroupName, GroupName),
+    #[error("Detected a cycle in `dependency-groups`: {0}")]
+    Cycle(String),
+}
+
+/// Collect the requirements of a dependency group into `requirements`, expanding any included
+/// groups.
+fn flatten_group(
+    name: &GroupName,
+    groups: &BTreeMap<GroupName, Vec<DependencyGroupSpecifier>>,
+    parents: &mut Vec<GroupName>,
+    requirements: &mut Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+) -> Result<(), DependencyGroupError> {
+    if parents.contains(name) {
+        return Err(DependencyGroupError::Cycle(
+            parents.iter().chain(std::iter::once(name)).join(" -> "),
+        ));
+    }
+
+    let specifiers = groups.get(name).map(Vec::as_slice).unwrap_or_default();
+    parents.push(name.clone());
+    for specifier in specifiers {
+        match specifier {
+            DependencyGroupSpecifier::Requirement(requirement) => {
+                requirements.push(requirement.clone());
+            }
+            DependencyGroupSpecifier::IncludeGroup { include_group } => {
+                if !groups.contains_key(include_group) {
+                    return Err(DependencyGroupError::MissingGroup(
+                        include_group.clone(),
+                        name.clone(),
+                    ));
+                }
+                flatten_group(include_group, groups, parents, requirements)?;
+            }
+        }
+    }
+    parents.pop();
+
+    Ok(())
+}
+
 /// PEP 621 project metadata (`project`).
 ///
 /// See <https://packaging.python.org/en/latest/specifications/pyproject-toml>.
@@ -202,6 +307,42 @@ pub struct ToolUv {
         "#
     )]
     pub dev_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
+    /// The [dependency groups](https://peps.python.org/pep-0735/) to enable by default in
+    /// `uv sync`, `uv run`, and `uv export`.
+    ///
+    /// Default groups can be disabled with `--no-default-groups`.
+    ///
+    /// !!! note
+    ///     uv will only read `default-groups` from the `pyproject.toml` at the workspace root,
+    ///     and will ignore any declarations in other workspace members.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"
+            default-groups = ["docs"]
+        "#
+    )]
+    pub default_groups: Option<Vec<GroupName>>,
+    /// Sets of [extras](https://packaging.python.org/en/latest/specifications/dependency-specifiers/#extras)
+    /// that are mutually exclusive, and so can't be enabled together.
+    ///
+    /// Some projects provide "variants" as extras (e.g., `cpu` and `cuda` builds of the same
+    /// dependency), of which at most one should be selected. Requesting every extra in a declared
+    /// set (e.g., `--extra cpu --extra cuda`) is an error, rather than an attempt to resolve the
+    /// incompatible combination.
+    ///
+    /// !!! note
+    ///     Conflicting extras are enforced when the project's requirements are read via
+    ///     `uv pip install` or `uv pip compile` (e.g., `-r pyproject.toml --extra cpu`). uv does
+    ///     not yet fork the resolution to produce a distinct lock for each variant.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[list[str]]",
+        example = r#"
+            conflicting-extras = [["cpu", "cuda"]]
+        "#
+    )]
+    pub conflicting_extras: Option<Vec<Vec<ExtraName>>>,
     /// A list of supported environments against which to resolve dependencies.
     ///
     /// By default, uv will resolve for all possible environments during a `uv lock` operation.
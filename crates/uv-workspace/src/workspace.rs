@@ -2,8 +2,9 @@
 
 use either::Either;
 use glob::{glob, GlobError, PatternError};
+use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use tracing::{debug, trace, warn};
 
@@ -14,7 +15,8 @@ use uv_pypi_types::{Requirement, RequirementSource, SupportedEnvironments, Verba
 use uv_warnings::{warn_user, warn_user_once};
 
 use crate::pyproject::{
-    Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources, ToolUvWorkspace,
+    DependencyGroupError, Project, PyProjectToml, PyprojectTomlError, Source, Sources,
+    ToolUvSources, ToolUvWorkspace,
 };
 
 #[derive(thiserror::Error, Debug)]
@@ -311,7 +313,7 @@ impl Workspace {
     /// `pyproject.toml`.
     ///
     /// Otherwise, returns an empty list.
-    pub fn non_project_requirements(&self) -> impl Iterator<Item = Requirement> + '_ {
+    pub fn non_project_requirements(&self) -> Result<Vec<Requirement>, DependencyGroupError> {
         if self
             .packages
             .values()
@@ -319,26 +321,58 @@ impl Workspace {
         {
             // If the workspace has an explicit root, the root is a member, so we don't need to
             // include any root-only requirements.
-            Either::Left(std::iter::empty())
+            Ok(Vec::new())
         } else {
-            // Otherwise, return the dev dependencies in the non-project workspace root.
-            Either::Right(
-                self.pyproject_toml
-                    .tool
-                    .as_ref()
-                    .and_then(|tool| tool.uv.as_ref())
-                    .and_then(|uv| uv.dev_dependencies.as_ref())
-                    .into_iter()
-                    .flatten()
-                    .map(|requirement| {
-                        Requirement::from(
-                            requirement
-                                .clone()
-                                .with_origin(RequirementOrigin::Workspace),
-                        )
-                    }),
-            )
+            // Otherwise, return the dev dependencies and dependency groups in the non-project
+            // workspace root.
+            let dev_dependencies = self
+                .pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.dev_dependencies.as_ref())
+                .into_iter()
+                .flatten()
+                .cloned();
+            let dependency_groups = self
+                .pyproject_toml
+                .dependency_groups()?
+                .into_values()
+                .flatten();
+            Ok(dev_dependencies
+                .chain(dependency_groups)
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect())
+        }
+    }
+
+    /// Returns the names of all dependency groups declared across the workspace, including those
+    /// on the workspace root and the legacy `dev-dependencies`.
+    pub fn group_names(&self) -> Result<BTreeSet<GroupName>, DependencyGroupError> {
+        let mut names = BTreeSet::new();
+        for pyproject_toml in std::iter::once(&self.pyproject_toml)
+            .chain(self.packages.values().map(WorkspaceMember::pyproject_toml))
+        {
+            if pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.dev_dependencies.as_ref())
+                .is_some()
+            {
+                names.insert(DEV_DEPENDENCIES.clone());
+            }
+            names.extend(pyproject_toml.dependency_groups()?.into_keys());
         }
+        Ok(names)
+    }
+
+    /// Returns the dependency groups that should be enabled by default, as declared in
+    /// `tool.uv.default-groups` on the workspace root.
+    pub fn default_groups(&self) -> &[GroupName] {
+        self.pyproject_toml.default_groups()
     }
 
     /// Returns the set of overrides for the workspace.
@@ -1480,35 +1514,38 @@ impl<'env> InstallTarget<'env> {
     /// Return the [`InstallTarget`] dependencies for the given group name.
     ///
     /// Returns dependencies that apply to the workspace root, but not any of its members. As such,
-    /// only returns a non-empty iterator for virtual workspaces, which can include dev dependencies
-    /// on the virtual root.
+    /// only returns a non-empty list for virtual workspaces, which can include dev dependencies
+    /// and dependency groups on the virtual root.
     pub fn group(
         &self,
         name: &GroupName,
-    ) -> impl Iterator<Item = &uv_pep508::Requirement<VerbatimParsedUrl>> {
+    ) -> Result<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>, DependencyGroupError> {
         match self {
             Self::Project(_) | Self::FrozenMember(..) => {
                 // For projects, dev dependencies are attached to the members.
-                Either::Left(std::iter::empty())
+                Ok(Vec::new())
             }
             Self::NonProject(workspace) => {
-                // For non-projects, we might have dev dependencies that are attached to the
-                // workspace root (which isn't a member).
+                // For non-projects, we might have dev dependencies or dependency groups that are
+                // attached to the workspace root (which isn't a member).
+                let mut requirements = Vec::new();
                 if name == &*DEV_DEPENDENCIES {
-                    Either::Right(
+                    requirements.extend(
                         workspace
                             .pyproject_toml
                             .tool
                             .as_ref()
                             .and_then(|tool| tool.uv.as_ref())
                             .and_then(|uv| uv.dev_dependencies.as_ref())
-                            .map(|dev| dev.iter())
                             .into_iter()
-                            .flatten(),
-                    )
-                } else {
-                    Either::Left(std::iter::empty())
+                            .flatten()
+                            .cloned(),
+                    );
+                }
+                if let Some(group) = workspace.pyproject_toml.dependency_groups()?.remove(name) {
+                    requirements.extend(group);
                 }
+                Ok(requirements)
             }
         }
     }
@@ -1614,6 +1651,7 @@ mod tests {
                 ],
                 "optional-dependencies": null
               },
+              "dependency-groups": null,
               "tool": null
             }
           }
@@ -1665,6 +1703,7 @@ mod tests {
                     ],
                     "optional-dependencies": null
                   },
+                  "dependency-groups": null,
                   "tool": null
                 }
               }
@@ -1750,6 +1789,7 @@ mod tests {
                     ],
                     "optional-dependencies": null
                   },
+                  "dependency-groups": null,
                   "tool": {
                     "uv": {
                       "sources": {
@@ -1768,6 +1808,7 @@ mod tests {
                       "managed": null,
                       "package": null,
                       "dev-dependencies": null,
+                      "default-groups": null,
                       "environments": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null
@@ -1843,6 +1884,7 @@ mod tests {
                 "sources": {},
                 "pyproject_toml": {
                   "project": null,
+                  "dependency-groups": null,
                   "tool": {
                     "uv": {
                       "sources": null,
@@ -1855,6 +1897,7 @@ mod tests {
                       "managed": null,
                       "package": null,
                       "dev-dependencies": null,
+                      "default-groups": null,
                       "environments": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null
@@ -1909,6 +1952,7 @@ mod tests {
                     ],
                     "optional-dependencies": null
                   },
+                  "dependency-groups": null,
                   "tool": null
                 }
               }
@@ -2040,6 +2084,7 @@ mod tests {
                     ],
                     "optional-dependencies": null
                   },
+                  "dependency-groups": null,
                   "tool": {
                     "uv": {
                       "sources": null,
@@ -2054,6 +2099,7 @@ mod tests {
                       "managed": null,
                       "package": null,
                       "dev-dependencies": null,
+                      "default-groups": null,
                       "environments": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null
@@ -2138,6 +2184,7 @@ mod tests {
                     ],
                     "optional-dependencies": null
                   },
+                  "dependency-groups": null,
                   "tool": {
                     "uv": {
                       "sources": null,
@@ -2153,6 +2200,7 @@ mod tests {
                       "managed": null,
                       "package": null,
                       "dev-dependencies": null,
+                      "default-groups": null,
                       "environments": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null
@@ -2250,6 +2298,7 @@ mod tests {
                     ],
                     "optional-dependencies": null
                   },
+                  "dependency-groups": null,
                   "tool": {
                     "uv": {
                       "sources": null,
@@ -2265,6 +2314,7 @@ mod tests {
                       "managed": null,
                       "package": null,
                       "dev-dependencies": null,
+                      "default-groups": null,
                       "environments": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null
@@ -2336,6 +2386,7 @@ mod tests {
                     ],
                     "optional-dependencies": null
                   },
+                  "dependency-groups": null,
                   "tool": {
                     "uv": {
                       "sources": null,
@@ -2351,6 +2402,7 @@ mod tests {
                       "managed": null,
                       "package": null,
                       "dev-dependencies": null,
+                      "default-groups": null,
                       "environments": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null
@@ -541,7 +541,7 @@ async fn build_package(
                 BuildOutput::Quiet
             }
         }
-        Printer::Quiet => BuildOutput::Quiet,
+        Printer::Quiet | Printer::Silent | Printer::Json => BuildOutput::Quiet,
     };
 
     let assets = match plan {
@@ -1,10 +1,34 @@
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 use std::str::FromStr;
-use std::sync::LazyLock;
+use std::sync::{LazyLock, OnceLock};
+use uv_cli::ErrorFormat;
 use uv_distribution_types::{Name, SourceDist};
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
 
+use crate::commands::pip::operations;
+
+/// The format in which errors should be rendered, as set by `--error-format`.
+static ERROR_FORMAT: OnceLock<ErrorFormat> = OnceLock::new();
+
+/// Set the format in which errors should be rendered for the remainder of the process.
+///
+/// Under [`ErrorFormat::Json`], user-facing warnings are rendered as JSON too, such that stderr
+/// consists solely of JSON objects.
+pub(crate) fn set_error_format(format: ErrorFormat) {
+    let _ = ERROR_FORMAT.set(format);
+    if format == ErrorFormat::Json {
+        uv_warnings::set_formatter(|message| ErrorReport::warning(message).print());
+    }
+}
+
+/// Returns `true` if errors should be rendered as JSON.
+pub(crate) fn is_json_error_format() -> bool {
+    ERROR_FORMAT.get() == Some(&ErrorFormat::Json)
+}
+
 /// Static map of common package name typos or misconfigurations to their correct package names.
 static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::new(|| {
     let suggestions: Vec<(String, String)> =
@@ -22,6 +46,16 @@ static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::ne
 
 /// Render a [`uv_resolver::ResolveError::FetchAndBuild`] with a help message.
 pub(crate) fn fetch_and_build(sdist: Box<SourceDist>, cause: uv_distribution::Error) {
+    if is_json_error_format() {
+        ErrorReport::build(
+            format!("Failed to download and build `{sdist}`"),
+            &sdist,
+            &cause,
+        )
+        .print();
+        return;
+    }
+
     #[derive(Debug, miette::Diagnostic, thiserror::Error)]
     #[error("Failed to download and build `{sdist}`")]
     #[diagnostic()]
@@ -50,6 +84,11 @@ pub(crate) fn fetch_and_build(sdist: Box<SourceDist>, cause: uv_distribution::Er
 
 /// Render a [`uv_resolver::ResolveError::Build`] with a help message.
 pub(crate) fn build(sdist: Box<SourceDist>, cause: uv_distribution::Error) {
+    if is_json_error_format() {
+        ErrorReport::build(format!("Failed to build `{sdist}`"), &sdist, &cause).print();
+        return;
+    }
+
     #[derive(Debug, miette::Diagnostic, thiserror::Error)]
     #[error("Failed to build `{sdist}`")]
     #[diagnostic()]
@@ -78,18 +117,32 @@ pub(crate) fn build(sdist: Box<SourceDist>, cause: uv_distribution::Error) {
 
 /// Render a [`uv_resolver::NoSolutionError`].
 pub(crate) fn no_solution(err: &uv_resolver::NoSolutionError) {
+    if is_json_error_format() {
+        ErrorReport::no_solution(err, err.header().to_string(), None).print();
+        return;
+    }
     let report = miette::Report::msg(format!("{err}")).context(err.header());
     anstream::eprint!("{report:?}");
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with dedicated context.
 pub(crate) fn no_solution_context(err: &uv_resolver::NoSolutionError, context: &'static str) {
+    if is_json_error_format() {
+        let header = err.header().with_context(context).to_string();
+        ErrorReport::no_solution(err, header, None).print();
+        return;
+    }
     let report = miette::Report::msg(format!("{err}")).context(err.header().with_context(context));
     anstream::eprint!("{report:?}");
 }
 
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
 pub(crate) fn no_solution_hint(err: uv_resolver::NoSolutionError, help: String) {
+    if is_json_error_format() {
+        ErrorReport::no_solution(&err, err.header().to_string(), Some(help)).print();
+        return;
+    }
+
     #[derive(Debug, miette::Diagnostic, thiserror::Error)]
     #[error("{header}")]
     #[diagnostic()]
@@ -110,3 +163,214 @@ pub(crate) fn no_solution_hint(err: uv_resolver::NoSolutionError, help: String)
     let report = miette::Report::new(Error { header, err, help });
     anstream::eprint!("{report:?}");
 }
+
+/// A machine-readable description of an error, rendered to stderr with `--error-format json`.
+#[derive(Debug, Serialize)]
+pub(crate) struct ErrorReport {
+    /// The kind of error.
+    kind: ErrorKind,
+    /// The top-level error message.
+    message: String,
+    /// The underlying causes of the error, from outermost to innermost.
+    causes: Vec<String>,
+    /// Any additional context relevant to the error.
+    context: ErrorContext,
+}
+
+#[derive(Debug, Default, Copy, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum ErrorKind {
+    /// The requirements could not be satisfied.
+    ResolutionConflict,
+    /// A requirement, requirements file, or configuration file could not be parsed.
+    Parse,
+    /// A downloaded distribution didn't match its expected hashes.
+    HashMismatch,
+    /// A requirements file or source tree does not exist.
+    FileNotFound,
+    /// A source distribution failed to build.
+    Build,
+    /// The command-line arguments were invalid.
+    Usage,
+    /// Not an error, but a user-facing warning.
+    Warning,
+    /// Any other error.
+    #[default]
+    Other,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct ErrorContext {
+    /// The packages involved in the error (e.g., the packages in a resolution conflict).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    packages: Vec<PackageName>,
+    /// The distribution involved in the error (e.g., the distribution with mismatched hashes).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    distribution: Option<String>,
+    /// The path to the file involved in the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    /// A hint for resolving the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+}
+
+impl ErrorReport {
+    /// Create an [`ErrorReport`] for an error returned at the top level of the CLI, classifying it
+    /// by the first recognized error in its chain of causes.
+    pub(crate) fn from_error(err: &anyhow::Error) -> Self {
+        let mut chain = err.chain().map(|err| strip(&err.to_string()));
+        let message = chain.next().unwrap_or_default();
+        let causes = chain.collect();
+        let (kind, context) = Self::classify(err).unwrap_or_default();
+        Self {
+            kind,
+            message,
+            causes,
+            context,
+        }
+    }
+
+    /// Create an [`ErrorReport`] for invalid command-line arguments.
+    pub(crate) fn usage(err: &clap::Error) -> Self {
+        let rendered = strip(&err.render().to_string());
+        let (message, help) = rendered.split_once('\n').unwrap_or((rendered.as_str(), ""));
+        let help = help.trim();
+        Self {
+            kind: ErrorKind::Usage,
+            message: message.trim_start_matches("error: ").to_string(),
+            causes: vec![],
+            context: ErrorContext {
+                help: (!help.is_empty()).then(|| help.to_string()),
+                ..ErrorContext::default()
+            },
+        }
+    }
+
+    /// Create an [`ErrorReport`] for a user-facing warning.
+    fn warning(message: &str) -> Self {
+        Self {
+            kind: ErrorKind::Warning,
+            message: strip(message),
+            causes: vec![],
+            context: ErrorContext::default(),
+        }
+    }
+
+    /// Create an [`ErrorReport`] for a [`uv_resolver::NoSolutionError`].
+    fn no_solution(
+        err: &uv_resolver::NoSolutionError,
+        header: String,
+        help: Option<String>,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::ResolutionConflict,
+            message: strip(header.trim_end_matches(':')),
+            causes: vec![strip(&err.to_string())],
+            context: ErrorContext {
+                packages: err.packages().into_iter().cloned().collect(),
+                help: help.as_deref().map(strip),
+                ..ErrorContext::default()
+            },
+        }
+    }
+
+    /// Create an [`ErrorReport`] for a source distribution that failed to build.
+    fn build(message: String, sdist: &SourceDist, cause: &uv_distribution::Error) -> Self {
+        let mut causes = vec![strip(&cause.to_string())];
+        let mut source = std::error::Error::source(cause);
+        while let Some(err) = source {
+            causes.push(strip(&err.to_string()));
+            source = err.source();
+        }
+        Self {
+            kind: ErrorKind::Build,
+            message: strip(&message),
+            causes,
+            context: ErrorContext {
+                packages: vec![sdist.name().clone()],
+                ..ErrorContext::default()
+            },
+        }
+    }
+
+    /// Determine the kind of error, and any relevant context, from the first recognized error in
+    /// the chain.
+    fn classify(err: &anyhow::Error) -> Option<(ErrorKind, ErrorContext)> {
+        err.chain().find_map(|err| {
+            if let Some(operations::Error::Anyhow(err)) = err.downcast_ref::<operations::Error>() {
+                return Self::classify(err);
+            }
+            if let Some(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) =
+                err.downcast_ref::<operations::Error>()
+            {
+                return Some(Self::no_solution_context(err));
+            }
+            if let Some(uv_resolver::ResolveError::NoSolution(err)) =
+                err.downcast_ref::<uv_resolver::ResolveError>()
+            {
+                return Some(Self::no_solution_context(err));
+            }
+            if let Some(err) = err.downcast_ref::<uv_resolver::NoSolutionError>() {
+                return Some(Self::no_solution_context(err));
+            }
+            if let Some(uv_distribution::Error::MismatchedHashes { distribution, .. }) =
+                err.downcast_ref::<uv_distribution::Error>()
+            {
+                return Some((
+                    ErrorKind::HashMismatch,
+                    ErrorContext {
+                        distribution: Some(distribution.clone()),
+                        ..ErrorContext::default()
+                    },
+                ));
+            }
+            if let Some(err) = err.downcast_ref::<uv_requirements::FileNotFound>() {
+                return Some((
+                    ErrorKind::FileNotFound,
+                    ErrorContext {
+                        path: Some(err.path.user_display().to_string()),
+                        ..ErrorContext::default()
+                    },
+                ));
+            }
+            if let Some(err) = err.downcast_ref::<uv_requirements_txt::RequirementsTxtFileError>() {
+                return Some((
+                    ErrorKind::Parse,
+                    ErrorContext {
+                        path: Some(err.file().user_display().to_string()),
+                        ..ErrorContext::default()
+                    },
+                ));
+            }
+            if err.is::<uv_pep508::Pep508Error>() || err.is::<toml::de::Error>() {
+                return Some((ErrorKind::Parse, ErrorContext::default()));
+            }
+            None
+        })
+    }
+
+    /// Return the kind and context for a [`uv_resolver::NoSolutionError`].
+    fn no_solution_context(err: &uv_resolver::NoSolutionError) -> (ErrorKind, ErrorContext) {
+        (
+            ErrorKind::ResolutionConflict,
+            ErrorContext {
+                packages: err.packages().into_iter().cloned().collect(),
+                ..ErrorContext::default()
+            },
+        )
+    }
+
+    /// Write the [`ErrorReport`] to stderr as a single line of JSON.
+    pub(crate) fn print(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => anstream::eprintln!("{json}"),
+            Err(err) => anstream::eprintln!("Failed to serialize error report: {err}"),
+        }
+    }
+}
+
+/// Strip any ANSI escape codes (e.g., colors) from an error message.
+fn strip(message: &str) -> String {
+    anstream::adapter::strip_str(message).to_string()
+}
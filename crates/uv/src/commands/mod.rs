@@ -3,6 +3,7 @@ use anyhow::Context;
 use owo_colors::OwoColorize;
 use std::borrow::Cow;
 use std::io::stdout;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
@@ -11,6 +12,7 @@ pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use diagnostics::{is_json_error_format, set_error_format, ErrorReport};
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
@@ -21,6 +23,7 @@ pub(crate) use pip::show::pip_show;
 pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
+pub(crate) use pip::why::pip_why;
 pub(crate) use project::add::add;
 pub(crate) use project::export::export;
 pub(crate) use project::init::{init, InitKind, InitProjectKind};
@@ -47,6 +50,7 @@ pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
 use uv_cache::Cache;
+use uv_configuration::{Concurrency, LineEnding};
 use uv_distribution_types::{IndexCapabilities, InstalledMetadata};
 use uv_fs::Simplified;
 use uv_git::GitResolver;
@@ -147,22 +151,31 @@ pub(super) struct DryRunEvent<T: Display> {
 /// initial run of any subsequent executions.
 ///
 /// See the `--compile` option on `pip sync` and `pip install`.
+///
+/// At most `concurrency` files are compiled at once, defaulting to the number of available cores.
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    concurrency: Option<NonZeroUsize>,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
     let mut files = 0;
+    let concurrency = concurrency.map_or_else(Concurrency::threads, NonZeroUsize::get);
     for site_packages in venv.site_packages() {
-        files += compile_tree(&site_packages, venv.python_executable(), cache.root())
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to bytecode-compile Python file in: {}",
-                    site_packages.user_display()
-                )
-            })?;
+        files += compile_tree(
+            &site_packages,
+            venv.python_executable(),
+            concurrency,
+            cache.root(),
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to bytecode-compile Python file in: {}",
+                site_packages.user_display()
+            )
+        })?;
     }
     let s = if files == 1 { "" } else { "s" };
     writeln!(
@@ -212,6 +225,7 @@ pub(crate) struct SharedState {
 struct OutputWriter<'a> {
     stdout: Option<AutoStream<std::io::Stdout>>,
     output_file: Option<&'a Path>,
+    line_ending: Option<LineEnding>,
     buffer: Vec<u8>,
 }
 
@@ -223,10 +237,18 @@ impl<'a> OutputWriter<'a> {
         Self {
             stdout,
             output_file,
+            line_ending: None,
             buffer: Vec::new(),
         }
     }
 
+    /// Normalize the line endings of the output file, ensuring a single trailing newline.
+    #[must_use]
+    fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = Some(line_ending);
+        self
+    }
+
     /// Write the given arguments to both standard output and the output buffer, if present.
     fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) -> std::io::Result<()> {
         use std::io::Write;
@@ -251,7 +273,12 @@ impl<'a> OutputWriter<'a> {
             let output_file = fs_err::read_link(output_file)
                 .map(Cow::Owned)
                 .unwrap_or(Cow::Borrowed(output_file));
-            let stream = anstream::adapter::strip_bytes(&self.buffer).into_vec();
+            let mut stream = anstream::adapter::strip_bytes(&self.buffer).into_vec();
+            if let Some(line_ending) = self.line_ending {
+                stream = line_ending
+                    .normalize(&String::from_utf8_lossy(&stream))
+                    .into_bytes();
+            }
             uv_fs::write_atomic(output_file, &stream).await?;
         }
         Ok(())
@@ -1,49 +1,58 @@
+use std::collections::BTreeSet;
 use std::env;
+use std::fmt::Write;
 use std::path::Path;
 
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, IndexStrategy,
-    NoBinary, NoBuild, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    AllowYanked, BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification,
+    IndexStrategy, NoBinary, NoBuild, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    UpgradeStrategy,
 };
-use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_configuration::{KeyringProviderType, LineEnding, OutputFormat, TargetTriple};
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{
-    DependencyMetadata, IndexCapabilities, IndexLocations, NameRequirementSpecification,
-    UnresolvedRequirementSpecification, Verbatim,
+    DependencyMetadata, IndexCapabilities, IndexLocations, IndexUrl, NameRequirementSpecification,
+    UnresolvedRequirement, UnresolvedRequirementSpecification, Verbatim,
 };
-use uv_fs::Simplified;
+use uv_fs::{Simplified, CWD};
 use uv_git::GitResolver;
 use uv_install_wheel::linker::LinkMode;
 use uv_normalize::PackageName;
-use uv_pypi_types::{Requirement, SupportedEnvironments};
+use uv_pep508::{MarkerEnvironment, MarkerOverride};
+use uv_pypi_types::{HashAlgorithm, Requirement, ResolverMarkerEnvironment, SupportedEnvironments};
 use uv_python::{
-    EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
-    PythonVersion, VersionRequest,
+    EnvironmentPreference, ImplementationName, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, VersionRequest,
 };
 use uv_requirements::{
     upgrade::read_requirements_txt, RequirementsSource, RequirementsSpecification,
 };
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PythonRequirement, RequiresPython,
+    AnnotationStyle, Denylist, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex,
+    InMemoryIndex, JsonResolution, OptionsBuilder, PackageResolutionMode, PackageResolutionModes,
+    PrereleaseMode, PylockToml, PythonRequirement, RequiresPython, ResolutionBudget,
     ResolutionMode, ResolverMarkers,
 };
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
-use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger};
+use crate::commands::pip::resolution_cache::{CachedResolution, OutputDigest, ResolutionInputs};
 use crate::commands::pip::{operations, resolution_environment};
 use crate::commands::{diagnostics, ExitStatus, OutputWriter};
 use crate::printer::Printer;
 
+/// The first line of the header written to generated `requirements.txt` files.
+const HEADER: &str = "# This file was autogenerated by uv via the following command:";
+
 /// Resolve a set of requirements into a set of pinned versions.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_compile(
@@ -51,16 +60,33 @@ pub(crate) async fn pip_compile(
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
+    max_include_depth: usize,
+    pin_files: &[RequirementsSource],
     constraints_from_workspace: Vec<Requirement>,
     overrides_from_workspace: Vec<Requirement>,
+    override_add: bool,
     environments: SupportedEnvironments,
     extras: ExtrasSpecification,
     output_file: Option<&Path>,
+    format: Option<OutputFormat>,
+    force: bool,
+    append: bool,
+    absolute_paths: bool,
+    line_ending: LineEnding,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
+    allow_yanked: AllowYanked,
+    allowed_packages: Option<BTreeSet<PackageName>>,
+    denied: Denylist,
+    resolution_packages: Vec<PackageResolutionMode>,
+    strict_extras: bool,
+    upgrade_strategy: UpgradeStrategy,
+    resolution_budget: ResolutionBudget,
+    cache_resolution: bool,
     generate_hashes: bool,
+    hash_algorithm: HashAlgorithm,
     no_emit_packages: Vec<PackageName>,
     include_extras: bool,
     include_markers: bool,
@@ -73,6 +99,7 @@ pub(crate) async fn pip_compile(
     include_marker_expression: bool,
     include_index_annotation: bool,
     index_locations: IndexLocations,
+    index_mirror: Vec<IndexUrl>,
     index_strategy: IndexStrategy,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
@@ -84,6 +111,8 @@ pub(crate) async fn pip_compile(
     build_options: BuildOptions,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    python_implementation: Option<ImplementationName>,
+    marker_overrides: &[MarkerOverride],
     universal: bool,
     exclude_newer: Option<ExcludeNewer>,
     sources: SourceStrategy,
@@ -98,14 +127,45 @@ pub(crate) async fn pip_compile(
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
-    // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
-    // return an error.
-    if !extras.is_empty() && !requirements.iter().any(RequirementsSource::allows_extras) {
+    // Each package may be assigned at most one resolution mode.
+    let mut resolution_modes = PackageResolutionModes::from_args(resolution_packages)?;
+
+    // If the output format isn't specified, infer it from the output file.
+    let format = format
+        .or_else(|| output_file.map(OutputFormat::from_path))
+        .unwrap_or_default();
+
+    // Merging into an existing output file is only supported for `requirements.txt` files.
+    if append && format != OutputFormat::RequirementsTxt {
         return Err(anyhow!(
-            "Requesting extras requires a `pyproject.toml`, `setup.cfg`, or `setup.py` file."
+            "`--append` is only supported for the `requirements.txt` output format"
         ));
     }
 
+    // Avoid overwriting a `requirements.txt` file that wasn't generated by uv (e.g., one that's
+    // maintained by hand), or that was edited since uv generated it, unless explicitly requested.
+    // When appending, the existing entries are retained, so there's nothing to clobber.
+    if let Some(output_file) = output_file {
+        if !force && !append && matches!(format, OutputFormat::RequirementsTxt) {
+            if let Ok(contents) = fs_err::read_to_string(output_file) {
+                if !contents.trim().is_empty() {
+                    if !contents.contains(HEADER) {
+                        return Err(anyhow!(
+                            "The output file `{}` was not generated by uv; use `--force` to overwrite it",
+                            output_file.user_display()
+                        ));
+                    }
+                    if OutputDigest::is_modified(output_file, &contents, &cache) {
+                        return Err(anyhow!(
+                            "The output file `{}` was edited since it was generated by uv; use `--force` to overwrite it",
+                            output_file.user_display()
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
@@ -113,6 +173,34 @@ pub(crate) async fn pip_compile(
         .allow_insecure_host(allow_insecure_host);
 
     // Read all requirements from the provided sources.
+    let mut spec = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        overrides,
+        false,
+        max_include_depth,
+        &client_builder,
+    )
+    .await?;
+
+    // Apply the requested extras to any requirement on the current project (e.g., `.[dev]`).
+    let self_reference = spec.apply_project_extras(&extras)?;
+
+    // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
+    // return an error.
+    if !extras.is_empty()
+        && !self_reference
+        && !requirements.iter().any(RequirementsSource::allows_extras)
+    {
+        return Err(anyhow!(
+            "Requesting extras requires a `pyproject.toml`, `setup.cfg`, or `setup.py` file."
+        ));
+    }
+
+    // Reject project dependencies that contradict the workspace constraints up-front, rather than
+    // failing (or backtracking) during resolution.
+    spec.check_constraints(&constraints_from_workspace)?;
+
     let RequirementsSpecification {
         project,
         requirements,
@@ -126,22 +214,43 @@ pub(crate) async fn pip_compile(
         find_links,
         no_binary,
         no_build,
-    } = RequirementsSpecification::from_sources(
-        requirements,
-        constraints,
-        overrides,
-        &client_builder,
-    )
-    .await?;
+        no_deps: _,
+        config_settings: config_settings_package,
+        prerelease,
+        requires_python,
+        project_dependencies: _,
+        require_hashes: _,
+    } = spec;
+
+    // Track the user-provided constraints and overrides, to report any that don't apply to the
+    // resolution.
+    let user_constraints = constraints
+        .iter()
+        .map(|entry| entry.requirement.clone())
+        .collect::<Vec<_>>();
+    let user_overrides = overrides
+        .iter()
+        .filter_map(|entry| match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => Some(requirement.clone()),
+            UnresolvedRequirement::Unnamed(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Read the frozen packages, which are pinned (as constraints) to their exact versions.
+    let frozen = operations::read_frozen(pin_files, &client_builder).await?;
 
-    let constraints = constraints
+    // The `[tool.uv]` constraints and overrides may be read from both a `pyproject.toml` source
+    // and the discovered configuration, so drop any duplicates.
+    let constraints: Vec<NameRequirementSpecification> = constraints
         .iter()
         .cloned()
+        .chain(frozen)
         .chain(
             constraints_from_workspace
                 .into_iter()
                 .map(NameRequirementSpecification::from),
         )
+        .unique()
         .collect();
 
     let overrides: Vec<UnresolvedRequirementSpecification> = overrides
@@ -152,15 +261,27 @@ pub(crate) async fn pip_compile(
                 .into_iter()
                 .map(UnresolvedRequirementSpecification::from),
         )
+        .unique()
         .collect();
 
+    // If requested, treat each override as a requirement, such that overridden packages are
+    // included in the resolution even if nothing else depends on them.
+    let requirements = if override_add {
+        requirements
+            .into_iter()
+            .chain(overrides.iter().cloned())
+            .collect()
+    } else {
+        requirements
+    };
+
     // Read build constraints.
     let build_constraints =
         operations::read_constraints(build_constraints, &client_builder).await?;
 
     // If all the metadata could be statically resolved, validate that every extra was used. If we
     // need to resolve metadata via PEP 517, we don't know which extras are used until much later.
-    if source_trees.is_empty() {
+    if source_trees.is_empty() && !self_reference {
         if let ExtrasSpecification::Some(extras) = &extras {
             let mut unused_extras = extras
                 .iter()
@@ -234,18 +355,28 @@ pub(crate) async fn pip_compile(
         source_index.clone()
     };
 
-    // Determine the Python requirement, if the user requested a specific version.
+    // Determine the `Requires-Python` declared by the project, if any.
+    let requires_python = requires_python
+        .as_ref()
+        .map(RequiresPython::from_specifiers)
+        .transpose()?;
+
+    // Determine the Python requirement. By default, resolve for the entire range declared by the
+    // project (if any), unless the user requested a specific version.
     let python_requirement = if universal {
-        let requires_python = RequiresPython::greater_than_equal_version(
-            if let Some(python_version) = python_version.as_ref() {
-                &python_version.version
-            } else {
-                interpreter.python_version()
-            },
-        );
+        let requires_python = if let Some(python_version) = python_version.as_ref() {
+            RequiresPython::greater_than_equal_version(&python_version.version)
+        } else if let Some(requires_python) = requires_python {
+            requires_python
+        } else {
+            RequiresPython::greater_than_equal_version(interpreter.python_version())
+        };
         PythonRequirement::from_requires_python(&interpreter, requires_python)
     } else if let Some(python_version) = python_version.as_ref() {
         PythonRequirement::from_python_version(&interpreter, python_version)
+    } else if let Some(requires_python) = requires_python {
+        debug!("Resolving for the declared `Requires-Python`: {requires_python}");
+        PythonRequirement::from_requires_python(&interpreter, requires_python)
     } else {
         PythonRequirement::from_interpreter(&interpreter)
     };
@@ -259,12 +390,31 @@ pub(crate) async fn pip_compile(
     } else {
         let (tags, markers) =
             resolution_environment(python_version, python_platform, &interpreter)?;
+
+        // Override the implementation markers, if the user requested a specific implementation.
+        let markers = if let Some(python_implementation) = python_implementation {
+            ResolverMarkerEnvironment::from(python_implementation.markers(&markers))
+        } else {
+            markers
+        };
+
+        // Override any individual markers requested by the user.
+        let markers = if marker_overrides.is_empty() {
+            markers
+        } else {
+            ResolverMarkerEnvironment::from(marker_overrides.iter().fold(
+                MarkerEnvironment::clone(&markers),
+                MarkerEnvironment::with_override,
+            ))
+        };
+
         (Some(tags), ResolverMarkers::specific_environment(markers))
     };
 
-    // Generate, but don't enforce hashes for the requirements.
-    let hasher = if generate_hashes {
-        HashStrategy::Generate
+    // Generate, but don't enforce hashes for the requirements. PEP 751 requires hashes for every
+    // archive in a `pylock.toml` file, so they're always generated for that format.
+    let hasher = if generate_hashes || format == OutputFormat::Pylock {
+        HashStrategy::Generate(hash_algorithm)
     } else {
         HashStrategy::None
     };
@@ -284,20 +434,183 @@ pub(crate) async fn pip_compile(
     // Initialize the registry client.
     let client = RegistryClientBuilder::try_from(client_builder)?
         .cache(cache.clone())
-        .index_urls(index_locations.index_urls())
+        .index_urls(index_locations.index_urls().with_mirrors(index_mirror))
         .index_strategy(index_strategy)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
 
     // Read the lockfile, if present.
-    let preferences = read_requirements_txt(output_file, &upgrade).await?;
+    let preferences = match (&upgrade, upgrade_strategy) {
+        // With the minimal upgrade strategy, retain the existing pins for the upgraded packages,
+        // but resolve them to their lowest compatible versions, such that each package only moves
+        // as far past its pin as its constraints (e.g., `--upgrade-package "requests>=2.32.0"`)
+        // require.
+        (Upgrade::Packages(packages), UpgradeStrategy::Minimal) => {
+            let preferences = read_requirements_txt(output_file, format, &Upgrade::None).await?;
+            for package in packages.keys() {
+                if preferences
+                    .iter()
+                    .any(|preference| preference.name() == package)
+                {
+                    debug!("Upgrading `{package}` to its lowest compatible version");
+                    resolution_modes.or_insert(package.clone(), ResolutionMode::Lowest);
+                }
+            }
+            preferences
+        }
+        (Upgrade::All, UpgradeStrategy::Minimal) => {
+            warn_user!(
+                "`--upgrade-strategy minimal` has no effect with `--upgrade`, which upgrades all packages to their latest compatible versions; use `--upgrade-package` instead"
+            );
+            read_requirements_txt(output_file, format, &upgrade).await?
+        }
+        (Upgrade::None, UpgradeStrategy::Minimal) => {
+            warn_user!("`--upgrade-strategy minimal` has no effect without `--upgrade-package`");
+            read_requirements_txt(output_file, format, &upgrade).await?
+        }
+        (_, UpgradeStrategy::Eager) => read_requirements_txt(output_file, format, &upgrade).await?,
+    };
+
     let git = GitResolver::default();
     let capabilities = IndexCapabilities::default();
 
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
+    // If requested, determine the inputs by which the resolution is cached, along with the contents
+    // of the existing output file. Resolutions that include local or direct URL requirements (or
+    // `--find-links` locations) are never cached, as their contents can change without any change
+    // to the inputs.
+    let resolution_inputs = if cache_resolution
+        && !append
+        && upgrade.is_none()
+        && project.is_none()
+        && source_trees.is_empty()
+        && index_locations.flat_index().next().is_none()
+    {
+        let mut inputs = ResolutionInputs::new();
+        let mut cacheable = true;
+        for requirement in &requirements {
+            cacheable &= inputs.push_unresolved_requirement(
+                "requirement",
+                &requirement.requirement,
+                &requirement.hashes,
+            )?;
+        }
+        for constraint in &constraints {
+            cacheable &= inputs.push_requirement(
+                "constraint",
+                &constraint.requirement,
+                &constraint.hashes,
+            )?;
+        }
+        for r#override in &overrides {
+            cacheable &= inputs.push_unresolved_requirement(
+                "override",
+                &r#override.requirement,
+                &r#override.hashes,
+            )?;
+        }
+        if cacheable {
+            inputs.push("extras", &extras)?;
+            inputs.push("interpreter-markers", interpreter.markers())?;
+            inputs.push("interpreter-platform", interpreter.platform())?;
+            inputs.push(
+                "python-requirement",
+                &(
+                    python_requirement.exact().to_string(),
+                    python_requirement.installed().to_string(),
+                    python_requirement.target().to_string(),
+                ),
+            )?;
+            inputs.push("markers", &markers.to_string())?;
+            inputs.push("tags", &tags.as_deref().map(ToString::to_string))?;
+            inputs.push("resolution-mode", &resolution_mode)?;
+            inputs.push("prerelease-mode", &prerelease_mode)?;
+            inputs.push("prerelease-packages", &prerelease)?;
+            inputs.push("dependency-mode", &dependency_mode)?;
+            inputs.push("allow-yanked", &allow_yanked)?;
+            inputs.push("allowed-packages", &allowed_packages)?;
+            inputs.push("denied", &denied)?;
+            inputs.push("resolution-modes", &resolution_modes)?;
+            inputs.push("strict-extras", &strict_extras)?;
+            inputs.push("exclude-newer", &exclude_newer)?;
+            inputs.push("index-locations", &index_locations)?;
+            inputs.push("index-strategy", &index_strategy)?;
+            inputs.push(
+                "dependency-metadata",
+                &dependency_metadata.values().sorted().collect::<Vec<_>>(),
+            )?;
+            inputs.push("config-settings", &config_settings)?;
+            inputs.push("config-settings-package", &config_settings_package)?;
+            inputs.push("no-build-isolation", &no_build_isolation)?;
+            inputs.push("no-build-isolation-package", &no_build_isolation_package)?;
+            inputs.push("build-options", &build_options)?;
+            inputs.push("sources", &sources)?;
+            inputs.push("format", &format)?;
+            inputs.push("generate-hashes", &generate_hashes)?;
+            inputs.push("hash-algorithm", &hash_algorithm)?;
+            inputs.push("no-emit-package", &no_emit_packages)?;
+            inputs.push("include-extras", &include_extras)?;
+            inputs.push("include-markers", &include_markers)?;
+            inputs.push("include-annotations", &include_annotations)?;
+            inputs.push("include-index-url", &include_index_url)?;
+            inputs.push("include-find-links", &include_find_links)?;
+            inputs.push("include-build-options", &include_build_options)?;
+            inputs.push("include-marker-expression", &include_marker_expression)?;
+            inputs.push("include-index-annotation", &include_index_annotation)?;
+            inputs.push("annotation-style", &annotation_style)?;
+            inputs.push("absolute-paths", &absolute_paths)?;
+            let existing_output = output_file.and_then(|path| fs_err::read_to_string(path).ok());
+            Some((inputs, existing_output))
+        } else {
+            debug!("Skipping resolution cache for local or direct URL requirements");
+            None
+        }
+    } else {
+        None
+    };
+
+    // Determine the header for the output file, if any. The header reflects the current command, so
+    // it's excluded from the cached resolution.
+    let header = (include_header && format == OutputFormat::RequirementsTxt).then(|| {
+        format!(
+            "{}\n{}\n",
+            HEADER.green(),
+            format!(
+                "#    {}",
+                cmd(
+                    include_index_url,
+                    include_find_links,
+                    custom_compile_command
+                )
+            )
+            .green()
+        )
+    });
+
+    // If the inputs are unchanged since a previous resolution, reuse its output.
+    let start = std::time::Instant::now();
+    if let Some(resolution) = resolution_inputs
+        .as_ref()
+        .and_then(|(inputs, existing_output)| {
+            CachedResolution::read(&inputs.with_output(existing_output.as_deref()), &cache)
+        })
+    {
+        DefaultResolveLogger.on_complete(resolution.packages, start, printer)?;
+        write_resolution(
+            &resolution,
+            header.as_deref(),
+            output_file,
+            line_ending,
+            quiet,
+            &cache,
+        )
+        .await?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(&client, &cache);
@@ -349,14 +662,17 @@ pub(crate) async fn pip_compile(
         exclude_newer,
         sources,
         concurrency,
-    );
+    )
+    .with_config_settings_package(config_settings_package);
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
+        .resolution_modes(resolution_modes)
         .prerelease_mode(prerelease_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
+        .budget(resolution_budget)
         .build();
 
     // Resolve the requirements.
@@ -368,12 +684,17 @@ pub(crate) async fn pip_compile(
         source_trees,
         project,
         None,
+        BTreeSet::default(),
+        prerelease,
         &extras,
         preferences,
         EmptyInstalledPackages,
         &hasher,
         &Reinstall::None,
         &upgrade,
+        &allow_yanked,
+        allowed_packages.as_ref(),
+        &denied,
         tags.as_deref(),
         markers.clone(),
         python_requirement,
@@ -404,141 +725,364 @@ pub(crate) async fn pip_compile(
         Err(err) => return Err(err.into()),
     };
 
-    // Write the resolved dependencies to the output channel.
-    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
-
-    if include_header {
-        writeln!(
-            writer,
-            "{}",
-            "# This file was autogenerated by uv via the following command:".green()
-        )?;
-        writeln!(
-            writer,
-            "{}",
-            format!(
-                "#    {}",
-                cmd(
-                    include_index_url,
-                    include_find_links,
-                    custom_compile_command
-                )
-            )
-            .green()
-        )?;
+    // If requested, reject any extras that aren't declared by the resolved packages.
+    if strict_extras {
+        operations::validate_extras(resolution.diagnostics())?;
     }
 
-    if include_marker_expression {
-        if let ResolverMarkers::SpecificEnvironment(markers) = &markers {
-            let relevant_markers = resolution.marker_tree(&top_level_index, markers)?;
-            if let Some(relevant_markers) = relevant_markers.contents() {
-                writeln!(
-                    writer,
-                    "{}",
-                    "# Pinned dependencies known to be valid for:".green()
-                )?;
-                writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
-            }
-        }
-    }
+    // Report any constraints or overrides that don't apply to the resolution.
+    let unused = operations::validate_unused(
+        &user_constraints,
+        &user_overrides,
+        |name| resolution.contains(name),
+        markers.marker_environment(),
+        false,
+    )?;
+
+    // Render the resolved dependencies.
+    let mut output = String::new();
 
-    let mut wrote_preamble = false;
+    // Determine the directory against which to rebase relative paths in the output.
+    let output_dir = output_file
+        .map(|path| uv_fs::normalize_path(&CWD.join(path)))
+        .and_then(|path| path.parent().map(Path::to_path_buf));
 
-    // If necessary, include the `--index-url` and `--extra-index-url` locations.
-    if include_index_url {
-        if let Some(index) = index_locations.index() {
-            writeln!(writer, "--index-url {}", index.verbatim())?;
-            wrote_preamble = true;
+    // The JSON and `pylock.toml` formats are structured, so omit the header and comments.
+    if matches!(format, OutputFormat::Json | OutputFormat::Pylock) {
+        let display = DisplayResolutionGraph::new(
+            &resolution,
+            &markers,
+            &no_emit_packages,
+            generate_hashes,
+            include_extras,
+            include_markers || universal,
+            include_annotations,
+            include_index_annotation,
+            annotation_style,
+            output_dir.as_deref(),
+            absolute_paths,
+        );
+        if format == OutputFormat::Json {
+            let json = serde_json::to_string_pretty(&JsonResolution::from_display(&display))?;
+            writeln!(output, "{json}")?;
+        } else {
+            let pylock = toml::to_string(&PylockToml::from_display(&display)?)?;
+            write!(output, "{pylock}")?;
         }
-        for extra_index in index_locations.extra_index() {
-            writeln!(writer, "--extra-index-url {}", extra_index.verbatim())?;
-            wrote_preamble = true;
+    } else {
+        if include_marker_expression {
+            if let ResolverMarkers::SpecificEnvironment(markers) = &markers {
+                let relevant_markers = resolution.marker_tree(&top_level_index, markers)?;
+                if let Some(relevant_markers) = relevant_markers.contents() {
+                    writeln!(
+                        output,
+                        "{}",
+                        "# Pinned dependencies known to be valid for:".green()
+                    )?;
+                    writeln!(output, "{}", format!("#    {relevant_markers}").green())?;
+                }
+            }
         }
-    }
 
-    // If necessary, include the `--find-links` locations.
-    if include_find_links {
-        for flat_index in index_locations.flat_index() {
-            writeln!(writer, "--find-links {}", flat_index.verbatim())?;
-            wrote_preamble = true;
-        }
-    }
+        let mut wrote_preamble = false;
 
-    // If necessary, include the `--no-binary` and `--only-binary` options.
-    if include_build_options {
-        match build_options.no_binary() {
-            NoBinary::None => {}
-            NoBinary::All => {
-                writeln!(writer, "--no-binary :all:")?;
+        // If necessary, include the `--index-url` and `--extra-index-url` locations.
+        if include_index_url {
+            if let Some(index) = index_locations.index() {
+                writeln!(output, "--index-url {}", index.verbatim())?;
                 wrote_preamble = true;
             }
-            NoBinary::Packages(packages) => {
-                for package in packages {
-                    writeln!(writer, "--no-binary {package}")?;
-                    wrote_preamble = true;
-                }
+            for extra_index in index_locations.extra_index() {
+                writeln!(output, "--extra-index-url {}", extra_index.verbatim())?;
+                wrote_preamble = true;
             }
         }
-        match build_options.no_build() {
-            NoBuild::None => {}
-            NoBuild::All => {
-                writeln!(writer, "--only-binary :all:")?;
+
+        // If necessary, include the `--find-links` locations.
+        if include_find_links {
+            for flat_index in index_locations.flat_index() {
+                writeln!(output, "--find-links {}", flat_index.verbatim())?;
                 wrote_preamble = true;
             }
-            NoBuild::Packages(packages) => {
-                for package in packages {
-                    writeln!(writer, "--only-binary {package}")?;
+        }
+
+        // If necessary, include the `--no-binary` and `--only-binary` options.
+        if include_build_options {
+            match build_options.no_binary() {
+                NoBinary::None => {}
+                NoBinary::All => {
+                    writeln!(output, "--no-binary :all:")?;
+                    wrote_preamble = true;
+                }
+                NoBinary::Packages(packages) => {
+                    for package in packages {
+                        writeln!(output, "--no-binary {package}")?;
+                        wrote_preamble = true;
+                    }
+                }
+            }
+            match build_options.no_build() {
+                NoBuild::None => {}
+                NoBuild::All => {
+                    writeln!(output, "--only-binary :all:")?;
                     wrote_preamble = true;
                 }
+                NoBuild::Packages(packages) => {
+                    for package in packages {
+                        writeln!(output, "--only-binary {package}")?;
+                        wrote_preamble = true;
+                    }
+                }
+            }
+        }
+
+        // If we wrote an index, add a newline to separate it from the requirements
+        if wrote_preamble {
+            writeln!(output)?;
+        }
+
+        write!(
+            output,
+            "{}",
+            DisplayResolutionGraph::new(
+                &resolution,
+                &markers,
+                &no_emit_packages,
+                generate_hashes,
+                include_extras,
+                include_markers || universal,
+                include_annotations,
+                include_index_annotation,
+                annotation_style,
+                output_dir.as_deref(),
+                absolute_paths,
+            )
+        )?;
+
+        // If any "unsafe" packages were excluded, notify the user.
+        let excluded = no_emit_packages
+            .into_iter()
+            .filter(|name| resolution.contains(name))
+            .collect::<Vec<_>>();
+        if !excluded.is_empty() {
+            writeln!(output)?;
+            writeln!(
+                output,
+                "{}",
+                "# The following packages were excluded from the output:".green()
+            )?;
+            for package in excluded {
+                writeln!(output, "# {package}")?;
             }
         }
     }
 
-    // If we wrote an index, add a newline to separate it from the requirements
-    if wrote_preamble {
-        writeln!(writer)?;
+    // If requested, merge the resolved dependencies into the existing output file.
+    if append {
+        let existing = output_file
+            .and_then(|path| fs_err::read_to_string(path).ok())
+            .unwrap_or_default();
+        output = append_requirements_txt(
+            &existing,
+            &anstream::adapter::strip_str(&output).to_string(),
+        );
     }
 
-    write!(
-        writer,
-        "{}",
-        DisplayResolutionGraph::new(
-            &resolution,
-            &markers,
-            &no_emit_packages,
-            generate_hashes,
-            include_extras,
-            include_markers || universal,
-            include_annotations,
-            include_index_annotation,
-            annotation_style,
-        )
-    )?;
+    let cached = CachedResolution {
+        packages: resolution.len(),
+        output,
+    };
 
-    // If any "unsafe" packages were excluded, notify the user.
-    let excluded = no_emit_packages
-        .into_iter()
-        .filter(|name| resolution.contains(name))
-        .collect::<Vec<_>>();
-    if !excluded.is_empty() {
-        writeln!(writer)?;
-        writeln!(
-            writer,
-            "{}",
-            "# The following packages were excluded from the output:".green()
-        )?;
-        for package in excluded {
-            writeln!(writer, "# {package}")?;
+    // Write the resolved dependencies to the output channel.
+    write_resolution(
+        &cached,
+        header.as_deref(),
+        output_file,
+        line_ending,
+        quiet,
+        &cache,
+    )
+    .await?;
+
+    // Notify the user of any resolution diagnostics.
+    operations::diagnose_resolution(resolution.diagnostics());
+
+    // Cache the resolution, unless it emitted any diagnostics or warnings. If the output file was
+    // updated, cache the resolution for the updated output file too, such that re-running the same
+    // command (which would now prefer the versions pinned in the output file) reuses the
+    // resolution.
+    if let Some((inputs, existing_output)) =
+        resolution_inputs.filter(|_| resolution.diagnostics().is_empty() && !unused)
+    {
+        let updated_output = output_file.and_then(|path| fs_err::read_to_string(path).ok());
+        let mut outputs = vec![existing_output];
+        if !outputs.contains(&updated_output) {
+            outputs.push(updated_output);
+        }
+        for output in outputs {
+            if let Err(err) = cached
+                .write(&inputs.with_output(output.as_deref()), &cache)
+                .await
+            {
+                warn!("Failed to cache resolution: {err}");
+            }
         }
     }
 
+    Ok(ExitStatus::Success)
+}
+
+/// Write a resolution to the output channel, preceded by the header, if any.
+///
+/// The digest of the written output file is recorded in the cache, such that subsequent runs can
+/// detect whether the file was edited in the interim.
+async fn write_resolution(
+    resolution: &CachedResolution,
+    header: Option<&str>,
+    output_file: Option<&Path>,
+    line_ending: LineEnding,
+    quiet: bool,
+    cache: &Cache,
+) -> Result<()> {
+    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file)
+        .with_line_ending(line_ending);
+    if let Some(header) = header {
+        write!(writer, "{header}")?;
+    }
+    write!(writer, "{}", resolution.output)?;
+
     // Commit the output to disk.
     writer.commit().await?;
 
-    // Notify the user of any resolution diagnostics.
-    operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+    if let Some(output_file) = output_file {
+        if let Ok(contents) = fs_err::read_to_string(output_file) {
+            if let Err(err) = OutputDigest::write(output_file, &contents, cache).await {
+                warn!("Failed to record digest of output file: {err}");
+            }
+        }
+    }
 
-    Ok(ExitStatus::Success)
+    Ok(())
+}
+
+/// Merge the rendered entries of a resolution into the existing contents of a `requirements.txt`
+/// output file.
+///
+/// Entries that are unchanged (including any hashes and annotations) are left untouched, entries
+/// that changed are updated in place, entries for newly-resolved packages are added after the last
+/// existing entry, and entries for packages that are no longer part of the resolution are removed.
+/// Any other lines in the existing file (e.g., comments) are retained, with the exception of the
+/// header, which is rewritten separately.
+fn append_requirements_txt(existing: &str, resolved: &str) -> String {
+    // Strip the header from the existing file.
+    let mut lines = existing.lines().peekable();
+    if lines.next_if(|line| *line == HEADER).is_some() {
+        lines.next_if(|line| line.starts_with("#    "));
+    }
+    let mut merged = split_entries(lines);
+
+    // Group the resolved entries by package, ignoring any other lines (e.g., `--index-url`).
+    let mut resolved_entries: Vec<(PackageName, Vec<Vec<&str>>)> = Vec::new();
+    for (name, entry) in split_entries(resolved.lines()) {
+        let Some(name) = name else {
+            continue;
+        };
+        match resolved_entries
+            .iter_mut()
+            .find(|(existing, _)| *existing == name)
+        {
+            Some((_, entries)) => entries.push(entry),
+            None => resolved_entries.push((name, vec![entry])),
+        }
+    }
+
+    // Drop any entries for packages that are no longer part of the resolution.
+    merged.retain(|(name, _)| {
+        name.as_ref().map_or(true, |name| {
+            resolved_entries
+                .iter()
+                .any(|(resolved, _)| resolved == name)
+        })
+    });
+
+    let mut added = Vec::new();
+    for (name, entries) in resolved_entries {
+        let positions = merged
+            .iter()
+            .positions(|(existing, _)| existing.as_ref() == Some(&name))
+            .collect::<Vec<_>>();
+        let Some(&first) = positions.first() else {
+            added.extend(entries.into_iter().map(|entry| (Some(name.clone()), entry)));
+            continue;
+        };
+
+        // If the package's entries are unchanged, retain the existing entries.
+        let normalize = |entry: &Vec<&str>| {
+            entry
+                .iter()
+                .map(|line| line.trim_end().to_string())
+                .collect::<Vec<_>>()
+        };
+        let current_entries = positions
+            .iter()
+            .map(|&position| normalize(&merged[position].1))
+            .collect::<BTreeSet<_>>();
+        let new_entries = entries.iter().map(normalize).collect::<BTreeSet<_>>();
+        if current_entries == new_entries {
+            continue;
+        }
+
+        // Otherwise, replace the existing entries with the resolved entries.
+        for &position in positions.iter().rev() {
+            merged.remove(position);
+        }
+        merged.splice(
+            first..first,
+            entries.into_iter().map(|entry| (Some(name.clone()), entry)),
+        );
+    }
+
+    // Add any new entries after the last existing entry.
+    let end = merged
+        .iter()
+        .rposition(|(name, _)| name.is_some())
+        .map_or(merged.len(), |position| position + 1);
+    merged.splice(end..end, added);
+
+    merged
+        .into_iter()
+        .flat_map(|(_, entry)| entry)
+        .fold(String::new(), |mut output, line| {
+            output.push_str(line);
+            output.push('\n');
+            output
+        })
+}
+
+/// Split the lines of a `requirements.txt` file into entries, where each requirement spans its
+/// own line along with any indented lines that follow it (e.g., hashes and annotations).
+///
+/// Requirements are returned along with the name of the package; any other line (e.g., a comment
+/// or an option) is returned as its own entry, without a name.
+fn split_entries<'a>(
+    lines: impl Iterator<Item = &'a str>,
+) -> Vec<(Option<PackageName>, Vec<&'a str>)> {
+    let mut entries: Vec<(Option<PackageName>, Vec<&'a str>)> = Vec::new();
+    for line in lines {
+        if line.starts_with(char::is_whitespace) && !line.trim().is_empty() {
+            if let Some((Some(_), entry)) = entries.last_mut() {
+                entry.push(line);
+                continue;
+            }
+        }
+        let name = line
+            .split(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))
+            .next()
+            .filter(|name| !name.is_empty())
+            .and_then(|name| PackageName::new(name.to_string()).ok());
+        entries.push((name, vec![line]));
+    }
+    entries
 }
 
 /// Format the uv command used to generate the output file.
@@ -592,27 +1136,31 @@ fn cmd(
                 }
             }
 
-            // Always skip the `--upgrade` flag.
-            if arg == "--upgrade" || arg == "-U" {
+            // Always skip the `--upgrade` and `--force` flags.
+            if arg == "--upgrade" || arg == "-U" || arg == "--force" {
                 *skip_next = None;
                 return Some(None);
             }
 
-            // Always skip the `--upgrade-package` and mark the next item to be skipped
-            if arg == "--upgrade-package" || arg == "-P" {
+            // Always skip the `--upgrade-package` and `--upgrade-strategy` options, and mark the
+            // next item to be skipped
+            if arg == "--upgrade-package" || arg == "-P" || arg == "--upgrade-strategy" {
                 *skip_next = Some(true);
                 return Some(None);
             }
 
             // Skip only this argument if option and value are together
-            if arg.starts_with("--upgrade-package=") || arg.starts_with("-P") {
+            if arg.starts_with("--upgrade-package=")
+                || arg.starts_with("-P")
+                || arg.starts_with("--upgrade-strategy=")
+            {
                 // Reset state; skip this iteration.
                 *skip_next = None;
                 return Some(None);
             }
 
             // Always skip the `--quiet` flag.
-            if arg == "--quiet" || arg == "-q" {
+            if arg == "--quiet" || arg == "-q" || arg == "-qq" {
                 *skip_next = None;
                 return Some(None);
             }
@@ -8,6 +8,7 @@ use uv_cache::Cache;
 use uv_distribution_types::{Diagnostic, InstalledDist, Name};
 use uv_installer::SitePackages;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_warnings::warn_user;
 
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::ExitStatus;
@@ -15,7 +16,7 @@ use crate::printer::Printer;
 
 /// Enumerate the installed packages in the current environment.
 pub(crate) fn pip_freeze(
-    exclude_editable: bool,
+    editable: Option<bool>,
     strict: bool,
     python: Option<&str>,
     system: bool,
@@ -33,9 +34,11 @@ pub(crate) fn pip_freeze(
 
     // Build the installed index.
     let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Filter if `--editable-only` or `--exclude-editable` is specified.
     for dist in site_packages
         .iter()
-        .filter(|dist| !(exclude_editable && dist.is_editable()))
+        .filter(|dist| editable.is_none() || editable == Some(dist.is_editable()))
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
     {
         match dist {
@@ -67,13 +70,7 @@ pub(crate) fn pip_freeze(
         let markers = environment.interpreter().resolver_markers();
 
         for diagnostic in site_packages.diagnostics(&markers)? {
-            writeln!(
-                printer.stderr(),
-                "{}{} {}",
-                "warning".yellow().bold(),
-                ":".bold(),
-                diagnostic.message().bold()
-            )?;
+            warn_user!("{}", diagnostic.message());
         }
     }
 
@@ -1,41 +1,55 @@
+use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tracing::{debug, enabled, Level};
 
 use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
-    IndexStrategy, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    AllowYanked, BuildOptions, Concurrency, ConfigSettings, Constraints, DryRun,
+    ExtrasSpecification, HashCheckingMode, IndexStrategy, Reinstall, SourceStrategy, TrustedHost,
+    Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
-use uv_dispatch::BuildDispatch;
+use uv_dispatch::{BuildDispatch, BuildLock};
 use uv_distribution_types::{
-    DependencyMetadata, IndexLocations, NameRequirementSpecification, Resolution,
-    UnresolvedRequirementSpecification,
+    DependencyMetadata, IndexLocations, IndexUrl, NameRequirementSpecification, Resolution,
+    UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
-use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::linker::{DistInfoMode, LinkMode};
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_pep508::PackageName;
 use uv_pypi_types::Requirement;
 use uv_python::{
-    EnvironmentPreference, Prefix, PythonEnvironment, PythonRequest, PythonVersion, Target,
+    EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
+    PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverMarkers,
+    Denylist, DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PackageResolutionMode,
+    PackageResolutionModes, PrereleaseMode, PythonRequirement, ResolutionBudget, ResolutionMode,
+    ResolverMarkers,
 };
 use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
 
-use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
+use crate::commands::pip::loggers::{
+    DefaultInstallLogger, DefaultResolveLogger, InstallLogger, ResolveLogger, Timings,
+    TimingsInstallLogger, TimingsResolveLogger,
+};
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::pip::operations::Modifications;
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    operations, resolution_markers, resolution_tags, should_compile_bytecode,
+};
 use crate::commands::{diagnostics, ExitStatus, SharedState};
 use crate::printer::Printer;
 
@@ -46,14 +60,26 @@ pub(crate) async fn pip_install(
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
+    max_include_depth: usize,
+    build_lock: Option<&Path>,
     constraints_from_workspace: Vec<Requirement>,
     overrides_from_workspace: Vec<Requirement>,
+    override_add: bool,
     extras: &ExtrasSpecification,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
+    allow_yanked: AllowYanked,
+    allowed_packages: Option<BTreeSet<PackageName>>,
+    denied: Denylist,
+    resolution_packages: Vec<PackageResolutionMode>,
+    strict_extras: bool,
+    resolution_budget: ResolutionBudget,
+    constrain_installed: bool,
+    ignore_installed: bool,
     index_locations: IndexLocations,
+    index_mirror: Vec<IndexUrl>,
     index_strategy: IndexStrategy,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
@@ -61,7 +87,9 @@ pub(crate) async fn pip_install(
     reinstall: Reinstall,
     link_mode: LinkMode,
     compile: bool,
+    compile_concurrency: Option<NonZeroUsize>,
     hash_checking: Option<HashCheckingMode>,
+    infer_require_hashes: bool,
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
     no_build_isolation: bool,
@@ -74,17 +102,36 @@ pub(crate) async fn pip_install(
     sources: SourceStrategy,
     python: Option<String>,
     system: bool,
+    python_preference: PythonPreference,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    dist_info: DistInfoMode,
     concurrency: Concurrency,
     native_tls: bool,
     cache: Cache,
     dry_run: bool,
+    verify_install: bool,
+    venv: Option<PathBuf>,
+    timings: bool,
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     let start = std::time::Instant::now();
 
+    // Each package may be assigned at most one resolution mode.
+    let resolution_modes = PackageResolutionModes::from_args(resolution_packages)?;
+
+    // Omitting the `.dist-info` directories breaks uninstall tracking (and so is only supported for
+    // `--target` and `--prefix` directories).
+    if dist_info == DistInfoMode::Omit {
+        warn_user!(
+            "`--no-dist-info` omits package metadata; the installed packages can't be detected, upgraded, or uninstalled"
+        );
+    }
+
+    // If requested, record the time spent in each phase.
+    let timings = timings.then(|| Arc::new(Timings::default()));
+
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
@@ -92,6 +139,23 @@ pub(crate) async fn pip_install(
         .allow_insecure_host(allow_insecure_host);
 
     // Read all requirements from the provided sources.
+    let spec = operations::read_requirements(
+        requirements,
+        constraints,
+        overrides,
+        extras,
+        strict,
+        max_include_depth,
+        &client_builder,
+    )
+    .await?;
+
+    // In `--strict` mode, reject project dependencies that contradict the workspace constraints
+    // up-front, rather than failing (or backtracking) during resolution.
+    if strict {
+        spec.check_constraints(&constraints_from_workspace)?;
+    }
+
     let RequirementsSpecification {
         project,
         requirements,
@@ -105,19 +169,47 @@ pub(crate) async fn pip_install(
         no_binary,
         no_build,
         extras: _,
-    } = operations::read_requirements(
-        requirements,
-        constraints,
-        overrides,
-        extras,
-        &client_builder,
-    )
-    .await?;
+        no_deps,
+        config_settings: config_settings_package,
+        prerelease,
+        requires_python: _,
+        project_dependencies: _,
+        require_hashes,
+    } = spec;
+
+    // As in pip, if any requirement includes a hash, require hashes for every requirement, unless
+    // hash-checking was explicitly configured.
+    let hash_checking = if hash_checking.is_none() && infer_require_hashes && require_hashes {
+        debug!("Enabling `--require-hashes`, since at least one requirement includes a hash");
+        Some(HashCheckingMode::Require)
+    } else {
+        hash_checking
+    };
 
     // Read build constraints.
     let build_constraints =
         operations::read_constraints(build_constraints, &client_builder).await?;
 
+    if let Some(timings) = timings.as_ref() {
+        timings.on_parse(start);
+    }
+
+    // Track the user-provided constraints and overrides, to report any that don't apply to the
+    // resolution.
+    let user_constraints = constraints
+        .iter()
+        .map(|entry| entry.requirement.clone())
+        .collect::<Vec<_>>();
+    let user_overrides = overrides
+        .iter()
+        .filter_map(|entry| match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => Some(requirement.clone()),
+            UnresolvedRequirement::Unnamed(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    // The `[tool.uv]` constraints and overrides may be read from both a `pyproject.toml` source
+    // and the discovered configuration, so drop any duplicates.
     let constraints: Vec<NameRequirementSpecification> = constraints
         .iter()
         .cloned()
@@ -126,6 +218,7 @@ pub(crate) async fn pip_install(
                 .into_iter()
                 .map(NameRequirementSpecification::from),
         )
+        .unique()
         .collect();
 
     let overrides: Vec<UnresolvedRequirementSpecification> = overrides
@@ -136,17 +229,62 @@ pub(crate) async fn pip_install(
                 .into_iter()
                 .map(UnresolvedRequirementSpecification::from),
         )
+        .unique()
         .collect();
 
-    // Detect the current Python interpreter.
-    let environment = PythonEnvironment::find(
-        &python
-            .as_deref()
-            .map(PythonRequest::parse)
-            .unwrap_or_default(),
-        EnvironmentPreference::from_system_flag(system, true),
-        &cache,
-    )?;
+    // If requested, treat each override as a requirement, such that overridden packages are
+    // included in the resolution even if nothing else depends on them.
+    let requirements = if override_add {
+        requirements
+            .into_iter()
+            .chain(overrides.iter().cloned())
+            .collect()
+    } else {
+        requirements
+    };
+
+    let python_request = python
+        .as_deref()
+        .map(PythonRequest::parse)
+        .unwrap_or_default();
+
+    let environment = if let Some(venv) = venv {
+        // Use the virtual environment at the given path, creating it if necessary.
+        if venv.exists() {
+            PythonEnvironment::from_root(&venv, &cache)?
+        } else {
+            let interpreter = PythonInstallation::find(
+                &python_request,
+                EnvironmentPreference::OnlySystem,
+                python_preference,
+                &cache,
+            )?
+            .into_interpreter();
+
+            writeln!(
+                printer.stderr(),
+                "Creating virtual environment at: {}",
+                venv.user_display().cyan()
+            )?;
+
+            uv_virtualenv::create_venv(
+                &venv,
+                interpreter,
+                uv_virtualenv::Prompt::None,
+                false,
+                false,
+                false,
+                false,
+            )?
+        }
+    } else {
+        // Detect the current Python interpreter.
+        PythonEnvironment::find(
+            &python_request,
+            EnvironmentPreference::from_system_flag(system, true),
+            &cache,
+        )?
+    };
 
     report_target_environment(&environment, &cache, printer)?;
 
@@ -197,8 +335,14 @@ pub(crate) async fn pip_install(
         interpreter,
     );
 
-    // Determine the set of installed packages.
-    let site_packages = SitePackages::from_environment(&environment)?;
+    // Determine the set of installed packages. Unless `--ignore-installed` is set, this includes
+    // any packages in the base interpreter's `site-packages`, for virtual environments created with
+    // `--system-site-packages`.
+    let site_packages = if ignore_installed {
+        SitePackages::from_environment(&environment)?
+    } else {
+        SitePackages::from_environment(&environment)?.with_system_site_packages(&environment)?
+    };
 
     // Check if the current environment satisfies the requirements.
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
@@ -222,6 +366,9 @@ pub(crate) async fn pip_install(
                 if dry_run {
                     writeln!(printer.stderr(), "Would make no changes")?;
                 }
+                if let Some(timings) = timings.as_ref() {
+                    timings.report(printer)?;
+                }
                 return Ok(ExitStatus::Success);
             }
             SatisfiesResult::Unsatisfied(requirement) => {
@@ -258,7 +405,34 @@ pub(crate) async fn pip_install(
             hash_checking,
         )?
     } else {
-        HashStrategy::None
+        // Even without `--require-hashes` or `--verify-hashes`, verify any hashes that are
+        // embedded in a direct URL.
+        HashStrategy::from_url_fragments(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| &entry.requirement),
+            Some(&markers),
+        )?
+    };
+
+    // If requested, constrain the resolution by the requirements of the installed packages,
+    // ignoring those of the packages that are being installed or upgraded.
+    let constraints = if constrain_installed {
+        let mut exclude = requirements
+            .iter()
+            .filter_map(|entry| match &entry.requirement {
+                UnresolvedRequirement::Named(requirement) => Some(&requirement.name),
+                UnresolvedRequirement::Unnamed(_) => None,
+            })
+            .collect::<FxHashSet<_>>();
+        if let Upgrade::Packages(packages) = &upgrade {
+            exclude.extend(packages.keys());
+        }
+        let installed = site_packages.installed_constraints(&markers, &exclude)?;
+        constraints.into_iter().chain(installed).collect()
+    } else {
+        constraints
     };
 
     // When resolving, don't take any external preferences into account.
@@ -279,7 +453,7 @@ pub(crate) async fn pip_install(
     // Initialize the registry client.
     let client = RegistryClientBuilder::try_from(client_builder)?
         .cache(cache.clone())
-        .index_urls(index_locations.index_urls())
+        .index_urls(index_locations.index_urls().with_mirrors(index_mirror))
         .index_strategy(index_strategy)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
@@ -327,6 +501,9 @@ pub(crate) async fn pip_install(
     // Initialize any shared state.
     let state = SharedState::default();
 
+    // Read the pinned build requirements, if any.
+    let build_pins = build_lock.map(BuildLock::read).transpose()?;
+
     // Create a build dispatch.
     let build_dispatch = BuildDispatch::new(
         &client,
@@ -349,16 +526,28 @@ pub(crate) async fn pip_install(
         exclude_newer,
         sources,
         concurrency,
-    );
+    )
+    .with_config_settings_package(config_settings_package)
+    .with_build_lock(build_pins.as_ref());
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
+        .resolution_modes(resolution_modes)
         .prerelease_mode(prerelease_mode)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
+        .budget(resolution_budget)
         .build();
 
+    let resolve_logger: Box<dyn ResolveLogger> = match timings.as_ref() {
+        Some(timings) => Box::new(TimingsResolveLogger::new(
+            Box::new(DefaultResolveLogger),
+            timings.clone(),
+        )),
+        None => Box::new(DefaultResolveLogger),
+    };
+
     // Resolve the requirements.
     let resolution = match operations::resolve(
         requirements,
@@ -368,12 +557,17 @@ pub(crate) async fn pip_install(
         source_trees,
         project,
         None,
+        no_deps,
+        prerelease,
         extras,
         preferences,
         site_packages.clone(),
         &hasher,
         &reinstall,
         &upgrade,
+        &allow_yanked,
+        allowed_packages.as_ref(),
+        &denied,
         Some(&tags),
         ResolverMarkers::specific_environment(markers.clone()),
         python_requirement,
@@ -383,7 +577,7 @@ pub(crate) async fn pip_install(
         &build_dispatch,
         concurrency,
         options,
-        Box::new(DefaultResolveLogger),
+        resolve_logger,
         printer,
     )
     .await
@@ -404,6 +598,29 @@ pub(crate) async fn pip_install(
         Err(err) => return Err(err.into()),
     };
 
+    // If requested, reject any extras that aren't declared by the resolved packages.
+    if strict_extras {
+        operations::validate_extras(resolution.diagnostics())?;
+    }
+
+    // Report any constraints or overrides that don't apply to the resolution, or reject them in
+    // `--strict` mode.
+    operations::validate_unused(
+        &user_constraints,
+        &user_overrides,
+        |name| resolution.packages().any(|package| package == name),
+        Some(&*markers),
+        strict,
+    )?;
+
+    let install_logger: Box<dyn InstallLogger> = match timings.as_ref() {
+        Some(timings) => Box::new(TimingsInstallLogger::new(
+            Box::new(DefaultInstallLogger),
+            timings.clone(),
+        )),
+        None => Box::new(DefaultInstallLogger),
+    };
+
     // Sync the environment.
     operations::install(
         &resolution,
@@ -412,7 +629,10 @@ pub(crate) async fn pip_install(
         &reinstall,
         &build_options,
         link_mode,
-        compile,
+        dist_info,
+        verify_install,
+        should_compile_bytecode(compile, python_version.as_ref(), interpreter),
+        compile_concurrency,
         &index_locations,
         config_settings,
         &hasher,
@@ -423,18 +643,30 @@ pub(crate) async fn pip_install(
         &build_dispatch,
         &cache,
         &environment,
-        Box::new(DefaultInstallLogger),
-        dry_run,
+        install_logger,
+        DryRun::from_args(dry_run, false),
         printer,
     )
     .await?;
 
+    // Record the build requirements used for any source distributions.
+    if let (Some(path), Some(build_pins)) = (build_lock, build_pins.as_ref()) {
+        if !dry_run {
+            build_pins.write(path)?;
+        }
+    }
+
     // Notify the user of any resolution diagnostics.
-    operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+    operations::diagnose_resolution(resolution.diagnostics());
 
     // Notify the user of any environment diagnostics.
     if strict && !dry_run {
-        operations::diagnose_environment(&resolution, &environment, &markers, printer)?;
+        operations::diagnose_environment(&resolution, &environment, &markers)?;
+    }
+
+    // Report the time spent in each phase.
+    if let Some(timings) = timings.as_ref() {
+        timings.report(printer)?;
     }
 
     Ok(ExitStatus::Success)
@@ -15,6 +15,7 @@ use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_python::PythonRequest;
 use uv_python::{EnvironmentPreference, PythonEnvironment};
+use uv_warnings::warn_user;
 
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::ExitStatus;
@@ -119,13 +120,7 @@ pub(crate) fn pip_list(
         let markers = environment.interpreter().resolver_markers();
 
         for diagnostic in site_packages.diagnostics(&markers)? {
-            writeln!(
-                printer.stderr(),
-                "{}{} {}",
-                "warning".yellow().bold(),
-                ":".bold(),
-                diagnostic.message().bold()
-            )?;
+            warn_user!("{}", diagnostic.message());
         }
     }
 
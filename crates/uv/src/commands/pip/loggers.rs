@@ -1,12 +1,14 @@
 use std::collections::BTreeSet;
 use std::fmt;
 use std::fmt::Write;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
-use uv_distribution_types::{InstalledMetadata, Name};
+use uv_distribution_types::{BuildableSource, CachedDist, InstalledMetadata, Name};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 
@@ -35,6 +37,11 @@ pub(crate) trait InstallLogger {
 
     /// Log the completion of the operation.
     fn on_complete(&self, changelog: &Changelog, printer: Printer) -> fmt::Result;
+
+    /// Return the [`Timings`] to which the operation should record its phases, if any.
+    fn timings(&self) -> Option<&Arc<Timings>> {
+        None
+    }
 }
 
 /// The default logger for install operations.
@@ -399,6 +406,11 @@ pub(crate) trait ResolveLogger {
     /// Log the completion of the operation.
     fn on_complete(&self, count: usize, start: std::time::Instant, printer: Printer)
         -> fmt::Result;
+
+    /// Return the [`Timings`] to which the operation should record its phases, if any.
+    fn timings(&self) -> Option<&Arc<Timings>> {
+        None
+    }
 }
 
 /// The default logger for resolve operations.
@@ -448,3 +460,186 @@ impl ResolveLogger for SummaryResolveLogger {
         Ok(())
     }
 }
+
+/// The time spent in each phase of an operation, as reported via `--timings`.
+///
+/// Each phase is recorded where it runs: the loggers record the overall phases of the operation,
+/// while the resolver and preparer reporters record the time spent fetching metadata, solving, and
+/// building, along with whether each distribution was served from the cache.
+#[derive(Debug, Default)]
+pub(crate) struct Timings(Mutex<PhaseTimings>);
+
+#[derive(Debug, Default)]
+struct PhaseTimings {
+    /// The time spent reading and parsing the requirements.
+    parse: Option<Duration>,
+    /// The time spent resolving the requirements.
+    resolve: Option<Duration>,
+    /// The time the solver spent waiting for metadata to be fetched.
+    fetch: Option<Duration>,
+    /// The time the solver spent solving, excluding the time spent waiting for metadata.
+    solve: Option<Duration>,
+    /// The cumulative time spent building source distributions.
+    build: Option<Duration>,
+    /// The start times of any in-progress builds.
+    builds: FxHashMap<String, Instant>,
+    /// The time spent downloading and building distributions.
+    prepare: Option<Duration>,
+    /// The time spent removing existing distributions.
+    uninstall: Option<Duration>,
+    /// The time spent installing distributions into the environment.
+    install: Option<Duration>,
+    /// The packages that were downloaded or built while preparing distributions.
+    fetched: FxHashSet<PackageName>,
+    /// The number of distributions that were served from the cache.
+    hits: usize,
+    /// The number of distributions that had to be downloaded or built.
+    misses: usize,
+}
+
+impl Timings {
+    fn phases(&self) -> MutexGuard<'_, PhaseTimings> {
+        self.0.lock().unwrap()
+    }
+
+    /// Record the completion of the requirements parsing phase.
+    pub(crate) fn on_parse(&self, start: Instant) {
+        self.phases().parse = Some(start.elapsed());
+    }
+
+    /// Record the time the solver spent solving, and waiting for metadata to be fetched.
+    pub(crate) fn on_solve(&self, solve: Duration, fetch: Duration) {
+        let mut phases = self.phases();
+        phases.solve = Some(phases.solve.unwrap_or_default() + solve);
+        phases.fetch = Some(phases.fetch.unwrap_or_default() + fetch);
+    }
+
+    /// Record the start of a source distribution build.
+    pub(crate) fn on_build_start(&self, source: &BuildableSource) {
+        self.phases()
+            .builds
+            .insert(source.to_string(), Instant::now());
+    }
+
+    /// Record the completion of a source distribution build.
+    pub(crate) fn on_build_complete(&self, source: &BuildableSource) {
+        let mut phases = self.phases();
+        if let Some(start) = phases.builds.remove(&source.to_string()) {
+            phases.build = Some(phases.build.unwrap_or_default() + start.elapsed());
+        }
+    }
+
+    /// Record that a package was downloaded or built while preparing distributions.
+    pub(crate) fn on_fetch(&self, name: &PackageName) {
+        self.phases().fetched.insert(name.clone());
+    }
+
+    /// Record that a distribution was prepared, counting it as a cache hit unless it had to be
+    /// downloaded or built.
+    pub(crate) fn on_prepared(&self, dist: &CachedDist) {
+        let mut phases = self.phases();
+        if phases.fetched.contains(dist.name()) {
+            phases.misses += 1;
+        } else {
+            phases.hits += 1;
+        }
+    }
+
+    /// Record distributions that were already available in the cache, and so didn't need to be
+    /// prepared.
+    pub(crate) fn on_cached(&self, count: usize) {
+        self.phases().hits += count;
+    }
+
+    /// Report the time spent in each phase, along with the number of cache hits and misses.
+    pub(crate) fn report(&self, printer: Printer) -> fmt::Result {
+        let phases = self.phases();
+        writeln!(printer.stderr(), "{}", "Timings:".bold())?;
+        for (phase, duration) in [
+            ("Parsing requirements", phases.parse),
+            ("Resolving", phases.resolve),
+            ("  Fetching metadata", phases.fetch),
+            ("  Solving", phases.solve),
+            ("Building", phases.build),
+            ("Downloading and building", phases.prepare),
+            ("Uninstalling", phases.uninstall),
+            ("Installing", phases.install),
+        ] {
+            if let Some(duration) = duration {
+                writeln!(printer.stderr(), "  {phase}: {}", elapsed(duration))?;
+            }
+        }
+        writeln!(
+            printer.stderr(),
+            "  Cache: {} hit{}, {} miss{}",
+            phases.hits,
+            if phases.hits == 1 { "" } else { "s" },
+            phases.misses,
+            if phases.misses == 1 { "" } else { "es" },
+        )
+    }
+}
+
+/// An install logger that records the time spent in each phase, delegating to another logger.
+pub(crate) struct TimingsInstallLogger {
+    inner: Box<dyn InstallLogger>,
+    timings: Arc<Timings>,
+}
+
+impl TimingsInstallLogger {
+    pub(crate) fn new(inner: Box<dyn InstallLogger>, timings: Arc<Timings>) -> Self {
+        Self { inner, timings }
+    }
+}
+
+impl InstallLogger for TimingsInstallLogger {
+    fn on_audit(&self, count: usize, start: Instant, printer: Printer) -> fmt::Result {
+        self.inner.on_audit(count, start, printer)
+    }
+
+    fn on_prepare(&self, count: usize, start: Instant, printer: Printer) -> fmt::Result {
+        self.timings.phases().prepare = Some(start.elapsed());
+        self.inner.on_prepare(count, start, printer)
+    }
+
+    fn on_uninstall(&self, count: usize, start: Instant, printer: Printer) -> fmt::Result {
+        self.timings.phases().uninstall = Some(start.elapsed());
+        self.inner.on_uninstall(count, start, printer)
+    }
+
+    fn on_install(&self, count: usize, start: Instant, printer: Printer) -> fmt::Result {
+        self.timings.phases().install = Some(start.elapsed());
+        self.inner.on_install(count, start, printer)
+    }
+
+    fn on_complete(&self, changelog: &Changelog, printer: Printer) -> fmt::Result {
+        self.inner.on_complete(changelog, printer)
+    }
+
+    fn timings(&self) -> Option<&Arc<Timings>> {
+        Some(&self.timings)
+    }
+}
+
+/// A resolve logger that records the time spent resolving, delegating to another logger.
+pub(crate) struct TimingsResolveLogger {
+    inner: Box<dyn ResolveLogger>,
+    timings: Arc<Timings>,
+}
+
+impl TimingsResolveLogger {
+    pub(crate) fn new(inner: Box<dyn ResolveLogger>, timings: Arc<Timings>) -> Self {
+        Self { inner, timings }
+    }
+}
+
+impl ResolveLogger for TimingsResolveLogger {
+    fn on_complete(&self, count: usize, start: Instant, printer: Printer) -> fmt::Result {
+        self.timings.phases().resolve = Some(start.elapsed());
+        self.inner.on_complete(count, start, printer)
+    }
+
+    fn timings(&self) -> Option<&Arc<Timings>> {
+        Some(&self.timings)
+    }
+}
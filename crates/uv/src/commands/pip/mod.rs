@@ -4,6 +4,7 @@ use uv_configuration::TargetTriple;
 use uv_platform_tags::{Tags, TagsError};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
+use uv_warnings::warn_user;
 
 pub(crate) mod check;
 pub(crate) mod compile;
@@ -12,10 +13,12 @@ pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
+pub(crate) mod resolution_cache;
 pub(crate) mod show;
 pub(crate) mod sync;
 pub(crate) mod tree;
 pub(crate) mod uninstall;
+pub(crate) mod why;
 
 pub(crate) fn resolution_markers(
     python_version: Option<&PythonVersion>,
@@ -70,6 +73,37 @@ pub(crate) fn resolution_tags<'env>(
     })
 }
 
+/// Determine whether to bytecode-compile the installed packages.
+///
+/// Bytecode is compiled by the environment's interpreter, so if the target Python version (as
+/// provided via `--python-version`) differs from that of the interpreter, the compiled files would
+/// be unusable (due to a mismatched magic number). In that case, compilation is skipped.
+pub(crate) fn should_compile_bytecode(
+    compile: bool,
+    python_version: Option<&PythonVersion>,
+    interpreter: &Interpreter,
+) -> bool {
+    if !compile {
+        return false;
+    }
+    let Some(python_version) = python_version else {
+        return true;
+    };
+    if (python_version.major(), python_version.minor())
+        == (interpreter.python_major(), interpreter.python_minor())
+    {
+        return true;
+    }
+    warn_user!(
+        "Skipping bytecode compilation, since the target Python version ({}.{}) differs from that of the interpreter ({}.{}); use `--python` to select a matching interpreter instead",
+        python_version.major(),
+        python_version.minor(),
+        interpreter.python_major(),
+        interpreter.python_minor(),
+    );
+    false
+}
+
 /// Determine the tags, markers, and interpreter to use for resolution.
 pub(crate) fn resolution_environment(
     python_version: Option<PythonVersion>,
@@ -5,6 +5,7 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 use std::collections::{BTreeSet, HashSet};
 use std::fmt::Write;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use tracing::debug;
 use uv_tool::InstalledTools;
@@ -12,8 +13,8 @@ use uv_tool::InstalledTools;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, Overrides,
-    Reinstall, Upgrade,
+    AllowYanked, BuildOptions, Concurrency, ConfigSettings, Constraints, DryRun,
+    ExtrasSpecification, Overrides, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -25,18 +26,21 @@ use uv_distribution_types::{
     DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution,
 };
 use uv_fs::Simplified;
-use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::linker::{DistInfoMode, LinkMode};
 use uv_installer::{Plan, Planner, Preparer, SitePackages};
 use uv_normalize::{GroupName, PackageName};
+use uv_pep440::Operator;
+use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Tags;
-use uv_pypi_types::ResolverMarkerEnvironment;
+use uv_pypi_types::{Requirement, RequirementSource, ResolverMarkerEnvironment};
 use uv_python::PythonEnvironment;
 use uv_requirements::{
     LookaheadResolver, NamedRequirementsResolver, RequirementsSource, RequirementsSpecification,
     SourceTreeResolver,
 };
+use uv_requirements_txt::DEFAULT_MAX_INCLUDE_DEPTH;
 use uv_resolver::{
-    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
+    Denylist, DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
     Preferences, PythonRequirement, ResolutionGraph, Resolver, ResolverMarkers,
 };
 use uv_types::{HashStrategy, InFlight, InstalledPackagesProvider};
@@ -53,25 +57,37 @@ pub(crate) async fn read_requirements(
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     extras: &ExtrasSpecification,
+    strict: bool,
+    max_include_depth: usize,
     client_builder: &BaseClientBuilder<'_>,
 ) -> Result<RequirementsSpecification, Error> {
+    // Read all requirements from the provided sources.
+    let mut spec = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        overrides,
+        strict,
+        max_include_depth,
+        client_builder,
+    )
+    .await?;
+
+    // Apply the requested extras to any requirement on the current project (e.g., `.[dev]`).
+    let self_reference = spec.apply_project_extras(extras)?;
+
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
     // return an error.
-    if !extras.is_empty() && !requirements.iter().any(RequirementsSource::allows_extras) {
+    if !extras.is_empty()
+        && !self_reference
+        && !requirements.iter().any(RequirementsSource::allows_extras)
+    {
         return Err(anyhow!(
             "Requesting extras requires a `pyproject.toml`, `setup.cfg`, or `setup.py` file."
         )
         .into());
     }
 
-    // Read all requirements from the provided sources.
-    Ok(RequirementsSpecification::from_sources(
-        requirements,
-        constraints,
-        overrides,
-        client_builder,
-    )
-    .await?)
+    Ok(spec)
 }
 
 /// Resolve a set of constraints.
@@ -79,11 +95,47 @@ pub(crate) async fn read_constraints(
     constraints: &[RequirementsSource],
     client_builder: &BaseClientBuilder<'_>,
 ) -> Result<Vec<NameRequirementSpecification>, Error> {
-    Ok(
-        RequirementsSpecification::from_sources(&[], constraints, &[], client_builder)
-            .await?
-            .constraints,
+    Ok(RequirementsSpecification::from_sources(
+        &[],
+        constraints,
+        &[],
+        false,
+        DEFAULT_MAX_INCLUDE_DEPTH,
+        client_builder,
     )
+    .await?
+    .constraints)
+}
+
+/// Read a set of frozen packages, each of which must be pinned to an exact version.
+///
+/// Frozen packages are enforced as constraints, such that each is held at its pinned version
+/// without otherwise being added to the resolution.
+pub(crate) async fn read_frozen(
+    frozen: &[RequirementsSource],
+    client_builder: &BaseClientBuilder<'_>,
+) -> Result<Vec<NameRequirementSpecification>, Error> {
+    let frozen = read_constraints(frozen, client_builder).await?;
+    for entry in &frozen {
+        let is_pinned = match &entry.requirement.source {
+            RequirementSource::Registry { specifier, .. } => {
+                specifier.len() == 1
+                    && specifier.iter().all(|specifier| {
+                        matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+                    })
+            }
+            _ => false,
+        };
+        if !is_pinned {
+            return Err(anyhow!(
+                "Frozen packages must be pinned to an exact version (e.g., `{}==1.0.0`), but found: `{}`",
+                entry.requirement.name,
+                entry.requirement
+            )
+            .into());
+        }
+    }
+    Ok(frozen)
 }
 
 /// Resolve a set of requirements, similar to running `pip compile`.
@@ -95,12 +147,17 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     source_trees: Vec<PathBuf>,
     mut project: Option<PackageName>,
     workspace_members: Option<BTreeSet<PackageName>>,
+    no_deps: BTreeSet<PackageName>,
+    prerelease: BTreeSet<PackageName>,
     extras: &ExtrasSpecification,
     preferences: Vec<Preference>,
     installed_packages: InstalledPackages,
     hasher: &HashStrategy,
     reinstall: &Reinstall,
     upgrade: &Upgrade,
+    allow_yanked: &AllowYanked,
+    allowed: Option<&BTreeSet<PackageName>>,
+    denied: &Denylist,
     tags: Option<&Tags>,
     markers: ResolverMarkers,
     python_requirement: PythonRequirement,
@@ -130,6 +187,16 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                     }
                 });
 
+        // Skip any unnamed requirements (e.g., `-e ./pkg ; python_version >= '3.9'`) whose markers
+        // don't apply to the target environment, rather than building them to infer their names.
+        // In universal mode, all unnamed requirements are retained.
+        let unnamed = unnamed
+            .into_iter()
+            .filter(|requirement| {
+                requirement.evaluate_optional_environment(markers.marker_environment(), &[])
+            })
+            .collect::<Vec<_>>();
+
         // Resolve any unnamed requirements.
         if !unnamed.is_empty() {
             requirements.extend(
@@ -276,7 +343,12 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         workspace_members,
         exclusions,
         lookaheads,
-    );
+    )
+    .with_no_deps(no_deps)
+    .with_allow_yanked(allow_yanked.clone())
+    .with_prerelease(prerelease)
+    .with_allowed(allowed.cloned())
+    .with_denied(denied.clone());
 
     // Resolve the dependencies.
     let resolution = {
@@ -286,7 +358,8 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             DependencyMode::Direct => {
                 ResolverReporter::from(printer).with_length(manifest.num_requirements() as u64)
             }
-        };
+        }
+        .with_timings(logger.timings().cloned());
 
         let resolver = Resolver::new(
             manifest,
@@ -388,7 +461,10 @@ pub(crate) async fn install(
     reinstall: &Reinstall,
     build_options: &BuildOptions,
     link_mode: LinkMode,
+    dist_info: DistInfoMode,
+    verify: bool,
     compile: bool,
+    compile_concurrency: Option<NonZeroUsize>,
     index_urls: &IndexLocations,
     config_settings: &ConfigSettings,
     hasher: &HashStrategy,
@@ -400,7 +476,7 @@ pub(crate) async fn install(
     cache: &Cache,
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
-    dry_run: bool,
+    dry_run: DryRun,
     printer: Printer,
 ) -> Result<Changelog, Error> {
     let start = std::time::Instant::now();
@@ -415,14 +491,18 @@ pub(crate) async fn install(
             hasher,
             index_urls,
             config_settings,
+            build_dispatch.config_settings_package(),
             cache,
             venv,
             tags,
         )
         .context("Failed to determine installation plan")?;
 
-    if dry_run {
-        report_dry_run(resolution, plan, modifications, start, printer)?;
+    if dry_run.enabled() {
+        let changed = report_dry_run(resolution, plan, modifications, start, printer)?;
+        if changed && dry_run == DryRun::Check {
+            return Err(Error::OutOfSync);
+        }
         return Ok(Changelog::default());
     }
 
@@ -439,6 +519,11 @@ pub(crate) async fn install(
         Modifications::Exact => extraneous,
     };
 
+    // Any distributions that the planner found in the cache don't need to be prepared.
+    if let Some(timings) = logger.timings() {
+        timings.on_cached(cached.len());
+    }
+
     // Nothing to do.
     if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty() {
         logger.on_audit(resolution.len(), start, printer)?;
@@ -458,7 +543,11 @@ pub(crate) async fn install(
             build_options,
             DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
         )
-        .with_reporter(PrepareReporter::from(printer).with_length(remote.len() as u64));
+        .with_reporter(
+            PrepareReporter::from(printer)
+                .with_length(remote.len() as u64)
+                .with_timings(logger.timings().cloned()),
+        );
 
         let wheels = preparer
             .prepare(remote.clone(), in_flight)
@@ -516,6 +605,8 @@ pub(crate) async fn install(
         let start = std::time::Instant::now();
         installs = uv_installer::Installer::new(venv)
             .with_link_mode(link_mode)
+            .with_dist_info(dist_info)
+            .with_verify(verify)
             .with_cache(cache)
             .with_reporter(InstallReporter::from(printer).with_length(installs.len() as u64))
             // This technically can block the runtime, but we are on the main thread and
@@ -527,7 +618,7 @@ pub(crate) async fn install(
     }
 
     if compile {
-        compile_bytecode(venv, cache, printer).await?;
+        compile_bytecode(venv, compile_concurrency, cache, printer).await?;
     }
 
     // Construct a summary of the changes made to the environment.
@@ -582,13 +673,15 @@ pub(crate) fn report_target_environment(
 }
 
 /// Report on the results of a dry-run installation.
+///
+/// Returns `true` if the installation would modify the environment.
 fn report_dry_run(
     resolution: &Resolution,
     plan: Plan,
     modifications: Modifications,
     start: std::time::Instant,
     printer: Printer,
-) -> Result<(), Error> {
+) -> Result<bool, Error> {
     let Plan {
         cached,
         remote,
@@ -606,7 +699,7 @@ fn report_dry_run(
     if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty() {
         DefaultInstallLogger.on_audit(resolution.len(), start, printer)?;
         writeln!(printer.stderr(), "Would make no changes")?;
-        return Ok(());
+        return Ok(false);
     }
 
     // Download, build, and unzip any missing distributions.
@@ -706,22 +799,24 @@ fn report_dry_run(
         }
     }
 
-    Ok(())
+    Ok(true)
 }
 
 /// Report any diagnostics on resolved distributions.
-pub(crate) fn diagnose_resolution(
-    diagnostics: &[ResolutionDiagnostic],
-    printer: Printer,
-) -> Result<(), Error> {
+pub(crate) fn diagnose_resolution(diagnostics: &[ResolutionDiagnostic]) {
     for diagnostic in diagnostics {
-        writeln!(
-            printer.stderr(),
-            "{}{} {}",
-            "warning".yellow().bold(),
-            ":".bold(),
-            diagnostic.message().bold()
-        )?;
+        warn_user!("{}", diagnostic.message());
+    }
+}
+
+/// Return an error if any requirement requested an extra that isn't declared by the resolved
+/// version of the package, as with `--strict-extras`.
+pub(crate) fn validate_extras(diagnostics: &[ResolutionDiagnostic]) -> Result<(), Error> {
+    if let Some(diagnostic) = diagnostics
+        .iter()
+        .find(|diagnostic| matches!(diagnostic, ResolutionDiagnostic::MissingExtra { .. }))
+    {
+        return Err(anyhow!("{}", diagnostic.message()).into());
     }
     Ok(())
 }
@@ -731,7 +826,6 @@ pub(crate) fn diagnose_environment(
     resolution: &Resolution,
     venv: &PythonEnvironment,
     markers: &ResolverMarkerEnvironment,
-    printer: Printer,
 ) -> Result<(), Error> {
     let site_packages = SitePackages::from_environment(venv)?;
     for diagnostic in site_packages.diagnostics(markers)? {
@@ -740,18 +834,61 @@ pub(crate) fn diagnose_environment(
             .packages()
             .any(|package| diagnostic.includes(package))
         {
-            writeln!(
-                printer.stderr(),
-                "{}{} {}",
-                "warning".yellow().bold(),
-                ":".bold(),
-                diagnostic.message().bold()
-            )?;
+            warn_user!("{}", diagnostic.message());
         }
     }
     Ok(())
 }
 
+/// Report any constraints or overrides for packages that aren't part of the resolution, which are
+/// likely stale or misspelled.
+///
+/// Constraints and overrides whose markers don't apply to the given environment are ignored. In
+/// `--strict` mode, any unused constraints or overrides are treated as an error.
+///
+/// Returns `true` if any constraints or overrides were reported as unused.
+pub(crate) fn validate_unused(
+    constraints: &[Requirement],
+    overrides: &[Requirement],
+    is_resolved: impl Fn(&PackageName) -> bool,
+    markers: Option<&MarkerEnvironment>,
+    strict: bool,
+) -> Result<bool, Error> {
+    let unused = |requirements: &'_ [Requirement]| {
+        requirements
+            .iter()
+            .filter(|requirement| requirement.evaluate_markers(markers, &[]))
+            .map(|requirement| &requirement.name)
+            .filter(|name| !is_resolved(name))
+            .collect::<BTreeSet<_>>()
+    };
+
+    let messages = [
+        ("Constraint", unused(constraints)),
+        ("Override", unused(overrides)),
+    ]
+    .into_iter()
+    .filter(|(_, names)| !names.is_empty())
+    .map(|(kind, names)| {
+        let names = names.iter().map(|name| format!("`{name}`")).join(", ");
+        if names.contains(", ") {
+            format!("{kind}s for {names} are unused, as they aren't part of the resolution")
+        } else {
+            format!("{kind} for {names} is unused, as it isn't part of the resolution")
+        }
+    })
+    .collect::<Vec<_>>();
+
+    if strict && !messages.is_empty() {
+        return Err(anyhow!("{}", messages.join("\n")).into());
+    }
+
+    for message in &messages {
+        warn_user!("{message}");
+    }
+    Ok(!messages.is_empty())
+}
+
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum Error {
     #[error(transparent)]
@@ -780,4 +917,7 @@ pub(crate) enum Error {
 
     #[error(transparent)]
     PubGrubSpecifier(#[from] uv_resolver::PubGrubSpecifierError),
+
+    #[error("The environment is out of sync with the requirements")]
+    OutOfSync,
 }
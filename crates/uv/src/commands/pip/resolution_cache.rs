@@ -0,0 +1,199 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness};
+use uv_cache_key::cache_digest;
+use uv_distribution_types::UnresolvedRequirement;
+use uv_fs::Simplified;
+use uv_pypi_types::{Requirement, RequirementSource};
+
+/// The output of a `uv pip compile` resolution, cached by a digest of its inputs.
+///
+/// Since the rendered output is a deterministic function of the resolution and the output options
+/// (both of which are captured by the [`ResolutionInputs`]), a cached resolution is reused as-is
+/// when the inputs are unchanged. Resolutions that emit diagnostics (or warnings, e.g., for unused
+/// constraints) are never cached, such that they're always reported.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CachedResolution {
+    /// The number of packages in the resolution.
+    pub(crate) packages: usize,
+    /// The rendered resolution, excluding the header.
+    pub(crate) output: String,
+}
+
+impl CachedResolution {
+    /// Read the cached resolution for the given inputs, if it exists and is fresh.
+    ///
+    /// Cache entries that predate a `--refresh` are treated as stale.
+    pub(crate) fn read(inputs: &ResolutionInputs, cache: &Cache) -> Option<Self> {
+        let cache_entry = Self::cache_entry(inputs, cache);
+        if !cache
+            .freshness(&cache_entry, None)
+            .is_ok_and(Freshness::is_fresh)
+        {
+            return None;
+        }
+        let data = fs_err::read(cache_entry.path()).ok()?;
+        match serde_json::from_slice::<Self>(&data) {
+            Ok(cached) => {
+                debug!(
+                    "Using cached resolution at: {}",
+                    cache_entry.path().user_display()
+                );
+                Some(cached)
+            }
+            Err(err) => {
+                warn!(
+                    "Broken resolution cache entry at {}, removing: {err}",
+                    cache_entry.path().user_display()
+                );
+                let _ = fs_err::remove_file(cache_entry.path());
+                None
+            }
+        }
+    }
+
+    /// Write the resolution to the cache for the given inputs.
+    pub(crate) async fn write(
+        &self,
+        inputs: &ResolutionInputs,
+        cache: &Cache,
+    ) -> anyhow::Result<()> {
+        let cache_entry = Self::cache_entry(inputs, cache);
+        fs_err::tokio::create_dir_all(cache_entry.dir()).await?;
+        uv_fs::write_atomic(cache_entry.path(), serde_json::to_vec(self)?).await?;
+        Ok(())
+    }
+
+    fn cache_entry(inputs: &ResolutionInputs, cache: &Cache) -> CacheEntry {
+        cache.entry(
+            CacheBucket::Resolutions,
+            "",
+            format!("{}.json", cache_digest(&inputs.0)),
+        )
+    }
+}
+
+/// The digest of the contents that `uv pip compile` last wrote to an output file, cached by the
+/// path of the output file.
+///
+/// Used to detect whether an output file was edited since uv generated it.
+pub(crate) struct OutputDigest;
+
+impl OutputDigest {
+    /// Returns `true` if the output file was modified since uv last wrote it.
+    ///
+    /// If uv has no record of writing the file (e.g., because it was generated on another machine,
+    /// or the cache was cleaned), the file is assumed to be unmodified.
+    pub(crate) fn is_modified(path: &Path, contents: &str, cache: &Cache) -> bool {
+        let Some(cache_entry) = Self::cache_entry(path, cache) else {
+            return false;
+        };
+        let Ok(digest) = fs_err::read_to_string(cache_entry.path()) else {
+            return false;
+        };
+        if digest == cache_digest(&contents) {
+            return false;
+        }
+        debug!(
+            "Output file at {} doesn't match the digest at: {}",
+            path.user_display(),
+            cache_entry.path().user_display()
+        );
+        true
+    }
+
+    /// Record the digest of the contents written to the output file.
+    pub(crate) async fn write(path: &Path, contents: &str, cache: &Cache) -> anyhow::Result<()> {
+        let Some(cache_entry) = Self::cache_entry(path, cache) else {
+            return Ok(());
+        };
+        fs_err::tokio::create_dir_all(cache_entry.dir()).await?;
+        uv_fs::write_atomic(cache_entry.path(), cache_digest(&contents)).await?;
+        Ok(())
+    }
+
+    fn cache_entry(path: &Path, cache: &Cache) -> Option<CacheEntry> {
+        let path = fs_err::canonicalize(path).ok()?;
+        Some(cache.entry(CacheBucket::Resolutions, "outputs", cache_digest(&path)))
+    }
+}
+
+/// The named inputs to a `uv pip compile` resolution, used as the cache key for a
+/// [`CachedResolution`].
+///
+/// Each input is stored in its serialized form, and the key is computed with [`cache_digest`],
+/// which is stable across platforms.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolutionInputs(Vec<(String, String)>);
+
+impl ResolutionInputs {
+    /// Create a new set of inputs, scoped to the current version of uv.
+    pub(crate) fn new() -> Self {
+        Self(vec![(
+            "version".to_string(),
+            env!("CARGO_PKG_VERSION").to_string(),
+        )])
+    }
+
+    /// Return the inputs with the contents of the existing output file, if any, from which the
+    /// resolution's preferences are read.
+    pub(crate) fn with_output(&self, contents: Option<&str>) -> Self {
+        let mut inputs = self.clone();
+        inputs.0.push((
+            "output".to_string(),
+            contents.unwrap_or_default().to_string(),
+        ));
+        inputs
+    }
+
+    /// Add a named input.
+    pub(crate) fn push(
+        &mut self,
+        name: &str,
+        value: &(impl Serialize + ?Sized),
+    ) -> Result<(), serde_json::Error> {
+        self.0
+            .push((name.to_string(), serde_json::to_string(value)?));
+        Ok(())
+    }
+
+    /// Add a requirement, along with its hashes and origin.
+    ///
+    /// Returns `false` if the requirement refers to a local path or a direct URL, the contents of
+    /// which can change without any change to the requirement itself, such that the resolution
+    /// can't be cached.
+    pub(crate) fn push_requirement(
+        &mut self,
+        name: &str,
+        requirement: &Requirement,
+        hashes: &[String],
+    ) -> Result<bool, serde_json::Error> {
+        if !matches!(requirement.source, RequirementSource::Registry { .. }) {
+            return Ok(false);
+        }
+        self.push(name, &requirement.to_string())?;
+        self.push("hashes", hashes)?;
+        self.push("origin", &requirement.origin)?;
+        Ok(true)
+    }
+
+    /// Add an unresolved requirement, along with its hashes and origin.
+    ///
+    /// Returns `false` if the requirement can't be cached, as in [`Self::push_requirement`].
+    pub(crate) fn push_unresolved_requirement(
+        &mut self,
+        name: &str,
+        requirement: &UnresolvedRequirement,
+        hashes: &[String],
+    ) -> Result<bool, serde_json::Error> {
+        match requirement {
+            UnresolvedRequirement::Named(requirement) => {
+                self.push_requirement(name, requirement, hashes)
+            }
+            UnresolvedRequirement::Unnamed(_) => Ok(false),
+        }
+    }
+}
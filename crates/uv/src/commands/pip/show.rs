@@ -2,7 +2,6 @@ use std::fmt::Write;
 
 use anyhow::Result;
 use itertools::{Either, Itertools};
-use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 
 use uv_cache::Cache;
@@ -11,6 +10,7 @@ use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_warnings::warn_user;
 
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::ExitStatus;
@@ -28,12 +28,7 @@ pub(crate) fn pip_show(
     if packages.is_empty() {
         #[allow(clippy::print_stderr)]
         {
-            writeln!(
-                printer.stderr(),
-                "{}{} Please provide a package name or names.",
-                "warning".yellow().bold(),
-                ":".bold(),
-            )?;
+            warn_user!("Please provide a package name or names.");
         }
         return Ok(ExitStatus::Failure);
     }
@@ -68,13 +63,7 @@ pub(crate) fn pip_show(
     });
 
     if !missing.is_empty() {
-        writeln!(
-            printer.stderr(),
-            "{}{} Package(s) not found for: {}",
-            "warning".yellow().bold(),
-            ":".bold(),
-            missing.iter().join(", ").bold()
-        )?;
+        warn_user!("Package(s) not found for: {}", missing.iter().join(", "));
     }
 
     let distributions = distributions.iter().flatten().collect_vec();
@@ -189,13 +178,7 @@ pub(crate) fn pip_show(
     // Validate that the environment is consistent.
     if strict {
         for diagnostic in site_packages.diagnostics(&markers)? {
-            writeln!(
-                printer.stderr(),
-                "{}{} {}",
-                "warning".yellow().bold(),
-                ":".bold(),
-                diagnostic.message().bold()
-            )?;
+            warn_user!("{}", diagnostic.message());
         }
     }
 
@@ -1,4 +1,6 @@
+use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::num::NonZeroUsize;
 
 use anyhow::Result;
 use owo_colors::OwoColorize;
@@ -8,14 +10,15 @@ use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
-    IndexStrategy, Reinstall, SourceStrategy, TrustedHost, Upgrade,
+    AllowYanked, BuildOptions, Concurrency, ConfigSettings, Constraints, DryRun,
+    ExtrasSpecification, HashCheckingMode, IndexStrategy, Reinstall, SourceStrategy, TrustedHost,
+    Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
-use uv_distribution_types::{DependencyMetadata, IndexLocations, Resolution};
+use uv_distribution_types::{DependencyMetadata, IndexLocations, IndexUrl, Resolution};
 use uv_fs::Simplified;
-use uv_install_wheel::linker::LinkMode;
+use uv_install_wheel::linker::{DistInfoMode, LinkMode};
 use uv_installer::SitePackages;
 use uv_pep508::PackageName;
 use uv_python::{
@@ -23,15 +26,17 @@ use uv_python::{
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverMarkers,
+    Denylist, DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode,
+    PythonRequirement, ResolutionMode, ResolverMarkers,
 };
 use uv_types::{BuildIsolation, HashStrategy};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::pip::operations::Modifications;
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    operations, resolution_markers, resolution_tags, should_compile_bytecode,
+};
 use crate::commands::{diagnostics, ExitStatus, SharedState};
 use crate::printer::Printer;
 
@@ -41,11 +46,15 @@ pub(crate) async fn pip_sync(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
+    max_include_depth: usize,
     reinstall: Reinstall,
     link_mode: LinkMode,
     compile: bool,
+    compile_concurrency: Option<NonZeroUsize>,
     hash_checking: Option<HashCheckingMode>,
+    infer_require_hashes: bool,
     index_locations: IndexLocations,
+    index_mirror: Vec<IndexUrl>,
     index_strategy: IndexStrategy,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
@@ -69,7 +78,8 @@ pub(crate) async fn pip_sync(
     concurrency: Concurrency,
     native_tls: bool,
     cache: Cache,
-    dry_run: bool,
+    dry_run: DryRun,
+    verify_install: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let client_builder = BaseClientBuilder::new()
@@ -87,6 +97,23 @@ pub(crate) async fn pip_sync(
     let dependency_mode = DependencyMode::Direct;
 
     // Read all requirements from the provided sources.
+    let spec = operations::read_requirements(
+        requirements,
+        constraints,
+        overrides,
+        &extras,
+        strict,
+        max_include_depth,
+        &client_builder,
+    )
+    .await?;
+
+    // In `--strict` mode, reject project dependencies that contradict the `pyproject.toml`
+    // constraints up-front, rather than failing during resolution.
+    if strict {
+        spec.check_constraints(&[])?;
+    }
+
     let RequirementsSpecification {
         project,
         requirements,
@@ -100,14 +127,22 @@ pub(crate) async fn pip_sync(
         no_binary,
         no_build,
         extras: _,
-    } = operations::read_requirements(
-        requirements,
-        constraints,
-        overrides,
-        &extras,
-        &client_builder,
-    )
-    .await?;
+        no_deps: _,
+        config_settings: config_settings_package,
+        prerelease,
+        requires_python: _,
+        project_dependencies: _,
+        require_hashes,
+    } = spec;
+
+    // As in pip, if any requirement includes a hash, require hashes for every requirement, unless
+    // hash-checking was explicitly configured.
+    let hash_checking = if hash_checking.is_none() && infer_require_hashes && require_hashes {
+        debug!("Enabling `--require-hashes`, since at least one requirement includes a hash");
+        Some(HashCheckingMode::Require)
+    } else {
+        hash_checking
+    };
 
     // Read build constraints.
     let build_constraints =
@@ -207,7 +242,12 @@ pub(crate) async fn pip_sync(
             hash_checking,
         )?
     } else {
-        HashStrategy::None
+        // Even without `--require-hashes` or `--verify-hashes`, verify any hashes that are
+        // embedded in a direct URL.
+        HashStrategy::from_url_fragments(
+            requirements.iter().map(|entry| &entry.requirement),
+            Some(&markers),
+        )?
     };
 
     // Incorporate any index locations from the provided sources.
@@ -222,7 +262,7 @@ pub(crate) async fn pip_sync(
     // Initialize the registry client.
     let client = RegistryClientBuilder::try_from(client_builder)?
         .cache(cache.clone())
-        .index_urls(index_locations.index_urls())
+        .index_urls(index_locations.index_urls().with_mirrors(index_mirror))
         .index_strategy(index_strategy)
         .markers(interpreter.markers())
         .platform(interpreter.platform())
@@ -298,7 +338,8 @@ pub(crate) async fn pip_sync(
         exclude_newer,
         sources,
         concurrency,
-    );
+    )
+    .with_config_settings_package(config_settings_package);
 
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_environment(&environment)?;
@@ -311,6 +352,12 @@ pub(crate) async fn pip_sync(
         .index_strategy(index_strategy)
         .build();
 
+    // Track the user-provided constraints, to report any that don't apply to the resolution.
+    let user_constraints = constraints
+        .iter()
+        .map(|entry| entry.requirement.clone())
+        .collect::<Vec<_>>();
+
     let resolution = match operations::resolve(
         requirements,
         constraints,
@@ -319,12 +366,17 @@ pub(crate) async fn pip_sync(
         source_trees,
         project,
         None,
+        BTreeSet::default(),
+        prerelease,
         &extras,
         preferences,
         site_packages.clone(),
         &hasher,
         &reinstall,
         &upgrade,
+        &AllowYanked::default(),
+        None,
+        &Denylist::default(),
         Some(&tags),
         ResolverMarkers::specific_environment(markers.clone()),
         python_requirement,
@@ -355,15 +407,28 @@ pub(crate) async fn pip_sync(
         Err(err) => return Err(err.into()),
     };
 
+    // Report any constraints that don't apply to the resolution, or reject them in `--strict`
+    // mode.
+    operations::validate_unused(
+        &user_constraints,
+        &[],
+        |name| resolution.packages().any(|package| package == name),
+        Some(&*markers),
+        strict,
+    )?;
+
     // Sync the environment.
-    operations::install(
+    match operations::install(
         &resolution,
         site_packages,
         Modifications::Exact,
         &reinstall,
         &build_options,
         link_mode,
-        compile,
+        DistInfoMode::default(),
+        verify_install,
+        should_compile_bytecode(compile, python_version.as_ref(), interpreter),
+        compile_concurrency,
         &index_locations,
         config_settings,
         &hasher,
@@ -378,14 +443,20 @@ pub(crate) async fn pip_sync(
         dry_run,
         printer,
     )
-    .await?;
+    .await
+    {
+        Ok(_) => {}
+        // Under `--check`, the changes that would be made have already been reported.
+        Err(operations::Error::OutOfSync) => return Ok(ExitStatus::Failure),
+        Err(err) => return Err(err.into()),
+    }
 
     // Notify the user of any resolution diagnostics.
-    operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+    operations::diagnose_resolution(resolution.diagnostics());
 
     // Notify the user of any environment diagnostics.
-    if strict && !dry_run {
-        operations::diagnose_environment(&resolution, &environment, &markers, printer)?;
+    if strict && !dry_run.enabled() {
+        operations::diagnose_environment(&resolution, &environment, &markers)?;
     }
 
     Ok(ExitStatus::Success)
@@ -12,6 +12,7 @@ use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pypi_types::{RequirementSource, ResolverMarkerEnvironment};
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_warnings::warn_user;
 
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::ExitStatus;
@@ -83,13 +84,7 @@ pub(crate) fn pip_tree(
     // Validate that the environment is consistent.
     if strict {
         for diagnostic in site_packages.diagnostics(&markers)? {
-            writeln!(
-                printer.stderr(),
-                "{}{} {}",
-                "warning".yellow().bold(),
-                ":".bold(),
-                diagnostic.message().bold()
-            )?;
+            warn_user!("{}", diagnostic.message());
         }
     }
 
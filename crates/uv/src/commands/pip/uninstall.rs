@@ -17,6 +17,7 @@ use uv_python::EnvironmentPreference;
 use uv_python::PythonRequest;
 use uv_python::{Prefix, PythonEnvironment, Target};
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_warnings::warn_user;
 
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::{elapsed, ExitStatus};
@@ -142,13 +143,7 @@ pub(crate) async fn pip_uninstall(
         for package in &names {
             let installed = site_packages.get_packages(package);
             if installed.is_empty() {
-                writeln!(
-                    printer.stderr(),
-                    "{}{} Skipping {} as it is not installed",
-                    "warning".yellow().bold(),
-                    ":".bold(),
-                    package.as_ref().bold()
-                )?;
+                warn_user!("Skipping {} as it is not installed", package.as_ref());
             } else {
                 distributions.extend(installed);
             }
@@ -158,13 +153,7 @@ pub(crate) async fn pip_uninstall(
         for url in &urls {
             let installed = site_packages.get_urls(url);
             if installed.is_empty() {
-                writeln!(
-                    printer.stderr(),
-                    "{}{} Skipping {} as it is not installed",
-                    "warning".yellow().bold(),
-                    ":".bold(),
-                    url.as_ref().bold()
-                )?;
+                warn_user!("Skipping {} as it is not installed", url.as_ref());
             } else {
                 distributions.extend(installed);
             }
@@ -177,12 +166,7 @@ pub(crate) async fn pip_uninstall(
     };
 
     if distributions.is_empty() {
-        writeln!(
-            printer.stderr(),
-            "{}{} No packages to uninstall",
-            "warning".yellow().bold(),
-            ":".bold(),
-        )?;
+        warn_user!("No packages to uninstall");
         return Ok(ExitStatus::Success);
     }
 
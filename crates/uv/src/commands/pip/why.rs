@@ -0,0 +1,176 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+
+use anyhow::Result;
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+use uv_cache::Cache;
+use uv_distribution_types::Name;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_warnings::warn_user;
+
+use crate::commands::pip::operations::report_target_environment;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Explain why a package is installed, by displaying the shortest chains of requirements that
+/// lead to it from the packages that aren't required by any other package.
+pub(crate) fn pip_why(
+    package: &PackageName,
+    python: Option<&str>,
+    system: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Detect the current Python interpreter.
+    let environment = PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::from_system_flag(system, false),
+        cache,
+    )?;
+
+    report_target_environment(&environment, cache, printer)?;
+
+    // Read packages from the virtual environment.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    if site_packages.get_packages(package).is_empty() {
+        warn_user!("Package not found: {}", package);
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Determine the markers to use for the resolution.
+    let markers = environment.interpreter().resolver_markers();
+
+    // Collect the requirements of each installed package, ignoring any optional dependencies.
+    let mut versions: FxHashMap<PackageName, Version> = FxHashMap::default();
+    let mut requirements: BTreeMap<PackageName, BTreeSet<PackageName>> = BTreeMap::new();
+    for distribution in site_packages.iter() {
+        let metadata = distribution.metadata()?;
+        versions
+            .entry(distribution.name().clone())
+            .or_insert_with(|| distribution.version().clone());
+        requirements.entry(metadata.name).or_default().extend(
+            metadata
+                .requires_dist
+                .into_iter()
+                .filter(|requirement| requirement.evaluate_markers(&markers, &[]))
+                .map(|requirement| requirement.name),
+        );
+    }
+
+    // Ignore any requirements that aren't installed.
+    for children in requirements.values_mut() {
+        children.retain(|child| versions.contains_key(child));
+    }
+
+    let display = |name: &PackageName| match versions.get(name) {
+        Some(version) => format!("{name} v{version}"),
+        None => name.to_string(),
+    };
+
+    let paths = shortest_paths(&requirements, package);
+    if paths.is_empty() {
+        writeln!(
+            printer.stdout(),
+            "{} is not required by any other installed package",
+            display(package)
+        )?;
+    } else {
+        for path in paths {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                path.into_iter().map(&display).join(" -> ")
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return all the shortest chains of requirements that lead to `package`, starting from each of
+/// the packages that require it (transitively) and aren't required by any other package.
+///
+/// If every package that requires `package` is part of a dependency cycle, the chains start from
+/// those that are furthest from `package` instead.
+fn shortest_paths<'a>(
+    requirements: &'a BTreeMap<PackageName, BTreeSet<PackageName>>,
+    package: &'a PackageName,
+) -> Vec<Vec<&'a PackageName>> {
+    // Invert the requirements, to map each package to the packages that require it.
+    let mut requirers: FxHashMap<&PackageName, Vec<&PackageName>> = FxHashMap::default();
+    for (parent, children) in requirements {
+        for child in children {
+            requirers.entry(child).or_default().push(parent);
+        }
+    }
+
+    // Compute the distance from each package that requires `package` (transitively) to `package`.
+    let mut distances: FxHashMap<&PackageName, usize> = FxHashMap::default();
+    distances.insert(package, 0);
+    let mut queue = VecDeque::from([package]);
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[current];
+        for parent in requirers.get(current).into_iter().flatten() {
+            if !distances.contains_key(parent) {
+                distances.insert(parent, distance + 1);
+                queue.push_back(parent);
+            }
+        }
+    }
+
+    // Determine the roots from which to start each chain.
+    let mut roots = distances
+        .iter()
+        .filter(|(name, distance)| **distance > 0 && !requirers.contains_key(*name))
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    if roots.is_empty() {
+        let furthest = distances.values().copied().max().unwrap_or_default();
+        if furthest > 0 {
+            roots = distances
+                .iter()
+                .filter(|(_, distance)| **distance == furthest)
+                .map(|(name, _)| *name)
+                .collect();
+        }
+    }
+    roots.sort_unstable();
+
+    // Enumerate the shortest chains from each root, following only those requirements that lead
+    // one step closer to `package`.
+    let mut paths = Vec::new();
+    for root in roots {
+        let mut path = vec![root];
+        visit(requirements, &distances, &mut path, &mut paths);
+    }
+    paths
+}
+
+/// Extend the chain in `path` towards the package at distance zero, collecting each complete
+/// chain in `paths`.
+fn visit<'a>(
+    requirements: &'a BTreeMap<PackageName, BTreeSet<PackageName>>,
+    distances: &FxHashMap<&'a PackageName, usize>,
+    path: &mut Vec<&'a PackageName>,
+    paths: &mut Vec<Vec<&'a PackageName>>,
+) {
+    let current = *path.last().expect("path is non-empty");
+    let distance = distances[current];
+    if distance == 0 {
+        paths.push(path.clone());
+        return;
+    }
+    for child in requirements.get(current).into_iter().flatten() {
+        if distances.get(child) == Some(&(distance - 1)) {
+            path.push(child);
+            visit(requirements, distances, path, paths);
+            path.pop();
+        }
+    }
+}
@@ -12,8 +12,8 @@ use uv_cache::Cache;
 use uv_cache_key::RepositoryUrl;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DevMode, EditableMode, ExtrasSpecification, InstallOptions,
-    SourceStrategy,
+    Concurrency, Constraints, DevMode, EditableMode, ExtrasSpecification, GroupsSpecification,
+    InstallOptions, SourceStrategy,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -776,6 +776,7 @@ async fn lock_and_sync(
         &lock,
         &extras,
         dev,
+        &GroupsSpecification::default(),
         EditableMode::Editable,
         InstallOptions::default(),
         Modifications::Sufficient,
@@ -9,16 +9,16 @@ use uv_cache::Cache;
 use uv_client::Connectivity;
 use uv_configuration::{
     Concurrency, DevMode, DevSpecification, EditableMode, ExportFormat, ExtrasSpecification,
-    InstallOptions,
+    GroupsSpecification, InstallOptions,
 };
-use uv_normalize::{PackageName, DEV_DEPENDENCIES};
+use uv_normalize::PackageName;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_resolver::RequirementsTxtExport;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace};
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::project::lock::do_safe_lock;
-use crate::commands::project::{ProjectError, ProjectInterpreter};
+use crate::commands::project::{resolve_groups, ProjectError, ProjectInterpreter};
 use crate::commands::{diagnostics, pip, ExitStatus, OutputWriter};
 use crate::printer::Printer;
 use crate::settings::ResolverSettings;
@@ -34,6 +34,7 @@ pub(crate) async fn export(
     output_file: Option<PathBuf>,
     extras: ExtrasSpecification,
     dev: DevMode,
+    groups: GroupsSpecification,
     editable: EditableMode,
     locked: bool,
     frozen: bool,
@@ -125,11 +126,11 @@ pub(crate) async fn export(
         Err(err) => return Err(err.into()),
     };
 
-    // Include development dependencies, if requested.
+    // Include development dependencies and dependency groups, if requested.
+    let groups = resolve_groups(project.workspace(), dev, &groups)?;
     let dev = match dev {
-        DevMode::Include => DevSpecification::Include(std::slice::from_ref(&DEV_DEPENDENCIES)),
-        DevMode::Exclude => DevSpecification::Exclude,
-        DevMode::Only => DevSpecification::Only(std::slice::from_ref(&DEV_DEPENDENCIES)),
+        DevMode::Include | DevMode::Exclude => DevSpecification::Include(&groups),
+        DevMode::Only => DevSpecification::Only(&groups),
     };
 
     // Write the resolved dependencies to the output channel.
@@ -194,7 +195,7 @@ fn cmd() -> String {
             }
 
             // Always skip the `--quiet` flag.
-            if arg == "--quiet" || arg == "-q" {
+            if arg == "--quiet" || arg == "-q" || arg == "-qq" {
                 *skip_next = None;
                 return Some(None);
             }
@@ -12,7 +12,7 @@ use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, Constraints, ExtrasSpecification, Reinstall, Upgrade,
+    AllowYanked, BuildOptions, Concurrency, Constraints, ExtrasSpecification, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -23,12 +23,12 @@ use uv_distribution_types::{
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::{PackageName, DEV_DEPENDENCIES};
 use uv_pep440::Version;
-use uv_pypi_types::{Requirement, SupportedEnvironments};
+use uv_pypi_types::{HashAlgorithm, Requirement, SupportedEnvironments};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_resolver::{
-    FlatIndex, Lock, Options, OptionsBuilder, PythonRequirement, RequiresPython, ResolverManifest,
-    ResolverMarkers, SatisfiesResult,
+    Denylist, FlatIndex, Lock, Options, OptionsBuilder, PythonRequirement, RequiresPython,
+    ResolverManifest, ResolverMarkers, SatisfiesResult,
 };
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
@@ -268,10 +268,14 @@ async fn do_lock(
     } = settings;
 
     // Collect the requirements, etc.
-    let requirements = workspace.non_project_requirements().collect::<Vec<_>>();
+    let requirements = workspace.non_project_requirements()?;
     let overrides = workspace.overrides().into_iter().collect::<Vec<_>>();
     let constraints = workspace.constraints();
-    let dev = vec![DEV_DEPENDENCIES.clone()];
+    let dev = {
+        let mut dev = workspace.group_names()?;
+        dev.insert(DEV_DEPENDENCIES.clone());
+        dev.into_iter().collect::<Vec<_>>()
+    };
     let source_trees = vec![];
 
     // Collect the list of members.
@@ -402,7 +406,7 @@ async fn do_lock(
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
         .build();
-    let hasher = HashStrategy::Generate;
+    let hasher = HashStrategy::Generate(HashAlgorithm::Sha256);
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
@@ -554,12 +558,17 @@ async fn do_lock(
                 // The root is always null in workspaces, it "depends on" the projects
                 None,
                 Some(workspace.packages().keys().cloned().collect()),
+                BTreeSet::default(),
+                BTreeSet::default(),
                 &extras,
                 preferences,
                 EmptyInstalledPackages,
                 &hasher,
                 &Reinstall::default(),
                 upgrade,
+                &AllowYanked::default(),
+                None,
+                &Denylist::default(),
                 None,
                 resolver_markers,
                 python_requirement,
@@ -578,7 +587,7 @@ async fn do_lock(
             logger.on_complete(resolution.len(), start, printer)?;
 
             // Notify the user of any resolution diagnostics.
-            pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+            pip::operations::diagnose_resolution(resolution.diagnostics());
 
             let manifest = ResolverManifest::new(
                 members,
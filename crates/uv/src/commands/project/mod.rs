@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
 
@@ -8,7 +9,10 @@ use tracing::debug;
 use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
-use uv_configuration::{Concurrency, Constraints, ExtrasSpecification, Reinstall, Upgrade};
+use uv_configuration::{
+    AllowYanked, Concurrency, Constraints, DevMode, DryRun, ExtrasSpecification,
+    GroupsSpecification, Reinstall, Upgrade,
+};
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
@@ -16,8 +20,9 @@ use uv_distribution_types::{
 };
 use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
+use uv_install_wheel::linker::DistInfoMode;
 use uv_installer::{SatisfiesResult, SitePackages};
-use uv_normalize::PackageName;
+use uv_normalize::{GroupName, PackageName, DEV_DEPENDENCIES};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::MarkerTreeContents;
 use uv_pypi_types::Requirement;
@@ -31,7 +36,7 @@ use uv_requirements::{
     NamedRequirementsError, NamedRequirementsResolver, RequirementsSpecification,
 };
 use uv_resolver::{
-    FlatIndex, Lock, OptionsBuilder, PythonRequirement, RequiresPython, ResolutionGraph,
+    Denylist, FlatIndex, Lock, OptionsBuilder, PythonRequirement, RequiresPython, ResolutionGraph,
     ResolverMarkers,
 };
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
@@ -129,6 +134,9 @@ pub(crate) enum ProjectError {
     #[error("Project virtual environment directory `{0}` cannot be used because {1}")]
     InvalidProjectEnvironmentDir(PathBuf, String),
 
+    #[error("Group `{0}` is not defined in the project's `dependency-groups` table")]
+    MissingGroup(GroupName),
+
     #[error("Failed to parse `pyproject.toml`")]
     TomlParse(#[source] toml::de::Error),
 
@@ -174,6 +182,9 @@ pub(crate) enum ProjectError {
     #[error(transparent)]
     PyprojectMut(#[from] uv_workspace::pyproject_mut::Error),
 
+    #[error(transparent)]
+    DependencyGroup(#[from] uv_workspace::pyproject::DependencyGroupError),
+
     #[error(transparent)]
     Fmt(#[from] std::fmt::Error),
 
@@ -184,6 +195,46 @@ pub(crate) enum ProjectError {
     Anyhow(#[from] anyhow::Error),
 }
 
+/// Determine the dependency groups to include for the [`Workspace`].
+///
+/// The legacy `tool.uv.dev-dependencies` are treated as the `dev` group, which is included
+/// unless `--no-dev` is provided. The groups listed in `tool.uv.default-groups` are included
+/// unless `--no-default-groups` is provided.
+pub(crate) fn resolve_groups(
+    workspace: &Workspace,
+    dev: DevMode,
+    groups: &GroupsSpecification,
+) -> Result<Vec<GroupName>, ProjectError> {
+    let group_names = workspace.group_names()?;
+
+    // Reject any groups that were requested explicitly, but aren't defined.
+    if let Some(group) = groups
+        .names()
+        .iter()
+        .find(|group| !group_names.contains(*group))
+    {
+        return Err(ProjectError::MissingGroup(group.clone()));
+    }
+
+    let mut resolved = BTreeSet::new();
+    if matches!(groups, GroupsSpecification::All) {
+        resolved.extend(group_names);
+    }
+    if groups.include_defaults() {
+        resolved.extend(workspace.default_groups().iter().cloned());
+    }
+    resolved.extend(groups.names().iter().cloned());
+    match dev {
+        DevMode::Include | DevMode::Only => {
+            resolved.insert(DEV_DEPENDENCIES.clone());
+        }
+        DevMode::Exclude => {
+            resolved.remove(&*DEV_DEPENDENCIES);
+        }
+    }
+    Ok(resolved.into_iter().collect())
+}
+
 /// Compute the `Requires-Python` bound for the [`Workspace`].
 ///
 /// For a [`Workspace`] with multiple packages, the `Requires-Python` bound is the union of the
@@ -881,12 +932,17 @@ pub(crate) async fn resolve_environment<'a>(
         source_trees,
         project,
         None,
+        BTreeSet::default(),
+        BTreeSet::default(),
         &extras,
         preferences,
         EmptyInstalledPackages,
         &hasher,
         &reinstall,
         &upgrade,
+        &AllowYanked::default(),
+        None,
+        &Denylist::default(),
         Some(tags),
         ResolverMarkers::specific_environment(markers),
         python_requirement,
@@ -968,7 +1024,7 @@ pub(crate) async fn sync_environment(
     // optional on the downstream APIs.
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
-    let dry_run = false;
+    let dry_run = DryRun::Disabled;
     let hasher = HashStrategy::default();
 
     // Resolve the flat indexes from `--find-links`.
@@ -1010,7 +1066,10 @@ pub(crate) async fn sync_environment(
         reinstall,
         build_options,
         link_mode,
+        DistInfoMode::default(),
+        false,
         compile_bytecode,
+        None,
         index_locations,
         config_setting,
         &hasher,
@@ -1028,7 +1087,7 @@ pub(crate) async fn sync_environment(
     .await?;
 
     // Notify the user of any resolution diagnostics.
-    pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+    pip::operations::diagnose_resolution(resolution.diagnostics());
 
     Ok(venv)
 }
@@ -1164,7 +1223,7 @@ pub(crate) async fn update_environment(
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
     let dev = Vec::default();
-    let dry_run = false;
+    let dry_run = DryRun::Disabled;
     let extras = ExtrasSpecification::default();
     let hasher = HashStrategy::default();
     let preferences = Vec::default();
@@ -1213,12 +1272,17 @@ pub(crate) async fn update_environment(
         source_trees,
         project,
         None,
+        BTreeSet::default(),
+        BTreeSet::default(),
         &extras,
         preferences,
         site_packages.clone(),
         &hasher,
         reinstall,
         upgrade,
+        &AllowYanked::default(),
+        None,
+        &Denylist::default(),
         Some(tags),
         ResolverMarkers::specific_environment(markers.clone()),
         python_requirement,
@@ -1245,7 +1309,10 @@ pub(crate) async fn update_environment(
         reinstall,
         build_options,
         *link_mode,
+        DistInfoMode::default(),
+        false,
         *compile_bytecode,
+        None,
         index_locations,
         config_setting,
         &hasher,
@@ -1263,7 +1330,7 @@ pub(crate) async fn update_environment(
     .await?;
 
     // Notify the user of any resolution diagnostics.
-    pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+    pip::operations::diagnose_resolution(resolution.diagnostics());
 
     Ok(EnvironmentUpdate {
         environment: venv,
@@ -5,7 +5,9 @@ use std::path::Path;
 use owo_colors::OwoColorize;
 use uv_cache::Cache;
 use uv_client::Connectivity;
-use uv_configuration::{Concurrency, DevMode, EditableMode, ExtrasSpecification, InstallOptions};
+use uv_configuration::{
+    Concurrency, DevMode, EditableMode, ExtrasSpecification, GroupsSpecification, InstallOptions,
+};
 use uv_fs::Simplified;
 use uv_pep508::PackageName;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
@@ -191,6 +193,7 @@ pub(crate) async fn remove(
     // TODO(ibraheem): Should we accept CLI overrides for this? Should we even sync here?
     let dev = DevMode::Include;
     let extras = ExtrasSpecification::All;
+    let groups = GroupsSpecification::All;
     let install_options = InstallOptions::default();
 
     // Initialize any shared state.
@@ -202,6 +205,7 @@ pub(crate) async fn remove(
         &lock,
         &extras,
         dev,
+        &groups,
         EditableMode::Editable,
         install_options,
         Modifications::Exact,
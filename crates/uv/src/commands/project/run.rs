@@ -15,7 +15,8 @@ use uv_cache::Cache;
 use uv_cli::ExternalCommand;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::{
-    Concurrency, DevMode, EditableMode, ExtrasSpecification, InstallOptions, SourceStrategy,
+    Concurrency, DevMode, EditableMode, ExtrasSpecification, GroupsSpecification, InstallOptions,
+    SourceStrategy,
 };
 use uv_distribution::LoweredRequirement;
 use uv_fs::{PythonExt, Simplified};
@@ -63,6 +64,7 @@ pub(crate) async fn run(
     no_config: bool,
     extras: ExtrasSpecification,
     dev: DevMode,
+    groups: GroupsSpecification,
     editable: EditableMode,
     python: Option<String>,
     settings: ResolverInstallerSettings,
@@ -296,6 +298,11 @@ pub(crate) async fn run(
         if matches!(dev, DevMode::Only) {
             warn_user!("`--only-dev` is not supported for Python scripts with inline metadata");
         }
+        if !groups.is_default() {
+            warn_user!(
+                "Dependency groups are not supported for Python scripts with inline metadata"
+            );
+        }
         if package.is_some() {
             warn_user!(
                 "`--package` is a no-op for Python scripts with inline metadata, which always run in isolation"
@@ -373,6 +380,9 @@ pub(crate) async fn run(
             if matches!(dev, DevMode::Only) {
                 warn_user!("`--only-dev` has no effect when used alongside `--no-project`");
             }
+            if !groups.is_default() {
+                warn_user!("Dependency groups have no effect when used alongside `--no-project`");
+            }
             if locked {
                 warn_user!("`--locked` has no effect when used alongside `--no-project`");
             }
@@ -393,6 +403,9 @@ pub(crate) async fn run(
             if matches!(dev, DevMode::Only) {
                 warn_user!("`--only-dev` has no effect when used outside of a project");
             }
+            if !groups.is_default() {
+                warn_user!("Dependency groups have no effect when used outside of a project");
+            }
             if locked {
                 warn_user!("`--locked` has no effect when used outside of a project");
             }
@@ -543,6 +556,7 @@ pub(crate) async fn run(
                     result.lock(),
                     &extras,
                     dev,
+                    &groups,
                     editable,
                     install_options,
                     Modifications::Sufficient,
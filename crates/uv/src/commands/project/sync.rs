@@ -14,13 +14,14 @@ use std::str::FromStr;
 use uv_cache::Cache;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DevMode, DevSpecification, EditableMode, ExtrasSpecification,
-    HashCheckingMode, InstallOptions,
+    Concurrency, Constraints, DevMode, DevSpecification, DryRun, EditableMode, ExtrasSpecification,
+    GroupsSpecification, HashCheckingMode, InstallOptions,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{DirectorySourceDist, Dist, ResolvedDist, SourceDist};
+use uv_install_wheel::linker::DistInfoMode;
 use uv_installer::SitePackages;
-use uv_normalize::{PackageName, DEV_DEPENDENCIES};
+use uv_normalize::PackageName;
 use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
 use uv_pypi_types::{
     LenientRequirement, ParsedArchiveUrl, ParsedGitUrl, ParsedUrl, VerbatimParsedUrl,
@@ -41,6 +42,7 @@ pub(crate) async fn sync(
     package: Option<PackageName>,
     extras: ExtrasSpecification,
     dev: DevMode,
+    groups: GroupsSpecification,
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
@@ -150,6 +152,7 @@ pub(crate) async fn sync(
         &lock,
         &extras,
         dev,
+        &groups,
         editable,
         install_options,
         modifications,
@@ -175,6 +178,7 @@ pub(super) async fn do_sync(
     lock: &Lock,
     extras: &ExtrasSpecification,
     dev: DevMode,
+    groups: &GroupsSpecification,
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
@@ -238,11 +242,11 @@ pub(super) async fn do_sync(
         }
     }
 
-    // Include development dependencies, if requested.
+    // Include development dependencies and dependency groups, if requested.
+    let groups = project::resolve_groups(target.workspace(), dev, groups)?;
     let dev = match dev {
-        DevMode::Include => DevSpecification::Include(std::slice::from_ref(&DEV_DEPENDENCIES)),
-        DevMode::Exclude => DevSpecification::Exclude,
-        DevMode::Only => DevSpecification::Only(std::slice::from_ref(&DEV_DEPENDENCIES)),
+        DevMode::Include | DevMode::Exclude => DevSpecification::Include(&groups),
+        DevMode::Only => DevSpecification::Only(&groups),
     };
 
     // Determine the tags to use for resolution.
@@ -298,7 +302,7 @@ pub(super) async fn do_sync(
     // optional on the downstream APIs.
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
-    let dry_run = false;
+    let dry_run = DryRun::Disabled;
 
     // Extract the hashes from the lockfile.
     let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
@@ -344,7 +348,10 @@ pub(super) async fn do_sync(
         reinstall,
         build_options,
         link_mode,
+        DistInfoMode::default(),
+        false,
         compile_bytecode,
+        None,
         index_locations,
         config_setting,
         &hasher,
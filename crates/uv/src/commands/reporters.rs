@@ -13,6 +13,7 @@ use uv_distribution_types::{
 use uv_normalize::PackageName;
 use uv_python::PythonInstallationKey;
 
+use crate::commands::pip::loggers::Timings;
 use crate::printer::Printer;
 
 #[derive(Debug)]
@@ -234,6 +235,7 @@ impl ProgressReporter {
 #[derive(Debug)]
 pub(crate) struct PrepareReporter {
     reporter: ProgressReporter,
+    timings: Option<Arc<Timings>>,
 }
 
 impl From<Printer> for PrepareReporter {
@@ -249,7 +251,10 @@ impl From<Printer> for PrepareReporter {
         root.set_message("Preparing packages...");
 
         let reporter = ProgressReporter::new(root, multi_progress, printer);
-        Self { reporter }
+        Self {
+            reporter,
+            timings: None,
+        }
     }
 }
 
@@ -259,10 +264,19 @@ impl PrepareReporter {
         self.reporter.root.set_length(length);
         self
     }
+
+    /// Record the time spent building, and whether each distribution was served from the cache.
+    #[must_use]
+    pub(crate) fn with_timings(self, timings: Option<Arc<Timings>>) -> Self {
+        Self { timings, ..self }
+    }
 }
 
 impl uv_installer::PrepareReporter for PrepareReporter {
-    fn on_progress(&self, _dist: &CachedDist) {
+    fn on_progress(&self, dist: &CachedDist) {
+        if let Some(timings) = self.timings.as_ref() {
+            timings.on_prepared(dist);
+        }
         self.reporter.root.inc(1);
     }
 
@@ -274,14 +288,26 @@ impl uv_installer::PrepareReporter for PrepareReporter {
     }
 
     fn on_build_start(&self, source: &BuildableSource) -> usize {
+        if let Some(timings) = self.timings.as_ref() {
+            timings.on_build_start(source);
+            if let Some(name) = source.name() {
+                timings.on_fetch(name);
+            }
+        }
         self.reporter.on_build_start(source)
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        if let Some(timings) = self.timings.as_ref() {
+            timings.on_build_complete(source);
+        }
         self.reporter.on_build_complete(source, id);
     }
 
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
+        if let Some(timings) = self.timings.as_ref() {
+            timings.on_fetch(name);
+        }
         self.reporter.on_download_start(name.to_string(), size)
     }
 
@@ -305,6 +331,7 @@ impl uv_installer::PrepareReporter for PrepareReporter {
 #[derive(Debug)]
 pub(crate) struct ResolverReporter {
     reporter: ProgressReporter,
+    timings: Option<Arc<Timings>>,
 }
 
 impl ResolverReporter {
@@ -313,6 +340,12 @@ impl ResolverReporter {
         self.reporter.root.set_length(length);
         self
     }
+
+    /// Record the time spent fetching metadata, solving, and building.
+    #[must_use]
+    pub(crate) fn with_timings(self, timings: Option<Arc<Timings>>) -> Self {
+        Self { timings, ..self }
+    }
 }
 
 impl From<Printer> for ResolverReporter {
@@ -328,7 +361,10 @@ impl From<Printer> for ResolverReporter {
         root.set_message("Resolving dependencies...");
 
         let reporter = ProgressReporter::new(root, multi_progress, printer);
-        Self { reporter }
+        Self {
+            reporter,
+            timings: None,
+        }
     }
 }
 
@@ -349,11 +385,23 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
         self.reporter.root.finish_and_clear();
     }
 
+    fn on_solve_complete(&self, solve: Duration, fetch: Duration) {
+        if let Some(timings) = self.timings.as_ref() {
+            timings.on_solve(solve, fetch);
+        }
+    }
+
     fn on_build_start(&self, source: &BuildableSource) -> usize {
+        if let Some(timings) = self.timings.as_ref() {
+            timings.on_build_start(source);
+        }
         self.reporter.on_build_start(source)
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        if let Some(timings) = self.timings.as_ref() {
+            timings.on_build_complete(source);
+        }
         self.reporter.on_build_complete(source, id);
     }
 
@@ -380,10 +428,16 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
 
 impl uv_distribution::Reporter for ResolverReporter {
     fn on_build_start(&self, source: &BuildableSource) -> usize {
+        if let Some(timings) = self.timings.as_ref() {
+            timings.on_build_start(source);
+        }
         self.reporter.on_build_start(source)
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        if let Some(timings) = self.timings.as_ref() {
+            timings.on_build_complete(source);
+        }
         self.reporter.on_build_complete(source, id);
     }
 
@@ -17,6 +17,7 @@ use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_requirements_txt::DEFAULT_MAX_INCLUDE_DEPTH;
 use uv_settings::{ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
 use uv_warnings::warn_user;
@@ -100,9 +101,13 @@ pub(crate) async fn install(
             } else {
                 RequirementsSource::Package(name.to_string())
             };
-            let requirements = RequirementsSpecification::from_source(&source, &client_builder)
-                .await?
-                .requirements;
+            let requirements = RequirementsSpecification::from_source(
+                &source,
+                DEFAULT_MAX_INCLUDE_DEPTH,
+                &client_builder,
+            )
+            .await?
+            .requirements;
             resolve_names(
                 requirements,
                 &interpreter,
@@ -167,9 +172,13 @@ pub(crate) async fn install(
             } else {
                 RequirementsSource::Package(from.to_string())
             };
-            let requirements = RequirementsSpecification::from_source(&source, &client_builder)
-                .await?
-                .requirements;
+            let requirements = RequirementsSpecification::from_source(
+                &source,
+                DEFAULT_MAX_INCLUDE_DEPTH,
+                &client_builder,
+            )
+            .await?
+            .requirements;
 
             // Parse the `--from` requirement.
             let from_requirement = resolve_names(
@@ -17,11 +17,12 @@ use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_cli::{
     compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand,
+    ErrorFormat, PipCommand, PipNamespace, ProjectCommand,
 };
 use uv_cli::{PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs};
 #[cfg(feature = "self-update")]
 use uv_cli::{SelfCommand, SelfNamespace, SelfUpdateArgs};
+use uv_configuration::IndexAuth;
 use uv_fs::CWD;
 use uv_requirements::RequirementsSource;
 use uv_scripts::Pep723Script;
@@ -29,12 +30,12 @@ use uv_settings::{Combine, FilesystemOptions, Options};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace};
 
-use crate::commands::{ExitStatus, RunCommand, ToolRunCommand};
+use crate::commands::{ErrorReport, ExitStatus, RunCommand, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
     CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
     PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    PipWhySettings, PublishSettings,
 };
 
 pub(crate) mod commands;
@@ -46,7 +47,7 @@ pub(crate) mod version;
 #[instrument(skip_all)]
 async fn run(cli: Cli) -> Result<ExitStatus> {
     // Enable flag to pick up warnings generated by workspace loading.
-    if !cli.top_level.global_args.quiet {
+    if cli.top_level.global_args.quiet < 2 {
         uv_warnings::enable();
     }
 
@@ -105,7 +106,12 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
     //    If found, this file is combined with the user configuration file.
     // 3. The nearest configuration file (`uv.toml` or `pyproject.toml`) in the directory tree,
     //    starting from the current directory.
-    let filesystem = if let Some(config_file) = cli.top_level.config_file.as_ref() {
+    //
+    // With `--no-config`, all file-based configuration (including any `--config-file`) is skipped,
+    // but environment variables and command-line arguments are still respected.
+    let filesystem = if deprecated_isolated || cli.top_level.no_config {
+        None
+    } else if let Some(config_file) = cli.top_level.config_file.as_ref() {
         if config_file
             .file_name()
             .is_some_and(|file_name| file_name == "pyproject.toml")
@@ -113,8 +119,6 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             warn_user!("The `--config-file` argument expects to receive a `uv.toml` file, not a `pyproject.toml`. If you're trying to run a command from another project, use the `--project` argument instead.");
         }
         Some(FilesystemOptions::from_file(config_file)?)
-    } else if deprecated_isolated || cli.top_level.no_config {
-        None
     } else if matches!(&*cli.command, Commands::Tool(_)) {
         // For commands that operate at the user-level, ignore local configuration.
         FilesystemOptions::user()?
@@ -171,9 +175,11 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         None
     };
 
-    // If the target is a PEP 723 script, merge the metadata into the filesystem metadata.
+    // If the target is a PEP 723 script, merge the metadata into the filesystem metadata (unless
+    // `--no-config` was provided).
     let filesystem = script
         .as_ref()
+        .filter(|_| !cli.top_level.no_config)
         .map(|script| &script.metadata)
         .and_then(|metadata| metadata.tool.as_ref())
         .and_then(|tool| tool.uv.as_ref())
@@ -202,8 +208,12 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
     )?;
 
     // Configure the `Printer`, which controls user-facing output in the CLI.
-    let printer = if globals.quiet {
+    let printer = if globals.quiet > 1 {
+        Printer::Silent
+    } else if globals.quiet > 0 {
         Printer::Quiet
+    } else if commands::is_json_error_format() {
+        Printer::Json
     } else if globals.verbose > 0 {
         Printer::Verbose
     } else if globals.no_progress {
@@ -212,8 +222,9 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         Printer::Default
     };
 
-    // Configure the `warn!` macros, which control user-facing warnings in the CLI.
-    if globals.quiet {
+    // Configure the `warn!` macros, which control user-facing warnings in the CLI. Warnings are
+    // shown under `--quiet`, but not under `-qq`.
+    if globals.quiet > 1 {
         uv_warnings::disable();
     } else {
         uv_warnings::enable();
@@ -237,6 +248,8 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         .build_global()
         .expect("failed to initialize global rayon pool");
 
+    uv_fs::open_files::set_max_open_files(globals.concurrency.open_files);
+
     debug!("uv {}", version::version());
 
     // Write out any resolved settings.
@@ -273,6 +286,25 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = PipCompileSettings::resolve(args, filesystem);
             show_settings!(args);
+            args.settings.validate()?;
+
+            // Add any index credentials to the authentication store, such that credentials
+            // provided on the command-line take precedence over those in the environment, which
+            // take precedence over those in the credentials file.
+            if let Some(path) = &args.index_auth_file {
+                uv_auth::store_credentials_from_file(path)?;
+            }
+            for auth in args
+                .settings
+                .index_locations
+                .indexes()
+                .filter_map(|index| IndexAuth::from_env(index.url()))
+            {
+                auth.store();
+            }
+            for auth in &args.index_auth {
+                auth.store();
+            }
 
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(
@@ -285,6 +317,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 .src_file
                 .into_iter()
                 .map(RequirementsSource::from_requirements_file)
+                .map(|source| source.with_tox_env(args.tox_env.as_deref()))
                 .collect::<Vec<_>>();
             let constraints = args
                 .constraint
@@ -301,22 +334,44 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 .into_iter()
                 .map(RequirementsSource::from_constraints_txt)
                 .collect::<Vec<_>>();
+            let pin_files = args
+                .pin_file
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
 
             commands::pip_compile(
                 &requirements,
                 &constraints,
                 &overrides,
                 &build_constraints,
+                args.max_include_depth,
+                &pin_files,
                 args.constraints_from_workspace,
                 args.overrides_from_workspace,
+                args.override_add,
                 args.environments,
                 args.settings.extras,
                 args.settings.output_file.as_deref(),
+                args.format,
+                args.force,
+                args.append,
+                args.absolute_paths,
+                args.line_ending,
                 args.settings.resolution,
                 args.settings.prerelease,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
+                args.allow_yanked,
+                args.allowed_packages,
+                args.denied,
+                args.resolution_packages,
+                args.strict_extras,
+                args.upgrade_strategy,
+                args.resolution_budget,
+                args.cache_resolution,
                 args.settings.generate_hashes,
+                args.hash_algorithm,
                 args.settings.no_emit_package,
                 args.settings.no_strip_extras,
                 args.settings.no_strip_markers,
@@ -329,6 +384,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.emit_marker_expression,
                 args.settings.emit_index_annotation,
                 args.settings.index_locations,
+                args.index_mirror,
                 args.settings.index_strategy,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
@@ -340,6 +396,8 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.build_options,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.python_implementation,
+                &args.markers,
                 args.settings.universal,
                 args.settings.exclude_newer,
                 args.settings.sources,
@@ -350,7 +408,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 globals.python_preference,
                 globals.concurrency,
                 globals.native_tls,
-                globals.quiet,
+                globals.quiet > 0,
                 cache,
                 printer,
             )
@@ -365,6 +423,24 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             let args = PipSyncSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            // Add any index credentials to the authentication store, such that credentials
+            // provided on the command-line take precedence over those in the environment, which
+            // take precedence over those in the credentials file.
+            if let Some(path) = &args.index_auth_file {
+                uv_auth::store_credentials_from_file(path)?;
+            }
+            for auth in args
+                .settings
+                .index_locations
+                .indexes()
+                .filter_map(|index| IndexAuth::from_env(index.url()))
+            {
+                auth.store();
+            }
+            for auth in &args.index_auth {
+                auth.store();
+            }
+
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(
                 args.refresh
@@ -376,6 +452,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 .src_file
                 .into_iter()
                 .map(RequirementsSource::from_requirements_file)
+                .map(|source| source.with_tox_env(args.tox_env.as_deref()))
                 .collect::<Vec<_>>();
             let constraints = args
                 .constraint
@@ -392,11 +469,15 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 &requirements,
                 &constraints,
                 &build_constraints,
+                args.max_include_depth,
                 args.settings.reinstall,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
+                args.settings.compile_bytecode_concurrency,
                 args.settings.hash_checking,
+                args.settings.infer_require_hashes,
                 args.settings.index_locations,
+                args.index_mirror,
                 args.settings.index_strategy,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
@@ -421,6 +502,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 globals.native_tls,
                 cache,
                 args.dry_run,
+                args.verify_install,
                 printer,
             )
             .await
@@ -433,6 +515,25 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = PipInstallSettings::resolve(args, filesystem);
             show_settings!(args);
+            args.settings.validate()?;
+
+            // Add any index credentials to the authentication store, such that credentials
+            // provided on the command-line take precedence over those in the environment, which
+            // take precedence over those in the credentials file.
+            if let Some(path) = &args.index_auth_file {
+                uv_auth::store_credentials_from_file(path)?;
+            }
+            for auth in args
+                .settings
+                .index_locations
+                .indexes()
+                .filter_map(|index| IndexAuth::from_env(index.url()))
+            {
+                auth.store();
+            }
+            for auth in &args.index_auth {
+                auth.store();
+            }
 
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(
@@ -449,7 +550,18 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 .chain(
                     args.requirement
                         .into_iter()
-                        .map(RequirementsSource::from_requirements_file),
+                        .map(RequirementsSource::from_requirements_file)
+                        .map(|source| source.with_tox_env(args.tox_env.as_deref())),
+                )
+                .chain(
+                    args.no_deps_requirement
+                        .into_iter()
+                        .map(RequirementsSource::from_no_deps_requirements_txt),
+                )
+                .chain(
+                    args.freeze_requirement
+                        .into_iter()
+                        .map(RequirementsSource::from_freeze),
                 )
                 .collect::<Vec<_>>();
             let constraints = args
@@ -473,14 +585,26 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 &constraints,
                 &overrides,
                 &build_constraints,
+                args.max_include_depth,
+                args.build_lock.as_deref(),
                 args.constraints_from_workspace,
                 args.overrides_from_workspace,
+                args.override_add,
                 &args.settings.extras,
                 args.settings.resolution,
                 args.settings.prerelease,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
+                args.allow_yanked,
+                args.allowed_packages,
+                args.denied,
+                args.resolution_packages,
+                args.strict_extras,
+                args.resolution_budget,
+                args.constrain_installed,
+                args.ignore_installed,
                 args.settings.index_locations,
+                args.index_mirror,
                 args.settings.index_strategy,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
@@ -488,12 +612,16 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.reinstall,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
+                args.settings.compile_bytecode_concurrency,
                 args.settings.hash_checking,
+                args.settings.infer_require_hashes,
                 globals.connectivity,
                 &args.settings.config_setting,
                 args.settings.no_build_isolation,
                 args.settings.no_build_isolation_package,
-                args.settings.build_options,
+                args.settings
+                    .build_options
+                    .with_prefer_sdist(args.prefer_sdist_package),
                 args.settings.python_version,
                 args.settings.python_platform,
                 args.settings.strict,
@@ -501,13 +629,18 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.sources,
                 args.settings.python,
                 args.settings.system,
+                globals.python_preference,
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
+                args.dist_info,
                 globals.concurrency,
                 globals.native_tls,
                 cache,
                 args.dry_run,
+                args.verify_install,
+                args.venv,
+                args.timings,
                 printer,
             )
             .await
@@ -559,7 +692,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             let cache = cache.init()?;
 
             commands::pip_freeze(
-                args.exclude_editable,
+                args.editable,
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
@@ -632,6 +765,24 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 printer,
             )
         }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Why(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipWhySettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::pip_why(
+                &args.package,
+                args.settings.python.as_deref(),
+                args.settings.system,
+                &cache,
+                printer,
+            )
+        }
         Commands::Pip(PipNamespace {
             command: PipCommand::Check(args),
         }) => {
@@ -1256,6 +1407,7 @@ async fn run_project(
                 no_config,
                 args.extras,
                 args.dev,
+                args.groups,
                 args.editable,
                 args.python,
                 args.settings,
@@ -1288,6 +1440,7 @@ async fn run_project(
                 args.package,
                 args.extras,
                 args.dev,
+                args.groups,
                 args.editable,
                 args.install_options,
                 args.modifications,
@@ -1462,6 +1615,7 @@ async fn run_project(
                 args.output_file,
                 args.extras,
                 args.dev,
+                args.groups,
                 args.editable,
                 args.locked,
                 args.frozen,
@@ -1472,7 +1626,7 @@ async fn run_project(
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
-                globals.quiet,
+                globals.quiet > 0,
                 &cache,
                 printer,
             )
@@ -1493,9 +1647,11 @@ where
     I: IntoIterator<Item = T>,
     T: Into<OsString> + Clone,
 {
+    let args: Vec<OsString> = args.into_iter().map(Into::into).collect();
+
     // `std::env::args` is not `Send` so we parse before passing to our runtime
     // https://github.com/rust-lang/rust/pull/48005
-    let cli = match Cli::try_parse_from(args) {
+    let cli = match Cli::try_parse_from(&args) {
         Ok(cli) => cli,
         Err(mut err) => {
             if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
@@ -1552,10 +1708,22 @@ where
                     _ => {}
                 }
             }
+
+            // If the arguments requested `--error-format json`, report any usage errors as JSON
+            // too (but continue to render `--help` and `--version` as text).
+            if err.use_stderr() && requests_json_errors(&args) {
+                ErrorReport::usage(&err).print();
+                std::process::exit(err.exit_code());
+            }
+
             err.exit()
         }
     };
 
+    // Determine how to render any errors, including those reported by individual commands.
+    let error_format = cli.top_level.global_args.error_format;
+    commands::set_error_format(error_format);
+
     // Windows has a default stack size of 1MB, which is lower than the linux and mac default.
     // https://learn.microsoft.com/en-us/cpp/build/reference/stack-stack-allocations?view=msvc-170
     // We support increasing the stack size to avoid stack overflows in debug mode on Windows. In
@@ -1598,6 +1766,10 @@ where
 
     match result {
         Ok(code) => code.into(),
+        Err(err) if error_format == ErrorFormat::Json => {
+            ErrorReport::from_error(&err).print();
+            ExitStatus::Error.into()
+        }
         Err(err) => {
             let mut causes = err.chain();
             eprintln!("{}: {}", "error".red().bold(), causes.next().unwrap());
@@ -1608,3 +1780,11 @@ where
         }
     }
 }
+
+/// Returns `true` if the (possibly invalid) command-line arguments include `--error-format json`.
+fn requests_json_errors(args: &[OsString]) -> bool {
+    args.iter().any(|arg| arg == "--error-format=json")
+        || args
+            .windows(2)
+            .any(|pair| pair[0] == "--error-format" && pair[1] == "json")
+}
@@ -5,12 +5,17 @@ use indicatif::ProgressDrawTarget;
 pub(crate) enum Printer {
     /// A printer that prints to standard streams (e.g., stdout).
     Default,
-    /// A printer that suppresses all output.
+    /// A printer that suppresses all output other than warnings and errors.
     Quiet,
+    /// A printer that suppresses all output other than errors, including warnings.
+    Silent,
     /// A printer that prints all output, including debug messages.
     Verbose,
     /// A printer that prints to standard streams, excluding all progress outputs
     NoProgress,
+    /// A printer that prints to stdout, but suppresses human-readable output on stderr, such that
+    /// stderr is reserved for the JSON reports of `--error-format json`.
+    Json,
 }
 
 impl Printer {
@@ -18,11 +23,12 @@ impl Printer {
     pub(crate) fn target(self) -> ProgressDrawTarget {
         match self {
             Self::Default => ProgressDrawTarget::stderr(),
-            Self::Quiet => ProgressDrawTarget::hidden(),
+            Self::Quiet | Self::Silent => ProgressDrawTarget::hidden(),
             // Confusingly, hide the progress bar when in verbose mode.
             // Otherwise, it gets interleaved with debug messages.
             Self::Verbose => ProgressDrawTarget::hidden(),
             Self::NoProgress => ProgressDrawTarget::hidden(),
+            Self::Json => ProgressDrawTarget::hidden(),
         }
     }
 
@@ -30,9 +36,10 @@ impl Printer {
     pub(crate) fn stdout(self) -> Stdout {
         match self {
             Self::Default => Stdout::Enabled,
-            Self::Quiet => Stdout::Disabled,
+            Self::Quiet | Self::Silent => Stdout::Disabled,
             Self::Verbose => Stdout::Enabled,
             Self::NoProgress => Stdout::Enabled,
+            Self::Json => Stdout::Enabled,
         }
     }
 
@@ -40,9 +47,10 @@ impl Printer {
     pub(crate) fn stderr(self) -> Stderr {
         match self {
             Self::Default => Stderr::Enabled,
-            Self::Quiet => Stderr::Disabled,
+            Self::Quiet | Self::Silent => Stderr::Disabled,
             Self::Verbose => Stderr::Enabled,
             Self::NoProgress => Stderr::Enabled,
+            Self::Json => Stderr::Disabled,
         }
     }
 }
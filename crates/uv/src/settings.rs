@@ -1,8 +1,10 @@
+use std::collections::BTreeSet;
 use std::env::VarError;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
 
 use url::Url;
 use uv_cache::{CacheArgs, Refresh};
@@ -13,24 +15,31 @@ use uv_cli::{
 use uv_cli::{
     AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
     PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolDirArgs,
+    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PipWhyArgs, PythonFindArgs, PythonInstallArgs,
+    PythonListArgs, PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolDirArgs,
     ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, DevMode, EditableMode, ExportFormat,
-    ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType,
-    NoBinary, NoBuild, PreviewMode, Reinstall, SourceStrategy, TargetTriple, TrustedHost,
-    TrustedPublishing, Upgrade, VersionControlSystem,
+    AllowYanked, BuildOptions, Concurrency, ConfigSettings, DevMode, DryRun, EditableMode,
+    ExportFormat, ExtrasSpecification, GroupsSpecification, HashCheckingMode, IndexAuth,
+    IndexStrategy, InstallOptions, KeyringProviderType, LineEnding, NoBinary, NoBuild,
+    OutputFormat, PreviewMode, Reinstall, SourceStrategy, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade, UpgradeStrategy, VersionControlSystem,
 };
-use uv_distribution_types::{DependencyMetadata, IndexLocations};
-use uv_install_wheel::linker::LinkMode;
+use uv_distribution_types::{DependencyMetadata, IndexLocations, IndexUrl};
+use uv_install_wheel::linker::{DistInfoMode, LinkMode};
 use uv_normalize::PackageName;
-use uv_pep508::{ExtraName, RequirementOrigin};
-use uv_pypi_types::{Requirement, SupportedEnvironments};
-use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
-use uv_resolver::{AnnotationStyle, DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use uv_pep508::{ExtraName, MarkerOverride, RequirementOrigin};
+use uv_pypi_types::{HashAlgorithm, Requirement, SupportedEnvironments};
+use uv_python::{
+    ImplementationName, Prefix, PythonDownloads, PythonPreference, PythonVersion, Target,
+};
+use uv_requirements_txt::DEFAULT_MAX_INCLUDE_DEPTH;
+use uv_resolver::{
+    AnnotationStyle, Denylist, DependencyMode, ExcludeNewer, PackageResolutionMode, PrereleaseMode,
+    ResolutionBudget, ResolutionMode,
+};
 use uv_settings::{
     Combine, FilesystemOptions, Options, PipOptions, PublishOptions, ResolverInstallerOptions,
     ResolverOptions,
@@ -48,7 +57,7 @@ const PYPI_PUBLISH_URL: &str = "https://upload.pypi.org/legacy/";
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct GlobalSettings {
-    pub(crate) quiet: bool,
+    pub(crate) quiet: u8,
     pub(crate) verbose: u8,
     pub(crate) color: ColorChoice,
     pub(crate) native_tls: bool,
@@ -106,6 +115,11 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_installs))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
+                open_files: args
+                    .max_open_files
+                    .combine(workspace.and_then(|workspace| workspace.globals.max_open_files))
+                    .map(NonZeroUsize::get)
+                    .unwrap_or_else(Concurrency::open_files),
             },
             connectivity: if flag(args.offline, args.no_offline)
                 .combine(workspace.and_then(|workspace| workspace.globals.offline))
@@ -221,6 +235,7 @@ pub(crate) struct RunSettings {
     pub(crate) frozen: bool,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: DevMode,
+    pub(crate) groups: GroupsSpecification,
     pub(crate) editable: EditableMode,
     pub(crate) with: Vec<String>,
     pub(crate) with_editable: Vec<String>,
@@ -247,6 +262,7 @@ impl RunSettings {
             no_dev,
             module: _,
             only_dev,
+            groups,
             no_editable,
             script: _,
             command: _,
@@ -274,6 +290,11 @@ impl RunSettings {
                 extra.unwrap_or_default(),
             ),
             dev: DevMode::from_args(dev, no_dev, only_dev),
+            groups: GroupsSpecification::from_args(
+                groups.group,
+                groups.all_groups,
+                groups.no_default_groups,
+            ),
             editable: EditableMode::from_args(no_editable),
             with,
             with_editable,
@@ -684,6 +705,7 @@ pub(crate) struct SyncSettings {
     pub(crate) frozen: bool,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: DevMode,
+    pub(crate) groups: GroupsSpecification,
     pub(crate) editable: EditableMode,
     pub(crate) install_options: InstallOptions,
     pub(crate) modifications: Modifications,
@@ -704,6 +726,7 @@ impl SyncSettings {
             dev,
             no_dev,
             only_dev,
+            groups,
             no_editable,
             inexact,
             exact,
@@ -732,6 +755,11 @@ impl SyncSettings {
                 extra.unwrap_or_default(),
             ),
             dev: DevMode::from_args(dev, no_dev, only_dev),
+            groups: GroupsSpecification::from_args(
+                groups.group,
+                groups.all_groups,
+                groups.no_default_groups,
+            ),
             editable: EditableMode::from_args(no_editable),
             install_options: InstallOptions::new(
                 no_install_project,
@@ -987,6 +1015,7 @@ pub(crate) struct ExportSettings {
     pub(crate) package: Option<PackageName>,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: DevMode,
+    pub(crate) groups: GroupsSpecification,
     pub(crate) editable: EditableMode,
     pub(crate) hashes: bool,
     pub(crate) install_options: InstallOptions,
@@ -1011,6 +1040,7 @@ impl ExportSettings {
             dev,
             no_dev,
             only_dev,
+            groups,
             no_editable,
             hashes,
             no_hashes,
@@ -1034,6 +1064,11 @@ impl ExportSettings {
                 extra.unwrap_or_default(),
             ),
             dev: DevMode::from_args(dev, no_dev, only_dev),
+            groups: GroupsSpecification::from_args(
+                groups.group,
+                groups.all_groups,
+                groups.no_default_groups,
+            ),
             editable: EditableMode::from_args(no_editable),
             hashes: flag(hashes, no_hashes).unwrap_or(true),
             install_options: InstallOptions::new(
@@ -1056,12 +1091,35 @@ impl ExportSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PipCompileSettings {
     pub(crate) src_file: Vec<PathBuf>,
+    pub(crate) tox_env: Option<String>,
+    pub(crate) max_include_depth: usize,
     pub(crate) constraint: Vec<PathBuf>,
     pub(crate) r#override: Vec<PathBuf>,
+    pub(crate) override_add: bool,
+    pub(crate) pin_file: Vec<PathBuf>,
     pub(crate) build_constraint: Vec<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) python_implementation: Option<ImplementationName>,
+    pub(crate) markers: Vec<MarkerOverride>,
+    pub(crate) format: Option<OutputFormat>,
+    pub(crate) force: bool,
+    pub(crate) append: bool,
+    pub(crate) absolute_paths: bool,
+    pub(crate) hash_algorithm: HashAlgorithm,
+    pub(crate) line_ending: LineEnding,
+    pub(crate) allow_yanked: AllowYanked,
+    pub(crate) allowed_packages: Option<BTreeSet<PackageName>>,
+    pub(crate) denied: Denylist,
+    pub(crate) resolution_packages: Vec<PackageResolutionMode>,
+    pub(crate) strict_extras: bool,
+    pub(crate) upgrade_strategy: UpgradeStrategy,
+    pub(crate) resolution_budget: ResolutionBudget,
+    pub(crate) cache_resolution: bool,
+    pub(crate) index_auth: Vec<IndexAuth>,
+    pub(crate) index_auth_file: Option<PathBuf>,
+    pub(crate) index_mirror: Vec<IndexUrl>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1071,8 +1129,12 @@ impl PipCompileSettings {
     pub(crate) fn resolve(args: PipCompileArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipCompileArgs {
             src_file,
+            tox_env,
+            max_include_depth,
             constraint,
             r#override,
+            override_add,
+            pin_file,
             extra,
             all_extras,
             no_all_extras,
@@ -1081,6 +1143,12 @@ impl PipCompileSettings {
             no_deps,
             deps,
             output_file,
+            force,
+            append,
+            absolute_paths,
+            relative_paths,
+            format,
+            line_ending,
             no_strip_extras,
             strip_extras,
             no_strip_markers,
@@ -1097,12 +1165,15 @@ impl PipCompileSettings {
             no_system,
             generate_hashes,
             no_generate_hashes,
+            hash_algorithm,
             no_build,
             build,
             no_binary,
             only_binary,
             python_version,
             python_platform,
+            python_implementation,
+            marker,
             universal,
             no_universal,
             no_emit_package,
@@ -1116,6 +1187,20 @@ impl PipCompileSettings {
             no_emit_marker_expression,
             emit_index_annotation,
             no_emit_index_annotation,
+            allow_yanked,
+            allow_yanked_package,
+            allow_package,
+            deny_package,
+            deny,
+            resolution_package,
+            strict_extras,
+            upgrade_strategy,
+            resolution_timeout,
+            max_resolution_steps,
+            cache_resolution,
+            index_auth,
+            index_auth_file,
+            index_mirror,
             compat_args: _,
         } = args;
 
@@ -1155,6 +1240,8 @@ impl PipCompileSettings {
 
         Self {
             src_file,
+            tox_env,
+            max_include_depth: max_include_depth.unwrap_or(DEFAULT_MAX_INCLUDE_DEPTH),
             constraint: constraint
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -1167,9 +1254,37 @@ impl PipCompileSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            override_add,
+            pin_file,
             constraints_from_workspace,
             overrides_from_workspace,
             environments,
+            python_implementation,
+            markers: marker,
+            format,
+            force,
+            append,
+            absolute_paths: flag(absolute_paths, relative_paths).unwrap_or(false),
+            hash_algorithm,
+            line_ending: line_ending.unwrap_or_default(),
+            allow_yanked: AllowYanked::from_args(allow_yanked, allow_yanked_package),
+            allowed_packages: (!allow_package.is_empty())
+                .then(|| allow_package.into_iter().collect()),
+            denied: Denylist::from_args(deny_package, deny),
+            resolution_packages: resolution_package,
+            strict_extras,
+            upgrade_strategy: upgrade_strategy.unwrap_or_default(),
+            resolution_budget: ResolutionBudget {
+                timeout: resolution_timeout.map(Duration::from_secs),
+                max_steps: max_resolution_steps,
+            },
+            cache_resolution,
+            index_auth,
+            index_auth_file,
+            index_mirror: index_mirror
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -1211,9 +1326,15 @@ impl PipCompileSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
+    pub(crate) tox_env: Option<String>,
+    pub(crate) max_include_depth: usize,
     pub(crate) constraint: Vec<PathBuf>,
     pub(crate) build_constraint: Vec<PathBuf>,
-    pub(crate) dry_run: bool,
+    pub(crate) dry_run: DryRun,
+    pub(crate) verify_install: bool,
+    pub(crate) index_auth: Vec<IndexAuth>,
+    pub(crate) index_auth_file: Option<PathBuf>,
+    pub(crate) index_mirror: Vec<IndexUrl>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1223,6 +1344,8 @@ impl PipSyncSettings {
     pub(crate) fn resolve(args: Box<PipSyncArgs>, filesystem: Option<FilesystemOptions>) -> Self {
         let PipSyncArgs {
             src_file,
+            tox_env,
+            max_include_depth,
             constraint,
             build_constraint,
             installer,
@@ -1248,12 +1371,20 @@ impl PipSyncSettings {
             python_platform,
             strict,
             no_strict,
+            compile_bytecode_concurrency,
             dry_run,
+            check,
+            verify_install,
+            index_auth,
+            index_auth_file,
+            index_mirror,
             compat_args: _,
         } = *args;
 
         Self {
             src_file,
+            tox_env,
+            max_include_depth: max_include_depth.unwrap_or(DEFAULT_MAX_INCLUDE_DEPTH),
             constraint: constraint
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -1262,7 +1393,14 @@ impl PipSyncSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
-            dry_run,
+            dry_run: DryRun::from_args(dry_run, check),
+            verify_install,
+            index_auth,
+            index_auth_file,
+            index_mirror: index_mirror
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -1283,6 +1421,7 @@ impl PipSyncSettings {
                     python_version,
                     python_platform,
                     strict: flag(strict, no_strict),
+                    compile_bytecode_concurrency,
                     ..PipOptions::from(installer)
                 },
                 filesystem,
@@ -1297,11 +1436,33 @@ impl PipSyncSettings {
 pub(crate) struct PipInstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirement: Vec<PathBuf>,
+    pub(crate) tox_env: Option<String>,
+    pub(crate) max_include_depth: usize,
+    pub(crate) no_deps_requirement: Vec<PathBuf>,
+    pub(crate) freeze_requirement: Vec<PathBuf>,
     pub(crate) editable: Vec<String>,
     pub(crate) constraint: Vec<PathBuf>,
     pub(crate) r#override: Vec<PathBuf>,
+    pub(crate) override_add: bool,
     pub(crate) build_constraint: Vec<PathBuf>,
+    pub(crate) build_lock: Option<PathBuf>,
     pub(crate) dry_run: bool,
+    pub(crate) venv: Option<PathBuf>,
+    pub(crate) dist_info: DistInfoMode,
+    pub(crate) verify_install: bool,
+    pub(crate) timings: bool,
+    pub(crate) allow_yanked: AllowYanked,
+    pub(crate) allowed_packages: Option<BTreeSet<PackageName>>,
+    pub(crate) denied: Denylist,
+    pub(crate) resolution_packages: Vec<PackageResolutionMode>,
+    pub(crate) strict_extras: bool,
+    pub(crate) prefer_sdist_package: Vec<PackageName>,
+    pub(crate) resolution_budget: ResolutionBudget,
+    pub(crate) constrain_installed: bool,
+    pub(crate) ignore_installed: bool,
+    pub(crate) index_auth: Vec<IndexAuth>,
+    pub(crate) index_auth_file: Option<PathBuf>,
+    pub(crate) index_mirror: Vec<IndexUrl>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) refresh: Refresh,
@@ -1314,13 +1475,19 @@ impl PipInstallSettings {
         let PipInstallArgs {
             package,
             requirement,
+            tox_env,
+            max_include_depth,
+            no_deps_requirement,
+            freeze_requirement,
             editable,
             constraint,
             r#override,
+            override_add,
             extra,
             all_extras,
             no_all_extras,
             build_constraint,
+            build_lock,
             refresh,
             no_deps,
             deps,
@@ -1336,15 +1503,35 @@ impl PipInstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            no_dist_info,
+            venv,
             no_build,
             build,
             no_binary,
             only_binary,
+            prefer_sdist_package,
             python_version,
             python_platform,
             strict,
             no_strict,
+            compile_bytecode_concurrency,
             dry_run,
+            verify_install,
+            timings,
+            allow_yanked,
+            allow_yanked_package,
+            allow_package,
+            deny_package,
+            deny,
+            resolution_package,
+            strict_extras,
+            resolution_timeout,
+            max_resolution_steps,
+            constrain_installed,
+            ignore_installed,
+            index_auth,
+            index_auth_file,
+            index_mirror,
             compat_args: _,
         } = args;
 
@@ -1379,6 +1566,10 @@ impl PipInstallSettings {
         Self {
             package,
             requirement,
+            tox_env,
+            max_include_depth: max_include_depth.unwrap_or(DEFAULT_MAX_INCLUDE_DEPTH),
+            no_deps_requirement,
+            freeze_requirement,
             editable,
             constraint: constraint
                 .into_iter()
@@ -1388,11 +1579,36 @@ impl PipInstallSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            override_add,
             build_constraint: build_constraint
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            build_lock,
             dry_run,
+            venv,
+            dist_info: DistInfoMode::from_args(no_dist_info),
+            verify_install,
+            timings,
+            allow_yanked: AllowYanked::from_args(allow_yanked, allow_yanked_package),
+            allowed_packages: (!allow_package.is_empty())
+                .then(|| allow_package.into_iter().collect()),
+            denied: Denylist::from_args(deny_package, deny),
+            resolution_packages: resolution_package,
+            strict_extras,
+            prefer_sdist_package,
+            resolution_budget: ResolutionBudget {
+                timeout: resolution_timeout.map(Duration::from_secs),
+                max_steps: max_resolution_steps,
+            },
+            constrain_installed,
+            ignore_installed,
+            index_auth,
+            index_auth_file,
+            index_mirror: index_mirror
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
             constraints_from_workspace,
             overrides_from_workspace,
             refresh: Refresh::from(refresh),
@@ -1407,6 +1623,7 @@ impl PipInstallSettings {
                     no_binary,
                     only_binary,
                     strict: flag(strict, no_strict),
+                    compile_bytecode_concurrency,
                     extra,
                     all_extras: flag(all_extras, no_all_extras),
                     no_deps: flag(no_deps, deps),
@@ -1478,7 +1695,7 @@ impl PipUninstallSettings {
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct PipFreezeSettings {
-    pub(crate) exclude_editable: bool,
+    pub(crate) editable: Option<bool>,
     pub(crate) settings: PipSettings,
 }
 
@@ -1487,6 +1704,7 @@ impl PipFreezeSettings {
     pub(crate) fn resolve(args: PipFreezeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipFreezeArgs {
             exclude_editable,
+            editable_only,
             strict,
             no_strict,
             python,
@@ -1496,7 +1714,7 @@ impl PipFreezeSettings {
         } = args;
 
         Self {
-            exclude_editable,
+            editable: flag(editable_only, exclude_editable),
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -1638,6 +1856,37 @@ impl PipTreeSettings {
     }
 }
 
+/// The resolved settings to use for a `pip why` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipWhySettings {
+    pub(crate) package: PackageName,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipWhySettings {
+    /// Resolve the [`PipWhySettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PipWhyArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PipWhyArgs {
+            package,
+            python,
+            system,
+            no_system,
+        } = args;
+
+        Self {
+            package,
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system),
+                    ..PipOptions::default()
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip check` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -2116,8 +2365,10 @@ pub(crate) struct PipSettings {
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
+    pub(crate) compile_bytecode_concurrency: Option<NonZeroUsize>,
     pub(crate) sources: SourceStrategy,
     pub(crate) hash_checking: Option<HashCheckingMode>,
+    pub(crate) infer_require_hashes: bool,
     pub(crate) upgrade: Upgrade,
     pub(crate) reinstall: Reinstall,
 }
@@ -2176,6 +2427,7 @@ impl PipSettings {
             annotation_style,
             link_mode,
             compile_bytecode,
+            compile_bytecode_concurrency,
             require_hashes,
             verify_hashes,
             no_sources,
@@ -2350,6 +2602,7 @@ impl PipSettings {
                     .combine(verify_hashes)
                     .unwrap_or_default(),
             ),
+            infer_require_hashes: args.require_hashes.combine(require_hashes).is_none(),
             python: args.python.combine(python),
             system: args.system.combine(system).unwrap_or_default(),
             break_system_packages: args
@@ -2362,6 +2615,9 @@ impl PipSettings {
                 .compile_bytecode
                 .combine(compile_bytecode)
                 .unwrap_or_default(),
+            compile_bytecode_concurrency: args
+                .compile_bytecode_concurrency
+                .combine(compile_bytecode_concurrency),
             sources: SourceStrategy::from_args(
                 args.no_sources.combine(no_sources).unwrap_or_default(),
             ),
@@ -2398,6 +2654,24 @@ impl PipSettings {
             ),
         }
     }
+
+    /// Validate that the [`PipSettings`] don't contain contradictory options.
+    pub(crate) fn validate(&self) -> anyhow::Result<()> {
+        validate_resolution(self.resolution, &self.upgrade)
+    }
+}
+
+/// Returns an error if the resolution strategy contradicts the upgrade strategy.
+///
+/// `--resolution lowest` selects the lowest compatible version of every package, while `--upgrade`
+/// requests the highest compatible versions, so the two can't be combined.
+fn validate_resolution(resolution: ResolutionMode, upgrade: &Upgrade) -> anyhow::Result<()> {
+    if resolution == ResolutionMode::Lowest && upgrade.is_all() {
+        anyhow::bail!(
+            "`--upgrade` cannot be used with `--resolution lowest`, which always selects the lowest compatible version of each package"
+        );
+    }
+    Ok(())
 }
 
 impl<'a> From<ResolverInstallerSettingsRef<'a>> for ResolverSettingsRef<'a> {
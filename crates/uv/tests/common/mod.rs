@@ -525,6 +525,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip why` command for testing.
+    pub fn pip_why(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("pip").arg("why");
+        self.add_shared_args(&mut command, true);
+        command
+    }
+
     /// Create a `uv help` command with options shared across scenarios.
     #[allow(clippy::unused_self)]
     pub fn help(&self) -> Command {
@@ -1223,6 +1231,47 @@ pub fn make_project(dir: &Path, name: &str, body: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Serve a file over HTTP from a background thread, returning the URL at which it's available.
+///
+/// The server responds to every request with the contents of the file, regardless of the path.
+/// Use the `127\.0\.0\.1:\d+` filter to redact the server address in snapshots.
+pub fn serve_file(path: &Path) -> anyhow::Result<String> {
+    use std::io::{BufRead, BufReader, Write};
+
+    let contents = fs_err::read(path)?;
+    let filename = path
+        .file_name()
+        .and_then(|filename| filename.to_str())
+        .ok_or_else(|| anyhow::anyhow!("Invalid filename: {}", path.display()))?
+        .to_string();
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+
+            // Read (and discard) the request line and headers.
+            let mut reader = BufReader::new(&stream);
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|read| read > 0) && line != "\r\n" {
+                line.clear();
+            }
+
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/octet-stream\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                contents.len()
+            );
+            let _ = stream.write_all(&contents);
+        }
+    });
+
+    Ok(format!("http://{addr}/{filename}"))
+}
+
 // This is a fine-grained token that only has read-only access to the `uv-private-pypackage` repository
 pub const READ_ONLY_GITHUB_TOKEN: &[&str] = &[
     "Z2l0aHViX3BhdA==",
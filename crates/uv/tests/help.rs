@@ -48,7 +48,7 @@ fn help() {
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
     Global options:
-      -q, --quiet                      Do not print any output
+      -q, --quiet...                   Use quiet output
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
@@ -116,7 +116,7 @@ fn help_flag() {
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
     Global options:
-      -q, --quiet                      Do not print any output
+      -q, --quiet...                   Use quiet output
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
@@ -183,7 +183,7 @@ fn help_short_flag() {
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
     Global options:
-      -q, --quiet                      Do not print any output
+      -q, --quiet...                   Use quiet output
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
@@ -301,8 +301,12 @@ fn help_subcommand() {
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
     Global options:
-      -q, --quiet
-              Do not print any output
+      -q, --quiet...
+              Use quiet output.
+              
+              Suppresses progress bars and informational output, while still showing warnings and
+              errors. Repeating this option, e.g., `-qq`, will also suppress warnings, such that only
+              errors are shown.
 
       -v, --verbose...
               Use verbose output.
@@ -321,6 +325,19 @@ fn help_subcommand() {
               - always: Enables colored output regardless of the detected environment
               - never:  Disables colored output
 
+          --error-format <ERROR_FORMAT>
+              The format in which to display errors.
+              
+              With `json`, a failing command prints a single JSON object to stderr, describing the
+              kind of error, its message, and any relevant context (e.g., the conflicting packages),
+              in lieu of human-readable text.
+              
+              [default: human]
+
+              Possible values:
+              - human: Display errors as human-readable text
+              - json:  Display errors as a machine-readable JSON object
+
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store.
               
@@ -339,6 +356,18 @@ fn help_subcommand() {
               
               When disabled, uv will only use locally cached data and locally available files.
 
+          --max-open-files <N>
+              The maximum number of files that may be open at once when downloading and installing
+              packages.
+              
+              The number of concurrent downloads and installs is reduced as needed to stay within this
+              limit, to avoid "too many open files" errors on systems with a low limit on open file
+              descriptors (e.g., `ulimit -n`).
+              
+              Defaults to half of the soft limit on open file descriptors for the current process.
+              
+              [env: UV_MAX_OPEN_FILES=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -379,6 +408,9 @@ fn help_subcommand() {
               Normally, configuration files are discovered in the current directory, parent directories,
               or user configuration directories.
               
+              Any `--config-file` is also ignored. Environment variables and command-line arguments are
+              still respected.
+              
               [env: UV_NO_CONFIG=]
 
       -h, --help
@@ -471,8 +503,12 @@ fn help_subsubcommand() {
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
     Global options:
-      -q, --quiet
-              Do not print any output
+      -q, --quiet...
+              Use quiet output.
+              
+              Suppresses progress bars and informational output, while still showing warnings and
+              errors. Repeating this option, e.g., `-qq`, will also suppress warnings, such that only
+              errors are shown.
 
       -v, --verbose...
               Use verbose output.
@@ -491,6 +527,19 @@ fn help_subsubcommand() {
               - always: Enables colored output regardless of the detected environment
               - never:  Disables colored output
 
+          --error-format <ERROR_FORMAT>
+              The format in which to display errors.
+              
+              With `json`, a failing command prints a single JSON object to stderr, describing the
+              kind of error, its message, and any relevant context (e.g., the conflicting packages),
+              in lieu of human-readable text.
+              
+              [default: human]
+
+              Possible values:
+              - human: Display errors as human-readable text
+              - json:  Display errors as a machine-readable JSON object
+
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store.
               
@@ -509,6 +558,18 @@ fn help_subsubcommand() {
               
               When disabled, uv will only use locally cached data and locally available files.
 
+          --max-open-files <N>
+              The maximum number of files that may be open at once when downloading and installing
+              packages.
+              
+              The number of concurrent downloads and installs is reduced as needed to stay within this
+              limit, to avoid "too many open files" errors on systems with a low limit on open file
+              descriptors (e.g., `ulimit -n`).
+              
+              Defaults to half of the soft limit on open file descriptors for the current process.
+              
+              [env: UV_MAX_OPEN_FILES=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -549,6 +610,9 @@ fn help_subsubcommand() {
               Normally, configuration files are discovered in the current directory, parent directories,
               or user configuration directories.
               
+              Any `--config-file` is also ignored. Environment variables and command-line arguments are
+              still respected.
+              
               [env: UV_NO_CONFIG=]
 
       -h, --help
@@ -595,7 +659,7 @@ fn help_flag_subcommand() {
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
     Global options:
-      -q, --quiet                      Do not print any output
+      -q, --quiet...                   Use quiet output
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
@@ -649,7 +713,7 @@ fn help_flag_subsubcommand() {
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
     Global options:
-      -q, --quiet                      Do not print any output
+      -q, --quiet...                   Use quiet output
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
@@ -792,7 +856,7 @@ fn help_with_global_option() {
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
     Global options:
-      -q, --quiet                      Do not print any output
+      -q, --quiet...                   Use quiet output
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
@@ -896,7 +960,7 @@ fn help_with_no_pager() {
               Disable automatic downloads of Python. [env: "UV_PYTHON_DOWNLOADS=never"]
 
     Global options:
-      -q, --quiet                      Do not print any output
+      -q, --quiet...                   Use quiet output
       -v, --verbose...                 Use verbose output
           --color <COLOR_CHOICE>       Control colors in output [default: auto] [possible values: auto,
                                        always, never]
@@ -190,3 +190,61 @@ fn check_multiple_incompatible_packages() -> Result<()> {
 
     Ok(())
 }
+
+// requests 2.31.0 requires idna (<4,>=2.5)
+// this test uninstalls idna to trigger a failure.
+#[test]
+fn check_missing_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0")?;
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context
+        .pip_uninstall()
+        .arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - idna==3.6
+    "###
+    );
+
+    uv_snapshot!(context.pip_check(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 4 packages in [TIME]
+    Found 1 incompatibility
+    The package `requests` requires `idna>=2.5,<4`, but it's not installed
+    "###
+    );
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use std::path::PathBuf;
 
 use anyhow::{bail, Context, Result};
 use assert_fs::prelude::*;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use url::Url;
 
 use common::{uv_snapshot, TestContext};
@@ -145,6 +145,101 @@ fn missing_venv() -> Result<()> {
     Ok(())
 }
 
+/// Compile an empty `requirements.in` file, which should emit the header alone.
+#[test]
+fn compile_empty_requirements_in() -> Result<()> {
+    let context = TestContext::new("3.12");
+    context.temp_dir.child("requirements.in").touch()?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+
+    ----- stderr -----
+    warning: Requirements file requirements.in does not contain any dependencies
+    Resolved in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Compile a `requirements.in` file that only contains comments and blank lines.
+#[test]
+fn compile_comments_only_requirements_in() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        # This file intentionally left blank.
+
+        # anyio==3.7.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+
+    ----- stderr -----
+    warning: Requirements file requirements.in does not contain any dependencies
+    Resolved in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Compile a `requirements.in` file that only contains index directives. The file isn't empty,
+/// but there's nothing to resolve.
+#[test]
+fn compile_index_url_only_requirements_in() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        --index-url https://test.pypi.org/simple
+        --extra-index-url https://pypi.org/simple
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+
+    ----- stderr -----
+    Resolved in [TIME]
+    "###
+    );
+
+    // The index URLs should be emitted if requested.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-index-url"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-index-url
+    --index-url https://test.pypi.org/simple
+    --extra-index-url https://pypi.org/simple
+
+    ----- stderr -----
+    Resolved in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific version of `anyio` from a `pyproject.toml` file.
 #[test]
 fn compile_pyproject_toml() -> Result<()> {
@@ -300,6 +395,87 @@ fn compile_constraints_txt() -> Result<()> {
     Ok(())
 }
 
+/// Freeze a subset of packages with `--pin-file`, while upgrading the rest.
+#[test]
+fn compile_pin_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+        blinker==1.6.2
+        click==8.1.3
+        flask==3.0.0
+        itsdangerous==2.1.2
+        jinja2==3.1.2
+        markupsafe==2.1.3
+        werkzeug==3.0.0
+    "})?;
+
+    let frozen_txt = context.temp_dir.child("frozen.txt");
+    frozen_txt.write_str("click==8.1.6\nwerkzeug==3.0.0")?;
+
+    // The frozen packages should be held at their pinned versions, while the others are upgraded.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--upgrade")
+            .arg("--pin-file")
+            .arg("frozen.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --pin-file frozen.txt
+    blinker==1.7.0
+        # via flask
+    click==8.1.6
+        # via
+        #   -c frozen.txt
+        #   flask
+    flask==3.0.2
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.0
+        # via
+        #   -c frozen.txt
+        #   flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    // Frozen packages must be pinned to an exact version.
+    frozen_txt.write_str("click>=8.1.6")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--pin-file")
+            .arg("frozen.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Frozen packages must be pinned to an exact version (e.g., `click==1.0.0`), but found: `click>=8.1.6`
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with an inline constraint.
 #[test]
 fn compile_constraints_inline() -> Result<()> {
@@ -367,6 +543,131 @@ fn compile_constraints_markers() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a package from a `requirements.in` file, with constraints on the same package split
+/// across two files with distinct markers. Each constraint should apply only on its own platform.
+#[test]
+fn compile_constraints_markers_multiple_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("urllib3")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("urllib3<2; python_version == '3.8'")?;
+
+    let constraints_py39_txt = context.temp_dir.child("constraints-py39.txt");
+    constraints_py39_txt.write_str("urllib3<1.27; python_version == '3.9'")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt")
+            .arg("--constraint")
+            .arg("constraints-py39.txt")
+            .arg("--python-version")
+            .arg("3.8"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt --constraint constraints-py39.txt --python-version 3.8
+    urllib3==1.26.18
+        # via
+        #   -c constraints.txt
+        #   -r requirements.in
+
+    ----- stderr -----
+    warning: The requested Python version 3.8 is not available; 3.12.[X] will be used to build dependencies instead.
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt")
+            .arg("--constraint")
+            .arg("constraints-py39.txt")
+            .arg("--python-version")
+            .arg("3.9"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt --constraint constraints-py39.txt --python-version 3.9
+    urllib3==1.26.18
+        # via
+        #   -c constraints-py39.txt
+        #   -r requirements.in
+
+    ----- stderr -----
+    warning: The requested Python version 3.9 is not available; 3.12.[X] will be used to build dependencies instead.
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // Neither constraint applies on Python 3.12.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt")
+            .arg("--constraint")
+            .arg("constraints-py39.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt --constraint constraints-py39.txt
+    urllib3==2.2.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a package from a `requirements.in` file, with constraints on the same package split
+/// across two files with the same markers. The constraints should be intersected, and both files
+/// should be reported as sources.
+#[test]
+fn compile_constraints_merged_multiple_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("urllib3")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("urllib3<2")?;
+
+    let constraints_legacy_txt = context.temp_dir.child("constraints-legacy.txt");
+    constraints_legacy_txt.write_str("urllib3<1.27")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt")
+            .arg("--constraint")
+            .arg("constraints-legacy.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt --constraint constraints-legacy.txt
+    urllib3==1.26.18
+        # via
+        #   -c constraints-legacy.txt
+        #   -c constraints.txt
+        #   -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with a `constraints.txt` file that uses an
 /// extra. The constraint should be enforced, but the extra should _not_ be included in the output
 /// (though it currently _is_ included).
@@ -416,36 +717,213 @@ fn compile_constraint_extra() -> Result<()> {
     Ok(())
 }
 
-/// Resolve a package from an optional extra in a `pyproject.toml` file.
+/// Resolve a package from a `requirements.in` file, with a `constraints.txt` file that spells the
+/// package name differently. Names are normalized, so the constraint should still be enforced.
 #[test]
-fn compile_pyproject_toml_extra() -> Result<()> {
+fn compile_constraint_unnormalized_name() -> Result<()> {
     let context = TestContext::new("3.12");
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"[build-system]
-requires = ["setuptools>=42"]
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask-login")?;
 
-[project]
-name = "project"
-version = "0.1.0"
-dependencies = []
-optional-dependencies.foo = [
-    "anyio==3.7.0",
-]
-"#,
-    )?;
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("Flask_Login<0.6")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
-            .arg("pyproject.toml")
-            .arg("--extra")
-            .arg("foo"), @r###"
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --extra foo
-    anyio==3.7.0
-        # via project (pyproject.toml)
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.2
+        # via flask-login
+    flask-login==0.5.0
+        # via
+        #   -c constraints.txt
+        #   -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Warn about constraints and overrides for packages that aren't part of the resolution.
+#[test]
+fn compile_unused_constraints_and_overrides() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("iniconfig<2\nflask==3.0.2\npython-dotenv==1.0.0\ncolorama<0.4 ; sys_platform == 'emscripten'")?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt")
+            .arg("--override")
+            .arg("overrides.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt --override overrides.txt
+    iniconfig==1.1.1
+        # via
+        #   -r requirements.in
+        #   -c constraints.txt
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Constraints for `flask`, `python-dotenv` are unused, as they aren't part of the resolution
+    warning: Override for `anyio` is unused, as it isn't part of the resolution
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a package whose extras are requested across multiple requirements files. The extras
+/// should be unioned, regardless of the order in which the files are provided.
+#[test]
+fn compile_extras_union_across_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_1 = context.temp_dir.child("requirements-1.in");
+    requirements_1.write_str("flask[dotenv]")?;
+
+    let requirements_2 = context.temp_dir.child("requirements-2.in");
+    requirements_2.write_str(indoc! {r"
+        flask
+        flask[async]
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements-1.in")
+            .arg("requirements-2.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements-1.in requirements-2.in
+    asgiref==3.8.1
+        # via flask
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.2
+        # via
+        #   -r requirements-1.in
+        #   -r requirements-2.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    python-dotenv==1.0.1
+        # via flask
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 9 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements-2.in")
+            .arg("requirements-1.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements-2.in requirements-1.in
+    asgiref==3.8.1
+        # via flask
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.2
+        # via
+        #   -r requirements-1.in
+        #   -r requirements-2.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    python-dotenv==1.0.1
+        # via flask
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 9 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a package from an optional extra in a `pyproject.toml` file.
+#[test]
+fn compile_pyproject_toml_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[build-system]
+requires = ["setuptools>=42"]
+
+[project]
+name = "project"
+version = "0.1.0"
+dependencies = []
+optional-dependencies.foo = [
+    "anyio==3.7.0",
+]
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--extra")
+            .arg("foo"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --extra foo
+    anyio==3.7.0
+        # via project (pyproject.toml)
     idna==3.6
         # via anyio
     sniffio==1.3.1
@@ -459,6 +937,72 @@ optional-dependencies.foo = [
     Ok(())
 }
 
+/// Resolve an extra with markers in a `pyproject.toml` file, ensuring that the markers are
+/// preserved on the extra's requirements.
+#[test]
+fn compile_pyproject_toml_extra_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[build-system]
+requires = ["setuptools>=42"]
+
+[project]
+name = "project"
+version = "0.1.0"
+dependencies = []
+optional-dependencies.foo = [
+    "iniconfig>=1 ; python_version < '3.9'",
+    "typing-extensions ; python_version >= '3.9'",
+]
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--extra")
+            .arg("foo")
+            .arg("-p")
+            .arg("3.8")
+            .arg("--universal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --extra foo -p 3.8 --universal
+    iniconfig==2.0.0 ; python_full_version < '3.9'
+        # via project (pyproject.toml)
+    typing-extensions==4.10.0 ; python_full_version >= '3.9'
+        # via project (pyproject.toml)
+
+    ----- stderr -----
+    warning: The requested Python version 3.8 is not available; 3.12.[X] will be used to build dependencies instead.
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    // Outside of universal mode, only the requirements that apply to the target Python version
+    // should be included.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--extra")
+            .arg("foo"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --extra foo
+    typing-extensions==4.10.0
+        # via project (pyproject.toml)
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from an extra with non-normalized names in a `pyproject.toml` file.
 #[test]
 fn compile_pyproject_toml_extra_name_normalization() -> Result<()> {
@@ -764,19 +1308,114 @@ setup(
     Ok(())
 }
 
-/// Compile a `setup.cfg` file.
+/// Compile a `pyproject.toml` file that isn't valid TOML.
 #[test]
-fn compile_setup_cfg() -> Result<()> {
+fn compile_pyproject_toml_invalid_syntax() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[build-system]
+requires = setuptools
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Failed to parse `pyproject.toml` during settings discovery:
+      TOML parse error at line 2, column 12
+        |
+      2 | requires = setuptools
+        |            ^
+      invalid string
+      expected `"`, `'`
+
+    error: Failed to parse `pyproject.toml`: not a valid TOML file
+      Caused by: TOML parse error at line 2, column 12
+      |
+    2 | requires = setuptools
+      |            ^
+    invalid string
+    expected `"`, `'`
+
+    "###
+    );
+
+    Ok(())
+}
+
+/// Compile a valid `pyproject.toml` file without a `[project]` table, which should defer to the
+/// build backend.
+#[test]
+fn compile_pyproject_toml_no_project() -> Result<()> {
     let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[build-system]
+requires = ["setuptools"]
+build-backend = "setuptools.build_meta"
+
+[tool.black]
+line-length = 88
+"#,
+    )?;
 
     let setup_cfg = context.temp_dir.child("setup.cfg");
     setup_cfg.write_str(
         r#"[options]
 packages = find:
 install_requires=
-    anyio
+    anyio==3.7.0
+"#,
+    )?;
 
-[options.extras_require]
+    let setup_py = context.temp_dir.child("setup.py");
+    setup_py.write_str(
+        r#"from setuptools import setup
+
+setup(name="dummypkg")
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml
+    anyio==3.7.0
+        # via dummypkg (pyproject.toml)
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Compile a `setup.cfg` file.
+#[test]
+fn compile_setup_cfg() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let setup_cfg = context.temp_dir.child("setup.cfg");
+    setup_cfg.write_str(
+        r#"[options]
+packages = find:
+install_requires=
+    anyio
+
+[options.extras_require]
 dev =
     iniconfig; python_version >= "3.7"
     mypy; python_version <= "3.8"
@@ -822,6 +1461,138 @@ setup(
     Ok(())
 }
 
+/// Compile the `deps` of a specific test environment in a `tox.ini` file.
+#[test]
+fn compile_tox_ini() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let tox_ini = context.temp_dir.child("tox.ini");
+    tox_ini.write_str(indoc! {r"
+        [tox]
+        envlist = py312, lint
+
+        [testenv]
+        deps =
+            pytest
+
+        [testenv:lint]
+        deps =
+            -r {toxinidir}/requirements-lint.txt
+            anyio
+    "})?;
+
+    let requirements_lint = context.temp_dir.child("requirements-lint.txt");
+    requirements_lint.write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("tox.ini")
+            .arg("--tox-env")
+            .arg("lint"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] tox.ini --tox-env lint
+    anyio==4.3.0
+        # via -r tox.ini
+    idna==3.6
+        # via anyio
+    iniconfig==2.0.0
+        # via -r requirements-lint.txt
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    // Index and build options in an included file should be respected.
+    requirements_lint.write_str(indoc! {r"
+        --index-url https://pypi.org/simple
+        --no-binary iniconfig
+        iniconfig
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("tox.ini")
+            .arg("--tox-env")
+            .arg("lint")
+            .arg("--emit-index-url")
+            .arg("--emit-build-options"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] tox.ini --tox-env lint --emit-index-url --emit-build-options
+    --index-url https://pypi.org/simple
+    --no-binary iniconfig
+
+    anyio==4.3.0
+        # via -r tox.ini
+    idna==3.6
+        # via anyio
+    iniconfig==2.0.0
+        # via -r requirements-lint.txt
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Limit the depth of nested `-r` includes with `--max-include-depth`.
+#[test]
+fn compile_max_include_depth() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("-r child.txt")?;
+
+    let child_txt = context.temp_dir.child("child.txt");
+    child_txt.write_str("-r grandchild.txt")?;
+
+    let grandchild_txt = context.temp_dir.child("grandchild.txt");
+    grandchild_txt.write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--max-include-depth")
+            .arg("1"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Error parsing included file in `requirements.in` at position 0
+      Caused by: Exceeded the maximum include depth of 1 in `child.txt` at position 0
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--max-include-depth")
+            .arg("2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --max-include-depth 2
+    iniconfig==2.0.0
+        # via -r grandchild.txt
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Compile a `setup.py` file.
 #[test]
 fn compile_setup_py() -> Result<()> {
@@ -1102,6 +1873,132 @@ fn compile_python_312_no_deps() -> Result<()> {
     Ok(())
 }
 
+/// Resolve requirements with implementation markers at Python 3.11, which should default to the
+/// interpreter's implementation (CPython) unless `--python-implementation` is provided.
+#[test]
+fn compile_python_version_implementation_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        iniconfig==2.0.0 ; platform_python_implementation == 'CPython' and python_full_version == '3.11.0'
+        idna==3.6 ; platform_python_implementation == 'PyPy'
+        sniffio==1.3.1 ; implementation_name == 'pypy'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-version")
+            .arg("3.11"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-version 3.11
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    warning: The requested Python version 3.11 is not available; 3.12.[X] will be used to build dependencies instead.
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-version")
+            .arg("3.11")
+            .arg("--python-implementation")
+            .arg("pypy"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-version 3.11 --python-implementation pypy
+    idna==3.6
+        # via -r requirements.in
+    sniffio==1.3.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    warning: The requested Python version 3.11 is not available; 3.12.[X] will be used to build dependencies instead.
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve requirements gated on `platform_machine` for an x86 Linux platform, overriding the
+/// `platform_machine` marker via `--marker`.
+#[test]
+fn compile_marker_override() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        iniconfig==2.0.0 ; platform_machine == 'x86_64'
+        idna==3.6 ; platform_machine == 'aarch64'
+        sniffio==1.3.1 ; platform_machine == 'aarch64' and os_name == 'posix'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-platform")
+            .arg("x86_64-unknown-linux-gnu"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform x86_64-unknown-linux-gnu
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-platform")
+            .arg("x86_64-unknown-linux-gnu")
+            .arg("--marker")
+            .arg("platform_machine=aarch64")
+            .arg("--marker")
+            .arg("os_name=posix"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform x86_64-unknown-linux-gnu --marker platform_machine=aarch64 --marker os_name=posix
+    idna==3.6
+        # via -r requirements.in
+    sniffio==1.3.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    // Only known marker names are accepted.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--marker")
+            .arg("platform_arch=aarch64"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'platform_arch=aarch64' for '--marker <KEY=VALUE>': `platform_arch` is not a known environment marker (expected one of: `implementation_name`, `implementation_version`, `os_name`, `platform_machine`, `platform_python_implementation`, `platform_release`, `platform_system`, `platform_version`, `python_full_version`, `python_version`, `sys_platform`)
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific version of Black at Python 3.7.
 #[test]
 fn compile_python_37() -> Result<()> {
@@ -1173,49 +2070,164 @@ fn compile_sdist_resolution_lowest() -> Result<()> {
     Ok(())
 }
 
-/// Resolve a specific version of Black against an invalid Python version.
+/// Resolve with `--resolution=lowest`, which should select the lowest compatible version of every
+/// package, including those that are constrained by multiple dependents.
+///
+/// Here, `idna` is required by both `anyio` (`>=2.8`) and `requests` (`>=2.5`), so the lowest
+/// version that satisfies both constraints is `2.8`.
 #[test]
-fn compile_python_invalid_version() -> Result<()> {
+fn compile_resolution_lowest() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("black==23.10.1")?;
+    requirements_in.write_str("anyio>=3.0.0\nrequests==2.31.0")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--python-version")
-            .arg("3.7.x"), @r###"
-    success: false
-    exit_code: 2
+            .arg("--resolution=lowest"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --resolution=lowest
+    anyio==3.0.0
+        # via -r requirements.in
+    certifi==2017.4.17
+        # via requests
+    charset-normalizer==2.0.0
+        # via requests
+    idna==2.8
+        # via
+        #   anyio
+        #   requests
+    requests==2.31.0
+        # via -r requirements.in
+    sniffio==1.1.0
+        # via anyio
+    urllib3==1.21.1
+        # via requests
 
     ----- stderr -----
-    error: invalid value '3.7.x' for '--python-version <PYTHON_VERSION>': Python version `3.7.x` could not be parsed: after parsing `3.7`, found `.x`, which is not part of a valid version
-
-    For more information, try '--help'.
+    Resolved 7 packages in [TIME]
     "###
     );
 
     Ok(())
 }
 
-/// Resolve a specific version of Black against an invalid Python version.
+/// `--upgrade` is contradictory with `--resolution=lowest`, and should be rejected.
 #[test]
-fn compile_python_dev_version() -> Result<()> {
+fn compile_resolution_lowest_upgrade() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("black==23.10.1")?;
+    requirements_in.write_str("anyio>=3.0.0")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--python-version")
-            .arg("3.7-dev"), @r###"
+            .arg("--resolution=lowest")
+            .arg("--upgrade"), @r###"
     success: false
     exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    error: invalid value '3.7-dev' for '--python-version <PYTHON_VERSION>': Python version `3.7-dev` is a development release
-
+    error: `--upgrade` cannot be used with `--resolution lowest`, which always selects the lowest compatible version of each package
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve `anyio` to its lowest compatible version with `--resolution-package`, while its
+/// dependencies continue to resolve to their highest compatible versions.
+#[test]
+fn compile_resolution_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>=3.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--resolution-package")
+            .arg("anyio=lowest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --resolution-package anyio=lowest
+    anyio==3.0.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    // Providing the same package multiple times is an error.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--resolution-package")
+            .arg("anyio=lowest")
+            .arg("--resolution-package")
+            .arg("anyio=highest"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `anyio` was provided multiple times to `--resolution-package`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a specific version of Black against an invalid Python version.
+#[test]
+fn compile_python_invalid_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black==23.10.1")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-version")
+            .arg("3.7.x"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '3.7.x' for '--python-version <PYTHON_VERSION>': Python version `3.7.x` could not be parsed: after parsing `3.7`, found `.x`, which is not part of a valid version
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a specific version of Black against an invalid Python version.
+#[test]
+fn compile_python_dev_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black==23.10.1")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-version")
+            .arg("3.7-dev"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '3.7-dev' for '--python-version <PYTHON_VERSION>': Python version `3.7-dev` is a development release
+
     For more information, try '--help'.
     "###
     );
@@ -1418,6 +2430,7 @@ fn compile_git_branch_https_dependency() -> Result<()> {
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
     uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979
         # via -r requirements.in
+        # resolved from test-branch
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -1446,6 +2459,53 @@ fn compile_git_tag_https_dependency() -> Result<()> {
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
     uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979
         # via -r requirements.in
+        # resolved from test-tag
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a Git dependency pinned to a tag, and lock the commit to which the tag points, noting
+/// the tag alongside it.
+#[test]
+#[cfg(feature = "git")]
+fn compile_git_tag_pinned_commit() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(
+        "uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@0.0.2",
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389
+        # via -r requirements.in
+        # resolved from 0.0.2
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // Without annotations, only the commit should be included.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-annotate"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-annotate
+    uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -1476,6 +2536,7 @@ fn compile_git_date_tag_https_dependency() -> Result<()> {
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
     uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979
         # via -r requirements.in
+        # resolved from 20240402
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -1559,6 +2620,7 @@ fn compile_git_refs_https_dependency() -> Result<()> {
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
     uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@9d01a806f17ddacb9c7b66b1b68574adf790b63f
         # via -r requirements.in
+        # resolved from refs/pull/4/head
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -1915,6 +2977,7 @@ fn compatible_repeated_url_dependency() -> Result<()> {
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
     uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389
         # via -r requirements.in
+        # resolved from 0.0.2
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -2106,10 +3169,11 @@ fn allowed_transitive_git_dependency() -> Result<()> {
 }
 
 /// Request `transitive_url_dependency`, which depends on `https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl`.
-/// Since this URL is declared as a constraint, we should accept it.
+/// Since this URL is declared as a constraint, and constraints can't be direct URL references, we
+/// should reject it.
 #[test]
 #[cfg(feature = "git")]
-fn allowed_transitive_url_dependency() -> Result<()> {
+fn rejected_transitive_url_dependency_constraint() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_in = context.temp_dir.child("requirements.in");
@@ -2122,20 +3186,12 @@ fn allowed_transitive_url_dependency() -> Result<()> {
         .arg("requirements.in")
         .arg("--constraint")
         .arg("constraints.txt"), @r###"
-    success: true
-    exit_code: 0
+    success: false
+    exit_code: 2
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt
-    hatchling-editable @ https://github.com/astral-sh/uv/files/14762645/hatchling_editable.zip
-        # via -r requirements.in
-    iniconfig @ git+https://github.com/pytest-dev/iniconfig@9cae43103df70bac6fde7b9f35ad11a9f1be0cb4
-        # via
-        #   -c constraints.txt
-        #   hatchling-editable
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    error: Direct URL references are not allowed as constraints (found: `iniconfig @ git+https://github.com/pytest-dev/iniconfig@9cae43103df70bac6fde7b9f35ad11a9f1be0cb4`). Constraints must be version specifiers; to install `iniconfig` from a URL, add it to your requirements file instead.
     "###
     );
 
@@ -2144,10 +3200,10 @@ fn allowed_transitive_url_dependency() -> Result<()> {
 
 /// Request `transitive_url_dependency`, which depends on `iniconfig @ git+https://github.com/pytest-dev/iniconfig@9cae43103df70bac6fde7b9f35ad11a9f1be0cb4`.
 /// Since this `iniconfig @ git+https://github.com/pytest-dev/iniconfig.git@9cae43103df70bac6fde7b9f35ad11a9f1be0cb4.git` is declared as a constraint, and
-/// those map to the same canonical URL, we should accept it.
+/// constraints can't be direct URL references, we should reject it.
 #[test]
 #[cfg(feature = "git")]
-fn allowed_transitive_canonical_url_dependency() -> Result<()> {
+fn rejected_transitive_canonical_url_dependency_constraint() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_in = context.temp_dir.child("requirements.in");
@@ -2160,20 +3216,12 @@ fn allowed_transitive_canonical_url_dependency() -> Result<()> {
         .arg("requirements.in")
         .arg("--constraint")
         .arg("constraints.txt"), @r###"
-    success: true
-    exit_code: 0
+    success: false
+    exit_code: 2
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt
-    hatchling-editable @ https://github.com/astral-sh/uv/files/14762645/hatchling_editable.zip
-        # via -r requirements.in
-    iniconfig @ git+https://github.com/pytest-dev/iniconfig.git@9cae43103df70bac6fde7b9f35ad11a9f1be0cb4
-        # via
-        #   -c constraints.txt
-        #   hatchling-editable
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    error: Direct URL references are not allowed as constraints (found: `iniconfig @ git+https://github.com/pytest-dev/iniconfig.git@9cae43103df70bac6fde7b9f35ad11a9f1be0cb4`). Constraints must be version specifiers; to install `iniconfig` from a URL, add it to your requirements file instead.
     "###
     );
 
@@ -2212,8 +3260,9 @@ fn allowed_transitive_url_path_dependency() -> Result<()> {
     Ok(())
 }
 
-/// A dependency with conflicting URLs in `requirements.in` and `constraints.txt` should be ignored
-/// if the dependency has an override.
+/// A dependency with conflicting URLs in `requirements.in` and `constraints.txt`. Constraints can't
+/// be direct URL references, so the constraint should be rejected, even if the dependency has an
+/// override.
 #[test]
 fn requirement_constraint_override_url() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2234,12 +3283,66 @@ fn requirement_constraint_override_url() -> Result<()> {
         .arg("--override")
         .arg("overrides.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because there is no version of anyio==3.7.0 and you require anyio==3.7.0, we can conclude that your requirements are unsatisfiable.
+    error: Direct URL references are not allowed as constraints (found: `anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl`). Constraints must be version specifiers; to install `anyio` from a URL, add it to your requirements file instead.
+    "###
+    );
+
+    Ok(())
+}
+
+/// An override for a package that isn't otherwise required has no effect, unless
+/// `--override-add` is provided, in which case it's added to the resolution.
+#[test]
+fn requirement_override_add() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("idna==3.6")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--override")
+        .arg("overrides.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --override overrides.txt
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Override for `idna` is unused, as it isn't part of the resolution
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--override")
+        .arg("overrides.txt")
+        .arg("--override-add"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --override overrides.txt --override-add
+    idna==3.6
+        # via
+        #   --override overrides.txt
+        #   -r overrides.txt
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
     "###
     );
 
@@ -2914,6 +4017,65 @@ fn compile_wheel_path_dependency_missing() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a local wheel, reading its dependencies from the `METADATA` file in the archive.
+#[test]
+fn compile_wheel_path_dependency_metadata() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `maturin` declares `tomli >=1.1.0 ; python_version < '3.11'`, along with some extras.
+    let maturin_wheel = context.temp_dir.child("maturin-1.4.0-py3-none-any.whl");
+    fs::copy(
+        context
+            .workspace_root
+            .join("scripts/links/maturin-1.4.0-py3-none-any.whl"),
+        &maturin_wheel,
+    )?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(&format!(
+        "maturin @ {}",
+        Url::from_file_path(maturin_wheel.path()).unwrap()
+    ))?;
+
+    // The dependencies are read from the wheel itself, so no network access is required.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --offline
+    maturin @ file://[TEMP_DIR]/maturin-1.4.0-py3-none-any.whl
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    // On Python 3.10, the declared dependency on `tomli` applies.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-version")
+            .arg("3.10"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-version 3.10
+    maturin @ file://[TEMP_DIR]/maturin-1.4.0-py3-none-any.whl
+        # via -r requirements.in
+    tomli==2.0.1
+        # via maturin
+
+    ----- stderr -----
+    warning: The requested Python version 3.10 is not available; 3.12.[X] will be used to build dependencies instead.
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// Resolve a yanked version of `attrs` by specifying the version directly.
 #[test]
 fn compile_yanked_version_direct() -> Result<()> {
@@ -2967,33 +4129,278 @@ fn compile_yanked_version_indirect() -> Result<()> {
     Ok(())
 }
 
-/// Flask==3.0.0 depends on Werkzeug>=3.0.0. Demonstrate that we can override this
-/// requirement with an incompatible version.
+/// Resolve a yanked version of `attrs` via a range that only the yanked version (`21.1.0`)
+/// satisfies, by passing `--allow-yanked` or `--allow-yanked-package`.
 #[test]
-fn override_dependency() -> Result<()> {
+fn compile_yanked_version_allow_yanked() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("flask==3.0.0")?;
-
-    let overrides_txt = context.temp_dir.child("overrides.txt");
-    overrides_txt.write_str("werkzeug==2.3.0")?;
+    requirements_in.write_str("attrs>20.3.0,<21.2.0")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--override")
-            .arg("overrides.txt"), @r###"
+            .arg("--allow-yanked"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --override overrides.txt
-    blinker==1.7.0
-        # via flask
-    click==8.1.7
-        # via flask
-    flask==3.0.0
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --allow-yanked
+    attrs==21.1.0
         # via -r requirements.in
-    itsdangerous==2.1.2
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: `attrs==21.1.0` is yanked (reason: "Installable but not importable on Python 3.4.")
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--allow-yanked-package")
+            .arg("attrs"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --allow-yanked-package attrs
+    attrs==21.1.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: `attrs==21.1.0` is yanked (reason: "Installable but not importable on Python 3.4.")
+    "###
+    );
+
+    Ok(())
+}
+
+/// Restrict the resolution to an allowlist of packages via `--allow-package`, rejecting any
+/// transitive dependency that isn't on the list.
+#[test]
+fn compile_allow_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==4.3.0")?;
+
+    // `sniffio` is required by `anyio`, but isn't allowed.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--allow-package")
+            .arg("anyio")
+            .arg("--allow-package")
+            .arg("idna"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because sniffio is not in the list of allowed packages and anyio==4.3.0 depends on sniffio>=1.1, we can conclude that anyio==4.3.0 cannot be used.
+          And because you require anyio==4.3.0, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    // Once every package is allowed, the resolution succeeds.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--allow-package")
+            .arg("anyio")
+            .arg("--allow-package")
+            .arg("idna")
+            .arg("--allow-package")
+            .arg("sniffio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --allow-package anyio --allow-package idna --allow-package sniffio
+    anyio==4.3.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Deny a specific version of a package via `--deny`, such that the resolver falls back to another
+/// version, or fails if no other version is acceptable.
+#[test]
+fn compile_deny_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    // The latest version is denied, so the resolver should fall back to the previous one.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--deny")
+            .arg("anyio==4.3.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --deny anyio==4.3.0
+    anyio==4.2.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    // If the denied version is the only acceptable version, the resolution should fail.
+    requirements_in.write_str("anyio==4.3.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--deny")
+            .arg("anyio==4.3.0"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because anyio==4.3.0 is denied and you require anyio==4.3.0, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Deny every version of a package via `--deny-package`, which fails the resolution if the package
+/// is required.
+#[test]
+fn compile_deny_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==4.3.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--deny-package")
+            .arg("sniffio"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because sniffio is denied and anyio==4.3.0 depends on sniffio>=1.1, we can conclude that anyio==4.3.0 cannot be used.
+          And because you require anyio==4.3.0, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    // Denying a package that isn't required has no effect.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--deny-package")
+            .arg("flask"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --deny-package flask
+    anyio==4.3.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Abort the resolution once it exceeds the solver step limit, reporting the packages that were
+/// tried.
+#[test]
+fn compile_max_resolution_steps() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--max-resolution-steps")
+            .arg("2"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution is too complex: exceeded the limit of 2 solver steps
+
+    hint: The most constrained packages were: `anyio` (1 version tried). Consider adding tighter bounds or constraints for these packages.
+    "###
+    );
+
+    // Without a limit, the resolution succeeds.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Flask==3.0.0 depends on Werkzeug>=3.0.0. Demonstrate that we can override this
+/// requirement with an incompatible version.
+#[test]
+fn override_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0")?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("werkzeug==2.3.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--override")
+            .arg("overrides.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --override overrides.txt
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.0
+        # via -r requirements.in
+    itsdangerous==2.1.2
         # via flask
     jinja2==3.1.3
         # via flask
@@ -3114,78 +4521,253 @@ fn constraint_dependency_from_pyproject() -> Result<()> {
     Ok(())
 }
 
-/// Check that `override-dependencies` in `uv.toml` is respected.
+/// Reject `pyproject.toml` dependencies that contradict the `tool.uv.constraint-dependencies`
+/// before resolving.
 #[test]
-fn override_dependency_from_specific_uv_toml() -> Result<()> {
+fn constraint_dependency_from_pyproject_conflict() -> Result<()> {
     let context = TestContext::new("3.12");
-    let _ = context.temp_dir.child("project").create_dir_all();
-    let pyproject_toml = context.temp_dir.child("project/pyproject.toml");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"[project]
     name = "example"
     version = "0.0.0"
     dependencies = [
-      "flask==3.0.0"
+      "anyio>=4"
     ]
-    "#,
-    )?;
 
-    let _ = context.temp_dir.child("uv").create_dir_all();
-    let uv_toml: assert_fs::fixture::ChildPath = context.temp_dir.child("uv").child("uv.toml");
-    uv_toml.write_str(
-        r#"
-        override-dependencies = [
-          "werkzeug==2.3.0"
-        ]
-        "#,
+    [tool.uv]
+    constraint-dependencies = [
+      "anyio<4"
+    ]
+    "#,
     )?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
-        .arg("pyproject.toml")
-        .arg("--config-file")
-        .arg("../uv/uv.toml")
-        .current_dir(context.temp_dir.child("project"))
-            , @r###"
-    success: true
-    exit_code: 0
+            .arg("pyproject.toml"), @r###"
+    success: false
+    exit_code: 2
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --config-file ../uv/uv.toml
-    blinker==1.7.0
-        # via flask
-    click==8.1.7
-        # via flask
-    flask==3.0.0
-        # via example (pyproject.toml)
-    itsdangerous==2.1.2
-        # via flask
-    jinja2==3.1.3
-        # via flask
-    markupsafe==2.1.5
-        # via
-        #   jinja2
-        #   werkzeug
-    werkzeug==2.3.0
-        # via
-        #   --override (workspace)
-        #   flask
 
     ----- stderr -----
-    Resolved 7 packages in [TIME]
+    error: The dependency `anyio>=4` in `pyproject.toml` contradicts the constraint `anyio<4` in `tool.uv.constraint-dependencies`
     "###
     );
 
     Ok(())
 }
 
-/// Black==23.10.1 depends on tomli>=1.1.0 for Python versions below 3.11. Demonstrate that we can
-/// override it with a multi-line override.
+/// Check that the dependencies, optional dependencies, constraints, and overrides declared in a
+/// `pyproject.toml` outside the working directory are all read from the `-r` source.
 #[test]
-fn override_multi_dependency() -> Result<()> {
+fn compile_pyproject_toml_constraints_and_overrides() -> Result<()> {
     let context = TestContext::new("3.12");
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("black==23.10.1")?;
-
+    let pyproject_toml = context.temp_dir.child("project").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["anyio==3.7.0"]
+        optional-dependencies.test = ["iniconfig"]
+
+        [tool.uv]
+        constraint-dependencies = ["idna<3.5", "flask<3"]
+        override-dependencies = ["sniffio==1.3.0"]
+    "#})?;
+
+    // `flask` is only a constraint, so it shouldn't be included in the resolution.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("project/pyproject.toml")
+            .arg("--extra")
+            .arg("test"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] project/pyproject.toml --extra test
+    anyio==3.7.0
+        # via project (project/pyproject.toml)
+    idna==3.4
+        # via
+        #   -c (workspace)
+        #   anyio
+    iniconfig==2.0.0
+        # via project (project/pyproject.toml)
+    sniffio==1.3.0
+        # via
+        #   --override (workspace)
+        #   anyio
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Check that `override-dependencies` in `uv.toml` is respected.
+#[test]
+fn override_dependency_from_specific_uv_toml() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let _ = context.temp_dir.child("project").create_dir_all();
+    let pyproject_toml = context.temp_dir.child("project/pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+    name = "example"
+    version = "0.0.0"
+    dependencies = [
+      "flask==3.0.0"
+    ]
+    "#,
+    )?;
+
+    let _ = context.temp_dir.child("uv").create_dir_all();
+    let uv_toml: assert_fs::fixture::ChildPath = context.temp_dir.child("uv").child("uv.toml");
+    uv_toml.write_str(
+        r#"
+        override-dependencies = [
+          "werkzeug==2.3.0"
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--config-file")
+        .arg("../uv/uv.toml")
+        .current_dir(context.temp_dir.child("project"))
+            , @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --config-file ../uv/uv.toml
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.0
+        # via example (pyproject.toml)
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==2.3.0
+        # via
+        #   --override (workspace)
+        #   flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Check that settings in a `--config-file` are respected, but overridden by explicit
+/// command-line arguments.
+#[test]
+fn resolution_from_specific_uv_toml_overridden_by_cli() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio<=3.7.0")?;
+
+    let _ = context.temp_dir.child("uv").create_dir_all();
+    let uv_toml = context.temp_dir.child("uv").child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [pip]
+        resolution = "lowest-direct"
+    "#})?;
+
+    // The `--resolution` flag should take precedence over the configuration file.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--config-file")
+        .arg("uv/uv.toml")
+        .arg("--resolution")
+        .arg("highest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --config-file uv/uv.toml --resolution highest
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Check that `--no-config` ignores `[tool.uv]` and `--config-file`, but still respects
+/// environment variables.
+#[test]
+fn no_config_respects_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio<=3.7.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [tool.uv.pip]
+        resolution = "lowest-direct"
+    "#})?;
+
+    let _ = context.temp_dir.child("uv").create_dir_all();
+    let uv_toml = context.temp_dir.child("uv").child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [pip]
+        resolution = "lowest-direct"
+    "#})?;
+
+    // The `resolution` setting should be ignored, but the custom compile command should be
+    // applied.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-config")
+        .arg("--config-file")
+        .arg("uv/uv.toml")
+        .env("UV_CUSTOM_COMPILE_COMMAND", "./custom-uv-compile.sh"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    ./custom-uv-compile.sh
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Black==23.10.1 depends on tomli>=1.1.0 for Python versions below 3.11. Demonstrate that we can
+/// override it with a multi-line override.
+#[test]
+fn override_multi_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black==23.10.1")?;
+
     let overrides_txt = context.temp_dir.child("overrides.txt");
     overrides_txt.write_str(
         "tomli>=1.1.0; python_version >= '3.11'\ntomli<1.0.0; python_version < '3.11'",
@@ -3250,6 +4832,7 @@ fn dont_add_override_for_non_activated_extra() -> Result<()> {
 
     ----- stderr -----
     Resolved 1 package in [TIME]
+    warning: Override for `pysocks` is unused, as it isn't part of the resolution
     "###
     );
 
@@ -3435,6 +5018,29 @@ fn missing_registry_extra() -> Result<()> {
     Ok(())
 }
 
+/// Request an extra that doesn't exist on the specified package, with `--strict-extras`.
+#[test]
+fn missing_registry_extra_strict() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests[foo]==2.31.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--strict-extras"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    error: The package `requests==2.31.0` does not have an extra named `foo`
+    "###
+    );
+
+    Ok(())
+}
+
 /// Request an extra that doesn't exist on the specified package.
 #[test]
 fn missing_url_extra() -> Result<()> {
@@ -3475,13 +5081,12 @@ fn missing_url_extra() -> Result<()> {
     Ok(())
 }
 
-/// Resolve a dependency from a URL, preserving the exact casing of the URL as specified in the
-/// requirements file.
+/// Request an extra on a URL wheel, which should pull in the extra's dependencies.
 #[test]
-fn preserve_url() -> Result<()> {
+fn url_extra() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("flask @ https://files.PYTHONHOSTED.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl")?;
+    requirements_in.write_str("flask[dotenv] @ https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in"), @r###"
@@ -3494,7 +5099,7 @@ fn preserve_url() -> Result<()> {
         # via flask
     click==8.1.7
         # via flask
-    flask @ https://files.PYTHONHOSTED.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl
+    flask @ https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl
         # via -r requirements.in
     itsdangerous==2.1.2
         # via flask
@@ -3504,30 +5109,18 @@ fn preserve_url() -> Result<()> {
         # via
         #   jinja2
         #   werkzeug
+    python-dotenv==1.0.1
+        # via flask
     werkzeug==3.0.1
         # via flask
 
     ----- stderr -----
-    Resolved 7 packages in [TIME]
+    Resolved 8 packages in [TIME]
     "###
     );
 
-    Ok(())
-}
-
-/// Resolve a dependency from a URL, preserving the unexpanded environment variable as specified in
-/// the requirements file.
-#[test]
-fn preserve_project_root() -> Result<()> {
-    let context = TestContext::new("3.12");
-    // Download a wheel.
-    let response = reqwest::blocking::get("https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl")?;
-    let flask_wheel = context.temp_dir.child("flask-3.0.0-py3-none-any.whl");
-    let mut flask_wheel_file = std::fs::File::create(flask_wheel)?;
-    std::io::copy(&mut response.bytes()?.as_ref(), &mut flask_wheel_file)?;
-
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("flask @ file://${PROJECT_ROOT}/flask-3.0.0-py3-none-any.whl")?;
+    // The same applies to extras on an unnamed URL requirement.
+    requirements_in.write_str("https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl[dotenv]")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in"), @r###"
@@ -3540,7 +5133,7 @@ fn preserve_project_root() -> Result<()> {
         # via flask
     click==8.1.7
         # via flask
-    flask @ file://${PROJECT_ROOT}/flask-3.0.0-py3-none-any.whl
+    flask @ https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl
         # via -r requirements.in
     itsdangerous==2.1.2
         # via flask
@@ -3550,28 +5143,29 @@ fn preserve_project_root() -> Result<()> {
         # via
         #   jinja2
         #   werkzeug
+    python-dotenv==1.0.1
+        # via flask
     werkzeug==3.0.1
         # via flask
 
     ----- stderr -----
-    Resolved 7 packages in [TIME]
+    Resolved 8 packages in [TIME]
     "###
     );
 
     Ok(())
 }
 
-/// Resolve a dependency from a URL, passing in the entire URL as an environment variable.
+/// Resolve a dependency from a URL, preserving the exact casing of the URL as specified in the
+/// requirements file.
 #[test]
-fn respect_http_env_var() -> Result<()> {
+fn preserve_url() -> Result<()> {
     let context = TestContext::new("3.12");
-
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("flask @ ${URL}")?;
+    requirements_in.write_str("flask @ https://files.PYTHONHOSTED.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
-            .arg("requirements.in")
-            .env("URL", "https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl"), @r###"
+            .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3581,7 +5175,7 @@ fn respect_http_env_var() -> Result<()> {
         # via flask
     click==8.1.7
         # via flask
-    flask @ ${URL}
+    flask @ https://files.PYTHONHOSTED.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl
         # via -r requirements.in
     itsdangerous==2.1.2
         # via flask
@@ -3602,17 +5196,22 @@ fn respect_http_env_var() -> Result<()> {
     Ok(())
 }
 
-/// A requirement defined as a single unnamed environment variable should be parsed as such.
+/// Resolve a dependency from a URL, preserving the unexpanded environment variable as specified in
+/// the requirements file.
 #[test]
-fn respect_unnamed_env_var() -> Result<()> {
+fn preserve_project_root() -> Result<()> {
     let context = TestContext::new("3.12");
+    // Download a wheel.
+    let response = reqwest::blocking::get("https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl")?;
+    let flask_wheel = context.temp_dir.child("flask-3.0.0-py3-none-any.whl");
+    let mut flask_wheel_file = std::fs::File::create(flask_wheel)?;
+    std::io::copy(&mut response.bytes()?.as_ref(), &mut flask_wheel_file)?;
 
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("${URL}")?;
+    requirements_in.write_str("flask @ file://${PROJECT_ROOT}/flask-3.0.0-py3-none-any.whl")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
-            .arg("requirements.in")
-            .env("URL", "https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl"), @r###"
+            .arg("requirements.in"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3622,7 +5221,7 @@ fn respect_unnamed_env_var() -> Result<()> {
         # via flask
     click==8.1.7
         # via flask
-    flask @ ${URL}
+    flask @ file://${PROJECT_ROOT}/flask-3.0.0-py3-none-any.whl
         # via -r requirements.in
     itsdangerous==2.1.2
         # via flask
@@ -3643,14 +5242,96 @@ fn respect_unnamed_env_var() -> Result<()> {
     Ok(())
 }
 
-/// A requirement defined as a single unnamed environment variable should error if the environment
-/// variable is not set.
+/// Resolve a dependency from a URL, passing in the entire URL as an environment variable.
 #[test]
-fn error_missing_unnamed_env_var() -> Result<()> {
+fn respect_http_env_var() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("${URL}")?;
+    requirements_in.write_str("flask @ ${URL}")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .env("URL", "https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask @ ${URL}
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// A requirement defined as a single unnamed environment variable should be parsed as such.
+#[test]
+fn respect_unnamed_env_var() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("${URL}")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .env("URL", "https://files.pythonhosted.org/packages/36/42/015c23096649b908c809c69388a805a571a3bea44362fe87e33fc3afa01f/flask-3.0.0-py3-none-any.whl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask @ ${URL}
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// A requirement defined as a single unnamed environment variable should error if the environment
+/// variable is not set.
+#[test]
+fn error_missing_unnamed_env_var() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("${URL}")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in"), @r###"
@@ -3774,6 +5455,92 @@ fn compile_editable() -> Result<()> {
     Ok(())
 }
 
+/// Rebase relative paths to local editables against the directory of the output file.
+#[test]
+fn compile_editable_output_file_subdirectory() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create an editable package.
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.0.0"
+        dependencies = ["iniconfig"]
+        requires-python = ">=3.8"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("-e ./editable")?;
+
+    // Write the output file to a subdirectory, such that the path must be rebased.
+    context.temp_dir.child("requirements").create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements/requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements/requirements.txt
+    -e ../editable
+        # via -r requirements.in
+    iniconfig==2.0.0
+        # via example
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // With `--absolute-paths`, the path should be written as an absolute path instead.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements/absolute.txt")
+        .arg("--absolute-paths"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements/absolute.txt --absolute-paths
+    -e [TEMP_DIR]/editable
+        # via -r requirements.in
+    iniconfig==2.0.0
+        # via example
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Written to the current directory, the path should be preserved as given.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    -e ./editable
+        # via -r requirements.in
+    iniconfig==2.0.0
+        # via example
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// If an editable is repeated, it should only be built once.
 #[test]
 fn deduplicate_editable() -> Result<()> {
@@ -4275,6 +6042,7 @@ fn generate_hashes_git() -> Result<()> {
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --generate-hashes
     anyio @ git+https://github.com/agronholm/anyio@437a7e310925a962cab4a58fcd2455fbcd578d51
         # via -r requirements.in
+        # resolved from 4.3.0
     idna==3.6 \
         --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
         --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
@@ -4442,6 +6210,122 @@ fn generate_hashes_find_links_directory() -> Result<()> {
     Ok(())
 }
 
+/// Generate SHA-512 hashes with `--hash-algorithm`, re-hashing any distributions for which only
+/// hashes with a different algorithm are available.
+#[test]
+fn generate_hashes_sha512() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--generate-hashes")
+        .arg("--hash-algorithm")
+        .arg("sha512")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts").join("links")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --generate-hashes --hash-algorithm sha512
+    tqdm==1000.0.0 \
+        --hash=sha512:9cf96ddf15bd07581e184c1d825f30e3dffe8c4a640946f9168bc566b20288a4ba26061137f6341982c4a294b9c3cc1174dc394a82a416cb829e1155377e1c9a
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // Write SHA-256 hashes to the "lockfile".
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --generate-hashes
+        tqdm==1000.0.0 \
+            --hash=sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13
+            # via -r requirements.in
+    "})?;
+
+    // The existing SHA-256 hashes should be replaced with SHA-512 hashes.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--generate-hashes")
+        .arg("--hash-algorithm")
+        .arg("sha512")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts").join("links")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --generate-hashes --hash-algorithm sha512
+    tqdm==1000.0.0 \
+        --hash=sha512:9cf96ddf15bd07581e184c1d825f30e3dffe8c4a640946f9168bc566b20288a4ba26061137f6341982c4a294b9c3cc1174dc394a82a416cb829e1155377e1c9a
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Generate SHA-512 hashes with `--hash-algorithm` for a package from a registry, which only
+/// serves SHA-256 hashes, such that the selected distribution is downloaded and re-hashed.
+#[test]
+fn generate_hashes_sha512_registry() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    // Write the SHA-256 hashes served by the registry to the "lockfile".
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --generate-hashes
+        iniconfig==2.0.0 \
+            --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+            --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+            # via -r requirements.in
+    "})?;
+
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--generate-hashes")
+        .arg("--hash-algorithm")
+        .arg("sha512")
+        .assert()
+        .success();
+
+    // The pinned version should be preserved, but the SHA-256 hashes should be replaced with a
+    // SHA-512 hash computed from the downloaded distribution.
+    let output = context.read("requirements.txt");
+    assert!(output.contains("iniconfig==2.0.0"), "{output}");
+    let hashes = output
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("--hash="))
+        .map(|hash| hash.trim_end_matches(" \\"))
+        .collect::<Vec<_>>();
+    assert!(!hashes.is_empty(), "{output}");
+    for hash in hashes {
+        let digest = hash
+            .strip_prefix("sha512:")
+            .unwrap_or_else(|| panic!("{output}"));
+        assert_eq!(digest.len(), 128, "{output}");
+    }
+
+    Ok(())
+}
+
 /// Include hashes from a `--find-links` index in the generated output.
 #[test]
 fn generate_hashes_find_links_url() -> Result<()> {
@@ -4763,6 +6647,7 @@ fn upgrade_none() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.pip_compile()
         .arg("requirements.in")
+        .arg("--force")
         .arg("--output-file")
         .arg("requirements.txt"), @r###"
     success: true
@@ -4876,83 +6761,486 @@ fn upgrade_package() -> Result<()> {
             .arg("requirements.in")
             .arg("--output-file")
             .arg("requirements.txt")
-            .arg("--upgrade-package")
-            .arg("click"), @r###"
+            .arg("--upgrade-package")
+            .arg("click"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    black==23.10.1
+        # via -r requirements.in
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.0
+        # via black
+    platformdirs==4.0.0
+        # via black
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Use an existing resolution for `black==23.10.1`, with a stale version of `click`. With the
+/// minimal upgrade strategy, `click` should be upgraded to the lowest version that satisfies the
+/// upgrade constraint, rather than the latest.
+#[test]
+fn upgrade_package_minimal() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black==23.10.1")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile requirements.in --python-version 3.12 --cache-dir [CACHE_DIR]
+        black==23.10.1
+        click==8.1.2
+            # via black
+        mypy-extensions==1.0.0
+            # via black
+        packaging==23.2
+            # via black
+        pathspec==0.11.0
+            # via black
+        platformdirs==4.0.0
+            # via black
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--upgrade-package")
+            .arg("click>8.1.2")
+            .arg("--upgrade-strategy")
+            .arg("minimal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    black==23.10.1
+        # via -r requirements.in
+    click==8.1.3
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.0
+        # via black
+    platformdirs==4.0.0
+        # via black
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    // The upgrade should still respect any constraint on the upgraded package.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--upgrade-package")
+            .arg("click>=8.1.6")
+            .arg("--upgrade-strategy")
+            .arg("minimal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    black==23.10.1
+        # via -r requirements.in
+    click==8.1.6
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.0
+        # via black
+    platformdirs==4.0.0
+        # via black
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    // If the existing pin is still compatible, it should be retained.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--upgrade-package")
+            .arg("click")
+            .arg("--upgrade-strategy")
+            .arg("minimal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    black==23.10.1
+        # via -r requirements.in
+    click==8.1.6
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.0
+        # via black
+    platformdirs==4.0.0
+        # via black
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    // Without `--upgrade-package`, the strategy has no effect.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--upgrade-strategy")
+            .arg("minimal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    black==23.10.1
+        # via -r requirements.in
+    click==8.1.6
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.0
+        # via black
+    platformdirs==4.0.0
+        # via black
+
+    ----- stderr -----
+    warning: `--upgrade-strategy minimal` has no effect without `--upgrade-package`
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    // With `--upgrade`, all packages are upgraded to their latest compatible versions.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--upgrade")
+            .arg("--upgrade-strategy")
+            .arg("minimal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    black==23.10.1
+        # via -r requirements.in
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==24.0
+        # via black
+    pathspec==0.12.1
+        # via black
+    platformdirs==4.2.0
+        # via black
+
+    ----- stderr -----
+    warning: `--upgrade-strategy minimal` has no effect with `--upgrade`, which upgrades all packages to their latest compatible versions; use `--upgrade-package` instead
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Upgrade a package with a constraint on the allowed upgrade.
+#[test]
+fn upgrade_constraint() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile requirements.in --python-version 3.12 --cache-dir [CACHE_DIR]
+        iniconfig==1.0.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--upgrade-package")
+            .arg("iniconfig<2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    iniconfig==1.1.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("-P")
+            .arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Refuse to overwrite an output file that wasn't generated by uv, unless `--force` is provided.
+#[test]
+fn compile_overwrite_hand_written() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    // A file generated by uv can be overwritten.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+        iniconfig==1.1.1
+            # via -r requirements.in
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--upgrade"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // A hand-written file can't be overwritten.
+    requirements_txt.write_str("iniconfig==1.1.1\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The output file `requirements.txt` was not generated by uv; use `--force` to overwrite it
+    "###
+    );
+
+    requirements_txt.assert("iniconfig==1.1.1\n");
+
+    // Unless `--force` is provided.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--force"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+    iniconfig==1.1.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Refuse to overwrite an output file that was edited since uv generated it, unless `--force` is
+/// provided.
+#[test]
+fn compile_overwrite_hand_edited() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Regenerating an unmodified file is fine.
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Edit the generated file by hand, retaining the header.
+    let edited = indoc! {r"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+        iniconfig==1.1.1
+            # via -r requirements.in
+    "};
+    requirements_txt.write_str(edited)?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The output file `requirements.txt` was edited since it was generated by uv; use `--force` to overwrite it
+    "###
+    );
+
+    requirements_txt.assert(edited);
+
+    // Unless `--force` is provided, in which case the edited pins are respected.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--force"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
-    black==23.10.1
+    iniconfig==1.1.1
         # via -r requirements.in
-    click==8.1.7
-        # via black
-    mypy-extensions==1.0.0
-        # via black
-    packaging==23.2
-        # via black
-    pathspec==0.11.0
-        # via black
-    platformdirs==4.0.0
-        # via black
 
     ----- stderr -----
-    Resolved 6 packages in [TIME]
+    Resolved 1 package in [TIME]
     "###
     );
 
     Ok(())
 }
 
-/// Upgrade a package with a constraint on the allowed upgrade.
+/// Merge a new requirement into an existing output file with `--append`.
 #[test]
-fn upgrade_constraint() -> Result<()> {
+fn compile_append() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("iniconfig")?;
+    requirements_in.write_str("anyio==3.7.0\nidna>=3.5\niniconfig")?;
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
     requirements_txt.write_str(indoc! {r"
         # This file was autogenerated by uv via the following command:
-        #    uv pip compile requirements.in --python-version 3.12 --cache-dir [CACHE_DIR]
-        iniconfig==1.0.0
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
+        anyio==3.7.0
+            # via -r requirements.in
+        # Pinned for compatibility with our deployment.
+        idna==3.4
+            # via anyio
+        sniffio==1.3.1
+            # via anyio
+        six==1.16.0
+            # via -r requirements.in
     "})?;
 
+    // The existing entries are retained, `idna` is updated in place, `iniconfig` is added, and
+    // `six` (which is no longer required) is removed.
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
             .arg("--output-file")
             .arg("requirements.txt")
-            .arg("--upgrade-package")
-            .arg("iniconfig<2"), @r###"
+            .arg("--append"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
-    iniconfig==1.1.1
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --append
+    anyio==3.7.0
+        # via -r requirements.in
+    # Pinned for compatibility with our deployment.
+    idna==3.6
+        # via
+        #   -r requirements.in
+        #   anyio
+    sniffio==1.3.1
+        # via anyio
+    iniconfig==2.0.0
         # via -r requirements.in
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
+    Resolved 4 packages in [TIME]
     "###
     );
 
+    // `--append` requires the `requirements.txt` format.
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
             .arg("--output-file")
-            .arg("requirements.txt")
-            .arg("-P")
-            .arg("iniconfig"), @r###"
-    success: true
-    exit_code: 0
+            .arg("requirements.json")
+            .arg("--append"), @r###"
+    success: false
+    exit_code: 2
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt
-    iniconfig==2.0.0
-        # via -r requirements.in
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
+    error: `--append` is only supported for the `requirements.txt` output format
     "###
     );
 
@@ -5457,26 +7745,92 @@ fn index_url_requirements_txt() -> Result<()> {
     Ok(())
 }
 
-/// Raise an error when multiple `requirements.txt` files include different `--index-url` flags.
+/// Warn when multiple `requirements.txt` files include different `--index-url` flags, and use the
+/// first. Under `--strict`, raise an error instead.
 #[test]
 fn conflicting_index_urls_requirements_txt() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("--index-url https://google.com\ntqdm")?;
+    requirements_in.write_str("--index-url https://pypi.org/simple\ntqdm")?;
 
     let constraints_in = context.temp_dir.child("constraints.in");
-    constraints_in.write_str("--index-url https://wikipedia.org\nflask")?;
+    constraints_in.write_str("--index-url https://test.pypi.org/simple\nflask")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
             .arg("--constraint")
-            .arg("constraints.in"), @r###"
+            .arg("constraints.in")
+            .arg("--emit-index-url"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.in --emit-index-url
+    --index-url https://pypi.org/simple
+
+    tqdm==4.66.2
+        # via -r requirements.in
+
+    ----- stderr -----
+    warning: Multiple index URLs specified: `https://pypi.org/simple` vs. `https://test.pypi.org/simple` (using: `https://pypi.org/simple`)
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+            .arg("-r")
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.in")
+            .arg("--strict"), @r###"
     success: false
     exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    error: Multiple index URLs specified: `https://google.com/` vs. `https://wikipedia.org/`
+    error: Multiple index URLs specified: `https://pypi.org/simple` vs. `https://test.pypi.org/simple`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Merge duplicate `--index-url` and `--extra-index-url` flags across `requirements.txt` files,
+/// listing each index once.
+#[test]
+fn duplicate_index_urls_requirements_txt() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        --index-url https://test.pypi.org/simple
+        --extra-index-url https://pypi.org/simple
+        tqdm
+    "})?;
+
+    let constraints_in = context.temp_dir.child("constraints.in");
+    constraints_in.write_str(indoc! {r"
+        --index-url https://test.pypi.org/simple
+        --extra-index-url https://pypi.org/simple
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.in")
+            .arg("--emit-index-url"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.in --emit-index-url
+    --index-url https://test.pypi.org/simple
+    --extra-index-url https://pypi.org/simple
+
+    tqdm==4.66.2
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
     "###
     );
 
@@ -5912,6 +8266,7 @@ fn compile_types_pytz() -> Result<()> {
     uv_snapshot!(context
         .pip_compile()
         .arg("requirements.in")
+        .arg("--force")
         .arg("-o")
         .arg("requirements.txt"), @r###"
     success: true
@@ -5943,6 +8298,7 @@ fn compile_unnamed_preference() -> Result<()> {
     uv_snapshot!(context
         .pip_compile()
         .arg("requirements.in")
+        .arg("--force")
         .arg("-o")
         .arg("requirements.txt"), @r###"
     success: true
@@ -5971,9 +8327,9 @@ fn compile_unnamed_preference() -> Result<()> {
 }
 
 /// Resolve a package from a `requirements.in` file, with a `constraints.txt` pinning that package
-/// to a specific URL.
+/// to a specific URL. Constraints can't be direct URL references, so this should fail.
 #[test]
-fn compile_constraints_compatible_url() -> Result<()> {
+fn compile_constraints_compatible_url_rejected() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
     requirements_in.write_str("anyio>4")?;
@@ -5981,6 +8337,33 @@ fn compile_constraints_compatible_url() -> Result<()> {
     let constraints_txt = context.temp_dir.child("constraints.txt");
     constraints_txt.write_str("anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl")?;
 
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Direct URL references are not allowed as constraints (found: `anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl`). Constraints must be version specifiers; to install `anyio` from a URL, add it to your requirements file instead.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a direct URL package from a `requirements.in` file, with a `constraints.txt` file
+/// pinning it to a specific version.
+#[test]
+fn compile_constraints_compatible_url_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio>4")?;
+
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
             .arg("--constraint")
@@ -6000,41 +8383,244 @@ fn compile_constraints_compatible_url() -> Result<()> {
         # via anyio
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a package from a `requirements.in` file, with a `constraints.txt` file pinning it to
+/// a specific URL with an incompatible version. Constraints can't be direct URL references, so the
+/// constraint should be rejected before resolution.
+#[test]
+fn compile_constraints_incompatible_url_rejected() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio<4")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Direct URL references are not allowed as constraints (found: `anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl`). Constraints must be version specifiers; to install `anyio` from a URL, add it to your requirements file instead.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject a direct URL reference in a `constraints.txt` file, suggesting that it be moved to the
+/// requirements file instead.
+#[test]
+fn compile_constraints_url_rejected() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(indoc! {r"
+        idna<3.7
+        sniffio @ https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Direct URL references are not allowed as constraints (found: `sniffio @ https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl`). Constraints must be version specifiers; to install `sniffio` from a URL, add it to your requirements file instead.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject a local path reference in a `constraints.txt` file, as with direct URL references.
+#[test]
+fn compile_constraints_path_rejected() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    context.temp_dir.child("iniconfig").create_dir_all()?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("iniconfig @ ./iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Direct URL references are not allowed as constraints (found: `iniconfig @ file://[TEMP_DIR]/iniconfig`). Constraints must be version specifiers; to install `iniconfig` from a URL, add it to your requirements file instead.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Compile to a JSON output file (inferred from the extension), then read it back in as a
+/// requirements source.
+#[test]
+fn compile_format_json_round_trip() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "created-by": "uv",
+      "packages": [
+        {
+          "name": "anyio",
+          "version": "3.7.0",
+          "index": "https://pypi.org/simple"
+        },
+        {
+          "name": "idna",
+          "version": "3.6",
+          "index": "https://pypi.org/simple"
+        },
+        {
+          "name": "sniffio",
+          "version": "1.3.1",
+          "index": "https://pypi.org/simple"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.json
+    anyio==3.7.0
+        # via -r requirements.json
+    idna==3.6
+        # via
+        #   -r requirements.json
+        #   anyio
+    sniffio==1.3.1
+        # via
+        #   -r requirements.json
+        #   anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject a JSON file that wasn't written by `uv pip compile`, rather than reading it as a
+/// resolution.
+#[test]
+fn compile_format_json_foreign() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_json = context.temp_dir.child("requirements.json");
+    requirements_json.write_str(indoc! {r#"
+        {
+          "packages": [
+            {
+              "name": "anyio",
+              "version": "3.7.0"
+            }
+          ]
+        }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.json"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `requirements.json` is not a JSON file written by `uv pip compile --format json`
     "###
     );
 
     Ok(())
 }
 
-/// Resolve a direct URL package from a `requirements.in` file, with a `constraints.txt` file
-/// pinning it to a specific version.
+/// Compile to a `pylock.toml` output file with `--format pylock`, then read it back in as a
+/// requirements source.
 #[test]
-fn compile_constraints_compatible_url_version() -> Result<()> {
+fn compile_format_pylock_round_trip() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl")?;
-
-    let constraints_txt = context.temp_dir.child("constraints.txt");
-    constraints_txt.write_str("anyio>4")?;
+    requirements_in.write_str("anyio==3.7.0")?;
 
+    // Hashes should be included even without `--generate-hashes`, as required by PEP 751.
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--constraint")
-            .arg("constraints.txt"), @r###"
+            .arg("--format")
+            .arg("pylock")
+            .arg("--output-file")
+            .arg("pylock.toml")
+            .arg("--quiet"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    let pylock = context.read("pylock.toml");
+    assert!(pylock.starts_with("lock-version = \"1.0\"\n"));
+    assert!(pylock.contains("created-by = \"uv\"\n"));
+    assert!(pylock.contains("[[packages.wheels]]\n"));
+    assert!(pylock.contains("sha256 = "));
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pylock.toml"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt
-    anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl
-        # via
-        #   -c constraints.txt
-        #   -r requirements.in
+    #    uv pip compile --cache-dir [CACHE_DIR] pylock.toml
+    anyio==3.7.0
+        # via -r pylock.toml
     idna==3.6
-        # via anyio
+        # via
+        #   -r pylock.toml
+        #   anyio
     sniffio==1.3.1
-        # via anyio
+        # via
+        #   -r pylock.toml
+        #   anyio
 
     ----- stderr -----
     Resolved 3 packages in [TIME]
@@ -6044,31 +8630,54 @@ fn compile_constraints_compatible_url_version() -> Result<()> {
     Ok(())
 }
 
-/// Resolve a package from a `requirements.in` file, with a `constraints.txt` file pinning it to
-/// a specific URL with an incompatible version.
+/// Write the output file with the requested line endings, and exactly one trailing newline.
 #[test]
-fn compile_constraints_incompatible_url() -> Result<()> {
+fn compile_line_ending() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("anyio<4")?;
+    requirements_in.write_str("anyio==3.7.0")?;
 
-    let constraints_txt = context.temp_dir.child("constraints.txt");
-    constraints_txt.write_str("anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl")?;
+    // By default, use `\n`, regardless of the platform.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--quiet"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    let requirements_txt = context.read("requirements.txt");
+    assert!(!requirements_txt.contains('\r'));
+    assert!(requirements_txt.ends_with("# via anyio\n"));
 
+    // With `--line-ending crlf`, use `\r\n`.
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--constraint")
-            .arg("constraints.txt"), @r###"
-    success: false
-    exit_code: 1
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--line-ending")
+            .arg("crlf")
+            .arg("--quiet"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because only anyio>=4 is available and you require anyio<4, we can conclude that your requirements are unsatisfiable.
     "###
     );
 
+    let requirements_txt = context.read("requirements.txt");
+    assert_eq!(
+        requirements_txt.matches('\n').count(),
+        requirements_txt.matches("\r\n").count()
+    );
+    assert!(requirements_txt.ends_with("# via anyio\r\n"));
+
     Ok(())
 }
 
@@ -6187,13 +8796,227 @@ fn no_deps_invalid_extra() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--no-deps"), @r###"
+            .arg("--no-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-deps
+    flask==3.0.2
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a package with `--no-deps` in which the requirements have a conflict in their
+/// transitive dependencies. The resolution should succeed, since `--no-deps` ignores the
+/// transitive dependencies.
+#[test]
+fn no_deps_transitive_conflict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create an editable with a dependency on `anyio` at a dedicated URL.
+    let editable_dir1 = context.temp_dir.child("editable1");
+    editable_dir1.create_dir_all()?;
+
+    let pyproject_toml = editable_dir1.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+[project]
+name = "editable1"
+version = "0.0.1"
+dependencies = [
+    "anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl"
+]
+"#,
+    )?;
+
+    // Create an editable with a dependency on `anyio` at a different, dedicated URL.
+    let editable_dir2 = context.temp_dir.child("editable2");
+    editable_dir2.create_dir_all()?;
+
+    let pyproject_toml = editable_dir2.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+[project]
+name = "editable2"
+version = "0.0.1"
+dependencies = [
+    "anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl"
+]
+"#,
+    )?;
+
+    // Write to a requirements file.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(&indoc::formatdoc! {r#"
+        -e {}
+        -e {}
+    "#,
+        editable_dir1.path().display(),
+        editable_dir2.path().display()
+    })?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-deps
+    -e [TEMP_DIR]/editable1
+        # via -r requirements.in
+    -e [TEMP_DIR]/editable2
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve an editable package with an invalid extra.
+#[test]
+fn editable_invalid_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("-e ../../scripts/packages/black_editable[empty]")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg(requirements_in.path())
+            .current_dir(current_dir()?), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] [TEMP_DIR]/requirements.in
+    -e ../../scripts/packages/black_editable
+        # via -r [TEMP_DIR]/requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: The package `black @ file://[WORKSPACE]/scripts/packages/black_editable` does not have an extra named `empty`
+    "###);
+
+    Ok(())
+}
+
+/// Resolve an editable with static metadata. The dependencies (and requested extras) should be
+/// read from the `pyproject.toml`, without invoking the build backend, which doesn't exist.
+#[test]
+fn editable_static_metadata() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("editable").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.0.0"
+        dependencies = ["anyio==4.0.0"]
+        requires-python = ">=3.8"
+
+        [project.optional-dependencies]
+        test = ["iniconfig"]
+
+        [build-system]
+        requires = ["does-not-exist"]
+        build-backend = "does_not_exist"
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("-e ./editable[test]")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    -e ./editable
+        # via -r requirements.in
+    anyio==4.0.0
+        # via example
+    idna==3.6
+        # via anyio
+    iniconfig==2.0.0
+        # via example
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve an editable whose extra adds a backport that's only needed on older Python versions
+/// (i.e., `typing-extensions>=4 ; python_version < '3.10' and extra == 'typing'`). The backport
+/// should only be included when the extra is requested _and_ the Python version matches.
+#[test]
+fn editable_extra_python_version_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("editable").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.0.0"
+        dependencies = []
+
+        [project.optional-dependencies]
+        typing = ["typing-extensions>=4 ; python_version < '3.10'"]
+
+        [build-system]
+        requires = ["does-not-exist"]
+        build-backend = "does_not_exist"
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("-e ./editable[typing]")?;
+
+    // The extra is requested, and the Python version matches.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-version")
+            .arg("3.9"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-version 3.9
+    -e ./editable
+        # via -r requirements.in
+    typing-extensions==4.10.0
+        # via example
+
+    ----- stderr -----
+    warning: The requested Python version 3.9 is not available; 3.12.[X] will be used to build dependencies instead.
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    // The extra is requested, but the Python version doesn't match.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-version")
+            .arg("3.12"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-deps
-    flask==3.0.2
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-version 3.12
+    -e ./editable
         # via -r requirements.in
 
     ----- stderr -----
@@ -6201,101 +9024,48 @@ fn no_deps_invalid_extra() -> Result<()> {
     "###
     );
 
-    Ok(())
-}
-
-/// Resolve a package with `--no-deps` in which the requirements have a conflict in their
-/// transitive dependencies. The resolution should succeed, since `--no-deps` ignores the
-/// transitive dependencies.
-#[test]
-fn no_deps_transitive_conflict() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    // Create an editable with a dependency on `anyio` at a dedicated URL.
-    let editable_dir1 = context.temp_dir.child("editable1");
-    editable_dir1.create_dir_all()?;
-
-    let pyproject_toml = editable_dir1.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-[project]
-name = "editable1"
-version = "0.0.1"
-dependencies = [
-    "anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl"
-]
-"#,
-    )?;
-
-    // Create an editable with a dependency on `anyio` at a different, dedicated URL.
-    let editable_dir2 = context.temp_dir.child("editable2");
-    editable_dir2.create_dir_all()?;
-
-    let pyproject_toml = editable_dir2.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-[project]
-name = "editable2"
-version = "0.0.1"
-dependencies = [
-    "anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl"
-]
-"#,
-    )?;
-
-    // Write to a requirements file.
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(&indoc::formatdoc! {r#"
-        -e {}
-        -e {}
-    "#,
-        editable_dir1.path().display(),
-        editable_dir2.path().display()
-    })?;
-
-    uv_snapshot!(context.filters(), context.pip_compile()
+    // In universal mode, the backport should be included, but only for older Python versions.
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
             .arg("requirements.in")
-            .arg("--no-deps"), @r###"
+            .arg("--python-version")
+            .arg("3.8")
+            .arg("--universal"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-deps
-    -e [TEMP_DIR]/editable1
-        # via -r requirements.in
-    -e [TEMP_DIR]/editable2
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-version 3.8 --universal
+    -e ./editable
         # via -r requirements.in
+    typing-extensions==4.10.0 ; python_full_version < '3.10'
+        # via example
 
     ----- stderr -----
+    warning: The requested Python version 3.8 is not available; 3.12.[X] will be used to build dependencies instead.
     Resolved 2 packages in [TIME]
     "###
     );
 
-    Ok(())
-}
-
-/// Resolve an editable package with an invalid extra.
-#[test]
-fn editable_invalid_extra() -> Result<()> {
-    let context = TestContext::new("3.12");
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("-e ../../scripts/packages/black_editable[empty]")?;
+    // The Python version matches, but the extra isn't requested.
+    requirements_in.write_str("-e ./editable")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
-            .arg(requirements_in.path())
-            .current_dir(current_dir()?), @r###"
+            .arg("requirements.in")
+            .arg("--python-version")
+            .arg("3.9"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] [TEMP_DIR]/requirements.in
-    -e ../../scripts/packages/black_editable
-        # via -r [TEMP_DIR]/requirements.in
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-version 3.9
+    -e ./editable
+        # via -r requirements.in
 
     ----- stderr -----
+    warning: The requested Python version 3.9 is not available; 3.12.[X] will be used to build dependencies instead.
     Resolved 1 package in [TIME]
-    warning: The package `black @ file://[WORKSPACE]/scripts/packages/black_editable` does not have an extra named `empty`
-    "###);
+    "###
+    );
 
     Ok(())
 }
@@ -7581,6 +10351,7 @@ fn existing_prerelease_preference() -> Result<()> {
     uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
             .env("UV_EXCLUDE_NEWER", EXCLUDE_NEWER)
             .arg("requirements.in")
+            .arg("--force")
             .arg("-o")
             .arg("requirements.txt"), @r###"
     success: true
@@ -7656,6 +10427,7 @@ fn universal_disjoint_prereleases_preference() -> Result<()> {
     uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
             .env("UV_EXCLUDE_NEWER", EXCLUDE_NEWER)
             .arg("requirements.in")
+            .arg("--force")
             .arg("-o")
             .arg("requirements.txt")
             .arg("--universal"), @r###"
@@ -7701,6 +10473,7 @@ fn universal_disjoint_prereleases_preference_marker() -> Result<()> {
     uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
             .env("UV_EXCLUDE_NEWER", EXCLUDE_NEWER)
             .arg("requirements.in")
+            .arg("--force")
             .arg("-o")
             .arg("requirements.txt")
             .arg("--universal"), @r###"
@@ -8756,6 +11529,75 @@ fn pre_alias() -> Result<()> {
     Ok(())
 }
 
+/// Allow pre-releases for a single package via a `# uv: pre` pragma, even when pre-releases are
+/// otherwise disallowed.
+#[test]
+fn prerelease_pragma() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask<2.0.0rc4  # uv: pre")?;
+
+    // Only `flask` should be allowed a pre-release; its dependencies should not.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--prerelease=disallow"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --prerelease=disallow
+    click==8.1.7
+        # via flask
+    flask==2.0.0rc2
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    // Without the pragma, the pre-release should be rejected.
+    requirements_in.write_str("flask<2.0.0rc4")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--prerelease=disallow"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --prerelease=disallow
+    click==7.1.2
+        # via flask
+    flask==1.1.4
+        # via -r requirements.in
+    itsdangerous==1.1.0
+        # via flask
+    jinja2==2.11.3
+        # via flask
+    markupsafe==2.1.5
+        # via jinja2
+    werkzeug==1.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Allow a pre-release for a version specifier in a constraint file.
 #[test]
 fn prerelease_constraint() -> Result<()> {
@@ -8930,6 +11772,96 @@ fn editable_direct_dependency() -> Result<()> {
     Ok(())
 }
 
+/// A local editable fork of a package should satisfy transitive requirements on that package, in
+/// lieu of any versions on the index.
+#[test]
+fn editable_fork_transitive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("idna").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "idna"
+        version = "3.6.1"
+        requires-python = ">=3.8"
+
+        [build-system]
+        requires = ["does-not-exist"]
+        build-backend = "does_not_exist"
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        -e ./idna
+        anyio==4.3.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    -e ./idna
+        # via
+        #   -r requirements.in
+        #   anyio
+    anyio==4.3.0
+        # via -r requirements.in
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// If a local editable fork of a package doesn't satisfy a transitive requirement on that
+/// package, resolution should fail, rather than falling back to the versions on the index.
+#[test]
+fn editable_fork_transitive_conflict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("idna").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "idna"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+
+        [build-system]
+        requires = ["does-not-exist"]
+        build-backend = "does_not_exist"
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        -e ./idna
+        anyio==4.3.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only idna<2.8 is available and anyio==4.3.0 depends on idna>=2.8, we can conclude that anyio==4.3.0 cannot be used.
+          And because you require anyio==4.3.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: idna was requested from `./idna`, which takes precedence over any versions of idna on the index
+    "###
+    );
+
+    Ok(())
+}
+
 /// Setting `UV_INDEX_URL` to the empty string should treat it as "unset".
 #[test]
 fn empty_index_url_env_var() -> Result<()> {
@@ -9538,6 +12470,75 @@ fn preserve_hashes_no_upgrade() -> Result<()> {
     Ok(())
 }
 
+/// `--generate-hashes` should emit the hashes in a stable order, regardless of the order in which
+/// they appear in the existing "lockfile".
+#[test]
+fn preserve_hashes_sorted() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==4.0.0")?;
+
+    // Write the hashes to the "lockfile" in reverse order.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile requirements.in --python-version 3.12 --cache-dir [CACHE_DIR]
+        anyio==4.0.0 \
+            --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a \
+            --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+        idna==3.6 \
+            --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f \
+            --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca
+        sniffio==1.3.1 \
+            --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc \
+            --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--generate-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --generate-hashes
+    anyio==4.0.0 \
+        --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f \
+        --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+        # via -r requirements.in
+    idna==3.6 \
+        --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
+        --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+        # via anyio
+    sniffio==1.3.1 \
+        --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+        --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    let locked = fs_err::read_to_string(requirements_txt.path())?;
+
+    // Resolving from scratch should produce byte-identical output.
+    fs_err::remove_file(requirements_txt.path())?;
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--generate-hashes")
+        .assert()
+        .success();
+    assert_eq!(fs_err::read_to_string(requirements_txt.path())?, locked);
+
+    Ok(())
+}
+
 /// `--generate-hashes` should update the hashes in the "lockfile" if the package is upgraded via
 /// `--upgrade`.
 #[test]
@@ -9906,6 +12907,7 @@ fn unnamed_git_requirement() -> Result<()> {
         # via flask
     flask @ git+https://github.com/pallets/flask.git@735a4701d6d5e848241e7d7535db898efb62d400
         # via -r requirements.in
+        # resolved from 3.0.0
     itsdangerous==2.1.2
         # via flask
     jinja2==3.1.3
@@ -10235,6 +13237,7 @@ fn local_version_of_remote_package() -> Result<()> {
     // we require the URL to always be in the input file.
     uv_snapshot!(context.filters(), context.pip_compile()
         .arg(requirements_in.canonicalize()?)
+        .arg("--force")
         .arg("--output-file")
         .arg(requirements_txt.canonicalize()?), @r###"
     success: true
@@ -10371,6 +13374,70 @@ requires-python = ">3.8"
     Ok(())
 }
 
+/// Resolve a pair of packages whose extras depend on one another (`a[x]` requires `b[y]`, which
+/// in turn requires `a[x]`), without looping indefinitely.
+#[test]
+fn mutually_recursive_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let a = context.temp_dir.child("a");
+    a.create_dir_all()?;
+    let b = context.temp_dir.child("b");
+    b.create_dir_all()?;
+
+    a.child("pyproject.toml").write_str(&format!(
+        r#"[project]
+name = "a"
+version = "0.0.0"
+dependencies = []
+requires-python = ">3.8"
+
+[project.optional-dependencies]
+x = ["b[y] @ {}"]
+"#,
+        Url::from_directory_path(b.path()).unwrap().as_str(),
+    ))?;
+
+    b.child("pyproject.toml").write_str(&format!(
+        r#"[project]
+name = "b"
+version = "0.0.0"
+dependencies = []
+requires-python = ">3.8"
+
+[project.optional-dependencies]
+y = ["a[x] @ {}", "idna"]
+"#,
+        Url::from_directory_path(a.path()).unwrap().as_str(),
+    ))?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("./a[x]")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    ./a
+        # via
+        #   -r requirements.in
+        #   b
+    b @ file://[TEMP_DIR]/b/
+        # via a
+    idna==3.6
+        # via b
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Allow URL dependencies recursively for local source trees, but respect overrides.
 #[test]
 fn allow_recursive_url_local_path_override() -> Result<()> {
@@ -11237,6 +14304,7 @@ fn git_source_branch() -> Result<()> {
     #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml
     uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979
         # via project (pyproject.toml)
+        # resolved from test-branch
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -11273,6 +14341,7 @@ fn git_source_tag() -> Result<()> {
     #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml
     uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979
         # via project (pyproject.toml)
+        # resolved from test-tag
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -11381,6 +14450,7 @@ fn git_source_refs() -> Result<()> {
     #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml
     uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@9d01a806f17ddacb9c7b66b1b68574adf790b63f
         # via project (pyproject.toml)
+        # resolved from refs/pull/4/head
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -11590,6 +14660,42 @@ fn tool_uv_sources() -> Result<()> {
     Ok(())
 }
 
+/// Ignore `tool.uv.sources` with `--no-sources`, resolving every dependency from the index.
+#[test]
+fn tool_uv_sources_no_sources() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.0.0"
+        dependencies = [
+          "iniconfig",
+        ]
+
+        [tool.uv.sources]
+        iniconfig = { git = "https://github.com/pytest-dev/iniconfig", rev = "v2.0.0" }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--no-sources"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --no-sources
+    iniconfig==2.0.0
+        # via project (pyproject.toml)
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn invalid_tool_uv_sources() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -11783,6 +14889,7 @@ fn ignore_invalid_constraint() -> Result<()> {
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
+    warning: Constraint for `eventlet` is unused, as it isn't part of the resolution
     "###
     );
 
@@ -11926,6 +15033,69 @@ fn invalid_extra() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a requirements file that references the extras of the current project (e.g., `.[dev]`),
+/// merging them with any extras requested on the command line.
+#[test]
+fn requirements_txt_self_reference_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        dev = ["iniconfig"]
+        test = ["typing-extensions"]
+    "#})?;
+
+    let requirements_dev = context.temp_dir.child("requirements-dev.txt");
+    requirements_dev.write_str(".[dev]")?;
+
+    // The `dev` extra should be included.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements-dev.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements-dev.txt
+    iniconfig==2.0.0
+        # via project
+    .
+        # via -r requirements-dev.txt
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // The `test` extra should be merged with the `dev` extra.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements-dev.txt")
+        .arg("--extra")
+        .arg("test"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements-dev.txt --extra test
+    iniconfig==2.0.0
+        # via project
+    .
+        # via -r requirements-dev.txt
+    typing-extensions==4.10.0
+        # via project
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// Respect symlinks of output files.
 #[test]
 #[cfg(not(windows))]
@@ -11945,6 +15115,7 @@ fn symlink() -> Result<()> {
     // Write to the symlink.
     uv_snapshot!(context.pip_compile()
         .arg("requirements.in")
+        .arg("--force")
         .arg("--output-file")
         .arg("requirements-symlink.txt"), @r###"
     success: true
@@ -12026,6 +15197,61 @@ fn universal_constrained_environment() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a `pyproject.toml` that declares a `requires-python`, which should produce a resolution
+/// that's valid for the entire declared range, rather than just the current interpreter.
+#[test]
+fn compile_pyproject_requires_python() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.8"
+        dependencies = ["numpy"]
+        "#,
+    )?;
+
+    // `numpy>=1.25` requires Python 3.9 or later, so the resolver should fall back to an older
+    // version that's compatible with Python 3.8.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("pyproject.toml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml
+    numpy==1.24.4
+        # via project (pyproject.toml)
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // `--python-version` takes precedence over the declared range.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--python-version")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --python-version 3.12
+    numpy==1.26.4
+        # via project (pyproject.toml)
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a version of Flask that ships a `requires.txt` file in an `egg-info` directory, but
 /// otherwise doesn't include static metadata.
 #[test]
@@ -12411,3 +15637,148 @@ fn prune_unreachable() -> Result<()> {
 
     Ok(())
 }
+
+/// Reuse a cached resolution with `--cache-resolution`, as long as the inputs are unchanged.
+#[test]
+fn compile_cache_resolution() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--cache-resolution"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --cache-resolution
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // Remove the cached index responses, such that any fetch from the index would repopulate them.
+    let simple = context.cache_dir.join("simple-v13");
+    fs::remove_dir_all(&simple)?;
+
+    // With identical inputs, the resolution is served from the cache, without any fetches (even
+    // when offline).
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--cache-resolution")
+        .arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --cache-resolution --offline
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+    assert!(!simple.exists());
+
+    // Changing the inputs invalidates the cached resolution.
+    requirements_in.write_str("anyio==3.7.0\nidna<3.5")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--cache-resolution"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --cache-resolution
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.4
+        # via
+        #   -r requirements.in
+        #   anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+    assert!(simple.exists());
+
+    // As do `--upgrade` and `--refresh`.
+    for flag in ["--upgrade", "--refresh"] {
+        fs::remove_dir_all(&simple)?;
+        let output = context
+            .pip_compile()
+            .arg("requirements.in")
+            .arg("--cache-resolution")
+            .arg(flag)
+            .output()?;
+        assert!(output.status.success());
+        assert!(simple.exists());
+    }
+
+    Ok(())
+}
+
+/// Resolve a source tree with dynamic metadata from two concurrent processes that share a cache.
+/// The metadata should be built once, with the second process reading it from the cache.
+#[test]
+fn compile_concurrent_source_tree_build() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a source tree with an in-tree build backend that records each metadata build.
+    let builds = context.temp_dir.child("builds.txt");
+    let counter = context.temp_dir.child("counter");
+    counter.child("pyproject.toml").write_str(indoc! {r#"
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+        "#
+    })?;
+    counter.child("backend.py").write_str(&formatdoc! {r#"
+        import os
+        import time
+
+        def prepare_metadata_for_build_wheel(metadata_directory, config_settings=None):
+            with open({builds:?}, "a") as fp:
+                fp.write("built\n")
+            time.sleep(1)
+            dist_info = "counter-0.1.0.dist-info"
+            os.makedirs(os.path.join(metadata_directory, dist_info))
+            with open(os.path.join(metadata_directory, dist_info, "METADATA"), "w") as fp:
+                fp.write("Metadata-Version: 2.1\nName: counter\nVersion: 0.1.0\n")
+            return dist_info
+        "#,
+        builds = builds.path().simplified_display().to_string(),
+    })?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("counter @ ./counter")?;
+
+    // Resolve from two processes at once.
+    let first = context.pip_compile().arg("requirements.in").spawn()?;
+    let second = context.pip_compile().arg("requirements.in").spawn()?;
+    assert!(first.wait_with_output()?.status.success());
+    assert!(second.wait_with_output()?.status.success());
+
+    // The backend should've been invoked exactly once.
+    assert_eq!(fs::read_to_string(builds.path())?.lines().count(), 1);
+
+    Ok(())
+}
@@ -177,6 +177,35 @@ fn freeze_with_editable() -> Result<()> {
     "###
     );
 
+    // Include only the editable package.
+    uv_snapshot!(context.filters(), context.pip_freeze()
+        .arg("--editable-only"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    -e file://[WORKSPACE]/scripts/packages/poetry_editable
+
+    ----- stderr -----
+    "###
+    );
+
+    // The two filters are mutually exclusive.
+    uv_snapshot!(context.filters(), context.pip_freeze()
+        .arg("--editable-only")
+        .arg("--exclude-editable"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--editable-only' cannot be used with '--exclude-editable'
+
+    Usage: uv pip freeze --cache-dir [CACHE_DIR] --editable-only
+
+    For more information, try '--help'.
+    "###
+    );
+
     Ok(())
 }
 
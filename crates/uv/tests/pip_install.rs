@@ -13,7 +13,9 @@ use url::Url;
 use common::{uv_snapshot, TestContext};
 use uv_fs::Simplified;
 
-use crate::common::{build_vendor_links_url, decode_token, get_bin, venv_bin_path};
+use crate::common::{
+    build_vendor_links_url, decode_token, get_bin, serve_file, venv_bin_path, venv_to_interpreter,
+};
 
 mod common;
 
@@ -38,6 +40,102 @@ fn missing_requirements_txt() {
     requirements_txt.assert(predicates::path::missing());
 }
 
+/// Report a missing requirements file as JSON with `--error-format json`.
+#[test]
+fn missing_requirements_txt_error_format_json() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--error-format")
+        .arg("json"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    {"kind":"file-not-found","message":"File not found: `requirements.txt`","causes":[],"context":{"path":"requirements.txt"}}
+    "###
+    );
+}
+
+/// Errors should be shown regardless of the `--quiet` level.
+#[test]
+fn missing_requirements_txt_quiet() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--quiet"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: File not found: `requirements.txt`
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("-qq"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: File not found: `requirements.txt`
+    "###
+    );
+}
+
+/// `--quiet` and `--verbose` can't be combined.
+#[test]
+fn quiet_verbose_conflict() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .arg("--quiet")
+        .arg("--verbose"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--quiet...' cannot be used with '--verbose...'
+
+    Usage: uv pip install --cache-dir [CACHE_DIR] --quiet... --exclude-newer <EXCLUDE_NEWER> <PACKAGE|--requirement <REQUIREMENT>|--no-deps-requirement <NO_DEPS_REQUIREMENT>|--freeze-requirement <FREEZE_REQUIREMENT>|--editable <EDITABLE>>
+
+    For more information, try '--help'.
+    "###
+    );
+}
+
+/// Report invalid arguments as JSON with `--error-format json`.
+#[test]
+fn quiet_verbose_conflict_error_format_json() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .arg("--quiet")
+        .arg("--verbose")
+        .arg("--error-format")
+        .arg("json"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    {"kind":"usage","message":"the argument '--quiet...' cannot be used with '--verbose...'","causes":[],"context":{"help":"Usage: uv pip install --cache-dir [CACHE_DIR] --quiet... --exclude-newer <EXCLUDE_NEWER> <PACKAGE|--requirement <REQUIREMENT>|--no-deps-requirement <NO_DEPS_REQUIREMENT>|--freeze-requirement <FREEZE_REQUIREMENT>|--editable <EDITABLE>>\n\nFor more information, try '--help'."}}
+    "###
+    );
+}
+
 #[test]
 fn empty_requirements_txt() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -194,7 +292,7 @@ fn invalid_pyproject_toml_option_unknown_field() -> Result<()> {
         |
       2 | unknown = "field"
         | ^^^^^^^
-      unknown field `unknown`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `publish-url`, `trusted-publishing`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `environments`, `workspace`, `sources`, `dev-dependencies`, `managed`, `package`
+      unknown field `unknown`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `max-open-files`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `publish-url`, `trusted-publishing`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `environments`, `workspace`, `sources`, `dev-dependencies`, `default-groups`, `managed`, `package`
 
     Resolved in [TIME]
     Audited in [TIME]
@@ -335,6 +433,47 @@ fn no_solution() {
     "###);
 }
 
+/// Report a resolution conflict as JSON with `--error-format json`.
+#[test]
+fn no_solution_error_format_json() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("flask>=3.0.2")
+        .arg("WerkZeug<1.0.0")
+        .arg("--strict")
+        .arg("--error-format")
+        .arg("json"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    {"kind":"resolution-conflict","message":"No solution found when resolving dependencies","causes":["Because only flask<=3.0.2 is available and flask==3.0.2 depends on werkzeug>=3.0.0, we can conclude that flask>=3.0.2 depends on werkzeug>=3.0.0.\nAnd because you require flask>=3.0.2 and werkzeug<1.0.0, we can conclude that your requirements are unsatisfiable."],"context":{"packages":["flask","werkzeug"]}}
+    "###);
+}
+
+/// Report warnings as JSON with `--error-format json`, omitting any human-readable output from
+/// stderr.
+#[test]
+fn warning_error_format_json() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("flask[missing]")
+        .arg("--error-format")
+        .arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    {"kind":"warning","message":"The package `flask==3.0.2` does not have an extra named `missing`","causes":[],"context":{}}
+    "###);
+
+    context.assert_command("import flask").success();
+}
+
 /// Install a package from the command line into a virtual environment.
 #[test]
 fn install_package() {
@@ -420,6 +559,128 @@ fn install_requirements_txt() -> Result<()> {
     Ok(())
 }
 
+/// Install the `deps` of a test environment in a `tox.ini` file.
+#[test]
+fn install_tox_ini() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let tox_ini = context.temp_dir.child("tox.ini");
+    tox_ini.write_str(indoc! {r"
+        [testenv]
+        deps =
+            anyio
+
+        [testenv:lint]
+        deps =
+            iniconfig
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("tox.ini")
+        .arg("--tox-env")
+        .arg("lint"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
+/// Install a package with a low limit on the number of simultaneously-open files, which should
+/// limit the number of concurrent downloads and installs rather than failing with "too many open
+/// files".
+#[test]
+fn install_max_open_files() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("Flask")
+        .arg("--max-open-files")
+        .arg("4")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Prepared 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    "###
+    );
+
+    context.assert_command("import flask").success();
+}
+
+/// Install a number of packages under a low limit on open file descriptors (i.e., `ulimit -n`),
+/// which should bound the number of files held open by concurrent downloads and installs, rather
+/// than failing with "too many open files".
+#[test]
+#[cfg(unix)]
+fn install_low_open_files_limit() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        black
+        boto3
+        flask
+        sphinx
+    "})?;
+
+    // Lower the soft limit on open file descriptors for the `uv` process.
+    let mut install = context.pip_install();
+    install
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("copy");
+    let mut command = Command::new("sh");
+    command
+        .arg("-c")
+        .arg(r#"ulimit -n 64 && exec "$0" "$@""#)
+        .arg(install.get_program())
+        .args(install.get_args());
+    if let Some(dir) = install.get_current_dir() {
+        command.current_dir(dir);
+    }
+    for (key, value) in install.get_envs() {
+        match value {
+            Some(value) => command.env(key, value),
+            None => command.env_remove(key),
+        };
+    }
+
+    let output = command.output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(!stderr.contains("Too many open files"), "{stderr}");
+
+    context
+        .assert_command("import black, boto3, flask, sphinx")
+        .success();
+
+    Ok(())
+}
+
 /// Install a requirements file with pins that conflict
 ///
 /// This is likely to occur in the real world when compiled on one platform then installed on another.
@@ -509,6 +770,84 @@ build-backend = "poetry.core.masonry.api"
     Ok(())
 }
 
+/// Reject a `pyproject.toml` install that enables extras declared as mutually exclusive.
+#[test]
+fn install_pyproject_toml_conflicting_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "project"
+version = "0.1.0"
+dependencies = []
+
+[project.optional-dependencies]
+cpu = ["iniconfig"]
+cuda = ["anyio"]
+all = ["project[cpu]", "project[cuda]"]
+
+[tool.uv]
+conflicting-extras = [["cpu", "cuda"]]
+
+[build-system]
+requires = ["setuptools>=42"]
+build-backend = "setuptools.build_meta"
+"#,
+    )?;
+
+    // Enabling both extras should fail.
+    uv_snapshot!(context.filters(), context.pip_install()
+            .arg("-r")
+            .arg("pyproject.toml")
+            .arg("--extra")
+            .arg("cpu")
+            .arg("--extra")
+            .arg("cuda"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The extras `cpu`, `cuda` of `project` are declared as conflicting in `tool.uv.conflicting-extras`, and can't be enabled together
+    "###
+    );
+
+    // As should enabling both extras indirectly.
+    uv_snapshot!(context.filters(), context.pip_install()
+            .arg("-r")
+            .arg("pyproject.toml")
+            .arg("--extra")
+            .arg("all"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The extras `cpu`, `cuda` of `project` are declared as conflicting in `tool.uv.conflicting-extras`, and can't be enabled together
+    "###
+    );
+
+    // Enabling a single extra should succeed.
+    uv_snapshot!(context.filters(), context.pip_install()
+            .arg("-r")
+            .arg("pyproject.toml")
+            .arg("--extra")
+            .arg("cpu"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
 /// Respect installed versions when resolving.
 #[test]
 fn respect_installed_and_reinstall() -> Result<()> {
@@ -1039,9 +1378,7 @@ fn install_editable_incompatible_constraint_url() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Requirements contain conflicting URLs for package `black`:
-    - [WORKSPACE]/scripts/packages/black_editable
-    - https://files.pythonhosted.org/packages/0f/89/294c9a6b6c75a08da55e9d05321d0707e9418735e3062b12ef0f54c33474/black-24.4.2-py3-none-any.whl
+    error: Direct URL references are not allowed as constraints (found: `black @ https://files.pythonhosted.org/packages/0f/89/294c9a6b6c75a08da55e9d05321d0707e9418735e3062b12ef0f54c33474/black-24.4.2-py3-none-any.whl`). Constraints must be version specifiers; to install `black` from a URL, add it to your requirements file instead.
     "###
     );
 
@@ -2072,30 +2409,103 @@ fn install_no_binary_cache() {
     );
 }
 
-/// Respect `--only-binary` flags in `requirements.txt`
+/// Prefer building a package from source with `--prefer-sdist-package`, even though a compatible
+/// wheel exists, falling back to the wheel if the build fails.
 #[test]
-fn only_binary_requirements_txt() {
+fn install_prefer_sdist_package() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt
-        .write_str(indoc! {r"
-        django_allauth==0.51.0
-        --only-binary django_allauth
-        "
-        })
-        .unwrap();
-
-    uv_snapshot!(context.pip_install()
-        .arg("-r")
-        .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: false
-    exit_code: 1
+    // Install `idna`, which should be built from source.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("idna==3.6")
+        .arg("--prefer-sdist-package")
+        .arg("idna"), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × No solution found when resolving dependencies:
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+
+    // Re-create the virtual environment.
+    context.venv().assert().success();
+
+    // Re-install with `--no-binary`. Since the distribution was built from source, it should be
+    // installed from the cache.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("idna==3.6")
+        .arg("--no-binary")
+        .arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "###
+    );
+
+    // If the source distribution fails to build (here, because its build backend can't be
+    // installed), fall back to the wheel.
+    let constraints_txt = context.temp_dir.child("build_constraints.txt");
+    constraints_txt.write_str("flit-core<1")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("idna==3.6")
+        .arg("--prefer-sdist-package")
+        .arg("idna")
+        .arg("--build-constraint")
+        .arg("build_constraints.txt")
+        .arg("--reinstall")
+        .arg("--no-cache"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Failed to build `idna==3.6` from source; falling back to a pre-built wheel
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ idna==3.6
+    "###
+    );
+
+    Ok(())
+}
+
+/// Respect `--only-binary` flags in `requirements.txt`
+#[test]
+fn only_binary_requirements_txt() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str(indoc! {r"
+        django_allauth==0.51.0
+        --only-binary django_allauth
+        "
+        })
+        .unwrap();
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
       ╰─▶ Because django-allauth==0.51.0 has no usable wheels and building from source is disabled and you require django-allauth==0.51.0, we can conclude that your requirements are unsatisfiable.
     "###
     );
@@ -2408,6 +2818,64 @@ fn no_deps_editable() {
     context.assert_command("import aiohttp").failure();
 }
 
+/// Upgrade a package while respecting the bounds imposed by the other installed packages.
+#[test]
+fn install_upgrade_constrain_installed() {
+    let context = TestContext::new("3.12");
+
+    // Install `botocore`, which requires `urllib3<2.1`.
+    uv_snapshot!(context.pip_install()
+        .arg("botocore==1.34.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + botocore==1.34.0
+     + jmespath==1.0.1
+     + python-dateutil==2.9.0.post0
+     + six==1.16.0
+     + urllib3==2.0.7
+    "###
+    );
+
+    // Upgrading `urllib3` should respect the upper bound imposed by `botocore`.
+    uv_snapshot!(context.pip_install()
+        .arg("urllib3")
+        .arg("--upgrade")
+        .arg("--constrain-installed"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    // Without `--constrain-installed`, `urllib3` is upgraded past the bound.
+    uv_snapshot!(context.pip_install()
+        .arg("urllib3")
+        .arg("--upgrade"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - urllib3==2.0.7
+     + urllib3==2.2.1
+    "###
+    );
+}
+
 /// Upgrade a package.
 #[test]
 fn install_upgrade() {
@@ -2537,6 +3005,69 @@ fn install_constraints_txt() -> Result<()> {
     Ok(())
 }
 
+/// Downgrade an installed package when a constraint excludes the installed version.
+#[test]
+fn install_constraints_downgrade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+            .arg("requests==2.31.0")
+            .arg("--no-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + requests==2.31.0
+    "###
+    );
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("requests<2.30")?;
+
+    // The installed version doesn't satisfy the constraint, so `requests` should be downgraded to
+    // the latest compatible version.
+    uv_snapshot!(context.pip_install()
+            .arg("requests")
+            .arg("--no-deps")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - requests==2.31.0
+     + requests==2.29.0
+    "###
+    );
+
+    // Once downgraded, the environment should satisfy the constraint.
+    uv_snapshot!(context.pip_install()
+            .arg("requests")
+            .arg("--no-deps")
+            .arg("--constraint")
+            .arg("constraints.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Check that `tool.uv.constraint-dependencies` in `pyproject.toml` is respected.
 #[test]
 fn install_constraints_from_pyproject() -> Result<()> {
@@ -2577,19 +3108,60 @@ fn install_constraints_from_pyproject() -> Result<()> {
     Ok(())
 }
 
-/// Install a package from a `requirements.txt` file, with an inline constraint.
+/// With `--strict`, reject `pyproject.toml` dependencies that contradict the
+/// `tool.uv.constraint-dependencies` before resolving.
 #[test]
-fn install_constraints_inline() -> Result<()> {
+fn install_constraints_from_pyproject_strict() -> Result<()> {
     let context = TestContext::new("3.12");
-    let requirementstxt = context.temp_dir.child("requirements.txt");
-    requirementstxt.write_str("anyio==3.7.0\n-c constraints.txt")?;
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+    name = "example"
+    version = "0.0.0"
+    dependencies = [
+      "anyio>=4"
+    ]
 
-    let constraints_txt = context.temp_dir.child("constraints.txt");
-    constraints_txt.write_str("idna<3.4")?;
+    [tool.uv]
+    constraint-dependencies = [
+      "anyio<4"
+    ]
+    "#,
+    )?;
 
     uv_snapshot!(context.pip_install()
             .arg("-r")
-            .arg("requirements.txt"), @r###"
+            .arg("pyproject.toml")
+            .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The dependency `anyio>=4` in `pyproject.toml` contradicts the constraint `anyio<4` in `tool.uv.constraint-dependencies`
+    "###
+    );
+
+    // Compatible constraints are accepted.
+    pyproject_toml.write_str(
+        r#"[project]
+    name = "example"
+    version = "0.0.0"
+    dependencies = [
+      "anyio>=3"
+    ]
+
+    [tool.uv]
+    constraint-dependencies = [
+      "anyio<4"
+    ]
+    "#,
+    )?;
+
+    uv_snapshot!(context.pip_install()
+            .arg("-r")
+            .arg("pyproject.toml")
+            .arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2598,8 +3170,8 @@ fn install_constraints_inline() -> Result<()> {
     Resolved 3 packages in [TIME]
     Prepared 3 packages in [TIME]
     Installed 3 packages in [TIME]
-     + anyio==3.7.0
-     + idna==3.3
+     + anyio==3.7.1
+     + idna==3.6
      + sniffio==1.3.1
     "###
     );
@@ -2607,35 +3179,88 @@ fn install_constraints_inline() -> Result<()> {
     Ok(())
 }
 
-/// Install a package from a `constraints.txt` file on a remote http server.
+/// Install from a `pyproject.toml` outside the working directory that declares dependencies,
+/// optional dependencies, constraints, and overrides, each of which should be read from the `-r`
+/// source and applied as such.
 #[test]
-fn install_constraints_remote() {
+fn install_pyproject_toml_constraints_and_overrides() -> Result<()> {
     let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("project").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["anyio==3.7.0"]
+        optional-dependencies.test = ["iniconfig"]
+
+        [tool.uv]
+        constraint-dependencies = ["idna<3.5", "flask<3"]
+        override-dependencies = ["sniffio==1.3.0"]
+    "#})?;
 
     uv_snapshot!(context.pip_install()
-            .arg("-c")
-            .arg("https://raw.githubusercontent.com/apache/airflow/constraints-2-6/constraints-3.11.txt")
-            .arg("typing_extensions>=4.0"), @r###"
+            .arg("-r")
+            .arg("project/pyproject.toml")
+            .arg("--extra")
+            .arg("test"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + typing-extensions==4.7.1
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.4
+     + iniconfig==2.0.0
+     + sniffio==1.3.0
     "###
-    ); // would yield typing-extensions==4.8.2 without constraint file
+    );
+
+    let version = |package: &str| {
+        format!("import importlib.metadata; print(importlib.metadata.version({package:?}), end='')")
+    };
+
+    // The dependencies are installed.
+    context
+        .assert_command(&version("anyio"))
+        .success()
+        .stdout("3.7.0");
+
+    // The optional dependencies for the requested extra are installed.
+    context
+        .assert_command(&version("iniconfig"))
+        .success()
+        .stdout("2.0.0");
+
+    // The constraints are applied to the transitive dependencies.
+    context
+        .assert_command(&version("idna"))
+        .success()
+        .stdout("3.4");
+
+    // The overrides replace the transitive dependencies' requirements.
+    context
+        .assert_command(&version("sniffio"))
+        .success()
+        .stdout("1.3.0");
+
+    // The constraints aren't treated as requirements.
+    context.assert_command(&version("flask")).failure();
+
+    Ok(())
 }
 
-/// Install a package from a `requirements.txt` file, with an inline constraint, which points
-/// to a remote http server.
+/// Install a package from a `requirements.txt` file, with an inline constraint.
 #[test]
-fn install_constraints_inline_remote() -> Result<()> {
+fn install_constraints_inline() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirementstxt = context.temp_dir.child("requirements.txt");
-    requirementstxt.write_str("typing-extensions>=4.0\n-c https://raw.githubusercontent.com/apache/airflow/constraints-2-6/constraints-3.11.txt")?;
+    requirementstxt.write_str("anyio==3.7.0\n-c constraints.txt")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("idna<3.4")?;
 
     uv_snapshot!(context.pip_install()
             .arg("-r")
@@ -2644,8 +3269,74 @@ fn install_constraints_inline_remote() -> Result<()> {
     exit_code: 0
     ----- stdout -----
 
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.3
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install a package from a `constraints.txt` file on a remote http server.
+#[test]
+fn install_constraints_remote() {
+    let context = TestContext::new("3.12");
+
+    let filters = std::iter::once((
+        r"Constraints for .* are unused",
+        "Constraints for [PACKAGES] are unused",
+    ))
+    .chain(context.filters())
+    .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_install()
+            .arg("-c")
+            .arg("https://raw.githubusercontent.com/apache/airflow/constraints-2-6/constraints-3.11.txt")
+            .arg("typing_extensions>=4.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Constraints for [PACKAGES] are unused, as they aren't part of the resolution
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + typing-extensions==4.7.1
+    "###
+    ); // would yield typing-extensions==4.8.2 without constraint file
+}
+
+/// Install a package from a `requirements.txt` file, with an inline constraint, which points
+/// to a remote http server.
+#[test]
+fn install_constraints_inline_remote() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirementstxt = context.temp_dir.child("requirements.txt");
+    requirementstxt.write_str("typing-extensions>=4.0\n-c https://raw.githubusercontent.com/apache/airflow/constraints-2-6/constraints-3.11.txt")?;
+
+    let filters = std::iter::once((
+        r"Constraints for .* are unused",
+        "Constraints for [PACKAGES] are unused",
+    ))
+    .chain(context.filters())
+    .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_install()
+            .arg("-r")
+            .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
     ----- stderr -----
     Resolved 1 package in [TIME]
+    warning: Constraints for [PACKAGES] are unused, as they aren't part of the resolution
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
      + typing-extensions==4.7.1
@@ -2693,6 +3384,96 @@ fn install_constraints_extra() -> Result<()> {
     Ok(())
 }
 
+/// Warn about constraints and overrides for packages that aren't part of the resolution.
+#[test]
+fn install_unused_constraints_and_overrides() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("iniconfig<2\nflask==3.0.2\npython-dotenv==1.0.0")?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .arg("-c")
+        .arg("constraints.txt")
+        .arg("--override")
+        .arg("overrides.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Constraints for `flask`, `python-dotenv` are unused, as they aren't part of the resolution
+    warning: Override for `anyio` is unused, as it isn't part of the resolution
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject constraints and overrides for packages that aren't part of the resolution in
+/// `--strict` mode.
+#[test]
+fn install_unused_constraints_and_overrides_strict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("iniconfig<2\nflask==3.0.2")?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .arg("-c")
+        .arg("constraints.txt")
+        .arg("--override")
+        .arg("overrides.txt")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Constraint for `flask` is unused, as it isn't part of the resolution
+    Override for `anyio` is unused, as it isn't part of the resolution
+    "###
+    );
+
+    // Constraints and overrides that apply to the resolution are accepted.
+    constraints_txt.write_str("iniconfig<2")?;
+    overrides_txt.write_str("iniconfig>=1")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .arg("-c")
+        .arg("constraints.txt")
+        .arg("--override")
+        .arg("overrides.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn install_constraints_respects_offline_mode() {
     let context = TestContext::new("3.12");
@@ -3004,6 +3785,69 @@ fn config_settings() {
     assert!(!finder.exists());
 }
 
+/// Pass `--config-settings` to a single requirement in a `requirements.txt` file.
+#[test]
+fn config_settings_requirements_txt() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "-e setuptools-editable @ file://{}/scripts/packages/setuptools_editable -C editable_mode=compat",
+        context.workspace_root.simplified_display()
+    ))?;
+
+    // Install the editable package with the file-scoped `--editable_mode=compat`.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + setuptools-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/setuptools_editable)
+    "###
+    );
+
+    // The setting should reach the build backend, so the `finder.py` file should _not_ be present.
+    let finder = context
+        .site_packages()
+        .join("__editable___setuptools_editable_0_1_0_finder.py");
+    assert!(!finder.exists());
+
+    // The file-scoped setting should take precedence over the command-line setting.
+    context
+        .pip_uninstall()
+        .arg("setuptools-editable")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("-C")
+        .arg("editable_mode=strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + setuptools-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/setuptools_editable)
+    "###
+    );
+
+    assert!(!finder.exists());
+
+    Ok(())
+}
+
 /// Reinstall a duplicate package in a virtual environment.
 #[test]
 fn reinstall_duplicate() -> Result<()> {
@@ -3777,6 +4621,70 @@ requires-python = ">=3.11,<3.13"
     Ok(())
 }
 
+/// Skip an editable with a marker that doesn't match the current interpreter, without building it.
+#[test]
+fn editable_with_marker() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.create_dir_all()?;
+    let pyproject_toml = editable_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+[project]
+name = "example"
+version = "0.1.0"
+dependencies = []
+requires-python = ">=3.11"
+"#,
+    )?;
+
+    // The marker doesn't match, so the editable should be skipped.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        iniconfig
+        -e ./editable ; python_version < '3.12'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The marker matches, so the editable should be installed.
+    requirements_txt.write_str(indoc! {r"
+        iniconfig
+        -e ./editable ; python_version >= '3.12'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example==0.1.0 (from file://[TEMP_DIR]/editable)
+    "###
+    );
+
+    Ok(())
+}
+
 /// Raise an error when an editable's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_editable() -> Result<()> {
@@ -5291,32 +6199,121 @@ fn already_installed_multiple_versions() -> Result<()> {
     Ok(())
 }
 
-/// Install a package from a remote URL
+/// Install a source distribution from a direct URL, building it and resolving its dependencies
+/// from the index.
 #[test]
-#[cfg(feature = "git")]
-fn already_installed_remote_url() {
-    let context = TestContext::new("3.8");
+fn install_sdist_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let url = serve_file(
+        &context
+            .workspace_root
+            .join("scripts/links/extras-0.0.1.tar.gz"),
+    )?;
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"127\.0\.0\.1:\d+", "[SERVER]")])
+        .collect::<Vec<_>>();
 
-    // First, install from the remote URL
-    uv_snapshot!(context.filters(), context.pip_install().arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage"), @r###"
+    uv_snapshot!(filters, context.pip_install().arg(&url), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
-    "###);
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + extras==0.0.1 (from http://[SERVER]/extras-0.0.1.tar.gz)
+     + iniconfig==2.0.0
+    "###
+    );
 
-    context.assert_installed("uv_public_pypackage", "0.1.0");
+    context.assert_installed("extras", "0.0.1");
 
-    // Request installation again with a different URL, but the same _canonical_ URL. We should
-    // resolve the package (since we installed a specific commit, but are now requesting the default
-    // branch), but not reinstall the package.
-    uv_snapshot!(context.filters(), context.pip_install().arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git"), @r###"
-    success: true
+    Ok(())
+}
+
+/// Install a source distribution from a direct URL with a hash fragment, which should be verified
+/// even without `--require-hashes`.
+#[test]
+fn install_sdist_url_hash_fragment() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let url = serve_file(
+        &context
+            .workspace_root
+            .join("scripts/links/extras-0.0.1.tar.gz"),
+    )?;
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"127\.0\.0\.1:\d+", "[SERVER]")])
+        .collect::<Vec<_>>();
+
+    // The wrong hash should be rejected.
+    uv_snapshot!(filters, context.pip_install()
+        .arg(format!("{url}#sha256=0000000000000000000000000000000000000000000000000000000000000000")), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `extras @ http://[SERVER]/extras-0.0.1.tar.gz#sha256=0000000000000000000000000000000000000000000000000000000000000000`
+      ╰─▶ Hash mismatch for `extras @ http://[SERVER]/extras-0.0.1.tar.gz#sha256=0000000000000000000000000000000000000000000000000000000000000000`
+
+          Expected:
+            sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+          Computed:
+            sha256:8647b718f60d556e1cc02e895a15ab922a310fd45284b2cd683a25bafd570459
+    "###
+    );
+
+    // The correct hash should be accepted.
+    uv_snapshot!(filters, context.pip_install()
+        .arg(format!("{url}#sha256=8647b718f60d556e1cc02e895a15ab922a310fd45284b2cd683a25bafd570459")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + extras==0.0.1 (from http://[SERVER]/extras-0.0.1.tar.gz#sha256=8647b718f60d556e1cc02e895a15ab922a310fd45284b2cd683a25bafd570459)
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install a package from a remote URL
+#[test]
+#[cfg(feature = "git")]
+fn already_installed_remote_url() {
+    let context = TestContext::new("3.8");
+
+    // First, install from the remote URL
+    uv_snapshot!(context.filters(), context.pip_install().arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    "###);
+
+    context.assert_installed("uv_public_pypackage", "0.1.0");
+
+    // Request installation again with a different URL, but the same _canonical_ URL. We should
+    // resolve the package (since we installed a specific commit, but are now requesting the default
+    // branch), but not reinstall the package.
+    uv_snapshot!(context.filters(), context.pip_install().arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git"), @r###"
+    success: true
     exit_code: 0
     ----- stdout -----
 
@@ -5460,6 +6457,114 @@ fn find_links_no_binary() {
     );
 }
 
+/// Install using multiple `--find-links` directories that provide the same distribution, in which
+/// case the first location should take precedence.
+#[test]
+fn find_links_precedence() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Provide a valid wheel in the first directory, and an invalid wheel with the same filename in
+    // the second.
+    let first = context.temp_dir.child("first");
+    first.create_dir_all()?;
+    fs::copy(
+        context
+            .workspace_root
+            .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+        first.child("ok-1.0.0-py3-none-any.whl"),
+    )?;
+    let second = context.temp_dir.child("second");
+    second.create_dir_all()?;
+    second
+        .child("ok-1.0.0-py3-none-any.whl")
+        .write_str("not a wheel")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(first.path())
+        .arg("--find-links")
+        .arg(second.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "###
+    );
+
+    // Reversing the order should select the invalid wheel.
+    context
+        .pip_install()
+        .arg("ok==1.0.0")
+        .arg("--reinstall")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(second.path())
+        .arg("--find-links")
+        .arg(first.path())
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+/// Install using multiple `--find-links` pages that provide the same distribution with different
+/// hashes, which should warn and use the distribution from the first page.
+#[test]
+fn find_links_conflicting_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = Url::from_file_path(
+        context
+            .workspace_root
+            .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+    )
+    .unwrap();
+
+    let first = context.temp_dir.child("first.html");
+    first.write_str(&format!(
+        r#"<a href="{wheel}#sha256=79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f">ok-1.0.0-py3-none-any.whl</a>"#
+    ))?;
+    let second = context.temp_dir.child("second.html");
+    second.write_str(&format!(
+        r#"<a href="{wheel}#sha256=0000000000000000000000000000000000000000000000000000000000000000">ok-1.0.0-py3-none-any.whl</a>"#
+    ))?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"127\.0\.0\.1:\d+", "[SERVER]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(serve_file(first.path())?)
+        .arg("--find-links")
+        .arg(serve_file(second.path())?), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `ok-1.0.0-py3-none-any.whl` was found in multiple `--find-links` locations with different hashes; using the file from `http://[SERVER]/first.html` over `http://[SERVER]/second.html`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "###
+    );
+
+    Ok(())
+}
+
 /// Provide valid hashes for all dependencies with `--require-hashes`.
 #[test]
 fn require_hashes() -> Result<()> {
@@ -5535,6 +6640,129 @@ fn require_hashes_no_deps() -> Result<()> {
     Ok(())
 }
 
+/// Repeat a package across multiple requirements files with `--require-hashes`. The allowed
+/// hashes should be the union of the hashes from each file.
+#[test]
+fn require_hashes_multiple_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Provide the wheel hash in one file, and the source distribution hash (along with a
+    // duplicate of the wheel hash) in the other.
+    let requirements_a = context.temp_dir.child("requirements-a.txt");
+    requirements_a.write_str(indoc::indoc! {r"
+        anyio==4.0.0 \
+            --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "})?;
+
+    let requirements_b = context.temp_dir.child("requirements-b.txt");
+    requirements_b.write_str(indoc::indoc! {r"
+        anyio==4.0.0 \
+            --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a \
+            --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements-a.txt")
+        .arg("-r")
+        .arg("requirements-b.txt")
+        .arg("--no-deps")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0
+    "###
+    );
+
+    // Swap the order, such that the file with only the source distribution hash comes last. The
+    // wheel should still be accepted.
+    requirements_b.write_str(indoc::indoc! {r"
+        anyio==4.0.0 \
+            --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements-a.txt")
+        .arg("-r")
+        .arg("requirements-b.txt")
+        .arg("--no-deps")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ anyio==4.0.0
+    "###
+    );
+
+    // If either file omits the hashes, the requirement should be rejected.
+    requirements_b.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements-a.txt")
+        .arg("-r")
+        .arg("requirements-b.txt")
+        .arg("--no-deps")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: anyio==4.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Provide the hash of a source distribution with `--require-hashes`, for a package that's only
+/// available as a source distribution. The hash should be verified against the archive, after
+/// which the package should be built and installed.
+#[test]
+fn require_hashes_source_build() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Write to a requirements file.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "source-distribution==0.0.1 --hash=sha256:1f83ed7498336c7f2ab9b002cf22583d91115ebc624053dc4eb3a45694490106",
+    )?;
+
+    uv_snapshot!(context.pip_install()
+        .env_remove("UV_EXCLUDE_NEWER")
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
+    "###
+    );
+
+    Ok(())
+}
+
 /// Provide the wrong hash with `--require-hashes`.
 #[test]
 fn require_hashes_mismatch() -> Result<()> {
@@ -5583,62 +6811,140 @@ fn require_hashes_mismatch() -> Result<()> {
     Ok(())
 }
 
-/// Omit a transitive dependency in `--require-hashes`.
+/// Report a hash mismatch as JSON with `--error-format json`.
 #[test]
-fn require_hashes_missing_dependency() -> Result<()> {
+fn require_hashes_mismatch_error_format_json() -> Result<()> {
     let context = TestContext::new("3.12");
 
     // Write to a requirements file.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(
-        "werkzeug==3.0.0 --hash=sha256:cbb2600f7eabe51dbc0502f58be0b3e1b96b893b05695ea2b35b43d4de2d9962",
-    )?;
+    requirements_txt.write_str(indoc::indoc! {r"
+        anyio==4.0.0 \
+            --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "})?;
 
-    // Install without error when `--require-hashes` is omitted.
     uv_snapshot!(context.pip_install()
         .arg("-r")
         .arg("requirements.txt")
-        .arg("--require-hashes"), @r###"
+        .arg("--no-deps")
+        .arg("--require-hashes")
+        .arg("--error-format")
+        .arg("json"), @r###"
     success: false
     exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must be pinned upfront with `==`, but found: `markupsafe`
+    {"kind":"hash-mismatch","message":"Failed to prepare distributions","causes":["Failed to fetch wheel: anyio==4.0.0","Hash mismatch for `anyio==4.0.0`\n\nExpected:\n  sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f\n\nComputed:\n  sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f"],"context":{"distribution":"anyio==4.0.0"}}
     "###
     );
 
     Ok(())
 }
 
-/// We disallow `--require-hashes` for editables' dependencies.
+/// A download that doesn't match the expected hashes should never be persisted to the cache.
 #[test]
-fn require_hashes_editable() -> Result<()> {
+fn require_hashes_mismatch_not_cached() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    // Write to a requirements file.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(&indoc::formatdoc! {r"
-        -e file://{workspace_root}/scripts/packages/black_editable[d]
-        ",
-        workspace_root = context.workspace_root.simplified_display(),
-    })?;
+    requirements_txt.write_str(indoc::indoc! {r"
+        anyio==4.0.0 \
+            --hash=sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "})?;
 
-    // Install the editable packages.
-    uv_snapshot!(context.filters(), context.pip_install()
+    uv_snapshot!(context.pip_install()
         .arg("-r")
-        .arg(requirements_txt.path())
+        .arg("requirements.txt")
+        .arg("--no-deps")
         .arg("--require-hashes"), @r###"
     success: false
     exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: file://[WORKSPACE]/scripts/packages/black_editable[d]
-    "###
-    );
-
-    Ok(())
-}
+    Resolved 1 package in [TIME]
+    error: Failed to prepare distributions
+      Caused by: Failed to fetch wheel: anyio==4.0.0
+      Caused by: Hash mismatch for `anyio==4.0.0`
+
+    Expected:
+      sha256:afdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+
+    Computed:
+      sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "###
+    );
+
+    // Neither the archive nor any temporary files should be left in the cache.
+    let archives = context.cache_dir.child("archive-v0");
+    assert!(!archives.exists() || fs_err::read_dir(&archives)?.next().is_none());
+    let temporary = fs_err::read_dir(&context.cache_dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(".tmp"))
+        .count();
+    assert_eq!(temporary, 0);
+
+    Ok(())
+}
+
+/// Omit a transitive dependency in `--require-hashes`.
+#[test]
+fn require_hashes_missing_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Write to a requirements file.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "werkzeug==3.0.0 --hash=sha256:cbb2600f7eabe51dbc0502f58be0b3e1b96b893b05695ea2b35b43d4de2d9962",
+    )?;
+
+    // Install without error when `--require-hashes` is omitted.
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must be pinned upfront with `==`, but found: `markupsafe`
+    "###
+    );
+
+    Ok(())
+}
+
+/// We disallow `--require-hashes` for editables' dependencies.
+#[test]
+fn require_hashes_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        -e file://{workspace_root}/scripts/packages/black_editable[d]
+        ",
+        workspace_root = context.workspace_root.simplified_display(),
+    })?;
+
+    // Install the editable packages.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: file://[WORKSPACE]/scripts/packages/black_editable[d]
+    "###
+    );
+
+    Ok(())
+}
 
 /// If a hash is only included as a constraint, that's good enough for `--require-hashes`.
 #[test]
@@ -5793,6 +7099,79 @@ fn require_hashes_constraint() -> Result<()> {
     Ok(())
 }
 
+/// A hash on a constraint should be enforced for a transitive dependency, even if no requirement
+/// includes a hash for it.
+#[test]
+fn verify_hashes_constraint_transitive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0")?;
+
+    // Include the wrong hash for `idna` in the constraint file.
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(
+        "idna==3.6 --hash=sha256:a05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f",
+    )?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("-c")
+        .arg("constraints.txt")
+        .arg("--verify-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: Failed to prepare distributions
+      Caused by: Failed to fetch wheel: idna==3.6
+      Caused by: Hash mismatch for `idna==3.6`
+
+    Expected:
+      sha256:a05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+
+    Computed:
+      sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    "###
+    );
+
+    // Include the right hash for `idna` in the constraint file.
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(
+        "idna==3.6 --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f",
+    )?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("-c")
+        .arg("constraints.txt")
+        .arg("--verify-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
 /// We allow `--require-hashes` for unnamed URL dependencies.
 #[test]
 fn require_hashes_unnamed() -> Result<()> {
@@ -6809,6 +8188,69 @@ fn compatible_build_constraint() -> Result<()> {
     Ok(())
 }
 
+/// Record the build requirements of a source distribution with `--build-lock`, and reuse the
+/// recorded versions on subsequent builds.
+#[test]
+fn build_lock() -> Result<()> {
+    let context = TestContext::new("3.8");
+
+    uv_snapshot!(context.pip_install()
+        .arg("requests==1.2")
+        .arg("--build-lock")
+        .arg("build.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + requests==1.2.0
+    "###
+    );
+
+    // The resolved build requirements should be recorded.
+    let build_lock = context.temp_dir.child("build.lock");
+    build_lock.assert(indoc! {r#"
+        [requests]
+        setuptools = "69.2.0"
+    "#});
+
+    // Pin `setuptools` to an older version.
+    build_lock.write_str(indoc! {r#"
+        [requests]
+        setuptools = "68.0.0"
+    "#})?;
+
+    // Rebuilding `requests` should reuse the recorded version, rather than resolving the latest.
+    uv_snapshot!(context.pip_install()
+        .arg("requests==1.2")
+        .arg("--reinstall")
+        .arg("--no-cache")
+        .arg("--build-lock")
+        .arg("build.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ requests==1.2.0
+    "###
+    );
+
+    build_lock.assert(indoc! {r#"
+        [requests]
+        setuptools = "68.0.0"
+    "#});
+
+    Ok(())
+}
+
 #[test]
 fn install_build_isolation_package() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -7132,3 +8574,598 @@ fn sklearn() {
     "###
     );
 }
+
+/// Install a universal lock on different platforms, selecting the variant whose marker matches
+/// the target environment.
+#[test]
+fn install_universal_lock_platform_variants() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        markupsafe==2.1.3 ; sys_platform == 'linux'
+        markupsafe==2.1.5 ; sys_platform == 'win32'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--python-platform")
+        .arg("x86_64-unknown-linux-gnu")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + markupsafe==2.1.3
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + markupsafe==2.1.5
+    "###
+    );
+
+    Ok(())
+}
+
+/// Error when multiple variants in a universal lock match the target environment.
+#[test]
+fn install_universal_lock_conflicting_variants() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        markupsafe==2.1.3 ; sys_platform == 'linux'
+        markupsafe==2.1.5 ; python_version >= '3.12'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--python-platform")
+        .arg("x86_64-unknown-linux-gnu")
+        .arg("--dry-run"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because you require markupsafe==2.1.3 and markupsafe==2.1.5, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Error when a package requires itself in order to be built, rather than recursing indefinitely.
+#[test]
+fn install_cyclic_build_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a package that lists itself in `build-system.requires`, using a build backend
+    // that's outside the default (setuptools) fallback.
+    let package = context.temp_dir.child("project");
+    package.create_dir_all()?;
+    let url = Url::from_directory_path(package.path()).unwrap();
+
+    package.child("pyproject.toml").write_str(&format!(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling", "project @ {url}"]
+        build-backend = "hatchling.build"
+        "#,
+    ))?;
+    package.child("src/project/__init__.py").touch()?;
+
+    context
+        .pip_install()
+        .arg(package.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Cyclic build dependency detected: `project` requires itself to be built",
+        ));
+
+    Ok(())
+}
+
+/// Install the packages from one requirements file without their dependencies, while resolving
+/// the dependencies of the packages from another requirements file as usual.
+#[test]
+fn install_no_deps_requirement() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `anyio` depends on `idna` and `sniffio`, which should _not_ be installed.
+    let pinned_txt = context.temp_dir.child("pinned.txt");
+    pinned_txt.write_str("anyio==4.3.0")?;
+
+    // `flask` depends on five other packages, which should be installed.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("flask==3.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-deps-requirement")
+        .arg("pinned.txt")
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    Would download 8 packages
+    Would install 8 packages
+     + anyio==4.3.0
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.0
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    "###
+    );
+
+    // Unnamed requirements can't be installed without dependencies, since the package name isn't
+    // known upfront.
+    pinned_txt.write_str("https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-deps-requirement")
+        .arg("pinned.txt")
+        .arg("--dry-run"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unnamed requirements are not allowed without dependencies (found: `https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl`)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve the dependencies of a package that's provided without dependencies in one
+/// requirements file, if it's also required (with dependencies) by another.
+#[test]
+fn install_no_deps_requirement_mixed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pinned_txt = context.temp_dir.child("pinned.txt");
+    pinned_txt.write_str("anyio==4.3.0")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio")?;
+
+    // `anyio` is required with dependencies by `requirements.txt`, so `idna` and `sniffio` should
+    // be installed.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-deps-requirement")
+        .arg("pinned.txt")
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would download 3 packages
+    Would install 3 packages
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install the output of `pip freeze`, including an editable Git install, which is installed as a
+/// regular requirement.
+#[test]
+#[cfg(feature = "git")]
+fn install_freeze_requirement() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let freeze_txt = context.temp_dir.child("freeze.txt");
+    freeze_txt.write_str(indoc! {r"
+        ## !! Could not determine repository location
+        iniconfig==2.0.0
+        -e git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389#egg=uv_public_pypackage
+    "})?;
+
+    // As a regular `requirements.txt` file, the editable Git requirement is rejected.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("freeze.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unsupported editable requirement in `freeze.txt`
+      Caused by: Editable must refer to a local directory, not a Git URL: `git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389#egg=uv_public_pypackage`
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--freeze-requirement")
+        .arg("freeze.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389)
+    "###
+    );
+
+    context.assert_installed("uv_public_pypackage", "0.1.0");
+
+    Ok(())
+}
+
+/// Install into a virtual environment that doesn't exist yet, creating it in the process.
+#[test]
+fn install_venv_create() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--venv")
+        .arg("fresh")
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Creating virtual environment at: fresh
+    Using Python 3.12.[X] environment at fresh
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The package should be importable from the new virtual environment.
+    let fresh = context.temp_dir.child("fresh");
+    fresh.child("pyvenv.cfg").assert(predicate::path::is_file());
+    Command::new(venv_to_interpreter(fresh.path()))
+        .arg("-c")
+        .arg("import iniconfig")
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    // If the virtual environment already exists, install into it.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--venv")
+        .arg("fresh")
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at fresh
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    // A dry run must not create the virtual environment.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--venv")
+        .arg("dry")
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--dry-run"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--venv <VENV>' cannot be used with '--dry-run'
+
+    Usage: uv pip install --cache-dir [CACHE_DIR] --venv <VENV> --exclude-newer <EXCLUDE_NEWER> <PACKAGE|--requirement <REQUIREMENT>|--no-deps-requirement <NO_DEPS_REQUIREMENT>|--freeze-requirement <FREEZE_REQUIREMENT>|--editable <EDITABLE>>
+
+    For more information, try '--help'.
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("dry")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Install an editable requirement that's already installed as a legacy editable (i.e., via an
+/// `.egg-link` file). The existing installation should be retained, rather than reinstalled.
+#[test]
+fn install_legacy_editable_satisfied() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let site_packages = context.site_packages();
+
+    let target = context.temp_dir.child("zstandard_project");
+    target.child("zstd").create_dir_all()?;
+    target.child("zstd").child("__init__.py").write_str("")?;
+
+    target.child("zstandard.egg-info").create_dir_all()?;
+    target
+        .child("zstandard.egg-info")
+        .child("PKG-INFO")
+        .write_str(
+            "Metadata-Version: 2.1
+Name: zstandard
+Version: 0.22.0
+",
+        )?;
+
+    fs::write(
+        site_packages.join("zstandard.egg-link"),
+        target.path().to_str().unwrap(),
+    )?;
+    fs::write(
+        site_packages.join("easy-install.pth"),
+        format!("{}\n", target.path().to_str().unwrap()),
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg("./zstandard_project"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    // The `.egg-link` file should be retained.
+    assert!(site_packages.join("zstandard.egg-link").exists());
+
+    Ok(())
+}
+
+/// Report the time spent in each phase of an installation with `--timings`.
+#[test]
+fn install_timings() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--timings"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Timings:
+      Parsing requirements: [TIME]
+      Resolving: [TIME]
+        Fetching metadata: [TIME]
+        Solving: [TIME]
+      Downloading and building: [TIME]
+      Installing: [TIME]
+      Cache: 0 hits, 1 miss
+    "###
+    );
+
+    context.pip_uninstall().arg("iniconfig").assert().success();
+
+    // Reinstalling should link the distribution from the cache.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--timings"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Timings:
+      Parsing requirements: [TIME]
+      Resolving: [TIME]
+        Fetching metadata: [TIME]
+        Solving: [TIME]
+      Installing: [TIME]
+      Cache: 1 hit, 0 misses
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install into a `--target` directory with `--no-dist-info`, which should omit the `.dist-info`
+/// directory while retaining the importable modules.
+#[test]
+fn install_target_no_dist_info() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--target")
+        .arg("target")
+        .arg("--no-dist-info"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--no-dist-info` omits package metadata; the installed packages can't be detected, upgraded, or uninstalled
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Ensure that the package is present in the target directory, but the metadata is not.
+    let target = context.temp_dir.child("target");
+    assert!(target.child("iniconfig").child("__init__.py").is_file());
+    assert!(!target.child("iniconfig-2.0.0.dist-info").exists());
+
+    // `--no-dist-info` is not allowed when installing into an environment.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-dist-info"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      <--target <TARGET>|--prefix <PREFIX>>
+
+    Usage: uv pip install --cache-dir [CACHE_DIR] --no-dist-info --exclude-newer <EXCLUDE_NEWER> <PACKAGE|--requirement <REQUIREMENT>|--no-deps-requirement <NO_DEPS_REQUIREMENT>|--freeze-requirement <FREEZE_REQUIREMENT>|--editable <EDITABLE>> <--target <TARGET>|--prefix <PREFIX>>
+
+    For more information, try '--help'.
+    "###
+    );
+}
+
+/// Install with `--verify-install`, which should detect a file that was corrupted after being
+/// extracted into the cache.
+#[test]
+fn install_verify_install() -> Result<()> {
+    use std::io::Write;
+
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"sha256=[\w-]+", "sha256=[HASH]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--verify-install"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Uninstall the package, then corrupt the extracted wheel in the cache.
+    context.pip_uninstall().arg("iniconfig").assert().success();
+    let module = fs::read_dir(context.cache_dir.child("archive-v0"))?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path().join("iniconfig").join("__init__.py"))
+        .find(|path| path.is_file())
+        .expect("the extracted wheel should be in the cache");
+    fs::OpenOptions::new()
+        .append(true)
+        .open(&module)?
+        .write_all(b"raise SystemExit\n")?;
+
+    // Reinstalling from the cache should fail, since the module no longer matches the `RECORD`.
+    uv_snapshot!(filters, context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--verify-install"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Failed to install: iniconfig-2.0.0-py3-none-any.whl (iniconfig==2.0.0)
+      Caused by: Installed file doesn't match the hash in RECORD: [SITE_PACKAGES]/iniconfig/__init__.py (expected `sha256=[HASH]`, computed `sha256=[HASH]`)
+    "###
+    );
+
+    Ok(())
+}
+
+/// When installing into a virtual environment created with `--system-site-packages`, packages
+/// installed in the base interpreter (like `pip`) should count as installed, unless
+/// `--ignore-installed` is provided.
+#[test]
+fn install_system_site_packages() {
+    let context = TestContext::new("3.12");
+
+    // Re-create the virtual environment with access to the base interpreter's `site-packages`.
+    context
+        .venv()
+        .arg("--system-site-packages")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("pip"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("pip")
+        .arg("--ignore-installed"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + pip==24.0
+    "###
+    );
+}
@@ -186,6 +186,20 @@ fn list_editable_only() {
     "###
     );
 
+    // `--editable-only` is an alias for `--editable`.
+    uv_snapshot!(filters, context.pip_list()
+        .arg("--editable-only"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Editable project location
+    [UNDERLINE]
+    poetry-editable 0.1.0 [WORKSPACE]/scripts/packages/poetry_editable
+
+    ----- stderr -----
+    "###
+    );
+
     uv_snapshot!(filters, context.pip_list()
         .arg("--exclude-editable"), @r###"
     success: true
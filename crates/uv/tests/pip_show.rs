@@ -308,7 +308,8 @@ fn show_found_one_out_of_two_quiet() -> Result<()> {
 
     context.assert_command("import markupsafe").success();
 
-    // Flask isn't installed, but markupsafe is, so the command should succeed.
+    // Flask isn't installed, but markupsafe is, so the command should succeed. Warnings are still
+    // shown under `--quiet`.
     uv_snapshot!(context.pip_show()
         .arg("markupsafe")
         .arg("flask")
@@ -317,6 +318,20 @@ fn show_found_one_out_of_two_quiet() -> Result<()> {
     exit_code: 0
     ----- stdout -----
 
+    ----- stderr -----
+    warning: Package(s) not found for: flask
+    "###
+    );
+
+    // Warnings are suppressed under `-qq`.
+    uv_snapshot!(context.pip_show()
+        .arg("markupsafe")
+        .arg("flask")
+        .arg("-qq"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
     ----- stderr -----
     "###
     );
@@ -363,6 +378,18 @@ fn show_empty_quiet() -> Result<()> {
     exit_code: 1
     ----- stdout -----
 
+    ----- stderr -----
+    warning: Package(s) not found for: flask
+    "###
+    );
+
+    uv_snapshot!(context.pip_show()
+        .arg("flask")
+        .arg("-qq"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
     ----- stderr -----
     "###
     );
@@ -1067,6 +1067,81 @@ fn warn_on_yanked_dry_run() -> Result<()> {
     Ok(())
 }
 
+/// Check whether the environment is in sync with the requirements, via `--check`.
+#[test]
+fn check_in_sync() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.3.0
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // The environment is in sync, so the check should pass.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited 3 packages in [TIME]
+    Would make no changes
+    "###
+    );
+
+    // Change the requirements, such that the environment drifts.
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.2.0
+        idna==3.6
+        iniconfig==2.0.0
+    "})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would download 2 packages
+    Would uninstall 2 packages
+    Would install 2 packages
+     - anyio==4.3.0
+     + anyio==4.2.0
+     + iniconfig==2.0.0
+     - sniffio==1.3.1
+    "###
+    );
+
+    // The environment should be left untouched.
+    context.assert_installed("sniffio", "1.3.1");
+
+    Ok(())
+}
+
 /// Resolve a local wheel.
 #[test]
 fn install_local_wheel() -> Result<()> {
@@ -2422,7 +2497,7 @@ fn sync_editable_and_registry() -> Result<()> {
 
     // Install the registry-based version of Black.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
+    requirements_txt.write_str(indoc! {r"
         black==24.1.0
         "
     })?;
@@ -2450,7 +2525,7 @@ fn sync_editable_and_registry() -> Result<()> {
     // Install the editable version of Black. This should remove the registry-based version.
     // Use the `file:` syntax for extra coverage.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
+    requirements_txt.write_str(indoc! {r"
         -e file:./black_editable
         "
     })?;
@@ -2474,7 +2549,7 @@ fn sync_editable_and_registry() -> Result<()> {
     // Re-install the registry-based version of Black. This should be a no-op, since we have a
     // version of Black installed (the editable version) that satisfies the requirements.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
+    requirements_txt.write_str(indoc! {r"
         black
         "
     })?;
@@ -2493,7 +2568,7 @@ fn sync_editable_and_registry() -> Result<()> {
 
     // Re-install Black at a specific version. This should replace the editable version.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
+    requirements_txt.write_str(indoc! {r"
         black==23.10.0
         "
     })?;
@@ -2537,7 +2612,7 @@ fn sync_editable_and_local() -> Result<()> {
 
     // Install the editable version of Black.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
+    requirements_txt.write_str(indoc! {r"
         -e file:./black_editable
         "
     })?;
@@ -2558,7 +2633,7 @@ fn sync_editable_and_local() -> Result<()> {
 
     // Install the non-editable version of Black. This should replace the editable version.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
+    requirements_txt.write_str(indoc! {r"
         black @ file:./black_editable
         "
     })?;
@@ -2580,7 +2655,7 @@ fn sync_editable_and_local() -> Result<()> {
 
     // Reinstall the editable version of Black. This should replace the non-editable version.
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(indoc::indoc! {r"
+    requirements_txt.write_str(indoc! {r"
         -e file:./black_editable
         "
     })?;
@@ -3015,6 +3090,7 @@ fn irrelevant_constraint() -> Result<()> {
 
     ----- stderr -----
     Resolved 1 package in [TIME]
+    warning: Constraint for `black` is unused, as it isn't part of the resolution
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
      + anyio==3.7.0
@@ -3298,6 +3374,87 @@ fn compile() -> Result<()> {
     Ok(())
 }
 
+/// Compile bytecode with its own concurrency limit, separate from that of installation, and
+/// warn about (but tolerate) files that fail to compile.
+#[test]
+fn compile_concurrency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    // Add a file that can't be compiled.
+    fs::write(context.site_packages().join("broken.py"), "def broken(:\n")?;
+
+    let output = context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--compile-bytecode-concurrency")
+        .arg("1")
+        .arg("--verbose")
+        .env("UV_CONCURRENT_INSTALLS", "4")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Starting 1 bytecode compilation workers"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("warning: Failed to compile `")
+            && stderr.contains("broken.py` to bytecode"),
+        "{stderr}"
+    );
+
+    assert!(context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__")
+        .join("__init__.cpython-312.pyc")
+        .exists());
+
+    Ok(())
+}
+
+/// Skip bytecode compilation when targeting a different Python version than that of the
+/// interpreter, since the compiled files would be unusable by the target.
+#[test]
+fn compile_python_version_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--target")
+        .arg("target")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--compile"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Skipping bytecode compilation, since the target Python version (3.11) differs from that of the interpreter (3.12); use `--python` to select a matching interpreter instead
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Ensure that the package was installed, but not compiled.
+    let target = context.temp_dir.child("target");
+    assert!(target.child("iniconfig").child("__init__.py").is_file());
+    assert!(!target.child("iniconfig").child("__pycache__").exists());
+
+    Ok(())
+}
+
 /// Raise an error when an editable's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_editable() -> Result<()> {
@@ -3473,6 +3630,78 @@ fn require_hashes_missing_hash() -> Result<()> {
     Ok(())
 }
 
+/// Include a hash for some, but not all, requirements, without `--require-hashes`.
+///
+/// As in pip, a single hash enables hash-checking mode for every requirement.
+#[test]
+fn require_hashes_inferred() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
+
+    // Without any hashes, hash-checking is disabled.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would download 3 packages
+    Would install 3 packages
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Adding a hash to one requirement requires a hash for every requirement.
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: idna==3.6, sniffio==1.3.1
+    "###
+    );
+
+    // Unless hash-checking is explicitly disabled.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-require-hashes")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would download 3 packages
+    Would install 3 packages
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
 /// Omit the version with `--require-hashes`.
 #[test]
 fn require_hashes_missing_version() -> Result<()> {
@@ -3483,9 +3712,10 @@ fn require_hashes_missing_version() -> Result<()> {
         "anyio --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
     )?;
 
-    // Install without error when `--require-hashes` is omitted.
+    // Install without error when hash-checking is disabled.
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+        .arg("requirements.txt")
+        .arg("--no-require-hashes"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3524,9 +3754,10 @@ fn require_hashes_invalid_operator() -> Result<()> {
         "anyio>4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
     )?;
 
-    // Install without error when `--require-hashes` is omitted.
+    // Install without error when hash-checking is disabled.
     uv_snapshot!(context.pip_sync()
-        .arg("requirements.txt"), @r###"
+        .arg("requirements.txt")
+        .arg("--no-require-hashes"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -3684,6 +3915,74 @@ fn require_hashes_source_only_binary() -> Result<()> {
     Ok(())
 }
 
+/// Build a single package from source via a `# uv: no-binary` pragma. Only the tagged package is
+/// built from source, as the hashes (which match only the source distribution of `anyio`, and only
+/// the wheel of `idna`) demonstrate.
+#[test]
+fn no_binary_pragma() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a  # uv: no-binary
+        idna==3.6 --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    "})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+    "###
+    );
+
+    Ok(())
+}
+
+/// A `# uv: no-binary` pragma is overridden by `--only-binary` on the command line.
+#[test]
+fn no_binary_pragma_only_binary() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a  # uv: no-binary",
+    )?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--only-binary")
+        .arg("anyio")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Failed to prepare distributions
+      Caused by: Failed to fetch wheel: anyio==4.0.0
+      Caused by: Hash mismatch for `anyio==4.0.0`
+
+    Expected:
+      sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+
+    Computed:
+      sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f
+    "###
+    );
+
+    Ok(())
+}
+
 /// Include the correct hash algorithm, but the wrong digest.
 #[test]
 fn require_hashes_wrong_digest() -> Result<()> {
@@ -5386,6 +5685,65 @@ fn target_no_build_isolation() -> Result<()> {
     Ok(())
 }
 
+/// Sync to a `--target` directory with two packages that require building from source, with
+/// `--no-build-isolation-package` disabling isolation for only one of them.
+#[test]
+fn target_no_build_isolation_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Install `flit_core` into the current environment.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flit_core")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + flit-core==3.9.0
+    "###);
+
+    // Install `wheel` (built with `flit_core` from the current environment) and `iniconfig` (built
+    // with `hatchling` in an isolated environment) to the target directory.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("wheel\niniconfig")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.in")
+        .arg("--no-build-isolation-package")
+        .arg("wheel")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--target")
+        .arg("target"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + wheel==0.43.0
+    "###);
+
+    // Ensure that the build requirements are not present in the target directory.
+    assert!(!context.temp_dir.child("target").child("flit_core").is_dir());
+    assert!(!context.temp_dir.child("target").child("hatchling").is_dir());
+
+    // Ensure that both packages are present in the target directory.
+    assert!(context.temp_dir.child("target").child("wheel").is_dir());
+    assert!(context.temp_dir.child("target").child("iniconfig").is_dir());
+
+    Ok(())
+}
+
 /// Sync to a `--prefix` directory.
 #[test]
 fn prefix() -> Result<()> {
@@ -5609,3 +5967,68 @@ fn sync_seed() -> Result<()> {
 
     Ok(())
 }
+
+/// With `--strict`, reject `pyproject.toml` dependencies that contradict the
+/// `tool.uv.constraint-dependencies` before resolving.
+#[test]
+fn sync_constraints_from_pyproject_strict() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+    name = "example"
+    version = "0.0.0"
+    dependencies = [
+      "anyio>=4"
+    ]
+
+    [tool.uv]
+    constraint-dependencies = [
+      "anyio<4"
+    ]
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+            .arg("pyproject.toml")
+            .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The dependency `anyio>=4` in `pyproject.toml` contradicts the constraint `anyio<4` in `tool.uv.constraint-dependencies`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject constraints for packages that aren't part of the resolution in `--strict` mode.
+#[test]
+fn sync_unused_constraints_strict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("iniconfig<2\nflask==3.0.2")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("-c")
+        .arg("constraints.txt")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Constraint for `flask` is unused, as it isn't part of the resolution
+    "###
+    );
+
+    Ok(())
+}
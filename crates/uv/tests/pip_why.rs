@@ -0,0 +1,131 @@
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::fixture::PathChild;
+
+use common::uv_snapshot;
+
+use crate::common::TestContext;
+
+mod common;
+
+#[test]
+fn not_installed() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_why().arg("flask"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Package not found: flask
+    "###
+    );
+}
+
+#[test]
+fn shortest_paths() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("flask==3.0.2").unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Prepared 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    "###
+    );
+
+    // `markupsafe` is required by both `jinja2` and `werkzeug`, each of which is required by
+    // `flask`.
+    uv_snapshot!(context.filters(), context.pip_why().arg("markupsafe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    flask v3.0.2 -> jinja2 v3.1.3 -> markupsafe v2.1.5
+    flask v3.0.2 -> werkzeug v3.0.1 -> markupsafe v2.1.5
+
+    ----- stderr -----
+    "###
+    );
+
+    // `click` is required directly by `flask`.
+    uv_snapshot!(context.filters(), context.pip_why().arg("click"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    flask v3.0.2 -> click v8.1.7
+
+    ----- stderr -----
+    "###
+    );
+
+    // `flask` isn't required by any other package.
+    uv_snapshot!(context.filters(), context.pip_why().arg("flask"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    flask v3.0.2 is not required by any other installed package
+
+    ----- stderr -----
+    "###
+    );
+}
+
+#[test]
+fn direct_and_transitive() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("requests==2.31.0\nurllib3==2.2.1\n")
+        .unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // Although `urllib3` was requested directly, it is also required by `requests`.
+    uv_snapshot!(context.filters(), context.pip_why().arg("urllib3"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0 -> urllib3 v2.2.1
+
+    ----- stderr -----
+    "###
+    );
+}
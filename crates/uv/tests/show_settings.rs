@@ -15,7 +15,8 @@ fn add_shared_args(mut command: Command) -> Command {
         .env("UV_LINK_MODE", "clone")
         .env("UV_CONCURRENT_DOWNLOADS", "50")
         .env("UV_CONCURRENT_BUILDS", "16")
-        .env("UV_CONCURRENT_INSTALLS", "8");
+        .env("UV_CONCURRENT_INSTALLS", "8")
+        .env("UV_MAX_OPEN_FILES", "1024");
     command
 }
 
@@ -56,6 +57,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -74,14 +76,43 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -134,6 +165,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -170,8 +202,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -198,6 +232,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -216,14 +251,43 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -276,6 +340,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -312,8 +377,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -341,6 +408,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -359,14 +427,43 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -419,6 +516,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -455,8 +553,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -516,6 +616,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -534,14 +635,43 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -594,6 +724,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -630,8 +761,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -660,6 +793,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -678,14 +812,43 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -715,6 +878,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -751,8 +915,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -790,6 +956,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -808,14 +975,43 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -868,6 +1064,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -904,8 +1101,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -957,6 +1156,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -975,14 +1175,43 @@ fn resolve_index_url() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1058,6 +1287,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1094,8 +1324,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -1124,6 +1356,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -1142,14 +1375,43 @@ fn resolve_index_url() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1247,6 +1509,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1283,8 +1546,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -1336,6 +1601,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -1354,14 +1620,43 @@ fn resolve_find_links() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1414,6 +1709,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1450,8 +1746,10 @@ fn resolve_find_links() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -1502,6 +1800,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -1520,14 +1819,43 @@ fn resolve_top_level() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1557,6 +1885,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1593,8 +1922,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -1638,6 +1969,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -1656,14 +1988,43 @@ fn resolve_top_level() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1738,6 +2099,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1774,8 +2136,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -1802,6 +2166,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -1820,14 +2185,43 @@ fn resolve_top_level() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1902,6 +2296,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1938,8 +2333,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -1990,6 +2387,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -2008,14 +2406,43 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2045,6 +2472,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2081,8 +2509,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -2116,6 +2546,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -2134,14 +2565,43 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2171,6 +2631,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2207,8 +2668,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -2242,6 +2705,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -2260,14 +2724,43 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2297,6 +2790,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2333,8 +2827,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -2370,6 +2866,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -2388,14 +2885,43 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2425,6 +2951,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2461,8 +2988,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -2517,6 +3046,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -2613,6 +3143,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
         },
         force: false,
@@ -2674,6 +3205,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -2692,14 +3224,43 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2729,6 +3290,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2765,8 +3327,10 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -2828,6 +3392,7 @@ fn resolve_both() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -2846,14 +3411,43 @@ fn resolve_both() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2906,6 +3500,7 @@ fn resolve_both() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2942,8 +3537,10 @@ fn resolve_both() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -2997,6 +3594,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -3015,14 +3613,43 @@ fn resolve_config_file() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3075,6 +3702,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3111,8 +3739,10 @@ fn resolve_config_file() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -3150,7 +3780,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `publish-url`, `trusted-publishing`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `environments`, `workspace`, `sources`, `dev-dependencies`, `managed`, `package`
+    unknown field `project`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `max-open-files`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `publish-url`, `trusted-publishing`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `environments`, `workspace`, `sources`, `dev-dependencies`, `default-groups`, `managed`, `package`
 
     "###
     );
@@ -3241,6 +3871,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -3259,14 +3890,43 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3296,6 +3956,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3332,8 +3993,10 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -3370,6 +4033,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -3388,14 +4052,43 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3425,6 +4118,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3461,8 +4155,10 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },
@@ -3507,6 +4203,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            open_files: 1024,
         },
         connectivity: Online,
         show_settings: true,
@@ -3525,14 +4222,43 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         src_file: [
             "requirements.in",
         ],
+        tox_env: None,
+        max_include_depth: 50,
         constraint: [],
         override: [],
+        override_add: false,
+        pin_file: [],
         build_constraint: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        python_implementation: None,
+        markers: [],
+        format: None,
+        force: false,
+        append: false,
+        absolute_paths: false,
+        hash_algorithm: Sha256,
+        line_ending: Lf,
+        allow_yanked: None,
+        allowed_packages: None,
+        denied: Denylist {
+            packages: {},
+            versions: {},
+        },
+        resolution_packages: [],
+        strict_extras: false,
+        upgrade_strategy: Eager,
+        resolution_budget: ResolutionBudget {
+            timeout: None,
+            max_steps: None,
+        },
+        cache_resolution: false,
+        index_auth: [],
+        index_auth_file: None,
+        index_mirror: [],
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3573,6 +4299,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                prefer_sdist: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3609,8 +4336,10 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            compile_bytecode_concurrency: None,
             sources: Enabled,
             hash_checking: None,
+            infer_require_hashes: true,
             upgrade: None,
             reinstall: None,
         },